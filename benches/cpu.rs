@@ -3,7 +3,7 @@ use criterion::{Bencher, Criterion, criterion_group, criterion_main};
 
 use iced::border;
 use iced::mouse;
-use iced::widget::{canvas, center_y, column, container, row, scrollable, space, text};
+use iced::widget::{canvas, center_y, column, container, memo, row, scrollable, space, text};
 use iced::{Center, Color, Element, Fill, Length, Never, Point, Rectangle, Size, Theme};
 use iced_renderer::Renderer;
 use iced_renderer::core::renderer::{self, Headless as _};
@@ -31,6 +31,12 @@ pub fn benchmark(c: &mut Criterion) {
         })
         .bench_function("cpu — application", |b| {
             draw(b, &mut renderer, application());
+        })
+        .bench_function("cpu — rebuild", |b| {
+            rebuild(b, &mut renderer, || list(false));
+        })
+        .bench_function("cpu — rebuild (memo)", |b| {
+            rebuild(b, &mut renderer, || list(true));
         });
 }
 
@@ -56,6 +62,33 @@ fn draw(bencher: &mut Bencher<'_>, renderer: &mut Renderer, view: Element<'stati
     });
 }
 
+fn rebuild(
+    bencher: &mut Bencher<'_>,
+    renderer: &mut Renderer,
+    view: impl Fn() -> Element<'static, Never>,
+) {
+    let mut cache = Some(user_interface::Cache::new());
+
+    bencher.iter(|| {
+        let ui = UserInterface::build(view(), VIEWPORT, cache.take().unwrap(), renderer);
+
+        cache = Some(ui.into_cache());
+    });
+}
+
+fn list(memoized: bool) -> Element<'static, Never> {
+    const ITEMS: usize = 10_000;
+
+    let items = column((0..ITEMS).map(|i| text!("Item {i}").into()));
+
+    scrollable(if memoized {
+        memo(ITEMS, items).into()
+    } else {
+        Element::from(items)
+    })
+    .into()
+}
+
 fn ipsum() -> Element<'static, Never> {
     text(include_str!("ipsum.txt"))
         .ellipsis(text::Ellipsis::End)
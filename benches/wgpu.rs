@@ -189,6 +189,7 @@ fn scene<'a, Message: 'a>(n: usize) -> Element<'a, Message, Theme, Renderer> {
                         shaping: text::Shaping::Basic,
                         wrapping: text::Wrapping::default(),
                         ellipsis: text::Ellipsis::default(),
+                        effects: text::Effects::NONE,
                         max_width: f32::INFINITY,
                     });
                 }
@@ -11,6 +11,31 @@ pub enum Alignment {
 
     /// Align at the end of the axis.
     End,
+
+    /// Align the first baselines of the contents.
+    ///
+    /// Contents without a baseline are aligned by their bottom edge.
+    ///
+    /// It only applies to the vertical axis of a container that lays
+    /// out its contents horizontally; like a `row`. Otherwise, it behaves
+    /// like [`Alignment::Start`].
+    Baseline,
+
+    /// Align the last baselines of the contents.
+    ///
+    /// Contents without a baseline are aligned by their bottom edge.
+    ///
+    /// It only applies to the vertical axis of a container that lays
+    /// out its contents horizontally; like a `row`. Otherwise, it behaves
+    /// like [`Alignment::Start`].
+    LastBaseline,
+}
+
+impl Alignment {
+    /// Returns true if the [`Alignment`] aligns baselines.
+    pub fn is_baseline(self) -> bool {
+        matches!(self, Self::Baseline | Self::LastBaseline)
+    }
 }
 
 impl From<Horizontal> for Alignment {
@@ -49,7 +74,7 @@ pub enum Horizontal {
 impl From<Alignment> for Horizontal {
     fn from(alignment: Alignment) -> Self {
         match alignment {
-            Alignment::Start => Self::Left,
+            Alignment::Start | Alignment::Baseline | Alignment::LastBaseline => Self::Left,
             Alignment::Center => Self::Center,
             Alignment::End => Self::Right,
         }
@@ -72,9 +97,88 @@ pub enum Vertical {
 impl From<Alignment> for Vertical {
     fn from(alignment: Alignment) -> Self {
         match alignment {
-            Alignment::Start => Self::Top,
+            Alignment::Start | Alignment::Baseline | Alignment::LastBaseline => Self::Top,
             Alignment::Center => Self::Center,
             Alignment::End => Self::Bottom,
         }
     }
 }
+
+/// The distribution of the free space of a container among its contents
+/// along an axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Justify {
+    /// Pack the contents at the start of the axis.
+    #[default]
+    Start,
+
+    /// Pack the contents around the center of the axis.
+    Center,
+
+    /// Pack the contents at the end of the axis.
+    End,
+
+    /// Distribute the free space evenly between the contents; with no space
+    /// before the first or after the last.
+    SpaceBetween,
+
+    /// Distribute the free space evenly around the contents; with half the
+    /// space before the first and after the last.
+    SpaceAround,
+
+    /// Distribute the free space evenly between the contents, including
+    /// before the first and after the last.
+    SpaceEvenly,
+}
+
+impl Justify {
+    /// Returns the offset of the first of the given amount of contents and
+    /// the extra space between each of them, given some free space.
+    pub fn distribute(self, free: f32, amount: usize) -> (f32, f32) {
+        if free <= 0.0 || amount == 0 {
+            return (0.0, 0.0);
+        }
+
+        let amount = amount as f32;
+
+        match self {
+            Self::Start => (0.0, 0.0),
+            Self::Center => (free / 2.0, 0.0),
+            Self::End => (free, 0.0),
+            Self::SpaceBetween if amount > 1.0 => (0.0, free / (amount - 1.0)),
+            Self::SpaceBetween => (0.0, 0.0),
+            Self::SpaceAround => (free / amount / 2.0, free / amount),
+            Self::SpaceEvenly => (free / (amount + 1.0), free / (amount + 1.0)),
+        }
+    }
+}
+
+impl From<Horizontal> for Justify {
+    fn from(horizontal: Horizontal) -> Self {
+        match horizontal {
+            Horizontal::Left => Self::Start,
+            Horizontal::Center => Self::Center,
+            Horizontal::Right => Self::End,
+        }
+    }
+}
+
+impl From<Vertical> for Justify {
+    fn from(vertical: Vertical) -> Self {
+        match vertical {
+            Vertical::Top => Self::Start,
+            Vertical::Center => Self::Center,
+            Vertical::Bottom => Self::End,
+        }
+    }
+}
+
+impl From<Alignment> for Justify {
+    fn from(alignment: Alignment) -> Self {
+        match alignment {
+            Alignment::Start | Alignment::Baseline | Alignment::LastBaseline => Self::Start,
+            Alignment::Center => Self::Center,
+            Alignment::End => Self::End,
+        }
+    }
+}
@@ -291,6 +291,10 @@ where
         self.widget.size()
     }
 
+    fn flex(&self) -> Option<layout::flex::Item> {
+        self.widget.flex()
+    }
+
     fn layout(
         &mut self,
         tree: &mut Tree,
@@ -401,6 +405,10 @@ where
         self.element.widget.size()
     }
 
+    fn flex(&self) -> Option<layout::flex::Item> {
+        self.element.widget.flex()
+    }
+
     fn tag(&self) -> tree::Tag {
         self.element.widget.tag()
     }
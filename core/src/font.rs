@@ -1,5 +1,6 @@
 //! Load and use fonts.
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
 
 /// A font.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -12,6 +13,10 @@ pub struct Font {
     pub stretch: Stretch,
     /// The [`Style`] of the [`Font`].
     pub style: Style,
+    /// The OpenType [`Feature`]s of the [`Font`].
+    pub features: &'static [Feature],
+    /// The [`Variation`]s of the axes of the [`Font`], if variable.
+    pub variations: &'static [Variation],
 }
 
 impl Font {
@@ -21,6 +26,8 @@ impl Font {
         weight: Weight::Normal,
         stretch: Stretch::Normal,
         style: Style::Normal,
+        features: &[],
+        variations: &[],
     };
 
     /// A monospaced font with normal [`Weight`].
@@ -59,6 +66,22 @@ impl Font {
     pub const fn style(self, style: Style) -> Self {
         Self { style, ..self }
     }
+
+    /// Sets the OpenType [`Feature`]s of the [`Font`].
+    ///
+    /// Use [`Feature::list`] to obtain the list for features only known
+    /// at runtime.
+    pub const fn features(self, features: &'static [Feature]) -> Self {
+        Self { features, ..self }
+    }
+
+    /// Sets the [`Variation`]s of the axes of the [`Font`].
+    ///
+    /// Use [`Variation::list`] to obtain the list for variations only known
+    /// at runtime.
+    pub const fn variations(self, variations: &'static [Variation]) -> Self {
+        Self { variations, ..self }
+    }
 }
 
 impl From<&'static str> for Font {
@@ -116,22 +139,28 @@ impl Family {
     ///
     /// The name is interned in a global cache and never freed.
     pub fn name(name: &str) -> Self {
-        use rustc_hash::FxHashSet;
-        use std::sync::{LazyLock, Mutex};
-
-        static NAMES: LazyLock<Mutex<FxHashSet<&'static str>>> = LazyLock::new(Mutex::default);
+        Self::Name(intern_name(name))
+    }
+}
 
-        let mut names = NAMES.lock().expect("lock font name cache");
+/// Interns the given family name in a global cache; never freeing it.
+fn intern_name(name: impl AsRef<str>) -> &'static str {
+    use rustc_hash::FxHashSet;
+    use std::sync::{LazyLock, Mutex};
 
-        let Some(name) = names.get(name) else {
-            let name: &'static str = name.to_owned().leak();
-            let _ = names.insert(name);
+    static NAMES: LazyLock<Mutex<FxHashSet<&'static str>>> = LazyLock::new(Mutex::default);
 
-            return Self::Name(name);
-        };
+    let name = name.as_ref();
+    let mut names = NAMES.lock().expect("lock font name cache");
 
-        Self::Name(name)
+    if let Some(name) = names.get(name) {
+        return name;
     }
+
+    let name: &'static str = name.to_owned().leak();
+    let _ = names.insert(name);
+
+    name
 }
 
 impl From<&str> for Family {
@@ -195,6 +224,454 @@ pub enum Style {
     Oblique,
 }
 
+/// An OpenType feature of a [`Font`]; like tabular numerals or ligatures.
+///
+/// Features not supported by a font are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Feature {
+    /// The tag of the feature; like `b"tnum"`.
+    pub tag: [u8; 4],
+    /// The value of the feature.
+    ///
+    /// `0` disables the feature and `1` enables it. Some features, like
+    /// alternates, use higher values to choose between different glyphs.
+    pub value: u32,
+}
+
+impl Feature {
+    /// Kerning.
+    pub const KERNING: Self = Self::enable(b"kern");
+
+    /// Standard ligatures; enabled by default.
+    pub const LIGATURES: Self = Self::enable(b"liga");
+
+    /// Contextual alternates; enabled by default.
+    pub const CONTEXTUAL_ALTERNATES: Self = Self::enable(b"calt");
+
+    /// Numerals with the same width, useful to align numbers in columns.
+    pub const TABULAR_NUMBERS: Self = Self::enable(b"tnum");
+
+    /// Numerals with different widths.
+    pub const PROPORTIONAL_NUMBERS: Self = Self::enable(b"pnum");
+
+    /// A zero with a slash or a dot.
+    pub const SLASHED_ZERO: Self = Self::enable(b"zero");
+
+    /// Small capitals for lowercase letters.
+    pub const SMALL_CAPS: Self = Self::enable(b"smcp");
+
+    /// Small capitals for uppercase letters.
+    pub const ALL_SMALL_CAPS: Self = Self::enable(b"c2sc");
+
+    /// Smaller glyphs raised above the baseline.
+    pub const SUPERSCRIPT: Self = Self::enable(b"sups");
+
+    /// Smaller glyphs lowered below the baseline.
+    pub const SUBSCRIPT: Self = Self::enable(b"subs");
+
+    /// Creates a [`Feature`] with the given tag and value.
+    pub const fn new(tag: &[u8; 4], value: u32) -> Self {
+        Self { tag: *tag, value }
+    }
+
+    /// Creates an enabled [`Feature`] with the given tag.
+    pub const fn enable(tag: &[u8; 4]) -> Self {
+        Self::new(tag, 1)
+    }
+
+    /// Creates a disabled [`Feature`] with the given tag.
+    pub const fn disable(tag: &[u8; 4]) -> Self {
+        Self::new(tag, 0)
+    }
+
+    /// Disables this [`Feature`]; like [`Feature::LIGATURES`].
+    pub const fn disabled(self) -> Self {
+        Self { value: 0, ..self }
+    }
+
+    /// Creates a [`Feature`] enabling the stylistic set with the given
+    /// number, from 1 to 20.
+    pub const fn stylistic_set(number: u8) -> Self {
+        let number = if number < 1 {
+            1
+        } else if number > 20 {
+            20
+        } else {
+            number
+        };
+
+        Self::enable(&[b's', b's', b'0' + number / 10, b'0' + number % 10])
+    }
+
+    /// Returns the given list of features as a static slice, usable in a
+    /// [`Font`].
+    ///
+    /// The list is interned in a global cache and never freed.
+    pub fn list(features: &[Feature]) -> &'static [Feature] {
+        use std::sync::{LazyLock, Mutex};
+
+        static LISTS: LazyLock<Mutex<rustc_hash::FxHashSet<&'static [Feature]>>> =
+            LazyLock::new(Mutex::default);
+
+        intern(&LISTS, features)
+    }
+}
+
+/// The value of an axis of a variable [`Font`]; like its weight or its
+/// optical size.
+///
+/// Axes not supported by a font are ignored. The built-in renderers
+/// currently honor the weight axis continuously, while the width, slant
+/// and italic axes only take part in choosing a font face. Other axes,
+/// like the optical size, are not supported by them yet and get ignored
+/// with a warning.
+#[derive(Debug, Clone, Copy)]
+pub struct Variation {
+    /// The tag of the axis; like `b"wght"`.
+    pub axis: [u8; 4],
+    /// The value of the axis.
+    pub value: f32,
+}
+
+impl Variation {
+    /// Creates a [`Variation`] of the given axis.
+    pub const fn new(axis: &[u8; 4], value: f32) -> Self {
+        Self { axis: *axis, value }
+    }
+
+    /// Creates a [`Variation`] of the weight axis; from `1.0` to `1000.0`.
+    ///
+    /// It takes precedence over the [`Weight`] of a [`Font`].
+    pub const fn weight(value: f32) -> Self {
+        Self::new(b"wght", value)
+    }
+
+    /// Creates a [`Variation`] of the width axis, as a percentage of the
+    /// normal width.
+    pub const fn width(value: f32) -> Self {
+        Self::new(b"wdth", value)
+    }
+
+    /// Creates a [`Variation`] of the slant axis, in degrees.
+    pub const fn slant(value: f32) -> Self {
+        Self::new(b"slnt", value)
+    }
+
+    /// Creates a [`Variation`] of the optical size axis, in points.
+    ///
+    /// The built-in renderers do not support this axis yet.
+    pub const fn optical_size(value: f32) -> Self {
+        Self::new(b"opsz", value)
+    }
+
+    /// Returns the given list of variations as a static slice, usable in a
+    /// [`Font`].
+    ///
+    /// The list is interned in a global cache and never freed; so avoid
+    /// interning a different list every frame.
+    pub fn list(variations: &[Variation]) -> &'static [Variation] {
+        use std::sync::{LazyLock, Mutex};
+
+        static LISTS: LazyLock<Mutex<rustc_hash::FxHashSet<&'static [Variation]>>> =
+            LazyLock::new(Mutex::default);
+
+        intern(&LISTS, variations)
+    }
+}
+
+impl PartialEq for Variation {
+    fn eq(&self, other: &Self) -> bool {
+        self.axis == other.axis && self.value.to_bits() == other.value.to_bits()
+    }
+}
+
+impl Eq for Variation {}
+
+impl Hash for Variation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.axis.hash(state);
+        self.value.to_bits().hash(state);
+    }
+}
+
+fn intern<T>(
+    lists: &std::sync::Mutex<rustc_hash::FxHashSet<&'static [T]>>,
+    list: &[T],
+) -> &'static [T]
+where
+    T: Clone + Eq + Hash + 'static,
+{
+    if list.is_empty() {
+        return &[];
+    }
+
+    let mut lists = lists.lock().expect("lock font list cache");
+
+    if let Some(list) = lists.get(list) {
+        return list;
+    }
+
+    let list: &'static [T] = list.to_vec().leak();
+    let _ = lists.insert(list);
+
+    list
+}
+
+/// A writing system, identified by its [ISO 15924] code.
+///
+/// [ISO 15924]: https://en.wikipedia.org/wiki/ISO_15924
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Script([u8; 4]);
+
+impl Script {
+    /// Characters shared by many scripts; like digits, punctuation or emoji.
+    pub const COMMON: Self = Self::new(b"Zyyy");
+
+    /// The Latin script.
+    pub const LATIN: Self = Self::new(b"Latn");
+
+    /// The Greek script.
+    pub const GREEK: Self = Self::new(b"Grek");
+
+    /// The Cyrillic script.
+    pub const CYRILLIC: Self = Self::new(b"Cyrl");
+
+    /// The Arabic script.
+    pub const ARABIC: Self = Self::new(b"Arab");
+
+    /// The Hebrew script.
+    pub const HEBREW: Self = Self::new(b"Hebr");
+
+    /// The Devanagari script.
+    pub const DEVANAGARI: Self = Self::new(b"Deva");
+
+    /// The Thai script.
+    pub const THAI: Self = Self::new(b"Thai");
+
+    /// The Han script; used by Chinese, Japanese and Korean.
+    pub const HAN: Self = Self::new(b"Hani");
+
+    /// The Hiragana script.
+    pub const HIRAGANA: Self = Self::new(b"Hira");
+
+    /// The Katakana script.
+    pub const KATAKANA: Self = Self::new(b"Kana");
+
+    /// The Hangul script.
+    pub const HANGUL: Self = Self::new(b"Hang");
+
+    /// Creates a [`Script`] from its four-letter ISO 15924 code; like
+    /// `b"Hani"`.
+    pub const fn new(code: &[u8; 4]) -> Self {
+        Self(*code)
+    }
+
+    /// Returns the four-letter ISO 15924 code of the [`Script`].
+    pub fn code(&self) -> &str {
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+/// The fonts used to render the characters missing in a [`Font`].
+///
+/// Fallback chains are lists of family names tried in order. The chains
+/// of the platform are still used after the ones of a [`Fallback`].
+///
+/// Family names can be owned; they are interned in a global cache and
+/// never freed, like the ones of [`Family::name`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Fallback {
+    /// The families tried for any script, after the ones of the script.
+    pub common: Vec<&'static str>,
+    /// The families that must never be used as a fallback.
+    pub forbidden: Vec<&'static str>,
+    /// The fallback chains of specific scripts.
+    pub chains: Vec<Chain>,
+}
+
+impl Fallback {
+    /// Creates an empty [`Fallback`]; which only uses the chains of the
+    /// platform.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given families to the chain used for any script.
+    pub fn common(mut self, families: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.common.extend(families.into_iter().map(intern_name));
+        self
+    }
+
+    /// Forbids the given family from being used as a fallback.
+    pub fn forbid(mut self, family: impl AsRef<str>) -> Self {
+        self.forbidden.push(intern_name(family));
+        self
+    }
+
+    /// Sets the fallback chain of the given [`Script`].
+    pub fn script(
+        mut self,
+        script: Script,
+        families: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        self.chains.push(Chain {
+            script,
+            locale: None,
+            families: families.into_iter().map(intern_name).collect(),
+        });
+        self
+    }
+
+    /// Sets the fallback chain of the given [`Script`] when the system
+    /// locale starts with the given language tag; like `"ja"` or `"zh-TW"`.
+    ///
+    /// This is useful to choose between the regional variants of Han
+    /// characters.
+    pub fn script_in_locale(
+        mut self,
+        script: Script,
+        locale: impl Into<String>,
+        families: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        self.chains.push(Chain {
+            script,
+            locale: Some(locale.into()),
+            families: families.into_iter().map(intern_name).collect(),
+        });
+        self
+    }
+
+    /// Returns the fallback chain of the given [`Script`] for the given
+    /// locale, if any.
+    ///
+    /// Chains of a matching locale take precedence.
+    pub fn chain(&self, script: Script, locale: &str) -> Option<&[&'static str]> {
+        let matches = |chain: &&Chain| chain.script == script;
+
+        self.chains
+            .iter()
+            .filter(matches)
+            .find(|chain| {
+                chain.locale.as_deref().is_some_and(|prefix| {
+                    locale
+                        .get(..prefix.len())
+                        .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+                })
+            })
+            .or_else(|| {
+                self.chains
+                    .iter()
+                    .filter(matches)
+                    .find(|chain| chain.locale.is_none())
+            })
+            .map(|chain| chain.families.as_slice())
+    }
+}
+
+/// The fallback chain of a [`Script`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chain {
+    /// The [`Script`] of the chain.
+    pub script: Script,
+    /// The prefix of the locale where the chain applies, if any.
+    pub locale: Option<String>,
+    /// The families of the chain, in order.
+    pub families: Vec<&'static str>,
+}
+
+/// A run of text rendered with a single font face.
+///
+/// This is useful to debug which font actually rendered some text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    /// The byte range of the run in the text.
+    pub range: Range<usize>,
+    /// The [`Family`] of the font face that rendered the run.
+    pub family: Family,
+    /// Whether any character of the run was missing in the font face;
+    /// which is rendered as a box.
+    pub is_missing: bool,
+}
+
 /// A font error.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Error {}
+pub enum Error {
+    /// The requested font could not be found.
+    NotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_are_interned() {
+        let features = [Feature::SMALL_CAPS, Feature::stylistic_set(3)];
+
+        let a = Feature::list(&features);
+        let b = Feature::list(&features.clone());
+
+        assert_eq!(a, features);
+        assert!(std::ptr::eq(a, b));
+        assert!(!std::ptr::eq(a, Feature::list(&features[..1])));
+        assert!(Feature::list(&[]).is_empty());
+
+        let variations = [Variation::weight(350.0), Variation::optical_size(12.0)];
+
+        let a = Variation::list(&variations);
+        let b = Variation::list(&variations);
+
+        assert_eq!(a, variations);
+        assert!(std::ptr::eq(a, b));
+        assert!(!std::ptr::eq(
+            a,
+            Variation::list(&[Variation::weight(350.0), Variation::optical_size(14.0)])
+        ));
+    }
+
+    #[test]
+    fn stylistic_sets_are_clamped() {
+        assert_eq!(Feature::stylistic_set(0).tag, *b"ss01");
+        assert_eq!(Feature::stylistic_set(7).tag, *b"ss07");
+        assert_eq!(Feature::stylistic_set(12).tag, *b"ss12");
+        assert_eq!(Feature::stylistic_set(42).tag, *b"ss20");
+    }
+
+    #[test]
+    fn fallback_chains_prefer_matching_locales() {
+        let fallback = Fallback::new()
+            .script(Script::HAN, ["Noto Sans SC"])
+            .script_in_locale(Script::HAN, "ja", [String::from("Noto Sans JP")])
+            .script_in_locale(Script::HAN, "zh-TW", ["Noto Sans TC"])
+            .script(Script::ARABIC, ["Noto Naskh Arabic"]);
+
+        assert_eq!(
+            fallback.chain(Script::HAN, "ja-JP"),
+            Some(["Noto Sans JP"].as_slice())
+        );
+        assert_eq!(
+            fallback.chain(Script::HAN, "ZH-tw"),
+            Some(["Noto Sans TC"].as_slice())
+        );
+        assert_eq!(
+            fallback.chain(Script::HAN, "zh-CN"),
+            Some(["Noto Sans SC"].as_slice())
+        );
+        assert_eq!(
+            fallback.chain(Script::ARABIC, "ja"),
+            Some(["Noto Naskh Arabic"].as_slice())
+        );
+        assert_eq!(fallback.chain(Script::LATIN, "en-US"), None);
+    }
+
+    #[test]
+    fn fallback_names_are_interned() {
+        let fallback = Fallback::new()
+            .common([String::from("Noto Sans")])
+            .forbid(String::from("Noto Sans"));
+
+        assert_eq!(fallback.common, ["Noto Sans"]);
+        assert!(std::ptr::eq(fallback.common[0], fallback.forbidden[0]));
+        assert_eq!(Family::name("Noto Sans"), Family::Name(fallback.common[0]));
+    }
+}
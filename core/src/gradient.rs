@@ -91,6 +91,41 @@ impl Linear {
         self
     }
 
+    /// Returns the [`Color`] of the [`Linear`] gradient at the given offset
+    /// along its path.
+    ///
+    /// Offsets before the first [`ColorStop`] or after the last one produce
+    /// the color of the nearest stop.
+    pub fn color_at(&self, offset: f32) -> Color {
+        let mut stops = self.stops.iter().flatten();
+
+        let Some(mut previous) = stops.next() else {
+            return Color::TRANSPARENT;
+        };
+
+        if offset <= previous.offset {
+            return previous.color;
+        }
+
+        for stop in stops {
+            if offset <= stop.offset {
+                let distance = stop.offset - previous.offset;
+
+                let factor = if distance > 0.0 {
+                    (offset - previous.offset) / distance
+                } else {
+                    1.0
+                };
+
+                return previous.color.mix(stop.color, factor);
+            }
+
+            previous = stop;
+        }
+
+        previous.color
+    }
+
     /// Scales the alpha channel of the [`Linear`] gradient by the given
     /// factor.
     pub fn scale_alpha(mut self, factor: f32) -> Self {
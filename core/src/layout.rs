@@ -1,9 +1,14 @@
 //! Position your widgets properly.
+mod direction;
 mod limits;
 mod node;
 
+pub mod cache;
 pub mod flex;
+pub mod grid;
 
+pub use cache::Cache;
+pub use direction::Direction;
 pub use limits::Limits;
 pub use node::Node;
 
@@ -14,6 +19,7 @@ use crate::{Length, Padding, Point, Rectangle, Size, Vector};
 pub struct Layout<'a> {
     position: Point,
     node: &'a Node,
+    direction: Direction,
 }
 
 impl<'a> Layout<'a> {
@@ -30,6 +36,7 @@ impl<'a> Layout<'a> {
         Self {
             position: Point::new(bounds.x, bounds.y) + offset,
             node,
+            direction: node.direction().unwrap_or_default(),
         }
     }
 
@@ -53,11 +60,20 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the [`Direction`] of the [`Layout`].
+    ///
+    /// The [`Direction`] of a [`Layout`] is inherited from its parent, unless
+    /// its [`Node`] sets one explicitly.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
     /// Returns an iterator over the children of this [`Layout`].
     pub fn children(self) -> impl DoubleEndedIterator<Item = Layout<'a>> + ExactSizeIterator {
-        self.node.children().iter().map(move |node| {
-            Layout::with_offset(Vector::new(self.position.x, self.position.y), node)
-        })
+        self.node
+            .children()
+            .iter()
+            .map(move |node| self.nested(node))
     }
 
     /// Returns the [`Layout`] of the child at the given index.
@@ -68,13 +84,24 @@ impl<'a> Layout<'a> {
     /// # Panics
     /// Panics if index is out of bounds.
     pub fn child(self, index: usize) -> Layout<'a> {
-        let node = &self.node.children()[index];
+        self.nested(&self.node.children()[index])
+    }
 
-        Layout::with_offset(Vector::new(self.position.x, self.position.y), node)
+    fn nested(self, node: &'a Node) -> Layout<'a> {
+        let bounds = node.bounds();
+
+        Layout {
+            position: Point::new(bounds.x, bounds.y)
+                + Vector::new(self.position.x, self.position.y),
+            node,
+            direction: node.direction().unwrap_or(self.direction),
+        }
     }
 }
 
 /// Produces a [`Node`] with two children nodes one right next to each other.
+///
+/// The resulting [`Node`] inherits the baselines of its children.
 pub fn next_to_each_other(
     limits: &Limits,
     spacing: f32,
@@ -105,6 +132,7 @@ pub fn next_to_each_other(
             right_node.move_to(Point::new(left_size.width + spacing, right_y)),
         ],
     )
+    .with_children_baselines()
 }
 
 /// Computes the resulting [`Node`] that fits the [`Limits`] given
@@ -142,6 +170,8 @@ pub fn sized(
 /// Computes the resulting [`Node`] that fits the [`Limits`] given
 /// some width and height requirements and a closure that produces
 /// the content [`Node`] inside the given [`Limits`].
+///
+/// The resulting [`Node`] inherits the baselines of its content.
 pub fn contained(
     limits: &Limits,
     width: impl Into<Length>,
@@ -155,11 +185,14 @@ pub fn contained(
     let content = f(&limits);
 
     Node::with_children(limits.resolve(width, height, content.size()), vec![content])
+        .with_children_baselines()
 }
 
 /// Computes the [`Node`] that fits the [`Limits`] given some width, height, and
 /// [`Padding`] requirements and a closure that produces the content [`Node`]
 /// inside the given [`Limits`].
+///
+/// The resulting [`Node`] inherits the baselines of its content.
 pub fn padded(
     limits: &Limits,
     width: impl Into<Length>,
@@ -195,4 +228,5 @@ pub fn positioned(
         size.expand(padding),
         vec![position(content.move_to((padding.left, padding.top)), size)],
     )
+    .with_children_baselines()
 }
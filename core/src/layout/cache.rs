@@ -0,0 +1,138 @@
+//! Reuse layouts that have not changed.
+use crate::layout::{Limits, Node};
+use crate::widget::tree::{self, Tree};
+
+/// A layout [`Node`] that can be reused as long as its dependencies and
+/// [`Limits`] do not change.
+///
+/// A widget can keep a [`Cache`] as its [`Tree`] state to skip laying out its
+/// contents when it knows their layout is unchanged; for instance, by hashing
+/// the data they depend on.
+#[derive(Debug, Clone, Default)]
+pub struct Cache {
+    entry: Option<Entry>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    hash: u64,
+    limits: Limits,
+    node: Node,
+}
+
+impl Cache {
+    /// Creates an empty [`Cache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached [`Node`] for the given `hash` and [`Limits`], if
+    /// it is still valid.
+    pub fn get(&self, hash: u64, limits: &Limits) -> Option<&Node> {
+        let entry = self.entry.as_ref()?;
+
+        (entry.hash == hash && entry.limits == *limits).then_some(&entry.node)
+    }
+
+    /// Returns the cached [`Node`] for the given `hash` and [`Limits`], or
+    /// computes and caches a new one with the given `layout` function.
+    pub fn get_or_insert_with(
+        &mut self,
+        hash: u64,
+        limits: &Limits,
+        layout: impl FnOnce() -> Node,
+    ) -> Node {
+        if let Some(node) = self.get(hash, limits) {
+            return node.clone();
+        }
+
+        let node = layout();
+
+        self.entry = Some(Entry {
+            hash,
+            limits: *limits,
+            node: node.clone(),
+        });
+
+        node
+    }
+
+    /// Clears the [`Cache`], forcing the next layout to be computed.
+    pub fn clear(&mut self) {
+        self.entry = None;
+    }
+}
+
+/// Invalidates every layout [`Cache`] kept as the state of a widget in the
+/// given [`Tree`].
+///
+/// This should be called whenever the layout of widgets can change for
+/// reasons outside of their control; like when fonts are loaded or the
+/// default text settings of a renderer change.
+pub fn invalidate(tree: &mut Tree) {
+    if let tree::State::Some(state) = &mut tree.state
+        && let Some(cache) = state.downcast_mut::<Cache>()
+    {
+        cache.clear();
+    }
+
+    for child in &mut tree.children {
+        invalidate(child);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Size;
+
+    #[test]
+    fn cache_is_keyed_by_hash_and_limits() {
+        let mut cache = Cache::new();
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let node = || Node::new(Size::new(10.0, 10.0));
+
+        let _ = cache.get_or_insert_with(1, &limits, node);
+
+        assert!(cache.get(1, &limits).is_some());
+        assert!(cache.get(2, &limits).is_none());
+        assert!(
+            cache
+                .get(1, &Limits::new(Size::ZERO, Size::new(50.0, 100.0)))
+                .is_none()
+        );
+
+        cache.clear();
+
+        assert!(cache.get(1, &limits).is_none());
+    }
+
+    #[test]
+    fn invalidate_clears_nested_caches() {
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let node = || Node::new(Size::new(10.0, 10.0));
+
+        let mut cache = Cache::new();
+        let _ = cache.get_or_insert_with(1, &limits, node);
+
+        let mut tree = Tree {
+            tag: tree::Tag::stateless(),
+            state: tree::State::None,
+            children: vec![Tree {
+                tag: tree::Tag::of::<Cache>(),
+                state: tree::State::new(cache),
+                children: Vec::new(),
+            }],
+        };
+
+        invalidate(&mut tree);
+
+        assert!(
+            tree.children[0]
+                .state
+                .downcast_ref::<Cache>()
+                .get(1, &limits)
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::alignment;
+
+/// The horizontal direction in which content flows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Content flows from left to right; like in English.
+    #[default]
+    LeftToRight,
+
+    /// Content flows from right to left; like in Arabic or Hebrew.
+    ///
+    /// Layouts are mirrored horizontally. Children of a `row` are placed
+    /// from right to left, horizontal padding and alignments are swapped,
+    /// and widgets with a horizontal sense—like a slider—are reversed.
+    RightToLeft,
+}
+
+impl Direction {
+    /// Returns true if the [`Direction`] is [`Direction::RightToLeft`].
+    pub fn is_rtl(self) -> bool {
+        self == Self::RightToLeft
+    }
+
+    /// Mirrors the given horizontal alignment, if the [`Direction`] is
+    /// [`Direction::RightToLeft`].
+    pub fn align(self, alignment: alignment::Horizontal) -> alignment::Horizontal {
+        match (self, alignment) {
+            (Self::RightToLeft, alignment::Horizontal::Left) => alignment::Horizontal::Right,
+            (Self::RightToLeft, alignment::Horizontal::Right) => alignment::Horizontal::Left,
+            _ => alignment,
+        }
+    }
+
+    /// Mirrors the given horizontal position of an element of the given
+    /// width inside some total width, if the [`Direction`] is
+    /// [`Direction::RightToLeft`].
+    pub fn mirror(self, x: f32, width: f32, total: f32) -> f32 {
+        match self {
+            Self::LeftToRight => x,
+            Self::RightToLeft => total - x - width,
+        }
+    }
+}
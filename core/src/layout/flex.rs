@@ -21,7 +21,7 @@ use crate::Element;
 use crate::layout::{Limits, Node};
 use crate::length;
 use crate::widget;
-use crate::{Alignment, Length, Padding, Size};
+use crate::{Alignment, Length, Padding, Size, Vector};
 
 /// The main axis of a flex layout.
 #[derive(Debug)]
@@ -56,6 +56,36 @@ impl Axis {
     }
 }
 
+/// The flex properties of an item in a flex layout.
+///
+/// Items with flex properties start with a main size equal to their
+/// [`basis`](Self::basis). Then, they [`grow`](Self::grow) into any remaining
+/// space—sharing it with any [`Length::FillPortion`] siblings—or
+/// [`shrink`](Self::shrink) if the items of the layout overflow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Item {
+    /// The initial size of the item in the main axis.
+    ///
+    /// If `None`, the intrinsic size of the item is used.
+    pub basis: Option<f32>,
+    /// The portion of the remaining space that the item takes, relative to
+    /// its siblings; like a [`Length::FillPortion`].
+    pub grow: u16,
+    /// How much the item shrinks when the items of the layout overflow,
+    /// relative to its siblings and weighted by its basis.
+    pub shrink: f32,
+}
+
+impl Default for Item {
+    fn default() -> Self {
+        Self {
+            basis: None,
+            grow: 0,
+            shrink: 1.0,
+        }
+    }
+}
+
 /// Computes the flex layout with the given axis and limits, applying spacing,
 /// padding and alignment to the items as needed.
 ///
@@ -115,6 +145,7 @@ where
         CrossFluid,
         CrossFluidDeferred(f32),
         MainFluid,
+        Flex(Item, f32),
     }
 
     let mut metas = Vec::with_capacity(items.len());
@@ -127,6 +158,43 @@ where
         let size = child.as_widget().size();
         let (size_main, size_cross) = axis.pack(size.width, size.height);
 
+        // Flex items only reserve their basis for now
+        if let Some(item) = child.as_widget().flex() {
+            let basis = if let Some(basis) = item.basis {
+                basis
+            } else {
+                let (max_width, max_height) = axis.pack(available, max_cross);
+                let (compress_width, compress_height) = axis.pack(true, cross_compress);
+
+                let child_limits = Limits::with_compression(
+                    Size::ZERO,
+                    Size::new(max_width, max_height),
+                    Size::new(compress_width, compress_height),
+                );
+
+                nodes[i] = child
+                    .as_widget_mut()
+                    .layout(&mut trees[i], renderer, &child_limits);
+
+                axis.main(nodes[i].size())
+            };
+
+            available -= basis;
+
+            if !main_compress {
+                fill_main_sum += item.grow;
+            }
+
+            metas.push(Meta {
+                main: size_main,
+                cross: size_cross,
+                category: Category::Flex(item, basis),
+                resolved: true,
+            });
+
+            continue;
+        }
+
         let fill_main_factor = size_main.fill_factor();
         let fill_cross_factor = size_cross.fill_factor();
         let main_is_static = main_compress || fill_main_factor == 0;
@@ -245,6 +313,7 @@ where
         }
     }
 
+    let overflow = (-available).max(0.0);
     let mut remaining = available.max(0.0);
 
     // MIN / MAX PASSES
@@ -369,6 +438,160 @@ where
         }
     }
 
+    // FLEX PASS (conditional)
+    // We lay out the items with flex properties. They grow into the remaining
+    // space based on their grow factors, or shrink based on their shrink factors
+    // and basis if the items overflow.
+    //
+    // Every item is clamped to its own bounds. Items that hit their bounds are
+    // frozen, and the free space is redistributed among the rest until no item
+    // violates its bounds; like in CSS flexbox.
+    let growing = overflow == 0.0 && !main_compress && remaining.is_finite();
+
+    let factor = |item: Item, basis: f32| {
+        if overflow > 0.0 {
+            item.shrink * basis
+        } else if growing {
+            f32::from(item.grow)
+        } else {
+            0.0
+        }
+    };
+
+    let mut free = if overflow > 0.0 {
+        -overflow
+    } else if growing {
+        remaining
+    } else {
+        0.0
+    };
+
+    // Any fluid siblings share the remaining space with growing items
+    let mut factor_sum: f32 = if growing {
+        fill_main_sum as f32
+    } else {
+        metas
+            .iter()
+            .map(|meta| match meta.category {
+                Category::Flex(item, basis) => factor(item, basis),
+                _ => 0.0,
+            })
+            .sum()
+    };
+
+    let mut mains = vec![0.0; items.len()];
+    let mut frozen = vec![true; items.len()];
+
+    for (i, meta) in metas.iter().enumerate() {
+        let Category::Flex(item, basis) = meta.category else {
+            continue;
+        };
+
+        if factor(item, basis) > 0.0 {
+            frozen[i] = false;
+            continue;
+        }
+
+        let (min, max) = main_bounds(meta.main);
+
+        mains[i] = basis.min(max).max(min);
+        free -= mains[i] - basis;
+    }
+
+    let mut targets = vec![0.0; items.len()];
+
+    while frozen.contains(&false) {
+        let mut violation = 0.0;
+
+        for (i, meta) in metas.iter().enumerate() {
+            let Category::Flex(item, basis) = meta.category else {
+                continue;
+            };
+
+            if frozen[i] {
+                continue;
+            }
+
+            let (min, max) = main_bounds(meta.main);
+
+            targets[i] = (basis + free * factor(item, basis) / factor_sum).max(0.0);
+            mains[i] = targets[i].min(max).max(min);
+            violation += mains[i] - targets[i];
+        }
+
+        if violation == 0.0 {
+            break;
+        }
+
+        for (i, meta) in metas.iter().enumerate() {
+            let Category::Flex(item, basis) = meta.category else {
+                continue;
+            };
+
+            let violates = if violation > 0.0 {
+                mains[i] > targets[i]
+            } else {
+                mains[i] < targets[i]
+            };
+
+            if frozen[i] || !violates {
+                continue;
+            }
+
+            frozen[i] = true;
+            free -= mains[i] - basis;
+            factor_sum -= factor(item, basis);
+        }
+    }
+
+    for (i, child) in items.iter_mut().enumerate() {
+        let meta = metas[i];
+
+        let Category::Flex(item, basis) = meta.category else {
+            continue;
+        };
+
+        let main = mains[i];
+
+        if growing {
+            remaining = (remaining - (main - basis)).max(0.0);
+            fill_main_sum -= item.grow;
+        }
+
+        // Items measured in the first pass keep their node if their size
+        // and limits do not change
+        if item.basis.is_none()
+            && main == basis
+            && (!cross_compress || meta.cross.fill_factor() == 0)
+        {
+            cross = cross.max(axis.cross(nodes[i].size()));
+            continue;
+        }
+
+        let (min_width, min_height) = axis.pack(main, 0.0);
+        let (max_width, max_height) = axis.pack(
+            main,
+            if !cross_compress || meta.cross.fill_factor() == 0 {
+                max_cross
+            } else {
+                cross
+            },
+        );
+
+        let child_limits = Limits::with_compression(
+            Size::new(min_width, min_height),
+            Size::new(max_width, max_height),
+            compression,
+        );
+
+        let layout = child
+            .as_widget_mut()
+            .layout(&mut trees[i], renderer, &child_limits);
+
+        cross = cross.max(axis.cross(layout.size()));
+        nodes[i] = layout;
+    }
+
     // MAIN FLUID PASS (conditional)
     // We lay out the elements that are fluid in the main axis.
     // We use the remaining space to evenly allocate space based on fill factors.
@@ -449,6 +672,34 @@ where
         }
     }
 
+    // BASELINE PASS (conditional)
+    // We find the tallest ascent and descent of the nodes from the baseline
+    // they must be aligned with, if needed.
+    let baseline = |node: &Node| {
+        match align_items {
+            Alignment::LastBaseline => node.last_baseline(),
+            _ => node.baseline(),
+        }
+        .unwrap_or(node.size().height)
+    };
+
+    let ascent = if let Axis::Horizontal = axis
+        && align_items.is_baseline()
+    {
+        let ascent = nodes.iter().map(baseline).fold(0.0, f32::max);
+
+        let descent = nodes
+            .iter()
+            .map(|node| node.size().height - baseline(node))
+            .fold(0.0, f32::max);
+
+        cross = f32::max(cross, ascent + descent);
+
+        Some(ascent)
+    } else {
+        None
+    };
+
     let pad = axis.pack(padding.left, padding.top);
     let mut main = pad.0;
 
@@ -468,7 +719,11 @@ where
 
         match axis {
             Axis::Horizontal => {
-                node.align_mut(Alignment::Start, align_items, Size::new(0.0, cross));
+                if let Some(ascent) = ascent {
+                    node.translate_mut(Vector::new(0.0, ascent - baseline(node)));
+                } else {
+                    node.align_mut(Alignment::Start, align_items, Size::new(0.0, cross));
+                }
             }
             Axis::Vertical => {
                 node.align_mut(align_items, Alignment::Start, Size::new(cross, 0.0));
@@ -485,5 +740,207 @@ where
 
     let size = Size::from(axis.pack(main, cross));
 
-    Node::with_children(size.expand(padding), nodes)
+    Node::with_children(size.expand(padding), nodes).with_children_baselines()
+}
+
+/// Returns the minimum and maximum main size of an item with the given
+/// [`Length`] in the main axis.
+fn main_bounds(length: Length) -> (f32, f32) {
+    match length {
+        Length::Bounded { bounds, .. } => match bounds {
+            length::Bounds::Min(min) => (min, f32::INFINITY),
+            length::Bounds::Max(max) => (0.0, max),
+            length::Bounds::Both { min, max } => (min, max),
+        },
+        _ => (0.0, f32::INFINITY),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Layout;
+    use crate::widget::Widget;
+    use crate::{Rectangle, mouse, renderer};
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// A widget with some intrinsic size that counts its layouts.
+    struct Block {
+        width: Length,
+        intrinsic: f32,
+        item: Option<Item>,
+        layouts: Rc<Cell<usize>>,
+    }
+
+    impl Block {
+        fn new(width: impl Into<Length>) -> Self {
+            Self {
+                width: width.into(),
+                intrinsic: 0.0,
+                item: None,
+                layouts: Rc::default(),
+            }
+        }
+
+        fn flex(basis: Option<f32>, grow: u16, shrink: f32) -> Self {
+            Self {
+                item: Some(Item {
+                    basis,
+                    grow,
+                    shrink,
+                }),
+                ..Self::new(Length::Shrink)
+            }
+        }
+
+        fn intrinsic(self, intrinsic: f32) -> Self {
+            Self { intrinsic, ..self }
+        }
+    }
+
+    impl Widget<(), (), ()> for Block {
+        fn size(&self) -> Size<Length> {
+            Size::new(self.width, Length::Shrink)
+        }
+
+        fn flex(&self) -> Option<Item> {
+            self.item
+        }
+
+        fn layout(&mut self, _tree: &mut widget::Tree, _renderer: &(), limits: &Limits) -> Node {
+            self.layouts.set(self.layouts.get() + 1);
+
+            Node::new(limits.resolve(self.width, Length::Shrink, Size::new(self.intrinsic, 10.0)))
+        }
+
+        fn draw(
+            &self,
+            _tree: &widget::Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn widths(blocks: impl IntoIterator<Item = Block>, width: f32) -> Vec<f32> {
+        let mut items: Vec<Element<'_, (), (), ()>> =
+            blocks.into_iter().map(Element::new).collect();
+        let mut trees: Vec<_> = items.iter().map(|_| widget::Tree::empty()).collect();
+
+        let node = resolve(
+            Axis::Horizontal,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(width, 100.0)),
+            Length::Fill,
+            Length::Shrink,
+            Padding::ZERO,
+            0.0,
+            Alignment::Start,
+            &mut items,
+            &mut trees,
+        );
+
+        node.children()
+            .iter()
+            .map(|node| node.size().width)
+            .collect()
+    }
+
+    #[test]
+    fn items_grow_into_remaining_space_with_fill_portions() {
+        let widths = widths(
+            [
+                Block::flex(Some(10.0), 1, 1.0),
+                Block::flex(Some(20.0), 2, 1.0),
+                Block::new(Length::Fill),
+            ],
+            130.0,
+        );
+
+        assert_eq!(widths, [35.0, 70.0, 25.0]);
+    }
+
+    #[test]
+    fn items_shrink_by_their_weighted_basis() {
+        let widths = widths(
+            [
+                Block::flex(Some(100.0), 0, 1.0),
+                Block::flex(Some(50.0), 0, 2.0),
+                Block::flex(Some(50.0), 0, 0.0),
+            ],
+            150.0,
+        );
+
+        assert_eq!(widths, [75.0, 25.0, 50.0]);
+    }
+
+    #[test]
+    fn fill_items_are_clamped_to_their_bounds() {
+        assert_eq!(
+            widths(
+                [Block::new(Length::Fill.max(30.0)), Block::new(Length::Fill)],
+                100.0
+            ),
+            [30.0, 70.0]
+        );
+
+        assert_eq!(
+            widths(
+                [Block::new(Length::Fill.min(80.0)), Block::new(Length::Fill)],
+                100.0
+            ),
+            [80.0, 20.0]
+        );
+    }
+
+    #[test]
+    fn flex_items_are_clamped_to_their_bounds_and_redistribute() {
+        assert_eq!(
+            widths(
+                [
+                    Block {
+                        width: Length::Shrink.max(20.0),
+                        ..Block::flex(Some(0.0), 1, 1.0)
+                    },
+                    Block::flex(Some(0.0), 1, 1.0),
+                    Block::flex(Some(0.0), 1, 1.0),
+                ],
+                120.0
+            ),
+            [20.0, 50.0, 50.0]
+        );
+
+        assert_eq!(
+            widths(
+                [
+                    Block {
+                        width: Length::Shrink.min(80.0),
+                        ..Block::flex(Some(100.0), 0, 1.0)
+                    },
+                    Block::flex(Some(100.0), 0, 1.0),
+                ],
+                100.0
+            ),
+            [80.0, 20.0]
+        );
+    }
+
+    #[test]
+    fn items_without_basis_are_measured_once() {
+        let fixed = Block::flex(None, 0, 1.0).intrinsic(40.0);
+        let fixed_layouts = fixed.layouts.clone();
+
+        let growing = Block::flex(None, 1, 1.0).intrinsic(20.0);
+        let growing_layouts = growing.layouts.clone();
+
+        assert_eq!(widths([fixed, growing], 100.0), [40.0, 60.0]);
+        assert_eq!(fixed_layouts.get(), 1);
+        assert_eq!(growing_layouts.get(), 2);
+    }
 }
@@ -0,0 +1,651 @@
+//! Distribute elements on a two-dimensional grid of tracks.
+use crate::Element;
+use crate::layout::{Limits, Node};
+use crate::length::{self, Bounds};
+use crate::widget;
+use crate::{Alignment, Length, Padding, Point, Size};
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// A rectangular region of a grid, measured in tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Area {
+    /// The first row of the [`Area`].
+    pub row: usize,
+    /// The first column of the [`Area`].
+    pub column: usize,
+    /// The amount of rows spanned by the [`Area`].
+    pub rows: usize,
+    /// The amount of columns spanned by the [`Area`].
+    pub columns: usize,
+}
+
+impl Area {
+    /// Creates a new [`Area`] covering the single cell at the given row and
+    /// column.
+    pub fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            rows: 1,
+            columns: 1,
+        }
+    }
+
+    /// Sets the amount of rows and columns spanned by the [`Area`].
+    pub fn span(self, rows: usize, columns: usize) -> Self {
+        Self {
+            rows: rows.max(1),
+            columns: columns.max(1),
+            ..self
+        }
+    }
+
+    /// Returns the range of rows covered by the [`Area`].
+    pub fn row_range(&self) -> Range<usize> {
+        self.row..self.row + self.rows
+    }
+
+    /// Returns the range of columns covered by the [`Area`].
+    pub fn column_range(&self) -> Range<usize> {
+        self.column..self.column + self.columns
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (usize, usize)> + use<> {
+        let columns = self.column_range();
+
+        self.row_range()
+            .flat_map(move |row| columns.clone().map(move |column| (row, column)))
+    }
+}
+
+/// A set of named [`Area`]s of a grid.
+///
+/// Just like `grid-template-areas` in CSS, [`Areas`] are described by rows of
+/// whitespace-separated names, where each name covers a cell of the grid and
+/// a `.` leaves a cell unnamed.
+///
+/// ```
+/// use iced_core::layout::grid::Areas;
+///
+/// let areas = Areas::parse(["header header", "sidebar main", "sidebar ."]);
+///
+/// assert_eq!(areas.get("header").map(|area| area.columns), Some(2));
+/// assert_eq!(areas.get("sidebar").map(|area| area.rows), Some(2));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Areas {
+    named: Vec<(String, Area)>,
+    rows: usize,
+    columns: usize,
+}
+
+impl Areas {
+    /// Parses the [`Areas`] described by the given rows.
+    ///
+    /// Names must cover a rectangular region of the grid; otherwise, they
+    /// are ignored.
+    pub fn parse<'a>(rows: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut named: Vec<(String, Area, usize)> = Vec::new();
+        let mut total_rows = 0;
+        let mut total_columns = 0;
+
+        for (row, line) in rows.into_iter().enumerate() {
+            total_rows = row + 1;
+
+            for (column, name) in line.split_whitespace().enumerate() {
+                total_columns = total_columns.max(column + 1);
+
+                if name.chars().all(|c| c == '.') {
+                    continue;
+                }
+
+                if let Some((_, area, cells)) = named.iter_mut().find(|(other, ..)| other == name) {
+                    let last_row = (area.row + area.rows).max(row + 1);
+                    let last_column = (area.column + area.columns).max(column + 1);
+
+                    area.column = area.column.min(column);
+                    area.rows = last_row - area.row;
+                    area.columns = last_column - area.column;
+
+                    *cells += 1;
+                } else {
+                    named.push((name.to_owned(), Area::new(row, column), 1));
+                }
+            }
+        }
+
+        let named = named
+            .into_iter()
+            .filter_map(|(name, area, cells)| {
+                if cells == area.rows * area.columns {
+                    Some((name, area))
+                } else {
+                    log::warn!("Grid area \"{name}\" is not rectangular and will be ignored.");
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            named,
+            rows: total_rows,
+            columns: total_columns,
+        }
+    }
+
+    /// Returns the [`Area`] with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<Area> {
+        self.named
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|(_, area)| *area)
+    }
+
+    /// Returns the amount of rows described by the [`Areas`].
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the amount of columns described by the [`Areas`].
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+}
+
+/// The placement of an item in a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Placement {
+    /// The item is placed in the first free cells that fit the given span,
+    /// in row-major order.
+    Auto {
+        /// The amount of rows spanned by the item.
+        rows: usize,
+        /// The amount of columns spanned by the item.
+        columns: usize,
+    },
+    /// The item is placed in the given [`Area`].
+    Area(Area),
+    /// The item is placed in the named [`Area`] with the given name.
+    Named(String),
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Self::Auto {
+            rows: 1,
+            columns: 1,
+        }
+    }
+}
+
+impl From<Area> for Placement {
+    fn from(area: Area) -> Self {
+        Self::Area(area)
+    }
+}
+
+impl From<&str> for Placement {
+    fn from(name: &str) -> Self {
+        Self::Named(name.to_owned())
+    }
+}
+
+impl From<String> for Placement {
+    fn from(name: String) -> Self {
+        Self::Named(name)
+    }
+}
+
+/// Resolves the [`Area`] of every [`Placement`] in a grid with the given
+/// amount of columns and named [`Areas`].
+///
+/// Explicit placements are resolved first. Then, automatic placements fill
+/// the free cells of the grid, adding new rows if necessary.
+pub fn place<'a>(
+    placements: impl IntoIterator<Item = &'a Placement>,
+    areas: &Areas,
+    columns: usize,
+) -> Vec<Area> {
+    let placements: Vec<_> = placements.into_iter().collect();
+
+    let mut resolved: Vec<Option<Area>> = placements
+        .iter()
+        .map(|placement| match placement {
+            Placement::Auto { .. } => None,
+            Placement::Area(area) => Some(area.span(area.rows, area.columns)),
+            Placement::Named(name) => {
+                let area = areas.get(name);
+
+                if area.is_none() {
+                    log::warn!("Grid area \"{name}\" does not exist.");
+                }
+
+                area
+            }
+        })
+        .collect();
+
+    let mut occupied: HashSet<(usize, usize)> =
+        resolved.iter().flatten().flat_map(Area::cells).collect();
+
+    let columns = resolved
+        .iter()
+        .flatten()
+        .map(|area| area.column + area.columns)
+        .fold(columns.max(areas.columns()), usize::max)
+        .max(1);
+
+    let mut cursor = (0, 0);
+
+    for (placement, resolved) in placements.iter().zip(&mut resolved) {
+        if resolved.is_some() {
+            continue;
+        }
+
+        let (rows, span) = match placement {
+            Placement::Auto { rows, columns } => (*rows, *columns),
+            _ => (1, 1),
+        };
+
+        let span = span.clamp(1, columns);
+        let (mut row, mut column) = cursor;
+
+        let area = loop {
+            if column + span > columns {
+                row += 1;
+                column = 0;
+                continue;
+            }
+
+            let area = Area::new(row, column).span(rows, span);
+
+            if area.cells().all(|cell| !occupied.contains(&cell)) {
+                break area;
+            }
+
+            column += 1;
+        };
+
+        occupied.extend(area.cells());
+        cursor = (area.row, area.column + area.columns);
+
+        *resolved = Some(area);
+    }
+
+    resolved.into_iter().flatten().collect()
+}
+
+/// An item of a grid, placed in some [`Area`] and aligned inside of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    /// The [`Area`] of the grid occupied by the [`Cell`].
+    pub area: Area,
+    /// The horizontal alignment of the item inside its [`Area`].
+    pub align_x: Alignment,
+    /// The vertical alignment of the item inside its [`Area`].
+    pub align_y: Alignment,
+}
+
+/// Computes the grid layout of the given items with the given row and
+/// column tracks, applying spacing, padding and alignment as needed.
+///
+/// Tracks are sized by their [`Length`]:
+///
+/// - [`Length::Fixed`] tracks have a fixed size.
+/// - [`Length::Shrink`] and [`Length::Fit`] tracks fit the items placed
+///   in them.
+/// - [`Length::Fill`] and [`Length::FillPortion`] tracks share the remaining
+///   space; like the `fr` unit in CSS.
+/// - [`Length::Bounded`] tracks are clamped to their bounds; like `minmax`
+///   in CSS.
+///
+/// Any [`Cell`] placed beyond the given tracks produces implicit
+/// [`Length::Shrink`] tracks.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Theme, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    columns: &[Length],
+    rows: &[Length],
+    column_spacing: f32,
+    row_spacing: f32,
+    cells: &[Cell],
+    items: &mut [Element<'_, Message, Theme, Renderer>],
+    trees: &mut [widget::Tree],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.width(width).height(height).shrink(padding);
+    let max = limits.max();
+    let compression = limits.compression();
+
+    let columns = tracks(
+        columns,
+        cells
+            .iter()
+            .map(|cell| cell.area.column + cell.area.columns)
+            .max()
+            .unwrap_or_default(),
+    );
+
+    let rows = tracks(
+        rows,
+        cells
+            .iter()
+            .map(|cell| cell.area.row + cell.area.rows)
+            .max()
+            .unwrap_or_default(),
+    );
+
+    let is_fixed = |track: &Length| matches!(track, Length::Fixed(_));
+
+    // COLUMN PASS
+    // We measure the intrinsic width of the items in non-fixed columns.
+    let mut widths = Contents::new(columns.len());
+
+    for ((item, tree), cell) in items.iter_mut().zip(trees.iter_mut()).zip(cells) {
+        let range = cell.area.column_range();
+
+        if columns[range.clone()].iter().all(is_fixed) {
+            continue;
+        }
+
+        let limits = Limits::with_compression(Size::ZERO, max, Size::new(true, true));
+        let node = item.as_widget_mut().layout(tree, renderer, &limits);
+
+        widths.add(range, node.size().width);
+    }
+
+    let column_sizes = sizes(
+        &columns,
+        &widths,
+        column_spacing,
+        max.width,
+        compression.width,
+    );
+
+    // ROW PASS
+    // We measure the intrinsic height of the items in non-fixed rows,
+    // given the width of their columns.
+    let mut heights = Contents::new(rows.len());
+
+    for ((item, tree), cell) in items.iter_mut().zip(trees.iter_mut()).zip(cells) {
+        let range = cell.area.row_range();
+
+        if rows[range.clone()].iter().all(is_fixed) {
+            continue;
+        }
+
+        let width = span(&column_sizes, cell.area.column_range(), column_spacing);
+
+        let limits = Limits::with_compression(
+            Size::ZERO,
+            Size::new(width, max.height),
+            Size::new(false, true),
+        );
+
+        let node = item.as_widget_mut().layout(tree, renderer, &limits);
+
+        heights.add(range, node.size().height);
+    }
+
+    let row_sizes = sizes(&rows, &heights, row_spacing, max.height, compression.height);
+
+    // PLACEMENT PASS
+    // We lay out every item in its area and align it.
+    let xs = offsets(&column_sizes, column_spacing, padding.left);
+    let ys = offsets(&row_sizes, row_spacing, padding.top);
+
+    let nodes = items
+        .iter_mut()
+        .zip(trees)
+        .zip(cells)
+        .map(|((item, tree), cell)| {
+            let area = Size::new(
+                span(&column_sizes, cell.area.column_range(), column_spacing),
+                span(&row_sizes, cell.area.row_range(), row_spacing),
+            );
+
+            item.as_widget_mut()
+                .layout(tree, renderer, &Limits::new(Size::ZERO, area))
+                .move_to(Point::new(xs[cell.area.column], ys[cell.area.row]))
+                .align(cell.align_x, cell.align_y, area)
+        })
+        .collect();
+
+    let intrinsic = Size::new(
+        span(&column_sizes, 0..column_sizes.len(), column_spacing),
+        span(&row_sizes, 0..row_sizes.len(), row_spacing),
+    );
+
+    let size = limits.resolve(width, height, intrinsic);
+
+    Node::with_children(size.expand(padding), nodes)
+}
+
+/// The intrinsic sizes of the items placed in some tracks.
+struct Contents {
+    single: Vec<f32>,
+    spanning: Vec<(Range<usize>, f32)>,
+}
+
+impl Contents {
+    fn new(tracks: usize) -> Self {
+        Self {
+            single: vec![0.0; tracks],
+            spanning: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, range: Range<usize>, size: f32) {
+        if range.len() == 1 {
+            self.single[range.start] = self.single[range.start].max(size);
+        } else {
+            self.spanning.push((range, size));
+        }
+    }
+}
+
+/// Returns the given tracks, followed by implicit [`Length::Shrink`] tracks
+/// up to the given amount.
+fn tracks(explicit: &[Length], amount: usize) -> Vec<Length> {
+    let mut tracks = explicit.to_vec();
+
+    if tracks.len() < amount {
+        tracks.resize(amount, Length::Shrink);
+    }
+
+    tracks
+}
+
+/// Sizes the given tracks to fit their contents in the available space.
+fn sizes(
+    tracks: &[Length],
+    contents: &Contents,
+    spacing: f32,
+    available: f32,
+    compress: bool,
+) -> Vec<f32> {
+    let is_fluid = !compress && available.is_finite();
+    let fills = |track: &Length| is_fluid && track.is_fill();
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .zip(&contents.single)
+        .map(|(track, content)| match track {
+            Length::Fixed(amount) => *amount,
+            _ if fills(track) => 0.0,
+            _ => clamp(track, *content, *content),
+        })
+        .collect();
+
+    // Items spanning multiple tracks grow the non-fixed, non-filling
+    // tracks they span evenly, if they do not fit
+    for (range, content) in &contents.spanning {
+        let current = span(&sizes, range.clone(), spacing);
+
+        let growable: Vec<usize> = range
+            .clone()
+            .filter(|i| !matches!(tracks[*i], Length::Fixed(_)) && !fills(&tracks[*i]))
+            .collect();
+
+        if *content <= current || growable.is_empty() {
+            continue;
+        }
+
+        let extra = (content - current) / growable.len() as f32;
+
+        for i in growable {
+            sizes[i] = clamp(&tracks[i], sizes[i] + extra, contents.single[i]);
+        }
+    }
+
+    if is_fluid {
+        let mut fluid: Vec<usize> = (0..tracks.len()).filter(|i| fills(&tracks[*i])).collect();
+
+        let mut remaining = available
+            - spacing * tracks.len().saturating_sub(1) as f32
+            - sizes
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !fluid.contains(i))
+                .map(|(_, size)| size)
+                .sum::<f32>();
+
+        // Filling tracks that would break their bounds are clamped, and the
+        // remaining space is distributed again among the rest
+        while !fluid.is_empty() {
+            let factors: f32 = fluid
+                .iter()
+                .map(|i| f32::from(tracks[*i].fill_factor()))
+                .sum();
+
+            let unit = remaining.max(0.0) / factors;
+
+            let clamped = fluid.iter().position(|i| {
+                let size = unit * f32::from(tracks[*i].fill_factor());
+
+                clamp(&tracks[*i], size, contents.single[*i]) != size
+            });
+
+            let Some(position) = clamped else {
+                for i in fluid {
+                    sizes[i] = unit * f32::from(tracks[i].fill_factor());
+                }
+
+                break;
+            };
+
+            let i = fluid.remove(position);
+            let size = unit * f32::from(tracks[i].fill_factor());
+
+            sizes[i] = clamp(&tracks[i], size, contents.single[i]);
+            remaining -= sizes[i];
+        }
+    }
+
+    sizes
+}
+
+/// Clamps the size of a track to the bounds of its [`Length`].
+fn clamp(track: &Length, size: f32, content: f32) -> f32 {
+    match track {
+        Length::Bounded { bounds, .. } => match bounds {
+            Bounds::Min(min) => size.max(*min),
+            Bounds::Max(max) => size.min(*max),
+            Bounds::Both { min, max } => size.min(*max).max(*min),
+        },
+        Length::Fluid(length::Constraint::Min(min)) => size.max(*min),
+        Length::Fluid(length::Constraint::Max) => size.min(content),
+        _ => size,
+    }
+}
+
+/// Returns the total size of a range of tracks, including the spacing
+/// between them.
+fn span(sizes: &[f32], range: Range<usize>, spacing: f32) -> f32 {
+    let tracks = range.len();
+
+    sizes[range].iter().sum::<f32>() + spacing * tracks.saturating_sub(1) as f32
+}
+
+/// Returns the offset of every track, given their sizes.
+fn offsets(sizes: &[f32], spacing: f32, start: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(start, |offset, size| {
+            let current = *offset;
+            *offset += size + spacing;
+
+            Some(current)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_rectangular_areas_are_ignored() {
+        let areas = Areas::parse(["a a b", "a . b"]);
+
+        assert_eq!(areas.get("a"), None);
+        assert_eq!(areas.get("b"), Some(Area::new(0, 2).span(2, 1)));
+        assert_eq!((areas.rows(), areas.columns()), (2, 3));
+    }
+
+    #[test]
+    fn auto_placement_skips_occupied_cells() {
+        let areas = Areas::parse(["header header", "sidebar main"]);
+
+        let placements = [
+            Placement::Auto {
+                rows: 1,
+                columns: 1,
+            },
+            Placement::from("header"),
+            Placement::Auto {
+                rows: 1,
+                columns: 2,
+            },
+            Placement::Area(Area::new(1, 1)),
+        ];
+
+        let areas = place(&placements, &areas, 0);
+
+        assert_eq!(
+            areas,
+            vec![
+                Area::new(1, 0),
+                Area::new(0, 0).span(1, 2),
+                Area::new(2, 0).span(1, 2),
+                Area::new(1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn fill_tracks_share_remaining_space() {
+        let tracks = [
+            Length::Fixed(100.0),
+            Length::Shrink,
+            Length::Fill,
+            Length::FillPortion(3).max(90.0),
+        ];
+
+        let mut contents = Contents::new(tracks.len());
+        contents.add(1..2, 50.0);
+
+        let sizes = sizes(&tracks, &contents, 10.0, 500.0, false);
+
+        assert_eq!(sizes, vec![100.0, 50.0, 230.0, 90.0]);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::layout::Direction;
 use crate::{Alignment, Padding, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
@@ -5,6 +6,8 @@ use crate::{Alignment, Padding, Point, Rectangle, Size, Vector};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    direction: Option<Direction>,
+    baselines: Option<(f32, f32)>,
 }
 
 impl Node {
@@ -23,15 +26,26 @@ impl Node {
                 height: size.height,
             },
             children,
+            direction: None,
+            baselines: None,
         }
     }
 
     /// Creates a new [`Node`] that wraps a single child with some [`Padding`].
+    ///
+    /// The [`Node`] inherits the baselines of the child.
     pub fn container(child: Self, padding: Padding) -> Self {
-        Self::with_children(
-            child.bounds.size().expand(padding),
-            vec![child.move_to(Point::new(padding.left, padding.top))],
-        )
+        let baselines = child
+            .baselines
+            .map(|(first, last)| (first + padding.top, last + padding.top));
+
+        Self {
+            baselines,
+            ..Self::with_children(
+                child.bounds.size().expand(padding),
+                vec![child.move_to(Point::new(padding.left, padding.top))],
+            )
+        }
     }
 
     /// Returns the [`Size`] of the [`Node`].
@@ -49,6 +63,92 @@ impl Node {
         &self.children
     }
 
+    /// Returns the distance from the top of the [`Node`] to the baseline of
+    /// its first line of text, if any.
+    pub fn baseline(&self) -> Option<f32> {
+        self.baselines.map(|(first, _last)| first)
+    }
+
+    /// Returns the distance from the top of the [`Node`] to the baseline of
+    /// its last line of text, if any.
+    pub fn last_baseline(&self) -> Option<f32> {
+        self.baselines.map(|(_first, last)| last)
+    }
+
+    /// Sets the baseline of the [`Node`]; measured from its top.
+    ///
+    /// The [`Node`] is assumed to have a single line of text. Use
+    /// [`with_baselines`](Self::with_baselines) otherwise.
+    pub fn with_baseline(self, baseline: f32) -> Self {
+        self.with_baselines(baseline, baseline)
+    }
+
+    /// Sets the first and last baselines of the [`Node`]; measured from its
+    /// top.
+    pub fn with_baselines(mut self, first: f32, last: f32) -> Self {
+        self.baselines = Some((first, last));
+        self
+    }
+
+    /// Sets the baselines of the [`Node`] to the ones of its children.
+    ///
+    /// The first baseline is taken from the first child with a baseline,
+    /// while the last baseline is taken from the last one.
+    pub fn with_children_baselines(mut self) -> Self {
+        let first = self
+            .children
+            .iter()
+            .find_map(|child| Some(child.bounds.y + child.baseline()?));
+
+        let last = self
+            .children
+            .iter()
+            .rev()
+            .find_map(|child| Some(child.bounds.y + child.last_baseline()?));
+
+        self.baselines = first.zip(last);
+        self
+    }
+
+    /// Returns the [`Direction`] of the [`Node`], if it was set explicitly.
+    pub fn direction(&self) -> Option<Direction> {
+        self.direction
+    }
+
+    /// Sets the [`Direction`] of the [`Node`] and its descendants.
+    ///
+    /// If the [`Direction`] is [`Direction::RightToLeft`], the descendants
+    /// of the [`Node`] are mirrored horizontally. Descendants that already
+    /// have a [`Direction`] are moved, but their contents are left untouched.
+    ///
+    /// Children are mirrored against the width of the contents of the
+    /// [`Node`]; so children wider than it, which are most likely
+    /// scrolled, are mirrored too.
+    pub fn directed(mut self, direction: Direction) -> Self {
+        if direction.is_rtl() {
+            self.mirror();
+        }
+
+        self.direction = Some(direction);
+        self
+    }
+
+    fn mirror(&mut self) {
+        let width = self
+            .children
+            .iter()
+            .map(|child| child.bounds.x + child.bounds.width)
+            .fold(self.bounds.width, f32::max);
+
+        for child in &mut self.children {
+            child.bounds.x = width - child.bounds.x - child.bounds.width;
+
+            if child.direction.is_none() {
+                child.mirror();
+            }
+        }
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(mut self, align_x: Alignment, align_y: Alignment, space: Size) -> Self {
         self.align_mut(align_x, align_y, space);
@@ -58,7 +158,7 @@ impl Node {
     /// Mutable reference version of [`Self::align`].
     pub fn align_mut(&mut self, align_x: Alignment, align_y: Alignment, space: Size) {
         match align_x {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline | Alignment::LastBaseline => {}
             Alignment::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -68,7 +168,7 @@ impl Node {
         }
 
         match align_y {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline | Alignment::LastBaseline => {}
             Alignment::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
@@ -103,3 +203,99 @@ impl Node {
         self.bounds += translation.into();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Node;
+    use crate::layout::Direction;
+    use crate::{Padding, Point, Size};
+
+    #[test]
+    fn right_to_left_mirrors_children() {
+        let row = Node::with_children(
+            Size::new(100.0, 10.0),
+            vec![
+                Node::new(Size::new(20.0, 10.0)),
+                Node::new(Size::new(30.0, 10.0)).move_to(Point::new(30.0, 0.0)),
+            ],
+        )
+        .directed(Direction::RightToLeft);
+
+        let xs: Vec<f32> = row.children().iter().map(|node| node.bounds().x).collect();
+
+        assert_eq!(xs, vec![80.0, 40.0]);
+        assert_eq!(row.direction(), Some(Direction::RightToLeft));
+    }
+
+    #[test]
+    fn nested_direction_is_preserved() {
+        let inner = Node::with_children(
+            Size::new(50.0, 10.0),
+            vec![Node::new(Size::new(10.0, 10.0))],
+        )
+        .directed(Direction::LeftToRight);
+
+        let outer = Node::with_children(Size::new(100.0, 10.0), vec![inner])
+            .directed(Direction::RightToLeft);
+
+        let inner = &outer.children()[0];
+
+        assert_eq!(inner.bounds().x, 50.0);
+        assert_eq!(inner.children()[0].bounds().x, 0.0);
+    }
+
+    #[test]
+    fn wide_children_are_mirrored_against_the_content_width() {
+        let scrollable = Node::with_children(
+            Size::new(100.0, 10.0),
+            vec![Node::with_children(
+                Size::new(300.0, 10.0),
+                vec![Node::new(Size::new(20.0, 10.0))],
+            )],
+        )
+        .directed(Direction::RightToLeft);
+
+        let content = &scrollable.children()[0];
+
+        assert_eq!(content.bounds().x, 0.0);
+        assert_eq!(content.children()[0].bounds().x, 280.0);
+
+        let row = Node::with_children(
+            Size::new(100.0, 10.0),
+            vec![
+                Node::new(Size::new(50.0, 10.0)),
+                Node::new(Size::new(100.0, 10.0)).move_to(Point::new(50.0, 0.0)),
+            ],
+        )
+        .directed(Direction::RightToLeft);
+
+        let xs: Vec<f32> = row.children().iter().map(|node| node.bounds().x).collect();
+
+        assert_eq!(xs, vec![100.0, 0.0]);
+    }
+
+    #[test]
+    fn baselines_are_inherited_from_children() {
+        let column = Node::with_children(
+            Size::new(100.0, 40.0),
+            vec![
+                Node::new(Size::new(100.0, 10.0)),
+                Node::new(Size::new(100.0, 10.0))
+                    .with_baseline(8.0)
+                    .move_to(Point::new(0.0, 10.0)),
+                Node::new(Size::new(100.0, 20.0))
+                    .with_baselines(8.0, 18.0)
+                    .move_to(Point::new(0.0, 20.0)),
+            ],
+        )
+        .with_children_baselines();
+
+        assert_eq!(column.baseline(), Some(18.0));
+        assert_eq!(column.last_baseline(), Some(38.0));
+
+        let container = Node::container(column, Padding::new(5.0));
+
+        assert_eq!(container.baseline(), Some(23.0));
+        assert_eq!(container.last_baseline(), Some(43.0));
+    }
+}
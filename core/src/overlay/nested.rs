@@ -9,6 +9,7 @@ use crate::{Event, Layout, Shell, Size};
 /// An overlay container that displays nested overlays
 pub struct Nested<'a, Message, Theme, Renderer> {
     overlay: overlay::Element<'a, Message, Theme, Renderer>,
+    direction: layout::Direction,
 }
 
 impl<'a, Message, Theme, Renderer> Nested<'a, Message, Theme, Renderer>
@@ -17,7 +18,22 @@ where
 {
     /// Creates a nested overlay from the provided [`overlay::Element`]
     pub fn new(element: overlay::Element<'a, Message, Theme, Renderer>) -> Self {
-        Self { overlay: element }
+        Self {
+            overlay: element,
+            direction: layout::Direction::LeftToRight,
+        }
+    }
+
+    /// Sets the layout [`Direction`](layout::Direction) of the [`Nested`]
+    /// overlay.
+    ///
+    /// If it is [`RightToLeft`](layout::Direction::RightToLeft), the contents
+    /// of each overlay are mirrored horizontally. Their positions are kept,
+    /// since they are already relative to the mirrored layout of the widgets
+    /// that produced them.
+    pub fn directed(mut self, direction: layout::Direction) -> Self {
+        self.direction = direction;
+        self
     }
 
     /// Returns the layout [`Node`] of the [`Nested`] overlay.
@@ -28,6 +44,7 @@ where
             element: &mut overlay::Element<'_, Message, Theme, Renderer>,
             renderer: &Renderer,
             bounds: Size,
+            direction: layout::Direction,
         ) -> layout::Node
         where
             Renderer: renderer::Renderer,
@@ -35,19 +52,31 @@ where
             let overlay = element.as_overlay_mut();
             let node = overlay.layout(renderer, bounds);
 
+            // Nodes with a direction come from another `Nested` overlay
+            let node = if node.direction().is_none() {
+                node.directed(direction)
+            } else {
+                node
+            };
+
             let nested_node = overlay
                 .overlay(Layout::new(&node), renderer)
                 .as_mut()
-                .map(|nested| recurse(nested, renderer, bounds));
+                .map(|nested| recurse(nested, renderer, bounds, direction));
 
-            if let Some(nested_node) = nested_node {
-                layout::Node::with_children(node.size(), vec![node, nested_node])
+            let children = if let Some(nested_node) = nested_node {
+                vec![node, nested_node]
             } else {
-                layout::Node::with_children(node.size(), vec![node])
-            }
+                vec![node]
+            };
+
+            // Overlays are positioned absolutely; so they must never be
+            // mirrored against each other
+            layout::Node::with_children(children[0].size(), children)
+                .directed(layout::Direction::LeftToRight)
         }
 
-        recurse(&mut self.overlay, renderer, bounds)
+        recurse(&mut self.overlay, renderer, bounds, self.direction)
     }
 
     /// Draws the [`Nested`] overlay using the associated `Renderer`.
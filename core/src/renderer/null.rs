@@ -1,5 +1,6 @@
 use crate::alignment;
 use crate::image::{self, Image};
+use crate::layout;
 use crate::renderer::{self, Renderer};
 use crate::svg;
 use crate::text::{self, Text};
@@ -160,6 +161,38 @@ impl text::Paragraph for () {
     fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
         vec![]
     }
+
+    fn span_baseline(&self, _index: usize) -> Option<f32> {
+        None
+    }
+
+    fn baseline(&self) -> Option<f32> {
+        None
+    }
+
+    fn last_baseline(&self) -> Option<f32> {
+        None
+    }
+
+    fn hit_position(&self, _point: Point) -> Option<text::paragraph::Position> {
+        None
+    }
+
+    fn range_bounds(
+        &self,
+        _start: text::paragraph::Position,
+        _end: text::paragraph::Position,
+    ) -> Vec<Rectangle> {
+        vec![]
+    }
+
+    fn line(&self, _index: usize) -> Option<&str> {
+        None
+    }
+
+    fn direction(&self) -> layout::Direction {
+        layout::Direction::LeftToRight
+    }
 }
 
 impl text::Editor for () {
@@ -167,6 +200,12 @@ impl text::Editor for () {
 
     fn with_text(_text: &str) -> Self {}
 
+    fn with_storage(_text: &str, _storage: text::editor::Storage) -> Self {}
+
+    fn storage(&self) -> text::editor::Storage {
+        text::editor::Storage::default()
+    }
+
     fn is_empty(&self) -> bool {
         true
     }
@@ -186,6 +225,22 @@ impl text::Editor for () {
         None
     }
 
+    fn region(
+        &self,
+        _start: text::editor::Position,
+        _end: text::editor::Position,
+    ) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    fn position_at(&self, _point: Point) -> Option<text::editor::Position> {
+        None
+    }
+
+    fn underlines(&self) -> Vec<(Rectangle, text::highlighter::Underline)> {
+        Vec::new()
+    }
+
     fn line(&self, _index: usize) -> Option<text::editor::Line<'_>> {
         None
     }
@@ -194,6 +249,10 @@ impl text::Editor for () {
         0
     }
 
+    fn text(&self) -> String {
+        String::new()
+    }
+
     fn perform(&mut self, _action: text::editor::Action) {}
 
     fn move_to(&mut self, _cursor: text::editor::Cursor) {}
@@ -1,5 +1,6 @@
 //! Configure your application.
 use crate::backend;
+use crate::layout;
 use crate::renderer;
 use crate::{Backend, Font, Pixels};
 
@@ -60,6 +61,11 @@ pub struct Settings {
     ///
     /// By default, it is enabled.
     pub vsync: bool,
+
+    /// The layout [`Direction`](layout::Direction) of the user interface.
+    ///
+    /// By default, it is [`Direction::LeftToRight`](layout::Direction::LeftToRight).
+    pub direction: layout::Direction,
 }
 
 impl Default for Settings {
@@ -76,6 +82,7 @@ impl Default for Settings {
             power_preference: backend::PowerPreference::None,
             antialiasing: true,
             vsync: true,
+            direction: layout::Direction::LeftToRight,
         }
     }
 }
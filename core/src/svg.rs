@@ -29,6 +29,10 @@ pub struct Svg<H = Handle> {
     ///
     /// 0 means transparent. 1 means opaque.
     pub opacity: f32,
+
+    /// The [`Stylesheet`] overrides to be applied to the elements
+    /// of the [`Svg`] before rasterizing it.
+    pub stylesheet: Stylesheet,
 }
 
 impl Svg<Handle> {
@@ -39,6 +43,7 @@ impl Svg<Handle> {
             color: None,
             rotation: Radians(0.0),
             opacity: 1.0,
+            stylesheet: Stylesheet::default(),
         }
     }
 
@@ -59,6 +64,12 @@ impl Svg<Handle> {
         self.opacity = opacity.into();
         self
     }
+
+    /// Sets the [`Stylesheet`] of the [`Svg`].
+    pub fn stylesheet(mut self, stylesheet: impl Into<Stylesheet>) -> Self {
+        self.stylesheet = stylesheet.into();
+        self
+    }
 }
 
 impl From<&Handle> for Svg {
@@ -147,6 +158,231 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// A set of style overrides for the elements of an [`Svg`].
+///
+/// A [`Stylesheet`] is injected as CSS into the SVG document when it
+/// is parsed; which allows changing the colors and the visibility of
+/// specific elements at runtime. This way, a single SVG asset can be
+/// used to display multiple states (e.g. an icon with an active badge).
+///
+/// Every declaration produced by the builder methods is marked as
+/// `!important`, so it takes precedence over the styles of the document.
+///
+/// # Example
+/// ```
+/// use iced_core::svg::{Selector, Stylesheet};
+/// use iced_core::Color;
+///
+/// let stylesheet = Stylesheet::new()
+///     .fill(Selector::id("badge"), Color::from_rgb8(0xFF, 0x00, 0x00))
+///     .stroke(Selector::class("outline"), Color::BLACK)
+///     .hide(Selector::id("check"));
+///
+/// assert!(stylesheet.as_str().contains("#badge"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Stylesheet {
+    css: Arc<String>,
+    id: u64,
+}
+
+impl Stylesheet {
+    /// Creates a new empty [`Stylesheet`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the fill [`Color`] of the elements matching the given [`Selector`].
+    pub fn fill(self, selector: impl Into<Selector>, color: impl Into<Color>) -> Self {
+        self.paint("fill", selector.into(), color.into())
+    }
+
+    /// Sets the stroke [`Color`] of the elements matching the given [`Selector`].
+    pub fn stroke(self, selector: impl Into<Selector>, color: impl Into<Color>) -> Self {
+        self.paint("stroke", selector.into(), color.into())
+    }
+
+    /// Sets the opacity of the elements matching the given [`Selector`].
+    ///
+    /// 0 means transparent. 1 means opaque.
+    pub fn opacity(self, selector: impl Into<Selector>, opacity: f32) -> Self {
+        self.rule(
+            selector.into(),
+            &[("opacity", opacity.clamp(0.0, 1.0).to_string())],
+        )
+    }
+
+    /// Hides the elements matching the given [`Selector`].
+    pub fn hide(self, selector: impl Into<Selector>) -> Self {
+        self.visible(selector, false)
+    }
+
+    /// Shows the elements matching the given [`Selector`]; even if
+    /// they are hidden by the document itself.
+    pub fn show(self, selector: impl Into<Selector>) -> Self {
+        self.visible(selector, true)
+    }
+
+    /// Sets whether the elements matching the given [`Selector`] are visible.
+    pub fn visible(self, selector: impl Into<Selector>, is_visible: bool) -> Self {
+        let display = if is_visible { "inline" } else { "none" };
+
+        self.rule(selector.into(), &[("display", display.to_owned())])
+    }
+
+    /// Appends some raw CSS to the [`Stylesheet`].
+    ///
+    /// Unlike the rest of the builder methods, the raw CSS is left untouched;
+    /// you will need to use `!important` to override the internal styles of
+    /// the document.
+    pub fn css(self, css: impl AsRef<str>) -> Self {
+        self.append(|buffer| {
+            buffer.push_str(css.as_ref());
+            buffer.push('\n');
+        })
+    }
+
+    /// Returns true if the [`Stylesheet`] has no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.css.is_empty()
+    }
+
+    /// Returns the CSS of the [`Stylesheet`].
+    pub fn as_str(&self) -> &str {
+        &self.css
+    }
+
+    /// Returns a hash identifying the contents of the [`Stylesheet`].
+    ///
+    /// An empty [`Stylesheet`] is always identified by `0`.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn paint(self, property: &str, selector: Selector, color: Color) -> Self {
+        let [r, g, b, _] = color.into_rgba8();
+
+        self.rule(
+            selector,
+            &[
+                (property, format!("#{r:02x}{g:02x}{b:02x}")),
+                (&format!("{property}-opacity"), color.a.to_string()),
+            ],
+        )
+    }
+
+    fn rule(self, selector: Selector, declarations: &[(&str, String)]) -> Self {
+        use std::fmt::Write;
+
+        self.append(|buffer| {
+            let _ = write!(buffer, "{selector} {{");
+
+            for (property, value) in declarations {
+                let _ = write!(buffer, " {property}: {value} !important;");
+            }
+
+            buffer.push_str(" }\n");
+        })
+    }
+
+    fn append(mut self, f: impl FnOnce(&mut String)) -> Self {
+        f(Arc::make_mut(&mut self.css));
+
+        self.id = if self.css.is_empty() {
+            0
+        } else {
+            let mut hasher = FxHasher::default();
+            self.css.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        self
+    }
+}
+
+/// The elements targeted by a rule of a [`Stylesheet`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Selector {
+    /// Matches the element with the given `id`.
+    Id(String),
+
+    /// Matches every element with the given `class`.
+    Class(String),
+
+    /// A raw CSS selector (e.g. `g#eyes > circle`).
+    Css(String),
+}
+
+impl Selector {
+    /// Creates a [`Selector`] matching the element with the given `id`.
+    pub fn id(id: impl Into<String>) -> Self {
+        Self::Id(id.into())
+    }
+
+    /// Creates a [`Selector`] matching every element with the given `class`.
+    pub fn class(class: impl Into<String>) -> Self {
+        Self::Class(class.into())
+    }
+}
+
+impl From<&str> for Selector {
+    fn from(selector: &str) -> Self {
+        Self::Css(selector.to_owned())
+    }
+}
+
+impl From<String> for Selector {
+    fn from(selector: String) -> Self {
+        Self::Css(selector)
+    }
+}
+
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Selector::Id(id) => {
+                f.write_str("#")?;
+                escape_identifier(id, f)
+            }
+            Selector::Class(class) => {
+                f.write_str(".")?;
+                escape_identifier(class, f)
+            }
+            Selector::Css(selector) => f.write_str(selector),
+        }
+    }
+}
+
+/// Escapes a CSS identifier, following the "serialize an identifier"
+/// algorithm of the CSSOM specification.
+fn escape_identifier(identifier: &str, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    use std::fmt::Write;
+
+    if identifier == "-" {
+        return f.write_str("\\-");
+    }
+
+    let starts_with_hyphen = identifier.starts_with('-');
+
+    for (i, c) in identifier.chars().enumerate() {
+        match c {
+            '\0' => f.write_char('\u{FFFD}')?,
+            '\u{1}'..='\u{1F}' | '\u{7F}' => write!(f, "\\{:x} ", c as u32)?,
+            '0'..='9' if i == 0 || (i == 1 && starts_with_hyphen) => {
+                write!(f, "\\{:x} ", c as u32)?;
+            }
+            '-' | '_' | '0'..='9' | 'a'..='z' | 'A'..='Z' | '\u{80}'.. => f.write_char(c)?,
+            _ => {
+                f.write_char('\\')?;
+                f.write_char(c)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// A [`Renderer`] that can render vector graphics.
 ///
 /// [renderer]: crate::renderer
@@ -157,3 +393,46 @@ pub trait Renderer: crate::Renderer {
     /// Draws an SVG with the given [`Handle`], an optional [`Color`] filter, and inside the provided `bounds`.
     fn draw_svg(&mut self, svg: Svg, bounds: Rectangle, clip_bounds: Rectangle);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stylesheet_builder_output() {
+        let stylesheet = Stylesheet::new()
+            .fill(Selector::id("badge"), Color::from_rgb8(0xFF, 0x00, 0x00))
+            .opacity(Selector::class("outline"), 0.5)
+            .hide("g > circle");
+
+        assert_eq!(
+            stylesheet.as_str(),
+            "#badge { fill: #ff0000 !important; fill-opacity: 1 !important; }\n\
+             .outline { opacity: 0.5 !important; }\n\
+             g > circle { display: none !important; }\n"
+        );
+    }
+
+    #[test]
+    fn stylesheet_id_changes_with_contents() {
+        let empty = Stylesheet::new();
+        let hidden = Stylesheet::new().hide(Selector::id("check"));
+        let shown = Stylesheet::new().show(Selector::id("check"));
+
+        assert_eq!(empty.id(), 0);
+        assert_ne!(hidden.id(), 0);
+        assert_ne!(hidden.id(), shown.id());
+        assert_eq!(hidden.id(), hidden.clone().id());
+    }
+
+    #[test]
+    fn selectors_escape_identifiers() {
+        assert_eq!(Selector::id("a{b}").to_string(), "#a\\{b\\}");
+        assert_eq!(Selector::class("x;y").to_string(), ".x\\;y");
+        assert_eq!(Selector::class("1st").to_string(), ".\\31 st");
+        assert_eq!(Selector::id("-2").to_string(), "#-\\32 ");
+        assert_eq!(Selector::id("-").to_string(), "#\\-");
+        assert_eq!(Selector::class("icon_ok-2").to_string(), ".icon_ok-2");
+        assert_eq!(Selector::from("g > *").to_string(), "g > *");
+    }
+}
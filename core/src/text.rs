@@ -1,9 +1,11 @@
 //! Draw and interact with text.
 pub mod editor;
+pub mod effects;
 pub mod highlighter;
 pub mod paragraph;
 
 pub use editor::Editor;
+pub use effects::{Effects, Outline};
 pub use highlighter::Highlighter;
 pub use paragraph::Paragraph;
 
@@ -123,7 +125,9 @@ impl From<alignment::Horizontal> for Alignment {
 impl From<crate::Alignment> for Alignment {
     fn from(alignment: crate::Alignment) -> Self {
         match alignment {
-            crate::Alignment::Start => Self::Left,
+            crate::Alignment::Start
+            | crate::Alignment::Baseline
+            | crate::Alignment::LastBaseline => Self::Left,
             crate::Alignment::Center => Self::Center,
             crate::Alignment::End => Self::Right,
         }
@@ -382,6 +386,24 @@ pub trait Renderer: crate::Renderer {
         clip_bounds: Rectangle,
     );
 
+    /// Draws the given [`Paragraph`] at the given position and with the given
+    /// [`Color`] and [`Effects`].
+    ///
+    /// By default, the [`Effects`] are ignored and the [`Paragraph`] is drawn
+    /// with [`fill_paragraph`](Self::fill_paragraph).
+    fn fill_paragraph_with_effects(
+        &mut self,
+        text: &Self::Paragraph,
+        position: Point,
+        color: Color,
+        effects: &Effects,
+        clip_bounds: Rectangle,
+    ) {
+        let _ = effects;
+
+        self.fill_paragraph(text, position, color, clip_bounds);
+    }
+
     /// Draws the given [`Editor`] at the given position and with the given
     /// [`Color`].
     fn fill_editor(
@@ -426,8 +448,20 @@ pub struct Span<'a, Link = (), Font = crate::Font> {
     pub padding: Padding,
     /// Whether the [`Span`] should be underlined or not.
     pub underline: bool,
+    /// The [`LineStyle`] of the underline of the [`Span`].
+    pub underline_style: LineStyle,
+    /// The [`Color`] of the underline of the [`Span`].
+    ///
+    /// By default, it is the [`Color`] of the [`Span`].
+    pub underline_color: Option<Color>,
     /// Whether the [`Span`] should be struck through or not.
     pub strikethrough: bool,
+    /// The [`Size`] of the inline element taking the place of the [`Span`],
+    /// if any.
+    ///
+    /// The text of an inline [`Span`] is ignored; instead, it is laid out as
+    /// an invisible box that flows and wraps with the rest of the text.
+    pub inline: Option<Size>,
 }
 
 /// A text highlight.
@@ -566,12 +600,35 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
         self
     }
 
+    /// Underlines the [`Span`] with the given [`LineStyle`].
+    pub fn underline_style(mut self, style: LineStyle) -> Self {
+        self.underline = true;
+        self.underline_style = style;
+        self
+    }
+
+    /// Sets the [`Color`] of the underline of the [`Span`].
+    pub fn underline_color(mut self, color: impl Into<Color>) -> Self {
+        self.underline_color = Some(color.into());
+        self
+    }
+
     /// Sets whether the [`Span`] should be struck through or not.
     pub fn strikethrough(mut self, strikethrough: bool) -> Self {
         self.strikethrough = strikethrough;
         self
     }
 
+    /// Turns the [`Span`] into a placeholder for an inline element of the
+    /// given [`Size`].
+    ///
+    /// The text of an inline [`Span`] is ignored; instead, it is laid out as
+    /// an invisible box that flows and wraps with the rest of the text.
+    pub fn inline(mut self, size: impl Into<Size>) -> Self {
+        self.inline = Some(size.into());
+        self
+    }
+
     /// Turns the [`Span`] into a static one.
     pub fn to_static(self) -> Span<'static, Link, Font> {
         Span {
@@ -584,7 +641,10 @@ impl<'a, Link, Font> Span<'a, Link, Font> {
             highlight: self.highlight,
             padding: self.padding,
             underline: self.underline,
+            underline_style: self.underline_style,
+            underline_color: self.underline_color,
             strikethrough: self.strikethrough,
+            inline: self.inline,
         }
     }
 }
@@ -601,7 +661,10 @@ impl<Link, Font> Default for Span<'_, Link, Font> {
             highlight: None,
             padding: Padding::default(),
             underline: false,
+            underline_style: LineStyle::Solid,
+            underline_color: None,
             strikethrough: false,
+            inline: None,
         }
     }
 }
@@ -619,6 +682,35 @@ impl<Link, Font: PartialEq> PartialEq for Span<'_, Link, Font> {
             && self.line_height == other.line_height
             && self.font == other.font
             && self.color == other.color
+            && self.inline == other.inline
+    }
+}
+
+/// The style of a line drawn along some text; like an underline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineStyle {
+    /// A single straight line.
+    #[default]
+    Solid,
+    /// Two parallel straight lines.
+    Double,
+    /// A line of dots.
+    Dotted,
+    /// A line of dashes.
+    Dashed,
+    /// A wavy line.
+    Wavy,
+}
+
+impl LineStyle {
+    /// Returns the total height of a line with this [`LineStyle`] and the
+    /// given thickness.
+    pub fn height(self, thickness: f32) -> f32 {
+        match self {
+            Self::Solid | Self::Dotted | Self::Dashed => thickness,
+            Self::Wavy => thickness * 2.0,
+            Self::Double => thickness * 3.0,
+        }
     }
 }
 
@@ -1,5 +1,5 @@
 //! Edit text.
-use crate::text::highlighter::{self, Highlighter};
+use crate::text::highlighter::{self, Highlighter, Underline};
 use crate::text::{LineHeight, Wrapping};
 use crate::{Pixels, Point, Rectangle, Size};
 
@@ -14,6 +14,13 @@ pub trait Editor: Sized + Default {
     /// Creates a new [`Editor`] laid out with the given text.
     fn with_text(text: &str) -> Self;
 
+    /// Creates a new [`Editor`] with the given text, using the given
+    /// [`Storage`].
+    fn with_storage(text: &str, storage: Storage) -> Self;
+
+    /// Returns the [`Storage`] of the [`Editor`].
+    fn storage(&self) -> Storage;
+
     /// Returns true if the [`Editor`] has no contents.
     fn is_empty(&self) -> bool;
 
@@ -26,12 +33,34 @@ pub trait Editor: Sized + Default {
     /// Returns the current selected text of the [`Editor`].
     fn copy(&self) -> Option<String>;
 
+    /// Returns the regions covered by the text between the given positions,
+    /// relative to the [`Editor`].
+    ///
+    /// If both positions are equal, a single region with no width is
+    /// returned; like a caret.
+    fn region(&self, start: Position, end: Position) -> Vec<Rectangle>;
+
+    /// Returns the [`Position`] of the text at the given [`Point`], relative
+    /// to the [`Editor`]; if any.
+    fn position_at(&self, point: Point) -> Option<Position>;
+
+    /// Returns the regions of text that have an [`Underline`], relative to
+    /// the [`Editor`].
+    ///
+    /// They are produced by the last [`highlight`] of the visible lines.
+    ///
+    /// [`highlight`]: Self::highlight
+    fn underlines(&self) -> Vec<(Rectangle, Underline)>;
+
     /// Returns the text of the given line in the [`Editor`], if it exists.
     fn line(&self, index: usize) -> Option<Line<'_>>;
 
     /// Returns the amount of lines in the [`Editor`].
     fn line_count(&self) -> usize;
 
+    /// Returns the whole text of the [`Editor`].
+    fn text(&self) -> String;
+
     /// Performs an [`Action`] on the [`Editor`].
     fn perform(&mut self, action: Action);
 
@@ -69,6 +98,25 @@ pub trait Editor: Sized + Default {
     );
 }
 
+/// The storage strategy of an [`Editor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Storage {
+    /// The whole text is shaped and laid out.
+    ///
+    /// This is the best choice for most texts.
+    #[default]
+    Full,
+    /// The text is stored in a rope, and only the lines around the visible
+    /// ones are shaped and laid out.
+    ///
+    /// The height of the text is estimated, assuming every line fits in a
+    /// single visual line.
+    ///
+    /// This is the best choice for very large texts; like logs or datasets
+    /// with millions of lines.
+    Lazy,
+}
+
 /// An interaction with an [`Editor`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Action {
@@ -82,6 +130,9 @@ pub enum Action {
     SelectLine,
     /// Select the entire buffer.
     SelectAll,
+    /// Move to the given [`Cursor`]; selecting any text between its
+    /// position and its selection.
+    MoveTo(Cursor),
     /// Perform an [`Edit`].
     Edit(Edit),
     /// Click the [`Editor`] at the given [`Point`].
@@ -0,0 +1,118 @@
+//! Decorate text with shadows, outlines, and gradients.
+use crate::{Color, Gradient, Rectangle, Shadow};
+
+/// The visual effects of some text.
+///
+/// Effects are drawn by a [`Renderer`](super::Renderer) back to front: first
+/// the [`Shadow`], then the [`Outline`], and finally the fill of the glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Effects {
+    /// The [`Shadow`] cast by the text, if any.
+    pub shadow: Option<Shadow>,
+    /// The [`Outline`] around the glyphs of the text, if any.
+    pub outline: Option<Outline>,
+    /// The [`Gradient`] used to fill the glyphs of the text, if any.
+    ///
+    /// When set, it takes precedence over the color of the text.
+    pub gradient: Option<Gradient>,
+}
+
+impl Effects {
+    /// No effects at all.
+    pub const NONE: Self = Self {
+        shadow: None,
+        outline: None,
+        gradient: None,
+    };
+
+    /// Returns true if there are no [`Effects`] to apply.
+    pub fn is_none(&self) -> bool {
+        self.shadow.is_none() && self.outline.is_none() && self.gradient.is_none()
+    }
+
+    /// Sets the [`Shadow`] of the [`Effects`].
+    pub fn shadow(mut self, shadow: impl Into<Shadow>) -> Self {
+        self.shadow = Some(shadow.into());
+        self
+    }
+
+    /// Sets the [`Outline`] of the [`Effects`].
+    pub fn outline(mut self, outline: impl Into<Outline>) -> Self {
+        self.outline = Some(outline.into());
+        self
+    }
+
+    /// Sets the [`Gradient`] fill of the [`Effects`].
+    pub fn gradient(mut self, gradient: impl Into<Gradient>) -> Self {
+        self.gradient = Some(gradient.into());
+        self
+    }
+
+    /// Returns the region covered by some text with the [`Effects`], given
+    /// the bounds of its glyphs.
+    pub fn bounds(&self, bounds: Rectangle) -> Rectangle {
+        let outline = self
+            .outline
+            .map(|outline| outline.width.max(0.0))
+            .unwrap_or(0.0);
+
+        let Some(shadow) = self.shadow else {
+            return bounds.expand(outline);
+        };
+
+        let shadow_bounds =
+            (bounds + shadow.offset).expand(outline + shadow.blur_radius.max(0.0) * 1.5);
+
+        bounds.expand(outline).union(&shadow_bounds)
+    }
+}
+
+/// The outline of some glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Outline {
+    /// The [`Color`] of the outline.
+    pub color: Color,
+    /// The width of the outline, in logical pixels.
+    pub width: f32,
+}
+
+impl Outline {
+    /// Creates a new [`Outline`] with the given [`Color`] and width.
+    pub fn new(color: impl Into<Color>, width: f32) -> Self {
+        Self {
+            color: color.into(),
+            width,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Point, Size, Vector};
+
+    #[test]
+    fn bounds_cover_outline_and_shadow() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 20.0));
+
+        assert_eq!(Effects::NONE.bounds(bounds), bounds);
+
+        let outlined = Effects::default().outline(Outline::new(Color::BLACK, 2.0));
+
+        assert_eq!(
+            outlined.bounds(bounds),
+            Rectangle::new(Point::new(-2.0, -2.0), Size::new(104.0, 24.0))
+        );
+
+        let shadowed = outlined.shadow(Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(10.0, 4.0),
+            blur_radius: 2.0,
+        });
+
+        assert_eq!(
+            shadowed.bounds(bounds),
+            Rectangle::new(Point::new(-2.0, -2.0), Size::new(117.0, 31.0))
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Highlight text.
 use crate::Color;
+use crate::text::LineStyle;
+use crate::text::editor::Position;
 
 use std::ops::Range;
 
@@ -29,6 +31,37 @@ pub trait Highlighter: 'static {
     /// Notifies the [`Highlighter`] that the line at the given index has changed.
     fn change_line(&mut self, line: usize);
 
+    /// Notifies the [`Highlighter`] that the text between the given positions
+    /// has been replaced with some new text.
+    ///
+    /// The positions are relative to the text before the edit, and their
+    /// columns are byte offsets. Edits are always followed by a call to
+    /// [`change_line`](Self::change_line).
+    ///
+    /// It is only useful for highlighters that keep a copy of the whole text,
+    /// so they can update it without going through every line in [`prepare`].
+    /// By default, it does nothing.
+    ///
+    /// [`prepare`]: Self::prepare
+    fn edit(&mut self, range: Range<Position>, text: &str) {
+        let _ = (range, text);
+    }
+
+    /// Prepares the [`Highlighter`] to highlight some lines, given every line
+    /// of the text.
+    ///
+    /// This is called before a sequence of [`highlight_line`] calls; which
+    /// starts at the [`current_line`].
+    ///
+    /// It is only useful for highlighters that need the whole text at
+    /// once; like those building a syntax tree. By default, it does nothing.
+    ///
+    /// [`highlight_line`]: Self::highlight_line
+    /// [`current_line`]: Self::current_line
+    fn prepare<'a>(&mut self, lines: impl Iterator<Item = &'a str>) {
+        let _ = lines;
+    }
+
     /// Highlights the given line.
     ///
     /// If a line changed prior to this, the first line provided here will be the
@@ -76,6 +109,8 @@ pub struct Format<Font> {
     pub color: Option<Color>,
     /// The `Font` of the text.
     pub font: Option<Font>,
+    /// The [`Underline`] of the text.
+    pub underline: Option<Underline>,
 }
 
 impl<Font> Default for Format<Font> {
@@ -83,6 +118,48 @@ impl<Font> Default for Format<Font> {
         Self {
             color: None,
             font: None,
+            underline: None,
+        }
+    }
+}
+
+/// The underline of some text.
+///
+/// It can be used to point out diagnostics; like errors or warnings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Underline {
+    /// A straight line of the given [`Color`].
+    Solid(Color),
+    /// A wavy line of the given [`Color`].
+    Squiggly(Color),
+    /// A dotted line of the given [`Color`].
+    Dotted(Color),
+    /// A dashed line of the given [`Color`].
+    Dashed(Color),
+    /// Two straight lines of the given [`Color`].
+    Double(Color),
+}
+
+impl Underline {
+    /// Returns the [`Color`] of the [`Underline`].
+    pub fn color(self) -> Color {
+        match self {
+            Self::Solid(color)
+            | Self::Squiggly(color)
+            | Self::Dotted(color)
+            | Self::Dashed(color)
+            | Self::Double(color) => color,
+        }
+    }
+
+    /// Returns the [`LineStyle`] of the [`Underline`].
+    pub fn style(self) -> LineStyle {
+        match self {
+            Self::Solid(_) => LineStyle::Solid,
+            Self::Squiggly(_) => LineStyle::Wavy,
+            Self::Dotted(_) => LineStyle::Dotted,
+            Self::Dashed(_) => LineStyle::Dashed,
+            Self::Double(_) => LineStyle::Double,
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Draw paragraphs.
 use crate::alignment;
+use crate::layout::Direction;
 use crate::text::{
     Alignment, Difference, Ellipsis, Hit, LineHeight, Shaping, Span, Text, Wrapping,
 };
@@ -70,9 +71,41 @@ pub trait Paragraph: Sized + Default {
     /// A [`Span`] can have multiple bounds for each line it's on.
     fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
 
+    /// Returns the baseline of the first line of the provided [`Span`] index
+    /// of the [`Paragraph`], relative to its top.
+    fn span_baseline(&self, index: usize) -> Option<f32>;
+
+    /// Returns the baseline of the first line of the [`Paragraph`], relative
+    /// to its top.
+    fn baseline(&self) -> Option<f32>;
+
+    /// Returns the baseline of the last line of the [`Paragraph`], relative
+    /// to its top.
+    fn last_baseline(&self) -> Option<f32>;
+
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
+    /// Tests whether the provided point is within the boundaries of the
+    /// [`Paragraph`], returning the [`Position`] of the nearest character.
+    ///
+    /// Unlike [`hit_test`](Self::hit_test), the resulting [`Position`]
+    /// includes the line of the character.
+    fn hit_position(&self, point: Point) -> Option<Position>;
+
+    /// Returns the bounds of the text between the given [`Position`]s.
+    /// The text can span multiple lines, producing one [`Rectangle`] per
+    /// line.
+    fn range_bounds(&self, start: Position, end: Position) -> Vec<Rectangle>;
+
+    /// Returns the contents of the given line of the [`Paragraph`], if it
+    /// exists.
+    fn line(&self, index: usize) -> Option<&str>;
+
+    /// Returns the base [`Direction`] of the text of the [`Paragraph`];
+    /// given by its first line.
+    fn direction(&self) -> Direction;
+
     /// Returns the minimum width that can fit the contents of the [`Paragraph`].
     fn min_width(&self) -> f32 {
         self.min_bounds().width
@@ -84,6 +117,15 @@ pub trait Paragraph: Sized + Default {
     }
 }
 
+/// The position of a character in a [`Paragraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    /// The index of the line of the character.
+    pub line: usize,
+    /// The byte index of the character in its line.
+    pub index: usize,
+}
+
 /// A [`Paragraph`] of plain text.
 #[derive(Debug, Clone, Default)]
 pub struct Plain<P: Paragraph> {
@@ -138,6 +138,18 @@ where
     fn is_void(&self) -> bool {
         false
     }
+
+    /// Returns the [`flex::Item`] properties of the [`Widget`], if any.
+    ///
+    /// Flex layouts—like a row or a column—use them to size the [`Widget`]
+    /// in their main axis, instead of its [`Length`].
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`flex::Item`]: layout::flex::Item
+    fn flex(&self) -> Option<layout::flex::Item> {
+        None
+    }
 }
 
 /// A zero-sized [`Widget`] that does nothing and will be filtered out by containers.
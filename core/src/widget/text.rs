@@ -20,16 +20,23 @@
 //!         .into()
 //! }
 //! ```
+pub mod selection;
+
+pub use selection::Selection;
+
 use crate::alignment;
+use crate::border;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::text::paragraph::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
-use crate::{Color, Element, Layout, Length, Pixels, Rectangle, Size, Theme, Widget};
+use crate::{
+    Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Theme, Widget,
+};
 
-pub use text::{Alignment, Ellipsis, LineHeight, Shaping, Wrapping};
+pub use text::{Alignment, Effects, Ellipsis, LineHeight, LineStyle, Outline, Shaping, Wrapping};
 
 /// A bunch of text.
 ///
@@ -62,6 +69,7 @@ where
     fragment: text::Fragment<'a>,
     format: Format<Renderer::Font>,
     class: Theme::Class<'a>,
+    selectable: bool,
 }
 
 impl<'a, Theme, Renderer> Text<'a, Theme, Renderer>
@@ -75,6 +83,7 @@ where
             fragment: fragment.into_fragment(),
             format: Format::default(),
             class: Theme::default(),
+            selectable: false,
         }
     }
 
@@ -154,6 +163,16 @@ where
         self
     }
 
+    /// Sets whether the contents of the [`Text`] can be selected and copied.
+    ///
+    /// A selectable [`Text`] can be selected by dragging the mouse over it,
+    /// double clicking a word, or triple clicking a line. Its selection
+    /// can be copied to the clipboard with the usual shortcut.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     /// Sets the style of the [`Text`].
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
     where
@@ -178,7 +197,11 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            selection: None,
+            effects: Effects::NONE,
+        })
     }
 
     /// Sets the style class of the [`Text`].
@@ -192,17 +215,32 @@ where
 /// The internal state of a [`Text`] widget.
 pub type State<P> = paragraph::Plain<P>;
 
+/// The internal state of a selectable [`Text`] widget.
+#[derive(Default)]
+struct Selectable<P: Paragraph> {
+    paragraph: paragraph::Plain<P>,
+    selection: Selection,
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Text<'_, Theme, Renderer>
 where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State<Renderer::Paragraph>>()
+        if self.selectable {
+            tree::Tag::of::<Selectable<Renderer::Paragraph>>()
+        } else {
+            tree::Tag::of::<State<Renderer::Paragraph>>()
+        }
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(paragraph::Plain::<Renderer::Paragraph>::default())
+        if self.selectable {
+            tree::State::new(Selectable::<Renderer::Paragraph>::default())
+        } else {
+            tree::State::new(paragraph::Plain::<Renderer::Paragraph>::default())
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -218,13 +256,19 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
-            tree.state.downcast_mut::<State<Renderer::Paragraph>>(),
-            renderer,
-            limits,
-            &self.fragment,
-            self.format,
-        )
+        let paragraph = if self.selectable {
+            let state = tree.state.downcast_mut::<Selectable<Renderer::Paragraph>>();
+
+            if state.paragraph.content() != self.fragment {
+                state.selection.clear();
+            }
+
+            &mut state.paragraph
+        } else {
+            tree.state.downcast_mut::<State<Renderer::Paragraph>>()
+        };
+
+        layout(paragraph, renderer, limits, &self.fragment, self.format)
     }
 
     fn draw(
@@ -237,27 +281,89 @@ where
         _cursor_position: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let style = theme.style(&self.class);
 
+        let paragraph = if self.selectable {
+            let state = tree.state.downcast_ref::<Selectable<Renderer::Paragraph>>();
+
+            state.selection.draw(
+                renderer,
+                state.paragraph.raw(),
+                layout.bounds(),
+                selection_color(defaults, style),
+            );
+
+            &state.paragraph
+        } else {
+            tree.state.downcast_ref::<State<Renderer::Paragraph>>()
+        };
+
         draw(
             renderer,
             defaults,
             layout.bounds(),
-            state.raw(),
+            paragraph.raw(),
             style,
             viewport,
         );
     }
 
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.selectable {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<Selectable<Renderer::Paragraph>>();
+
+        state
+            .selection
+            .update(state.paragraph.raw(), event, layout.bounds(), cursor, shell);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.selectable {
+            return mouse::Interaction::None;
+        }
+
+        let state = tree.state.downcast_ref::<Selectable<Renderer::Paragraph>>();
+
+        state.selection.mouse_interaction(layout.bounds(), cursor)
+    }
+
     fn operate(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn super::Operation,
     ) {
         operation.text(None, layout.bounds(), &self.fragment);
+
+        if self.selectable {
+            let state = tree.state.downcast_mut::<Selectable<Renderer::Paragraph>>();
+
+            operation.custom(None, layout.bounds(), &mut state.selection);
+
+            state
+                .selection
+                .resolve(state.paragraph.raw(), layout.bounds());
+        }
     }
 }
 
@@ -308,7 +414,7 @@ pub fn layout<Renderer>(
 where
     Renderer: text::Renderer,
 {
-    layout::sized(limits, format.width, format.height, |limits| {
+    let node = layout::sized(limits, format.width, format.height, |limits| {
         let bounds = limits.max();
 
         let size = format.size.unwrap_or_else(|| renderer.default_size());
@@ -329,7 +435,25 @@ where
         });
 
         paragraph.min_bounds()
-    })
+    });
+
+    with_baselines(node, paragraph.raw())
+}
+
+/// Sets the baselines of the given [`layout::Node`] to the ones of the
+/// given [`Paragraph`], as drawn by [`draw`].
+pub fn with_baselines<P: Paragraph>(node: layout::Node, paragraph: &P) -> layout::Node {
+    let (Some(first), Some(last)) = (paragraph.baseline(), paragraph.last_baseline()) else {
+        return node;
+    };
+
+    let anchor = Rectangle::with_size(node.size()).anchor(
+        paragraph.min_bounds(),
+        paragraph.align_x(),
+        paragraph.align_y(),
+    );
+
+    node.with_baselines(anchor.y + first, anchor.y + last)
 }
 
 /// Draws text using the same logic as the [`Text`] widget.
@@ -349,14 +473,112 @@ pub fn draw<Renderer>(
         paragraph.align_y(),
     );
 
-    renderer.fill_paragraph(
+    renderer.fill_paragraph_with_effects(
         paragraph,
         anchor,
         appearance.color.unwrap_or(style.text_color),
+        &appearance.effects,
         *viewport,
     );
 }
 
+/// Draws a horizontal line of the given [`LineStyle`], [`Color`], and
+/// thickness; like an underline.
+///
+/// The line starts at the given position and extends to the right by the
+/// given width and downwards by [`LineStyle::height`].
+pub fn draw_line<Renderer>(
+    renderer: &mut Renderer,
+    position: Point,
+    width: f32,
+    thickness: f32,
+    style: LineStyle,
+    color: Color,
+    clip_bounds: Rectangle,
+) where
+    Renderer: crate::Renderer,
+{
+    let mut fill = |bounds: Rectangle, radius: f32| {
+        if let Some(bounds) = clip_bounds.intersection(&bounds) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: border::rounded(radius),
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    };
+
+    let mut segments = |length: f32, gap: f32, y: &dyn Fn(usize) -> f32| {
+        let step = length + gap;
+        let steps = (width / step).ceil() as usize;
+
+        for i in 0..steps {
+            let x = position.x + i as f32 * step;
+
+            fill(
+                Rectangle {
+                    x,
+                    y: y(i),
+                    width: length.min(position.x + width - x),
+                    height: thickness,
+                },
+                0.0,
+            );
+        }
+    };
+
+    match style {
+        LineStyle::Solid => {
+            segments(width, 0.0, &|_| position.y);
+        }
+        LineStyle::Double => {
+            segments(width, 0.0, &|_| position.y);
+            segments(width, 0.0, &|_| position.y + thickness * 2.0);
+        }
+        LineStyle::Dotted => {
+            segments(thickness, thickness, &|_| position.y);
+        }
+        LineStyle::Dashed => {
+            segments(thickness * 3.0, thickness * 2.0, &|_| position.y);
+        }
+        LineStyle::Wavy => {
+            use std::f32::consts::TAU;
+
+            // A sine wave traced by overlapping round dots, with a peak
+            // to peak amplitude of one thickness
+            let wavelength = thickness * 4.0;
+            let step = (thickness / 2.0).max(1.0);
+            let steps = ((width - thickness) / step).ceil().max(0.0) as usize;
+
+            for i in 0..=steps {
+                let x = (thickness / 2.0 + i as f32 * step).min(width - thickness / 2.0);
+                let phase = TAU * x / wavelength;
+
+                fill(
+                    Rectangle {
+                        x: position.x + x - thickness / 2.0,
+                        y: position.y + thickness / 2.0 * (1.0 - phase.cos()),
+                        width: thickness,
+                        height: thickness,
+                    },
+                    thickness / 2.0,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the [`Color`] used to highlight the selected text of a [`Text`]
+/// widget with the given [`Style`].
+pub fn selection_color(defaults: &renderer::Style, style: Style) -> Color {
+    style
+        .selection
+        .unwrap_or_else(|| style.color.unwrap_or(defaults.text_color).scale_alpha(0.3))
+}
+
 impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -395,6 +617,13 @@ pub struct Style {
     ///
     /// The default, `None`, means using the inherited color.
     pub color: Option<Color>,
+    /// The [`Color`] of the highlight of selected text.
+    ///
+    /// The default, `None`, means using a translucent version
+    /// of the text color.
+    pub selection: Option<Color>,
+    /// The [`Effects`] of the text; like shadows, outlines, or gradients.
+    pub effects: Effects,
 }
 
 /// The theme catalog of a [`Text`].
@@ -428,13 +657,19 @@ impl Catalog for Theme {
 
 /// The default text styling; color is inherited.
 pub fn default(_theme: &Theme) -> Style {
-    Style { color: None }
+    Style {
+        color: None,
+        selection: None,
+        effects: Effects::NONE,
+    }
 }
 
 /// Text with the default base color.
 pub fn base(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().text),
+        selection: None,
+        effects: Effects::NONE,
     }
 }
 
@@ -442,6 +677,8 @@ pub fn base(theme: &Theme) -> Style {
 pub fn primary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().primary),
+        selection: None,
+        effects: Effects::NONE,
     }
 }
 
@@ -449,6 +686,8 @@ pub fn primary(theme: &Theme) -> Style {
 pub fn secondary(theme: &Theme) -> Style {
     Style {
         color: Some(theme.palette().secondary.base.color),
+        selection: None,
+        effects: Effects::NONE,
     }
 }
 
@@ -456,6 +695,8 @@ pub fn secondary(theme: &Theme) -> Style {
 pub fn success(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().success),
+        selection: None,
+        effects: Effects::NONE,
     }
 }
 
@@ -463,6 +704,8 @@ pub fn success(theme: &Theme) -> Style {
 pub fn warning(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().warning),
+        selection: None,
+        effects: Effects::NONE,
     }
 }
 
@@ -470,5 +713,7 @@ pub fn warning(theme: &Theme) -> Style {
 pub fn danger(theme: &Theme) -> Style {
     Style {
         color: Some(theme.seed().danger),
+        selection: None,
+        effects: Effects::NONE,
     }
 }
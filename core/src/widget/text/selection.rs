@@ -0,0 +1,476 @@
+//! Select and copy the contents of some text.
+use crate::clipboard;
+use crate::keyboard;
+use crate::mouse;
+use crate::renderer;
+use crate::text::Paragraph;
+use crate::text::paragraph::Position;
+use crate::{Color, Event, Point, Rectangle, Shell};
+
+/// The selection of some text.
+///
+/// A [`Selection`] can be changed by dragging the mouse over the text,
+/// double clicking a word, triple clicking a line, or pressing the
+/// select all shortcut. The selected contents can be copied to the
+/// clipboard with the copy shortcut.
+#[derive(Debug, Clone)]
+pub struct Selection {
+    anchor: Position,
+    focus: Position,
+    origin: (Position, Position),
+    kind: mouse::click::Kind,
+    contents: String,
+    request: Option<Request>,
+    last_click: Option<mouse::Click>,
+    modifiers: keyboard::Modifiers,
+    is_dragging: bool,
+    is_focused: bool,
+    is_grouped: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Request {
+    start: Point,
+    end: Point,
+    kind: mouse::click::Kind,
+}
+
+impl Selection {
+    /// Creates a new empty [`Selection`].
+    pub fn new() -> Self {
+        Self {
+            anchor: Position::default(),
+            focus: Position::default(),
+            origin: (Position::default(), Position::default()),
+            kind: mouse::click::Kind::Single,
+            contents: String::new(),
+            request: None,
+            last_click: None,
+            modifiers: keyboard::Modifiers::default(),
+            is_dragging: false,
+            is_focused: false,
+            is_grouped: false,
+        }
+    }
+
+    /// Returns true if nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.focus
+    }
+
+    /// Returns true if the [`Selection`] is controlled by a container
+    /// of multiple texts, instead of by the text itself.
+    pub fn is_grouped(&self) -> bool {
+        self.is_grouped
+    }
+
+    /// Returns the start and end [`Position`] of the [`Selection`],
+    /// in order.
+    pub fn range(&self) -> (Position, Position) {
+        (self.anchor.min(self.focus), self.anchor.max(self.focus))
+    }
+
+    /// Returns the selected contents.
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+
+    /// Selects all the contents of the given [`Paragraph`].
+    pub fn select_all(&mut self, paragraph: &impl Paragraph) {
+        self.anchor = Position::default();
+        self.focus = end(paragraph);
+        self.sync(paragraph);
+    }
+
+    /// Clears the [`Selection`].
+    pub fn clear(&mut self) {
+        self.anchor = Position::default();
+        self.focus = Position::default();
+        self.contents.clear();
+    }
+
+    /// Hands control of the [`Selection`] over to a container of multiple
+    /// texts and clears it.
+    ///
+    /// A grouped [`Selection`] ignores mouse and keyboard events; the
+    /// container changes it with [`select_between`](Self::select_between)
+    /// instead.
+    pub fn group(&mut self) {
+        self.is_grouped = true;
+        self.is_dragging = false;
+        self.is_focused = false;
+        self.clear();
+    }
+
+    /// Requests the text between the given points to be selected, in
+    /// reading order.
+    ///
+    /// The points are absolute; text above the first point or below the
+    /// last point is left unselected. Double and triple click kinds extend
+    /// the selection to whole words and lines, respectively.
+    ///
+    /// The request will be applied the next time the text widget calls
+    /// [`resolve`](Self::resolve).
+    pub fn select_between(&mut self, start: Point, end: Point, kind: mouse::click::Kind) {
+        self.request = Some(Request { start, end, kind });
+    }
+
+    /// Applies the pending [`select_between`](Self::select_between) request
+    /// of the [`Selection`], if any.
+    pub fn resolve(&mut self, paragraph: &impl Paragraph, bounds: Rectangle) {
+        let Some(request) = self.request.take() else {
+            return;
+        };
+
+        let start = locate(paragraph, bounds, request.start);
+        let end = locate(paragraph, bounds, request.end);
+
+        self.anchor = unit(paragraph, start.min(end), request.kind).0;
+        self.focus = unit(paragraph, start.max(end), request.kind).1;
+        self.sync(paragraph);
+    }
+
+    /// Processes an [`Event`] and updates the [`Selection`] accordingly.
+    ///
+    /// The `bounds` must be the same bounds used to draw the [`Paragraph`].
+    pub fn update<Message>(
+        &mut self,
+        paragraph: &impl Paragraph,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            self.modifiers = *modifiers;
+            return;
+        }
+
+        if self.is_grouped {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    if self.is_focused || !self.is_empty() {
+                        self.is_focused = false;
+                        self.clear();
+
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, self.last_click);
+                let hit = hit(paragraph, bounds, position);
+
+                if self.is_focused
+                    && self.modifiers.shift()
+                    && click.kind() == mouse::click::Kind::Single
+                {
+                    self.focus = hit;
+                    self.origin = (self.anchor, self.anchor);
+                } else {
+                    self.origin = unit(paragraph, hit, click.kind());
+                    (self.anchor, self.focus) = self.origin;
+                }
+
+                self.kind = click.kind();
+                self.last_click = Some(click);
+                self.is_dragging = true;
+                self.is_focused = true;
+                self.sync(paragraph);
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if self.is_dragging => {
+                let Some(position) = cursor.land().position() else {
+                    return;
+                };
+
+                let (start, end) = unit(paragraph, hit(paragraph, bounds, position), self.kind);
+
+                let (anchor, focus) = if start < self.origin.0 {
+                    (self.origin.1, start)
+                } else {
+                    (self.origin.0, end.max(self.origin.1))
+                };
+
+                if (anchor, focus) != (self.anchor, self.focus) {
+                    self.anchor = anchor;
+                    self.focus = focus;
+                    self.sync(paragraph);
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.is_dragging = false;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, physical_key, ..
+            }) if self.is_focused => match key.to_latin(*physical_key) {
+                Some('a') if self.modifiers.command() => {
+                    self.select_all(paragraph);
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+                Some('c') if self.modifiers.command() && !self.is_empty() => {
+                    shell.write_clipboard(clipboard::Content::Text(self.contents.clone()));
+                    shell.capture_event();
+                }
+                _ if *key == keyboard::Key::Named(keyboard::key::Named::Escape)
+                    && !self.is_empty() =>
+                {
+                    self.clear();
+
+                    shell.request_redraw();
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Returns the [`mouse::Interaction`] of some selectable text with the
+    /// given bounds.
+    pub fn mouse_interaction(
+        &self,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if self.is_dragging || cursor.is_over(bounds) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    /// Draws the highlight of the [`Selection`] of the given [`Paragraph`].
+    ///
+    /// The `bounds` must be the same bounds used to draw the [`Paragraph`].
+    pub fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        paragraph: &impl Paragraph,
+        bounds: Rectangle,
+        color: Color,
+    ) where
+        Renderer: renderer::Renderer,
+    {
+        if self.is_empty() {
+            return;
+        }
+
+        let (start, end) = self.range();
+        let translation = origin(paragraph, bounds) - Point::ORIGIN;
+
+        for region in paragraph.range_bounds(start, end) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: region + translation,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+
+    fn sync(&mut self, paragraph: &impl Paragraph) {
+        let (start, end) = self.range();
+
+        self.contents = contents(paragraph, start, end);
+    }
+}
+
+impl Default for Selection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn origin(paragraph: &impl Paragraph, bounds: Rectangle) -> Point {
+    bounds.anchor(
+        paragraph.min_bounds(),
+        paragraph.align_x(),
+        paragraph.align_y(),
+    )
+}
+
+fn hit(paragraph: &impl Paragraph, bounds: Rectangle, position: Point) -> Position {
+    let origin = origin(paragraph, bounds);
+
+    paragraph
+        .hit_position(Point::ORIGIN + (position - origin))
+        .unwrap_or_default()
+}
+
+fn locate(paragraph: &impl Paragraph, bounds: Rectangle, position: Point) -> Position {
+    if position.y < bounds.y {
+        Position::default()
+    } else if position.y >= bounds.y + bounds.height {
+        end(paragraph)
+    } else {
+        hit(paragraph, bounds, position)
+    }
+}
+
+fn end(paragraph: &impl Paragraph) -> Position {
+    let mut end = Position {
+        line: 0,
+        index: paragraph.line(0).map_or(0, str::len),
+    };
+
+    while let Some(line) = paragraph.line(end.line + 1) {
+        end.line += 1;
+        end.index = line.len();
+    }
+
+    end
+}
+
+fn unit(
+    paragraph: &impl Paragraph,
+    position: Position,
+    kind: mouse::click::Kind,
+) -> (Position, Position) {
+    let line = paragraph.line(position.line).unwrap_or_default();
+
+    match kind {
+        mouse::click::Kind::Single => (position, position),
+        mouse::click::Kind::Double => {
+            let (start, end) = word(line, position.index);
+
+            (
+                Position {
+                    line: position.line,
+                    index: start,
+                },
+                Position {
+                    line: position.line,
+                    index: end,
+                },
+            )
+        }
+        mouse::click::Kind::Triple => (
+            Position {
+                line: position.line,
+                index: 0,
+            },
+            Position {
+                line: position.line,
+                index: line.len(),
+            },
+        ),
+    }
+}
+
+/// Returns the byte range of the word at the given index of a line.
+///
+/// Runs of whitespace and runs of punctuation are considered words too.
+fn word(line: &str, index: usize) -> (usize, usize) {
+    fn class(c: char) -> u8 {
+        if c.is_alphanumeric() || c == '_' {
+            0
+        } else if c.is_whitespace() {
+            1
+        } else {
+            2
+        }
+    }
+
+    let index = index.min(line.len());
+
+    if !line.is_char_boundary(index) {
+        return (index, index);
+    }
+
+    let Some(target) = line[index..]
+        .chars()
+        .next()
+        .or_else(|| line[..index].chars().next_back())
+        .map(class)
+    else {
+        return (index, index);
+    };
+
+    let start = line[..index]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| class(*c) == target)
+        .last()
+        .map_or(index, |(i, _)| i);
+
+    let end = line[index..]
+        .char_indices()
+        .take_while(|(_, c)| class(*c) == target)
+        .last()
+        .map_or(index, |(i, c)| index + i + c.len_utf8());
+
+    (start, end)
+}
+
+fn contents(paragraph: &impl Paragraph, start: Position, end: Position) -> String {
+    let mut contents = String::new();
+
+    for index in start.line..=end.line {
+        let Some(line) = paragraph.line(index) else {
+            break;
+        };
+
+        let to = if index == end.line {
+            end.index.min(line.len())
+        } else {
+            line.len()
+        };
+
+        let from = if index == start.line {
+            start.index.min(to)
+        } else {
+            0
+        };
+
+        if index > start.line {
+            contents.push('\n');
+        }
+
+        // Inline elements are replaced by an object replacement character
+        contents.extend(
+            line.get(from..to)
+                .unwrap_or_default()
+                .chars()
+                .filter(|c| *c != '\u{FFFC}'),
+        );
+    }
+
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::word;
+
+    #[test]
+    fn word_boundaries() {
+        let line = "let answer = 42;";
+
+        assert_eq!(word(line, 0), (0, 3));
+        assert_eq!(word(line, 6), (4, 10));
+        assert_eq!(word(line, 10), (10, 11));
+        assert_eq!(word(line, 11), (11, 12));
+        assert_eq!(word(line, line.len()), (15, 16));
+        assert_eq!(word("", 0), (0, 0));
+    }
+
+    #[test]
+    fn word_boundaries_unicode() {
+        let line = "¡hola señor!";
+
+        assert_eq!(word(line, 2), (2, 6));
+        assert_eq!(word(line, 8), (7, 13));
+    }
+}
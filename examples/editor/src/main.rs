@@ -196,6 +196,13 @@ impl Editor {
         ]
         .spacing(10);
 
+        let extension = self
+            .file
+            .as_deref()
+            .and_then(Path::extension)
+            .and_then(ffi::OsStr::to_str)
+            .unwrap_or("rs");
+
         column![
             controls,
             text_editor(&self.content)
@@ -207,14 +214,8 @@ impl Editor {
                 } else {
                     text::Wrapping::None
                 })
-                .highlight(
-                    self.file
-                        .as_deref()
-                        .and_then(Path::extension)
-                        .and_then(ffi::OsStr::to_str)
-                        .unwrap_or("rs"),
-                    self.theme,
-                )
+                .highlight(extension, self.theme)
+                .smart(text_editor::Smart::for_language(extension))
                 .key_binding(|key_press| {
                     match key_press.key.as_ref() {
                         keyboard::Key::Character("s") if key_press.modifiers.command() => {
@@ -2,8 +2,8 @@ use iced::border;
 use iced::keyboard;
 use iced::mouse;
 use iced::widget::{
-    button, canvas, center, center_y, checkbox, column, container, pick_list, pin, responsive, row,
-    rule, scrollable, space, stack, text,
+    Masonry, button, canvas, center, center_y, checkbox, column, container, grid, pick_list, pin,
+    responsive, row, rule, scrollable, space, stack, text,
 };
 use iced::{
     Center, Element, Fill, FillPortion, Font, Length, Point, Rectangle, Renderer, Shrink,
@@ -168,6 +168,14 @@ impl Example {
             title: "Responsive",
             view: responsive_,
         },
+        Self {
+            title: "Tracks",
+            view: tracks,
+        },
+        Self {
+            title: "Masonry",
+            view: masonry,
+        },
     ];
 
     fn is_first(self) -> bool {
@@ -376,6 +384,44 @@ fn responsive_<'a>() -> Element<'a, Message> {
     .into()
 }
 
+fn tracks<'a>() -> Element<'a, Message> {
+    let panel = |content| center(content).style(container::bordered_box);
+
+    grid::tracks(
+        [Fill.min(150).max(200), Fill, FillPortion(2)],
+        [Shrink, Fill, Fill],
+    )
+    .areas([
+        "sidebar header header",
+        "sidebar chart  table",
+        "sidebar chart  logs",
+    ])
+    .push_cell(grid::cell(panel("Header!").padding(10)).area("header"))
+    .push_cell(grid::cell(panel("Sidebar!")).area("sidebar"))
+    .push_cell(grid::cell(panel("Chart!")).area("chart"))
+    .push_cell(grid::cell(panel("Table!")).area("table"))
+    .push_cell(grid::cell(panel("Logs!")).area("logs"))
+    .spacing(10)
+    .height(Fill)
+    .into()
+}
+
+fn masonry<'a>() -> Element<'a, Message> {
+    let ratios = [1.0, 0.75, 1.5, 0.5, 1.25, 2.0, 0.8];
+
+    scrollable((0..200).map(|i| (i, ratios[i % ratios.len()])).fold(
+        Masonry::new().fluid(150).spacing(10),
+        |masonry, (i, ratio)| {
+            masonry.push_with_ratio(
+                ratio,
+                center(text!("{i}").font(Font::MONOSPACE)).style(container::bordered_box),
+            )
+        },
+    ))
+    .spacing(10)
+    .into()
+}
+
 fn square<'a>(size: impl Into<Length> + Copy) -> Element<'a, Message> {
     struct Square;
 
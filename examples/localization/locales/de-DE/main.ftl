@@ -0,0 +1,10 @@
+title = Lokalisierung
+language = Sprache
+volume = Lautstärke
+downloads = { $count ->
+    [0] Noch keine Downloads.
+    [one] Eine Datei heruntergeladen.
+   *[other] { $count } Dateien heruntergeladen.
+}
+download = Herunterladen
+today = Heute ist der { $date }.
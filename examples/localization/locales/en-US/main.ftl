@@ -0,0 +1,10 @@
+title = Localization
+language = Language
+volume = Volume
+downloads = { $count ->
+    [0] No downloads yet.
+    [one] One file downloaded.
+   *[other] { $count } files downloaded.
+}
+download = Download
+today = Today is { $date }.
@@ -0,0 +1,10 @@
+title = Localización
+language = Idioma
+volume = Volumen
+downloads = { $count ->
+    [0] Aún no hay descargas.
+    [one] Un archivo descargado.
+   *[other] { $count } archivos descargados.
+}
+download = Descargar
+today = Hoy es { $date }.
@@ -0,0 +1,121 @@
+use iced::i18n::{self, format, t};
+use iced::widget::{button, center, column, pick_list, row, slider, text};
+use iced::{Center, Element};
+
+pub fn main() -> iced::Result {
+    for (locale, source) in [
+        ("en-US", include_str!("../locales/en-US/main.ftl")),
+        ("es-ES", include_str!("../locales/es-ES/main.ftl")),
+        ("de-DE", include_str!("../locales/de-DE/main.ftl")),
+    ] {
+        i18n::add(locale, source).expect("Add translations");
+    }
+
+    iced::application(
+        Localization::default,
+        Localization::update,
+        Localization::view,
+    )
+    .title(|_: &Localization| t!("title"))
+    .run()
+}
+
+#[derive(Default)]
+struct Localization {
+    language: Language,
+    volume: f32,
+    downloads: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    LanguageSelected(Language),
+    VolumeChanged(f32),
+    Download,
+}
+
+impl Localization {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::LanguageSelected(language) => {
+                self.language = language;
+
+                i18n::set_locale(language.locale()).expect("Set locale");
+            }
+            Message::VolumeChanged(volume) => {
+                self.volume = volume;
+            }
+            Message::Download => {
+                self.downloads += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let language = row![
+            text(t!("language")),
+            pick_list(Some(self.language), Language::ALL, Language::to_string)
+                .on_select(Message::LanguageSelected),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        let volume = column![
+            text(t!("volume")),
+            row![
+                slider(0.0..=1.0, self.volume, Message::VolumeChanged).step(0.001),
+                text(format::percent(f64::from(self.volume), 1)).width(60),
+            ]
+            .spacing(10)
+            .align_y(Center),
+        ]
+        .spacing(10);
+
+        let downloads = row![
+            button(text(t!("download"))).on_press(Message::Download),
+            text(t!("downloads", count = self.downloads)),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        let today = text(t!("today", date = format::date(2025, 3, 7)));
+
+        center(
+            column![language, volume, downloads, today]
+                .spacing(20)
+                .max_width(400),
+        )
+        .padding(20)
+        .into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+}
+
+impl Language {
+    const ALL: &[Self] = &[Self::English, Self::Spanish, Self::German];
+
+    fn locale(self) -> &'static str {
+        match self {
+            Self::English => "en-US",
+            Self::Spanish => "es-ES",
+            Self::German => "de-DE",
+        }
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::English => "English",
+            Self::Spanish => "Español",
+            Self::German => "Deutsch",
+        })
+    }
+}
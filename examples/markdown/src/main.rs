@@ -13,6 +13,7 @@ use iced::{Animation, Element, Fill, Font, Function, Subscription, Task, Theme};
 
 use std::collections::HashMap;
 use std::io;
+use std::ops::Range;
 use std::sync::Arc;
 
 pub fn main() -> iced::Result {
@@ -56,6 +57,7 @@ enum Message {
     Edit(text_editor::Action),
     Copy(String),
     LinkClicked(markdown::Uri),
+    TaskToggled(Range<usize>, bool),
     ImageShown(markdown::Uri),
     ImageDownloaded(markdown::Uri, Result<image::Handle, Error>),
     ToggleStream(bool),
@@ -98,9 +100,23 @@ impl Markdown {
             }
             Message::Copy(content) => clipboard::write(content).discard(),
             Message::LinkClicked(link) => {
+                if link.starts_with('#') {
+                    return markdown::scroll_to("preview", &link);
+                }
+
                 let _ = webbrowser::open(&link);
                 Task::none()
             }
+            Message::TaskToggled(marker, done) => {
+                let mut raw = self.raw.text();
+                raw.replace_range(marker, if done { "[x]" } else { "[ ]" });
+
+                self.raw = text_editor::Content::with_text(&raw);
+                self.content = markdown::Content::parse(&raw);
+                self.mode = Mode::Preview;
+
+                Task::none()
+            }
             Message::ImageShown(uri) => {
                 if self.images.contains_key(&uri) {
                     return Task::none();
@@ -244,6 +260,10 @@ impl<'a> markdown::Viewer<'a, Message> for CustomViewer<'a> {
         Message::LinkClicked(url)
     }
 
+    fn on_task_toggle(&self) -> Option<fn(Range<usize>, bool) -> Message> {
+        Some(Message::TaskToggled)
+    }
+
     fn image(
         &self,
         _settings: markdown::Settings,
@@ -74,6 +74,35 @@ pub trait Compositor: Sized {
         Ok(families.into_iter().map(font::Family::name).collect())
     }
 
+    /// Sets the fallback chains used for the characters missing in a font.
+    fn set_font_fallback(&mut self, fallback: font::Fallback) {
+        crate::text::font_system()
+            .write()
+            .expect("Write to font system")
+            .set_fallback(fallback);
+    }
+
+    /// Finds the available font family with the given name.
+    fn find_font(&mut self, name: &str) -> Result<font::Family, font::Error> {
+        crate::text::font_system()
+            .read()
+            .expect("Read from font system")
+            .find(name)
+            .ok_or(font::Error::NotFound)
+    }
+
+    /// Returns the runs of the given text rendered by every font face.
+    fn resolve_fonts(
+        &mut self,
+        text: &str,
+        font: font::Font,
+    ) -> Result<Vec<font::Run>, font::Error> {
+        Ok(crate::text::font_system()
+            .write()
+            .expect("Write to font system")
+            .resolve(text, font))
+    }
+
     /// Presents the [`Renderer`] primitives to the next frame of the given [`Surface`].
     ///
     /// [`Renderer`]: Self::Renderer
@@ -187,6 +216,20 @@ impl Compositor for () {
         Ok(Vec::new())
     }
 
+    fn set_font_fallback(&mut self, _fallback: font::Fallback) {}
+
+    fn find_font(&mut self, _name: &str) -> Result<font::Family, font::Error> {
+        Err(font::Error::NotFound)
+    }
+
+    fn resolve_fonts(
+        &mut self,
+        _text: &str,
+        _font: font::Font,
+    ) -> Result<Vec<font::Run>, font::Error> {
+        Ok(Vec::new())
+    }
+
     fn information(&self) -> Information {
         Information {
             adapter: String::from("Null Renderer"),
@@ -2,15 +2,30 @@
 pub mod arc;
 
 mod builder;
+mod segment;
+mod svg;
 
 #[doc(no_inline)]
 pub use arc::Arc;
 pub use builder::Builder;
+pub use svg::ParseError;
 
 pub use lyon_path;
 
 use crate::core::border;
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
+use crate::geometry::fill;
+
+use segment::Segment;
+
+use lyon_path::PathEvent;
+use lyon_path::geom::LineSegment;
+use lyon_path::iterator::PathIterator;
+use lyon_path::math;
+
+/// The maximum distance between a curve and its flattened approximation
+/// when measuring or hit testing a [`Path`].
+const TOLERANCE: f32 = 0.01;
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -63,6 +78,195 @@ impl Path {
         })
     }
 
+    /// Parses a [`Path`] from the given [SVG path data].
+    ///
+    /// All of the commands of the specification are supported; including
+    /// relative, smooth, and arc commands.
+    ///
+    /// [SVG path data]: https://www.w3.org/TR/SVG/paths.html#PathData
+    pub fn from_svg(data: &str) -> Result<Self, ParseError> {
+        svg::parse(data)
+    }
+
+    /// Serializes the [`Path`] into [SVG path data].
+    ///
+    /// Only absolute `M`, `L`, `Q`, `C`, and `Z` commands are produced.
+    /// Sub-paths with non-finite coordinates are skipped.
+    ///
+    /// [SVG path data]: https://www.w3.org/TR/SVG/paths.html#PathData
+    pub fn to_svg(&self) -> String {
+        svg::serialize(self)
+    }
+
+    /// Returns the smallest [`Rectangle`] containing the whole [`Path`].
+    ///
+    /// The bounds of an empty [`Path`] are zero-sized.
+    pub fn bounds(&self) -> Rectangle {
+        let mut bounds: Option<math::Box2D> = None;
+
+        for event in self.raw.iter() {
+            let segment_bounds = match event {
+                PathEvent::Begin { at } => math::Box2D::new(at, at),
+                _ => match Segment::from_event(event) {
+                    Some(segment) => segment.bounding_box(),
+                    None => continue,
+                },
+            };
+
+            bounds = Some(bounds.map_or(segment_bounds, |bounds| {
+                // `Box2D::union` ignores empty boxes, so we
+                // union manually to include single points
+                math::Box2D::new(
+                    bounds.min.min(segment_bounds.min),
+                    bounds.max.max(segment_bounds.max),
+                )
+            }));
+        }
+
+        bounds
+            .map(|bounds| {
+                Rectangle::new(
+                    Point::new(bounds.min.x, bounds.min.y),
+                    Size::new(bounds.width(), bounds.height()),
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the given [`Point`] is inside the [`Path`] when
+    /// filled using the given [`fill::Rule`].
+    ///
+    /// Open sub-paths are implicitly closed, like when filling.
+    pub fn contains(&self, point: Point, rule: fill::Rule) -> bool {
+        if !self.bounds().contains(point) {
+            return false;
+        }
+
+        let point = math::Point::new(point.x, point.y);
+        let mut winding = 0;
+
+        for event in self.raw.iter().flattened(TOLERANCE) {
+            let (from, to) = match event {
+                PathEvent::Line { from, to } => (from, to),
+                PathEvent::End { last, first, .. } => (last, first),
+                _ => continue,
+            };
+
+            let side = (to - from).cross(point - from);
+
+            if from.y <= point.y {
+                if to.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if to.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+
+        match rule {
+            fill::Rule::NonZero => winding != 0,
+            fill::Rule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    /// Returns the distance between the given [`Point`] and the closest
+    /// point of the outline of the [`Path`].
+    ///
+    /// Returns [`f32::INFINITY`] if the [`Path`] is empty.
+    pub fn distance(&self, point: Point) -> f32 {
+        let point = math::Point::new(point.x, point.y);
+
+        self.raw
+            .iter()
+            .flattened(TOLERANCE)
+            .filter_map(|event| match event {
+                PathEvent::Line { from, to }
+                | PathEvent::End {
+                    last: from,
+                    first: to,
+                    close: true,
+                } => Some(LineSegment { from, to }),
+                PathEvent::Begin { at } => Some(LineSegment { from: at, to: at }),
+                _ => None,
+            })
+            .map(|segment| segment.distance_to_point(point))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Returns true if the given [`Point`] lies on the stroke of the [`Path`]
+    /// with the given `width`.
+    ///
+    /// This is useful to pick thin shapes—like lines or curves—with the mouse.
+    /// Line caps and joins are not taken into account.
+    pub fn stroke_contains(&self, point: Point, width: f32) -> bool {
+        self.distance(point) <= width / 2.0
+    }
+
+    /// Returns the total length of the [`Path`]; including the closing
+    /// segments of its closed sub-paths.
+    pub fn length(&self) -> f32 {
+        self.raw
+            .iter()
+            .filter_map(Segment::from_event)
+            .map(|segment| segment.length())
+            .sum()
+    }
+
+    /// Returns the part of the [`Path`] between the given fractions of
+    /// its total [`length`](Self::length).
+    ///
+    /// Both `start` and `end` are clamped to the [0.0, 1.0] range, where
+    /// `0.0` is the beginning of the [`Path`] and `1.0` is its end.
+    ///
+    /// Trimming a [`Path`] from `0.0` to an increasing `end` is a common way
+    /// to animate it being drawn.
+    pub fn trim(&self, start: f32, end: f32) -> Path {
+        let length = self.length();
+        let start = start.clamp(0.0, 1.0) * length;
+        let end = end.clamp(0.0, 1.0) * length;
+
+        let mut builder = lyon_path::Path::builder();
+        let mut is_open = false;
+        let mut traveled = 0.0;
+
+        for event in self.raw.iter() {
+            let segment = Segment::from_event(event);
+
+            if let Some(segment) = segment {
+                let segment_length = segment.length();
+                let segment_start = traveled;
+                traveled += segment_length;
+
+                if traveled > start && segment_start < end && segment_length > 0.0 {
+                    let segment = segment.split_range(
+                        segment.parameter_at((start - segment_start).max(0.0)),
+                        segment.parameter_at((end - segment_start).min(segment_length)),
+                    );
+
+                    if !is_open {
+                        let _ = builder.begin(segment.start());
+                        is_open = true;
+                    }
+
+                    segment.append_to(&mut builder);
+                }
+            }
+
+            if matches!(event, PathEvent::End { .. }) && is_open {
+                builder.end(false);
+                is_open = false;
+            }
+        }
+
+        if is_open {
+            builder.end(false);
+        }
+
+        Path {
+            raw: builder.build(),
+        }
+    }
+
     /// Returns the internal [`lyon_path::Path`].
     #[inline]
     pub fn raw(&self) -> &lyon_path::Path {
@@ -77,3 +281,140 @@ impl Path {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Radians;
+
+    use std::f32::consts::PI;
+
+    fn star() -> Path {
+        Path::new(|p| {
+            for i in 0..5 {
+                let angle = -PI / 2.0 + i as f32 * PI * 4.0 / 5.0;
+                let point = Point::new(10.0 * angle.cos(), 10.0 * angle.sin());
+
+                if i == 0 {
+                    p.move_to(point);
+                } else {
+                    p.line_to(point);
+                }
+            }
+
+            p.close();
+        })
+    }
+
+    #[test]
+    fn contains_follows_fill_rule() {
+        let star = star();
+        let center = Point::ORIGIN;
+        let tip = Point::new(0.0, -8.0);
+        let outside = Point::new(9.0, 9.0);
+
+        assert!(star.contains(center, fill::Rule::NonZero));
+        assert!(!star.contains(center, fill::Rule::EvenOdd));
+
+        assert!(star.contains(tip, fill::Rule::NonZero));
+        assert!(star.contains(tip, fill::Rule::EvenOdd));
+
+        assert!(!star.contains(outside, fill::Rule::NonZero));
+        assert!(!star.contains(outside, fill::Rule::EvenOdd));
+    }
+
+    #[test]
+    fn distance_to_curves() {
+        let circle = Path::circle(Point::ORIGIN, 10.0);
+
+        assert!((circle.distance(Point::ORIGIN) - 10.0).abs() < 0.05);
+        assert!((circle.distance(Point::new(20.0, 0.0)) - 10.0).abs() < 0.05);
+        assert!(circle.distance(Point::new(0.0, 10.0)) < 0.05);
+
+        let curve = Path::new(|p| {
+            p.move_to(Point::ORIGIN);
+            p.quadratic_curve_to(Point::new(5.0, 10.0), Point::new(10.0, 0.0));
+        });
+
+        assert!((curve.distance(Point::new(5.0, 10.0)) - 5.0).abs() < 0.05);
+        assert!(curve.stroke_contains(Point::new(5.0, 5.5), 2.0));
+        assert!(!curve.stroke_contains(Point::new(5.0, 7.0), 2.0));
+
+        assert_eq!(Path::new(|_| {}).distance(Point::ORIGIN), f32::INFINITY);
+    }
+
+    #[test]
+    fn length_of_lines_and_arcs() {
+        let line = Path::line(Point::ORIGIN, Point::new(3.0, 4.0));
+        assert_eq!(line.length(), 5.0);
+
+        // Arcs are approximated with quadratic curves
+        let circle = Path::circle(Point::ORIGIN, 10.0);
+        assert!((circle.length() - 2.0 * PI * 10.0).abs() < 0.2);
+
+        let half = Path::new(|p| {
+            p.arc(Arc {
+                center: Point::ORIGIN,
+                radius: 10.0,
+                start_angle: Radians(0.0),
+                end_angle: Radians(PI),
+            });
+        });
+        assert!((half.length() - PI * 10.0).abs() < 0.1);
+
+        let square = Path::rectangle(Point::ORIGIN, Size::new(10.0, 10.0));
+        assert_eq!(square.length(), 40.0);
+    }
+
+    #[test]
+    fn bounds_include_curves_and_points() {
+        let curve = Path::new(|p| {
+            p.move_to(Point::ORIGIN);
+            p.quadratic_curve_to(Point::new(5.0, 10.0), Point::new(10.0, 0.0));
+        });
+
+        assert_eq!(
+            curve.bounds(),
+            Rectangle::new(Point::ORIGIN, Size::new(10.0, 5.0))
+        );
+
+        let point = Path::new(|p| p.move_to(Point::new(3.0, 4.0)));
+
+        assert_eq!(
+            point.bounds(),
+            Rectangle::new(Point::new(3.0, 4.0), Size::ZERO)
+        );
+        assert_eq!(Path::new(|_| {}).bounds(), Rectangle::default());
+    }
+
+    #[test]
+    fn trim_at_ends_and_middle() {
+        let line = Path::line(Point::ORIGIN, Point::new(10.0, 0.0));
+
+        let full = line.trim(0.0, 1.0);
+        assert_eq!(full.length(), 10.0);
+        assert_eq!(full.bounds(), line.bounds());
+
+        assert_eq!(line.trim(0.0, 0.0).length(), 0.0);
+        assert_eq!(line.trim(1.0, 1.0).length(), 0.0);
+
+        let middle = line.trim(0.25, 0.75);
+        assert_eq!(
+            middle.bounds(),
+            Rectangle::new(Point::new(2.5, 0.0), Size::new(5.0, 0.0))
+        );
+
+        let clamped = line.trim(-1.0, 2.0);
+        assert_eq!(clamped.length(), 10.0);
+
+        let square = Path::rectangle(Point::ORIGIN, Size::new(10.0, 10.0));
+        let half = square.trim(0.0, 0.5);
+
+        assert_eq!(half.length(), 20.0);
+        assert_eq!(
+            half.bounds(),
+            Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0))
+        );
+        assert_eq!(half.to_svg(), "M 0 0 L 10 0 L 10 10");
+    }
+}
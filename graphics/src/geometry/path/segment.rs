@@ -0,0 +1,129 @@
+use crate::geometry::path::TOLERANCE;
+
+use lyon_path::PathEvent;
+use lyon_path::geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment};
+use lyon_path::math::{Box2D, Point};
+
+/// A single segment of a [`Path`](super::Path).
+#[derive(Debug, Clone, Copy)]
+pub enum Segment {
+    Line(LineSegment<f32>),
+    Quadratic(QuadraticBezierSegment<f32>),
+    Cubic(CubicBezierSegment<f32>),
+}
+
+impl Segment {
+    /// Returns the [`Segment`] drawn by the given [`PathEvent`], if any.
+    ///
+    /// The [`Segment`] of a closing event is the straight line that joins
+    /// the end of the sub-path with its beginning.
+    pub fn from_event(event: PathEvent) -> Option<Self> {
+        match event {
+            PathEvent::Begin { .. } => None,
+            PathEvent::Line { from, to } => Some(Self::Line(LineSegment { from, to })),
+            PathEvent::Quadratic { from, ctrl, to } => {
+                Some(Self::Quadratic(QuadraticBezierSegment { from, ctrl, to }))
+            }
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => Some(Self::Cubic(CubicBezierSegment {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            })),
+            PathEvent::End {
+                last,
+                first,
+                close: true,
+            } if last != first => Some(Self::Line(LineSegment {
+                from: last,
+                to: first,
+            })),
+            PathEvent::End { .. } => None,
+        }
+    }
+
+    pub fn start(&self) -> Point {
+        match self {
+            Self::Line(line) => line.from,
+            Self::Quadratic(curve) => curve.from,
+            Self::Cubic(curve) => curve.from,
+        }
+    }
+
+    pub fn length(&self) -> f32 {
+        match self {
+            Self::Line(line) => line.length(),
+            Self::Quadratic(curve) => curve.length(),
+            Self::Cubic(curve) => curve.approximate_length(TOLERANCE),
+        }
+    }
+
+    pub fn bounding_box(&self) -> Box2D {
+        match self {
+            Self::Line(line) => line.bounding_box(),
+            Self::Quadratic(curve) => curve.bounding_box(),
+            Self::Cubic(curve) => curve.bounding_box(),
+        }
+    }
+
+    /// Returns the parameter `t` of the point at the given distance
+    /// from the start of the [`Segment`].
+    pub fn parameter_at(&self, distance: f32) -> f32 {
+        let length = self.length();
+
+        if length <= 0.0 {
+            return 0.0;
+        }
+
+        let target = (distance / length).clamp(0.0, 1.0);
+
+        if let Self::Line(_) = self {
+            return target;
+        }
+
+        if target == 0.0 || target == 1.0 {
+            return target;
+        }
+
+        // Curves are not parameterized by arc length,
+        // so we bisect until we are close enough
+        let mut low = 0.0;
+        let mut high = 1.0;
+
+        for _ in 0..16 {
+            let t = (low + high) / 2.0;
+
+            if self.split_range(0.0, t).length() < distance {
+                low = t;
+            } else {
+                high = t;
+            }
+        }
+
+        (low + high) / 2.0
+    }
+
+    pub fn split_range(&self, start: f32, end: f32) -> Self {
+        match self {
+            Self::Line(line) => Self::Line(line.split_range(start..end)),
+            Self::Quadratic(curve) => Self::Quadratic(curve.split_range(start..end)),
+            Self::Cubic(curve) => Self::Cubic(curve.split_range(start..end)),
+        }
+    }
+
+    pub fn append_to<B>(&self, builder: &mut lyon_path::builder::NoAttributes<B>)
+    where
+        B: lyon_path::builder::PathBuilder,
+    {
+        let _ = match self {
+            Self::Line(line) => builder.line_to(line.to),
+            Self::Quadratic(curve) => builder.quadratic_bezier_to(curve.ctrl, curve.to),
+            Self::Cubic(curve) => builder.cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to),
+        };
+    }
+}
@@ -0,0 +1,363 @@
+use crate::geometry::path::Path;
+
+use lyon_path::builder::SvgPathBuilder;
+use lyon_path::math::{Angle, Point, Vector};
+use lyon_path::{ArcFlags, PathEvent};
+
+use std::fmt::Write;
+
+/// An error produced when parsing SVG path data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    /// An unknown command or an invalid character was found.
+    #[error("unexpected character `{character}` at byte {position}")]
+    UnexpectedCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The byte position of the character.
+        position: usize,
+    },
+
+    /// A command was missing some of its arguments.
+    #[error("expected a number at byte {position}")]
+    ExpectedNumber {
+        /// The byte position where the number was expected.
+        position: usize,
+    },
+
+    /// An arc command contained an invalid flag.
+    #[error("expected an arc flag (`0` or `1`) at byte {position}")]
+    ExpectedFlag {
+        /// The byte position where the flag was expected.
+        position: usize,
+    },
+
+    /// The path data did not start with a move command.
+    #[error("path data must start with a move command")]
+    MissingMoveTo,
+}
+
+/// Parses the given SVG path data into a [`Path`].
+pub fn parse(data: &str) -> Result<Path, ParseError> {
+    let mut parser = Parser {
+        bytes: data.as_bytes(),
+        position: 0,
+    };
+
+    let mut builder = lyon_path::Path::builder().with_svg();
+    let mut command = None;
+
+    loop {
+        parser.skip_separators();
+
+        let Some(byte) = parser.peek() else {
+            break;
+        };
+
+        let next = if byte.is_ascii_alphabetic() {
+            parser.position += 1;
+            byte
+        } else {
+            match command {
+                // Implicit commands repeat the last one, except for move
+                // commands; which are followed by implicit line commands
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(b'Z' | b'z') | None => {
+                    return Err(parser.unexpected());
+                }
+                Some(command) => command,
+            }
+        };
+
+        if command.is_none() && !matches!(next, b'M' | b'm') {
+            return Err(ParseError::MissingMoveTo);
+        }
+
+        match next {
+            b'M' => {
+                let _ = builder.move_to(parser.point()?);
+            }
+            b'm' => builder.relative_move_to(parser.vector()?),
+            b'L' => {
+                let _ = builder.line_to(parser.point()?);
+            }
+            b'l' => builder.relative_line_to(parser.vector()?),
+            b'H' => builder.horizontal_line_to(parser.number()?),
+            b'h' => builder.relative_horizontal_line_to(parser.number()?),
+            b'V' => builder.vertical_line_to(parser.number()?),
+            b'v' => builder.relative_vertical_line_to(parser.number()?),
+            b'C' => {
+                let _ = builder.cubic_bezier_to(parser.point()?, parser.point()?, parser.point()?);
+            }
+            b'c' => {
+                builder.relative_cubic_bezier_to(
+                    parser.vector()?,
+                    parser.vector()?,
+                    parser.vector()?,
+                );
+            }
+            b'S' => builder.smooth_cubic_bezier_to(parser.point()?, parser.point()?),
+            b's' => builder.smooth_relative_cubic_bezier_to(parser.vector()?, parser.vector()?),
+            b'Q' => {
+                let _ = builder.quadratic_bezier_to(parser.point()?, parser.point()?);
+            }
+            b'q' => builder.relative_quadratic_bezier_to(parser.vector()?, parser.vector()?),
+            b'T' => builder.smooth_quadratic_bezier_to(parser.point()?),
+            b't' => builder.smooth_relative_quadratic_bezier_to(parser.vector()?),
+            b'A' => {
+                let (radii, x_rotation, flags) = parser.arc()?;
+                builder.arc_to(radii, x_rotation, flags, parser.point()?);
+            }
+            b'a' => {
+                let (radii, x_rotation, flags) = parser.arc()?;
+                builder.relative_arc_to(radii, x_rotation, flags, parser.vector()?);
+            }
+            b'Z' | b'z' => builder.close(),
+            _ => {
+                parser.position -= 1;
+                return Err(parser.unexpected());
+            }
+        }
+
+        command = Some(next);
+    }
+
+    Ok(Path {
+        raw: builder.build(),
+    })
+}
+
+/// Serializes the given [`Path`] into SVG path data.
+///
+/// Sub-paths with non-finite coordinates cannot be represented and are skipped.
+pub fn serialize(path: &Path) -> String {
+    let mut data = String::new();
+    let mut subpath = String::new();
+    let mut is_finite = true;
+
+    for event in path.raw.iter() {
+        is_finite &= match event {
+            PathEvent::Begin { at } => {
+                subpath.clear();
+                command(&mut subpath, 'M', &[at])
+            }
+            PathEvent::Line { to, .. } => command(&mut subpath, 'L', &[to]),
+            PathEvent::Quadratic { ctrl, to, .. } => command(&mut subpath, 'Q', &[ctrl, to]),
+            PathEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => command(&mut subpath, 'C', &[ctrl1, ctrl2, to]),
+            PathEvent::End { close, .. } => {
+                if close {
+                    let _ = command(&mut subpath, 'Z', &[]);
+                }
+
+                if is_finite {
+                    data.push_str(&subpath);
+                }
+
+                is_finite = true;
+                continue;
+            }
+        };
+    }
+
+    data.trim_start().to_owned()
+}
+
+/// Writes a command with the given points, returning whether they are all finite.
+fn command(data: &mut String, name: char, points: &[Point]) -> bool {
+    let _ = write!(data, " {name}");
+
+    for point in points {
+        let _ = write!(data, " {} {}", point.x, point.y);
+    }
+
+    points
+        .iter()
+        .all(|point| point.x.is_finite() && point.y.is_finite())
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn skip_separators(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|byte| byte.is_ascii_whitespace() || byte == b',')
+        {
+            self.position += 1;
+        }
+    }
+
+    fn unexpected(&self) -> ParseError {
+        let character = std::str::from_utf8(&self.bytes[self.position..])
+            .ok()
+            .and_then(|rest| rest.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+        ParseError::UnexpectedCharacter {
+            character,
+            position: self.position,
+        }
+    }
+
+    fn number(&mut self) -> Result<f32, ParseError> {
+        self.skip_separators();
+
+        let start = self.position;
+
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.position += 1;
+        }
+
+        let integer = self.digits();
+
+        let fraction = if self.peek() == Some(b'.') {
+            self.position += 1;
+            self.digits()
+        } else {
+            0
+        };
+
+        if integer == 0 && fraction == 0 {
+            self.position = start;
+
+            return Err(ParseError::ExpectedNumber { position: start });
+        }
+
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mantissa = self.position;
+            self.position += 1;
+
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.position += 1;
+            }
+
+            if self.digits() == 0 {
+                self.position = mantissa;
+            }
+        }
+
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or(ParseError::ExpectedNumber { position: start })
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.position;
+
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+
+        self.position - start
+    }
+
+    fn flag(&mut self) -> Result<bool, ParseError> {
+        self.skip_separators();
+
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => {
+                return Err(ParseError::ExpectedFlag {
+                    position: self.position,
+                });
+            }
+        };
+
+        self.position += 1;
+
+        Ok(flag)
+    }
+
+    fn point(&mut self) -> Result<Point, ParseError> {
+        Ok(Point::new(self.number()?, self.number()?))
+    }
+
+    fn vector(&mut self) -> Result<Vector, ParseError> {
+        Ok(Vector::new(self.number()?, self.number()?))
+    }
+
+    fn arc(&mut self) -> Result<(Vector, Angle, ArcFlags), ParseError> {
+        let radii = self.vector()?;
+        let x_rotation = Angle::degrees(self.number()?);
+        let large_arc = self.flag()?;
+        let sweep = self.flag()?;
+
+        Ok((
+            Vector::new(radii.x.abs(), radii.y.abs()),
+            x_rotation,
+            ArcFlags { large_arc, sweep },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_serialize() {
+        let tests = [
+            ("M 10 10 L 20 10 L 20 20 Z", "M 10 10 L 20 10 L 20 20 Z"),
+            ("M10,10h10v10H10z", "M 10 10 L 20 10 L 20 20 L 10 20 Z"),
+            ("m 1 1 2 2", "M 1 1 L 3 3"),
+            ("M.5.5L1e1-1", "M 0.5 0.5 L 10 -1"),
+            ("M 0 0 Q 1 1 2 0 T 4 0", "M 0 0 Q 1 1 2 0 Q 3 -1 4 0"),
+        ];
+
+        for (data, expected) in tests {
+            let path = parse(data).expect("path data must parse");
+
+            assert_eq!(serialize(&path), expected);
+        }
+
+        assert_eq!(parse("L 1 1").unwrap_err(), ParseError::MissingMoveTo);
+        assert_eq!(
+            parse("M 1").unwrap_err(),
+            ParseError::ExpectedNumber { position: 3 }
+        );
+        assert_eq!(
+            parse("M 1 1 X").unwrap_err(),
+            ParseError::UnexpectedCharacter {
+                character: 'X',
+                position: 6
+            }
+        );
+    }
+
+    #[test]
+    fn serialize_skips_non_finite_subpaths() {
+        use lyon_path::math::Transform;
+
+        let line = parse("M 0 0 L 1 1").expect("path data must parse");
+        let infinite = line.transform(&Transform::scale(f32::INFINITY, 1.0));
+        let nan = line.transform(&Transform::translation(0.0, f32::NAN));
+
+        assert_eq!(serialize(&infinite), "");
+        assert_eq!(serialize(&nan), "");
+
+        let mut builder = lyon_path::Path::builder();
+        builder.extend_from_paths(&[
+            infinite.raw().as_slice(),
+            line.raw().as_slice(),
+            nan.raw().as_slice(),
+        ]);
+
+        let mixed = Path {
+            raw: builder.build(),
+        };
+
+        assert_eq!(serialize(&mixed), "M 0 0 L 1 1");
+    }
+}
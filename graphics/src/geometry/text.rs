@@ -1,8 +1,9 @@
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Alignment, Ellipsis, LineHeight, Paragraph, Shaping, Wrapping};
-use crate::core::{Color, Font, Pixels, Point, Size, Vector};
-use crate::geometry::Path;
+use crate::core::text::{Alignment, Effects, Ellipsis, LineHeight, Paragraph, Shaping, Wrapping};
+use crate::core::{Color, Font, Pixels, Point, Rectangle, Size, Vector};
+use crate::geometry::{LineJoin, Path, Stroke, frame};
+use crate::gradient;
 use crate::text;
 
 /// A bunch of text that can be drawn to a canvas
@@ -44,12 +45,88 @@ pub struct Text {
     pub wrapping: Wrapping,
     /// The ellipsis strategy of the text.
     pub ellipsis: Ellipsis,
+    /// The [`Effects`] of the text; like shadows, outlines, or gradients.
+    pub effects: Effects,
 }
 
 impl Text {
     /// Computes the [`Path`]s of the [`Text`] and draws them using
     /// the given closure.
-    pub fn draw_with(&self, mut f: impl FnMut(Path, Color)) {
+    pub fn draw_with(&self, f: impl FnMut(Path, Color)) {
+        let (paragraph, position) = self.layout();
+
+        Self::draw_buffer(paragraph.buffer(), position, self.color, f);
+    }
+
+    /// Draws the [`Text`] and its [`Effects`] as [`Path`]s in the given
+    /// frame [`Backend`](frame::Backend).
+    ///
+    /// The [`Outline`](core::text::Outline) is stroked around the glyphs and
+    /// the [`Gradient`](core::Gradient) fill is painted across all of them.
+    /// Since paths cannot be blurred, a blurred [`Shadow`](core::Shadow) is
+    /// approximated by translucent copies of the glyphs.
+    pub fn draw_effects(&self, frame: &mut impl frame::Backend) {
+        let (paragraph, position) = self.layout();
+        let bounds = Rectangle::new(position, paragraph.min_bounds());
+
+        let mut glyphs = Vec::new();
+
+        Self::draw_buffer(paragraph.buffer(), position, self.color, |path, color| {
+            glyphs.push((path, color));
+        });
+
+        if let Some(shadow) = self.effects.shadow {
+            let spread = spread(shadow.blur_radius);
+
+            // Every copy is translucent, so that the overlapping copies at
+            // the center of the shadow add up to its original opacity
+            let color = Color {
+                a: 1.0 - (1.0 - shadow.color.a).powf(1.0 / spread.len() as f32),
+                ..shadow.color
+            };
+
+            for offset in spread {
+                frame.push_transform();
+                frame.translate(shadow.offset + offset);
+
+                for (glyph, _color) in &glyphs {
+                    frame.fill(glyph, color);
+                }
+
+                frame.pop_transform();
+            }
+        }
+
+        if let Some(outline) = self.effects.outline.filter(|outline| outline.width > 0.0) {
+            let stroke = Stroke::default()
+                .with_color(outline.color)
+                .with_width(outline.width * 2.0)
+                .with_line_join(LineJoin::Round);
+
+            for (glyph, _color) in &glyphs {
+                frame.stroke(glyph, stroke);
+            }
+        }
+
+        let gradient = self.effects.gradient.map(|gradient| match gradient {
+            core::Gradient::Linear(linear) => {
+                let (start, end) = bounds.chord(linear.angle);
+
+                gradient::Linear::new(start, end).add_stops(linear.stops.into_iter().flatten())
+            }
+        });
+
+        for (glyph, color) in glyphs {
+            match gradient {
+                Some(gradient) => frame.fill(&glyph, gradient),
+                None => frame.fill(&glyph, color),
+            }
+        }
+    }
+
+    /// Lays out the [`Text`] and returns its paragraph and the position of
+    /// its top-left corner.
+    fn layout(&self) -> (text::Paragraph, Point) {
         let paragraph = text::Paragraph::with_text(core::text::Text {
             content: &self.content,
             bounds: Size::new(self.max_width, f32::INFINITY),
@@ -78,8 +155,21 @@ impl Text {
             }
         };
 
-        let buffer = paragraph.buffer();
+        (paragraph, Point::new(translation_x, translation_y))
+    }
+
+    /// Computes the [`Path`]s of the glyphs in the given [`cosmic_text::Buffer`]
+    /// positioned at the given [`Point`] and draws them using the given closure.
+    ///
+    /// All the glyphs are drawn with the provided [`Color`].
+    pub fn draw_buffer(
+        buffer: &cosmic_text::Buffer,
+        position: Point,
+        color: Color,
+        mut f: impl FnMut(Path, Color),
+    ) {
         let mut swash_cache = cosmic_text::SwashCache::new();
+        let scroll = buffer.scroll();
 
         let mut font_system = text::font_system().write().expect("Write font system");
 
@@ -87,8 +177,8 @@ impl Text {
             for glyph in run.glyphs.iter() {
                 let physical_glyph = glyph.physical((0.0, 0.0), 1.0);
 
-                let start_x = translation_x + glyph.x + glyph.x_offset;
-                let start_y = translation_y + glyph.y_offset + run.line_y;
+                let start_x = position.x - scroll.horizontal + glyph.x + glyph.x_offset;
+                let start_y = position.y + glyph.y_offset + run.line_y;
                 let offset = Vector::new(start_x, start_y);
 
                 if let Some(commands) =
@@ -125,10 +215,10 @@ impl Text {
                         }
                     });
 
-                    f(glyph, self.color);
+                    f(glyph, color);
                 } else {
                     // TODO: Raster image support for `Canvas`
-                    let [r, g, b, a] = self.color.into_rgba8();
+                    let [r, g, b, a] = color.into_rgba8();
 
                     swash_cache.with_pixels(
                         font_system.raw(),
@@ -140,12 +230,7 @@ impl Text {
                                     Point::new(x as f32, y as f32) + offset,
                                     Size::new(1.0, 1.0),
                                 ),
-                                Color::from_rgba8(
-                                    color.r(),
-                                    color.g(),
-                                    color.b(),
-                                    color.a() as f32 / 255.0,
-                                ),
+                                from_color(color),
                             );
                         },
                     );
@@ -170,6 +255,7 @@ impl Default for Text {
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
             ellipsis: Ellipsis::default(),
+            effects: Effects::NONE,
         }
     }
 }
@@ -188,3 +274,33 @@ impl From<&str> for Text {
         String::from(content).into()
     }
 }
+
+fn from_color(color: cosmic_text::Color) -> Color {
+    let [r, g, b, a] = color.as_rgba();
+
+    Color::from_rgba8(r, g, b, a as f32 / 255.0)
+}
+
+/// Returns the offsets of the copies used to approximate a blur of the
+/// given radius.
+fn spread(blur_radius: f32) -> Vec<Vector> {
+    use std::f32::consts::TAU;
+
+    if blur_radius < 0.5 {
+        return vec![Vector::ZERO];
+    }
+
+    let rings = (blur_radius / 2.0).ceil().min(3.0) as usize;
+
+    std::iter::once(Vector::ZERO)
+        .chain((1..=rings).flat_map(|ring| {
+            let radius = blur_radius * ring as f32 / rings as f32;
+
+            (0..8).map(move |i| {
+                let angle = TAU * i as f32 / 8.0;
+
+                Vector::new(angle.cos(), angle.sin()) * radius
+            })
+        }))
+        .collect()
+}
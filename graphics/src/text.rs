@@ -1,6 +1,7 @@
 //! Draw text.
 pub mod cache;
 pub mod editor;
+pub mod effects;
 pub mod paragraph;
 
 pub use cache::Cache;
@@ -11,12 +12,13 @@ pub use cosmic_text;
 
 use crate::core::alignment;
 use crate::core::font::{self, Font};
-use crate::core::text::{Alignment, Ellipsis, Shaping, Wrapping};
+use crate::core::text::{Alignment, Effects, Ellipsis, Shaping, Wrapping};
 use crate::core::{Color, Pixels, Point, Rectangle, Size, Transformation};
 
 use std::borrow::Cow;
 use std::collections::HashSet;
-use std::sync::{Arc, OnceLock, RwLock, Weak};
+use std::ops::Range;
+use std::sync::{Arc, Mutex, OnceLock, RwLock, Weak};
 
 /// A text primitive.
 #[derive(Debug, Clone, PartialEq)]
@@ -27,6 +29,7 @@ pub enum Text {
         paragraph: paragraph::Weak,
         position: Point,
         color: Color,
+        effects: Effects,
         clip_bounds: Rectangle,
         transformation: Transformation,
     },
@@ -81,10 +84,12 @@ impl Text {
             Text::Paragraph {
                 position,
                 paragraph,
+                effects,
                 clip_bounds,
                 transformation,
                 ..
-            } => Rectangle::new(*position, paragraph.min_bounds)
+            } => effects
+                .bounds(Rectangle::new(*position, paragraph.min_bounds))
                 .intersection(clip_bounds)
                 .map(|bounds| bounds * *transformation),
             Text::Editor {
@@ -201,6 +206,94 @@ impl FontSystem {
             .map(|(name, _)| name.as_str())
     }
 
+    /// Sets the [`font::Fallback`] chains of the [`FontSystem`].
+    pub fn set_fallback(&mut self, fallback: font::Fallback) {
+        let locale = self.raw.locale().to_owned();
+        let database = self.raw.db().clone();
+
+        self.raw = cosmic_text::FontSystem::new_with_locale_and_db_and_fallback(
+            locale,
+            database,
+            Fallback::new(fallback),
+        );
+
+        self.version = Version(self.version.0 + 1);
+    }
+
+    /// Finds the [`font::Family`] of the font faces in the font database
+    /// with the given name, ignoring case.
+    pub fn find(&self, name: &str) -> Option<font::Family> {
+        self.families()
+            .find(|family| family.eq_ignore_ascii_case(name))
+            .map(font::Family::name)
+    }
+
+    /// Shapes the given text with the given [`Font`] and returns the runs
+    /// of text rendered by every font face.
+    pub fn resolve(&mut self, text: &str, font: Font) -> Vec<font::Run> {
+        let mut buffer = cosmic_text::Buffer::new(
+            &mut self.raw,
+            cosmic_text::Metrics {
+                font_size: 16.0,
+                line_height: 16.0,
+            },
+        );
+
+        buffer.set_text(
+            text,
+            &to_attributes(font),
+            cosmic_text::Shaping::Advanced,
+            None,
+        );
+        buffer.shape_until_scroll(&mut self.raw, false);
+
+        let offsets: Vec<usize> = buffer
+            .lines
+            .iter()
+            .scan(0, |offset, line| {
+                let start = *offset;
+                *offset += line.text().len() + line.ending().as_str().len();
+
+                Some(start)
+            })
+            .collect();
+
+        let mut runs: Vec<(Range<usize>, cosmic_text::fontdb::ID, bool)> = Vec::new();
+
+        for run in buffer.layout_runs() {
+            let offset = offsets[run.line_i];
+
+            for glyph in run.glyphs {
+                let range = offset + glyph.start..offset + glyph.end;
+                let is_missing = glyph.glyph_id == 0;
+
+                match runs.last_mut() {
+                    Some((last, id, missing))
+                        if *id == glyph.font_id && last.end == range.start =>
+                    {
+                        last.end = range.end;
+                        *missing |= is_missing;
+                    }
+                    _ => runs.push((range, glyph.font_id, is_missing)),
+                }
+            }
+        }
+
+        runs.into_iter()
+            .map(|(range, id, is_missing)| font::Run {
+                range,
+                family: self
+                    .raw
+                    .db()
+                    .face(id)
+                    .and_then(|face| face.families.first())
+                    .map(|(name, _language)| font::Family::name(name))
+                    .unwrap_or_default(),
+                is_missing,
+            })
+            .collect()
+    }
+
     /// Returns the current [`Version`] of the [`FontSystem`].
     ///
     /// Loading a font will increase the version of a [`FontSystem`].
@@ -209,6 +302,63 @@ impl FontSystem {
     }
 }
 
+/// The fallback chains of a [`FontSystem`], on top of the ones of the
+/// platform.
+struct Fallback {
+    chains: font::Fallback,
+    platform: cosmic_text::PlatformFallback,
+    common: Vec<&'static str>,
+    forbidden: Vec<&'static str>,
+}
+
+impl Fallback {
+    fn new(chains: font::Fallback) -> Self {
+        use cosmic_text::Fallback as _;
+
+        let platform = cosmic_text::PlatformFallback;
+
+        let common = chains
+            .common
+            .iter()
+            .chain(platform.common_fallback())
+            .copied()
+            .collect();
+
+        let forbidden = chains
+            .forbidden
+            .iter()
+            .chain(platform.forbidden_fallback())
+            .copied()
+            .collect();
+
+        Self {
+            chains,
+            platform,
+            common,
+            forbidden,
+        }
+    }
+}
+
+impl cosmic_text::Fallback for Fallback {
+    fn common_fallback(&self) -> &[&'static str] {
+        &self.common
+    }
+
+    fn forbidden_fallback(&self) -> &[&'static str] {
+        &self.forbidden
+    }
+
+    fn script_fallback(&self, script: unicode_script::Script, locale: &str) -> &[&'static str] {
+        let code = script.short_name().as_bytes();
+
+        code.try_into()
+            .ok()
+            .and_then(|code| self.chains.chain(font::Script::new(code), locale))
+            .unwrap_or_else(|| self.platform.script_fallback(script, locale))
+    }
+}
+
 /// A version number.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Version(u32);
@@ -293,11 +443,58 @@ pub fn align(
 
 /// Returns the attributes of the given [`Font`].
 pub fn to_attributes(font: Font) -> cosmic_text::Attrs<'static> {
-    cosmic_text::Attrs::new()
+    let mut attributes = cosmic_text::Attrs::new()
         .family(to_family(font.family))
         .weight(to_weight(font.weight))
         .stretch(to_stretch(font.stretch))
-        .style(to_style(font.style))
+        .style(to_style(font.style));
+
+    if !font.features.is_empty() {
+        let mut features = cosmic_text::FontFeatures::new();
+
+        for feature in font.features {
+            let _ = features.set(cosmic_text::FeatureTag::new(&feature.tag), feature.value);
+        }
+
+        attributes = attributes.font_features(features);
+    }
+
+    // TODO: Support arbitrary axes once `cosmic-text` does
+    for variation in font.variations {
+        attributes = match &variation.axis {
+            b"wght" => attributes.weight(cosmic_text::Weight(
+                variation.value.clamp(1.0, 1000.0).round() as u16,
+            )),
+            b"wdth" => attributes.stretch(to_width(variation.value)),
+            b"slnt" if variation.value != 0.0 => attributes.style(cosmic_text::Style::Oblique),
+            b"ital" if variation.value >= 0.5 => attributes.style(cosmic_text::Style::Italic),
+            b"slnt" | b"ital" => attributes,
+            axis => {
+                ignore_axis(*axis);
+                attributes
+            }
+        };
+    }
+
+    attributes
+}
+
+/// Warns about an unsupported axis of a variable font, once per axis.
+fn ignore_axis(axis: [u8; 4]) {
+    static IGNORED: OnceLock<Mutex<HashSet<[u8; 4]>>> = OnceLock::new();
+
+    let is_new = IGNORED
+        .get_or_init(Mutex::default)
+        .lock()
+        .expect("Lock ignored axes")
+        .insert(axis);
+
+    if is_new {
+        log::warn!(
+            "The `{}` axis of variable fonts is not supported; ignoring it...",
+            String::from_utf8_lossy(&axis)
+        );
+    }
 }
 
 fn to_family(family: font::Family) -> cosmic_text::Family<'static> {
@@ -339,6 +536,23 @@ fn to_stretch(stretch: font::Stretch) -> cosmic_text::Stretch {
     }
 }
 
+fn to_width(percentage: f32) -> cosmic_text::Stretch {
+    [
+        (cosmic_text::Stretch::UltraCondensed, 50.0),
+        (cosmic_text::Stretch::ExtraCondensed, 62.5),
+        (cosmic_text::Stretch::Condensed, 75.0),
+        (cosmic_text::Stretch::SemiCondensed, 87.5),
+        (cosmic_text::Stretch::Normal, 100.0),
+        (cosmic_text::Stretch::SemiExpanded, 112.5),
+        (cosmic_text::Stretch::Expanded, 125.0),
+        (cosmic_text::Stretch::ExtraExpanded, 150.0),
+        (cosmic_text::Stretch::UltraExpanded, 200.0),
+    ]
+    .into_iter()
+    .min_by(|(_, a), (_, b)| (a - percentage).abs().total_cmp(&(b - percentage).abs()))
+    .map_or(cosmic_text::Stretch::Normal, |(stretch, _)| stretch)
+}
+
 fn to_style(style: font::Style) -> cosmic_text::Style {
     match style {
         font::Style::Normal => cosmic_text::Style::Normal,
@@ -422,3 +636,123 @@ pub trait Renderer {
     /// Draws the given [`Raw`] text.
     fn fill_raw(&mut self, raw: Raw);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::font::{Feature, Variation};
+
+    #[test]
+    fn features_are_mapped_to_attributes() {
+        let font = Font::DEFAULT.features(Feature::list(&[
+            Feature::SMALL_CAPS,
+            Feature::LIGATURES.disabled(),
+        ]));
+        let attributes = to_attributes(font);
+
+        let features: Vec<_> = attributes
+            .font_features
+            .features
+            .iter()
+            .map(|feature| (feature.tag, feature.value))
+            .collect();
+
+        assert_eq!(
+            features,
+            [
+                (cosmic_text::FeatureTag::new(b"smcp"), 1),
+                (cosmic_text::FeatureTag::new(&Feature::LIGATURES.tag), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn variations_are_mapped_to_attributes() {
+        let font = Font::DEFAULT
+            .weight(font::Weight::Bold)
+            .variations(Variation::list(&[
+                Variation::weight(351.6),
+                Variation::width(80.0),
+                Variation::slant(-10.0),
+            ]));
+
+        let attributes = to_attributes(font);
+
+        assert_eq!(attributes.weight, cosmic_text::Weight(352));
+        assert_eq!(attributes.stretch, cosmic_text::Stretch::Condensed);
+        assert_eq!(attributes.style, cosmic_text::Style::Oblique);
+
+        let upright =
+            to_attributes(Font::DEFAULT.variations(Variation::list(&[Variation::slant(0.0)])));
+
+        assert_eq!(upright.style, cosmic_text::Style::Normal);
+    }
+
+    #[test]
+    fn unsupported_variations_are_ignored() {
+        let font = Font::MONOSPACE.style(font::Style::Italic);
+
+        let attributes = to_attributes(font.variations(Variation::list(&[
+            Variation::optical_size(12.0),
+            Variation::new(b"GRAD", 100.0),
+        ])));
+
+        assert_eq!(attributes, to_attributes(font));
+    }
+
+    fn font_system() -> FontSystem {
+        let mut database = cosmic_text::fontdb::Database::new();
+
+        database.load_font_data(include_bytes!("../fonts/FiraSans-Regular.ttf").to_vec());
+        database.load_font_data(include_bytes!("../fonts/Iced-Icons.ttf").to_vec());
+
+        FontSystem {
+            raw: cosmic_text::FontSystem::new_with_locale_and_db(String::from("en-US"), database),
+            loaded_fonts: HashSet::new(),
+            version: Version::default(),
+        }
+    }
+
+    #[test]
+    fn runs_are_resolved_by_font_face() {
+        let mut font_system = font_system();
+
+        let runs = font_system.resolve("Hi\u{e800}\nyo", Font::new("Fira Sans"));
+
+        assert_eq!(
+            runs,
+            [
+                font::Run {
+                    range: 0..2,
+                    family: font::Family::Name("Fira Sans"),
+                    is_missing: false,
+                },
+                font::Run {
+                    range: 2..5,
+                    family: font::Family::Name("Iced-Icons"),
+                    is_missing: false,
+                },
+                font::Run {
+                    range: 6..8,
+                    family: font::Family::Name("Fira Sans"),
+                    is_missing: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn forbidden_fallbacks_are_not_used() {
+        let mut font_system = font_system();
+
+        font_system.set_fallback(font::Fallback::new().forbid(String::from("Iced-Icons")));
+
+        let runs = font_system.resolve("Hi\u{e800}", Font::new("Fira Sans"));
+
+        assert!(
+            runs.iter()
+                .all(|run| run.family == font::Family::Name("Fira Sans"))
+        );
+        assert!(runs.last().is_some_and(|run| run.is_missing));
+    }
+}
@@ -1,16 +1,21 @@
 //! Draw and edit text.
+mod rope;
+
 use crate::core::text::editor::{
-    self, Action, Cursor, Direction, Edit, Motion, Position, Selection,
+    self, Action, Cursor, Direction, Edit, Motion, Position, Selection, Storage,
 };
-use crate::core::text::highlighter::{self, Highlighter};
+use crate::core::text::highlighter::{self, Highlighter, Underline};
 use crate::core::text::{LineHeight, Wrapping};
 use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
+use rope::Rope;
+
 use cosmic_text::Edit as _;
 
 use std::borrow::Cow;
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc, RwLock};
 
 /// A multi-line text editor.
@@ -20,7 +25,10 @@ pub struct Editor(Option<Arc<Internal>>);
 struct Internal {
     editor: cosmic_text::Editor<'static>,
     selection: RwLock<Option<Selection>>,
+    underlines: Vec<(usize, Range<usize>, Underline)>,
+    lazy: Option<Lazy>,
     history: History,
+    edits: Vec<(Range<Position>, String)>,
     font: Font,
     bounds: Size,
     topmost_line_changed: Option<usize>,
@@ -29,6 +37,24 @@ struct Internal {
     version: text::Version,
 }
 
+/// The state of an [`Editor`] with [`Storage::Lazy`].
+///
+/// The buffer of the editor only contains the lines of the rope around
+/// the visible ones.
+struct Lazy {
+    rope: Rope,
+    /// The line of the rope at the start of the buffer.
+    offset: usize,
+    /// The cursor of the editor, if it does not fit in the buffer.
+    detached: Option<Cursor>,
+    /// The first line of the buffer that may be missing its highlighting.
+    unhighlighted: Option<usize>,
+}
+
+/// The amount of lines kept in the buffer of a lazy [`Editor`] around the
+/// visible ones.
+const MARGIN: usize = 128;
+
 impl Editor {
     /// Creates a new empty [`Editor`].
     pub fn new() -> Self {
@@ -108,13 +134,144 @@ impl editor::Editor for Editor {
         })))
     }
 
+    fn with_storage(text: &str, storage: Storage) -> Self {
+        match storage {
+            Storage::Full => Self::with_text(text),
+            Storage::Lazy => {
+                let rope = Rope::new(text);
+                let mut editor = Self::with_text(&rope.get(0..MARGIN));
+
+                editor.with_internal_mut(|internal| {
+                    internal.lazy = Some(Lazy {
+                        rope,
+                        offset: 0,
+                        detached: None,
+                        unhighlighted: None,
+                    });
+                });
+
+                editor
+            }
+        }
+    }
+
+    fn storage(&self) -> Storage {
+        if self.internal().lazy.is_some() {
+            Storage::Lazy
+        } else {
+            Storage::Full
+        }
+    }
+
     fn is_empty(&self) -> bool {
-        let buffer = self.buffer();
+        let internal = self.internal();
+
+        if let Some(lazy) = &internal.lazy {
+            return lazy.rope.is_empty();
+        }
+
+        let buffer = buffer_from_editor(&internal.editor);
 
         buffer.lines.is_empty() || (buffer.lines.len() == 1 && buffer.lines[0].text().is_empty())
     }
 
+    fn region(&self, start: Position, end: Position) -> Vec<Rectangle> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+
+        let line_count = internal
+            .lazy
+            .as_ref()
+            .map_or(buffer.lines.len(), |lazy| lazy.rope.line_count());
+
+        if start.line >= line_count || end.line >= line_count {
+            return Vec::new();
+        }
+
+        if start == end {
+            let Some(line) = start
+                .line
+                .checked_sub(offset(internal))
+                .filter(|line| *line < buffer.lines.len())
+            else {
+                return Vec::new();
+            };
+
+            if buffer.lines[line].layout_opt().is_none() {
+                return Vec::new();
+            }
+
+            let line_height = buffer.metrics().line_height / internal.hint_factor;
+
+            return vec![Rectangle::new(
+                caret(
+                    internal,
+                    cosmic_text::Cursor {
+                        line,
+                        index: start.column,
+                        affinity: cosmic_text::Affinity::Before,
+                    },
+                ),
+                Size::new(0.0, line_height),
+            )];
+        }
+
+        clamped_regions(internal, start, end)
+    }
+
+    fn position_at(&self, point: Point) -> Option<Position> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+        let scroll = buffer.scroll();
+
+        let cursor = buffer.hit(
+            (point.x + scroll.horizontal) * internal.hint_factor,
+            point.y * internal.hint_factor,
+        )?;
+
+        Some(Position {
+            line: offset(internal) + cursor.line,
+            column: cursor.index,
+        })
+    }
+
+    fn underlines(&self) -> Vec<(Rectangle, Underline)> {
+        let internal = self.internal();
+        let buffer = buffer_from_editor(&internal.editor);
+        let offset = offset(internal);
+
+        internal
+            .underlines
+            .iter()
+            .filter_map(|(line, range, underline)| {
+                let line = line
+                    .checked_sub(offset)
+                    .filter(|line| *line < buffer.lines.len())?;
+
+                Some((line, range, underline))
+            })
+            .flat_map(|(line, range, underline)| {
+                let cursor = |index| cosmic_text::Cursor {
+                    line,
+                    index,
+                    affinity: cosmic_text::Affinity::Before,
+                };
+
+                regions(internal, cursor(range.start), cursor(range.end))
+                    .into_iter()
+                    .map(|region| (region, *underline))
+            })
+            .collect()
+    }
+
     fn line(&self, index: usize) -> Option<editor::Line<'_>> {
+        if let Some(lazy) = &self.internal().lazy {
+            return lazy.rope.line(index).map(|(text, ending)| editor::Line {
+                text: Cow::Borrowed(text),
+                ending,
+            });
+        }
+
         self.buffer().lines.get(index).map(|line| editor::Line {
             text: Cow::Borrowed(line.text()),
             ending: match line.ending() {
@@ -128,11 +285,50 @@ impl editor::Editor for Editor {
     }
 
     fn line_count(&self) -> usize {
+        if let Some(lazy) = &self.internal().lazy {
+            return lazy.rope.line_count();
+        }
+
         self.buffer().lines.len()
     }
 
+    fn text(&self) -> String {
+        if let Some(lazy) = &self.internal().lazy {
+            return lazy.rope.text();
+        }
+
+        let lines = &self.buffer().lines;
+        let mut text = String::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            text.push_str(line.text());
+
+            if i + 1 < lines.len() {
+                text.push_str(match line.ending() {
+                    cosmic_text::LineEnding::None => editor::LineEnding::default().as_str(),
+                    ending => ending.as_str(),
+                });
+            }
+        }
+
+        text
+    }
+
     fn copy(&self) -> Option<String> {
-        self.internal().editor.copy_selection()
+        let internal = self.internal();
+
+        if let Some(lazy) = &internal.lazy
+            && let Some(Cursor {
+                position,
+                selection: Some(selection),
+            }) = lazy.detached
+        {
+            let (start, end) = ordered(position, selection);
+
+            return Some(lazy.rope.slice(start, end));
+        }
+
+        internal.editor.copy_selection()
     }
 
     fn selection(&self) -> editor::Selection {
@@ -142,115 +338,30 @@ impl editor::Editor for Editor {
             return cursor.clone();
         }
 
-        let cursor = internal.editor.cursor();
-        let buffer = buffer_from_editor(&internal.editor);
-        let scroll = buffer.scroll();
-
-        let cursor = match internal.editor.selection_bounds() {
-            Some((start, end)) => {
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
-
-                let visual_lines_offset = visual_lines_offset(start.line, buffer);
+        let detached = internal.lazy.as_ref().and_then(|lazy| lazy.detached);
 
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(
-                                Rectangle {
-                                    x: x - scroll.horizontal,
-                                    width,
-                                    y: (visual_line as i32 + visual_lines_offset) as f32
-                                        * line_height
-                                        - scroll.vertical,
-                                    height: line_height,
-                                } * (1.0 / internal.hint_factor),
-                            )
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                Selection::Range(regions)
+        let cursor = if let Some(Cursor {
+            position,
+            selection,
+        }) = detached
+        {
+            match selection {
+                Some(selection) => Selection::Range(clamped_regions(internal, position, selection)),
+                None => {
+                    let buffer = buffer_from_editor(&internal.editor);
+                    let top = offset(internal) + buffer.scroll().line;
+
+                    Selection::Caret(Point::new(
+                        0.0,
+                        (position.line as f32 - top as f32) * buffer.metrics().line_height
+                            / internal.hint_factor,
+                    ))
+                }
             }
-            _ => {
-                let line_height = buffer.metrics().line_height;
-
-                let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
-
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
-
-                let layout = line.layout_opt().expect("Line layout should be cached");
-
-                let mut lines = layout.iter().enumerate();
-
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
-                        let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
-
-                        let is_cursor_before_start = start > cursor.index;
-
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => cursor.index <= end,
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
-
-                Selection::Caret(Point::new(
-                    (offset - scroll.horizontal) / internal.hint_factor,
-                    ((visual_lines_offset + visual_line as i32) as f32 * line_height
-                        - scroll.vertical)
-                        / internal.hint_factor,
-                ))
+        } else {
+            match internal.editor.selection_bounds() {
+                Some((start, end)) => Selection::Range(regions(internal, start, end)),
+                _ => Selection::Caret(caret(internal, internal.editor.cursor())),
             }
         };
 
@@ -260,37 +371,20 @@ impl editor::Editor for Editor {
     }
 
     fn cursor(&self) -> Cursor {
-        let editor = &self.internal().editor;
-
-        let position = {
-            let cursor = editor.cursor();
-
-            Position {
-                line: cursor.line,
-                column: cursor.index,
-            }
-        };
-
-        let selection = match editor.selection() {
-            cosmic_text::Selection::None => None,
-            cosmic_text::Selection::Normal(cursor)
-            | cosmic_text::Selection::Line(cursor)
-            | cosmic_text::Selection::Word(cursor) => Some(Position {
-                line: cursor.line,
-                column: cursor.index,
-            }),
-        };
-
-        Cursor {
-            position,
-            selection,
-        }
+        cursor(self.internal())
     }
 
     fn perform(&mut self, action: Action) {
         let mut font_system = text::font_system().write().expect("Write font system");
 
         self.with_internal_mut(|internal| {
+            let Some(action) = perform_lazy(internal, action, font_system.raw()) else {
+                return;
+            };
+
+            let is_edit = matches!(action, Action::Edit(_));
+            let window = buffer_from_editor(&internal.editor).lines.len();
+            let offset = offset(internal);
             let editor = &mut internal.editor;
 
             match action {
@@ -387,6 +481,10 @@ impl editor::Editor for Editor {
                     }
                 }
 
+                Action::MoveTo(cursor) => {
+                    set_cursor(editor, internal.lazy.as_mut(), cursor);
+                }
+
                 // Editing events
                 Action::Edit(edit) => {
                     let topmost_line_before_edit = editor
@@ -422,16 +520,20 @@ impl editor::Editor for Editor {
                             editor.action(font_system.raw(), cosmic_text::Action::Delete);
                         }
                         Edit::Undo => {
-                            if let Some(change) = internal.history.undo() {
+                            if let Some(Change::Buffer(change)) = internal.history.undo() {
                                 let mut change = change.clone();
                                 change.reverse();
 
-                                let _ = editor.apply_change(&change);
+                                if editor.apply_change(&change) {
+                                    record(&mut internal.edits, &change, offset);
+                                }
                             }
                         }
                         Edit::Redo => {
-                            if let Some(change) = internal.history.redo() {
-                                let _ = editor.apply_change(change);
+                            if let Some(Change::Buffer(change)) = internal.history.redo()
+                                && editor.apply_change(change)
+                            {
+                                record(&mut internal.edits, change, offset);
                             }
                         }
                     }
@@ -443,7 +545,7 @@ impl editor::Editor for Editor {
                         .unwrap_or(cursor);
 
                     internal.topmost_line_changed =
-                        Some(selection_start.line.min(topmost_line_before_edit));
+                        Some(offset + selection_start.line.min(topmost_line_before_edit));
                 }
 
                 // Mouse events
@@ -490,15 +592,33 @@ impl editor::Editor for Editor {
             if let Some(change) = editor.finish_change()
                 && !change.items.is_empty()
             {
-                internal.history.push(change);
+                record(&mut internal.edits, &change, offset);
+                internal.history.push(Change::Buffer(change));
             }
 
-            shape_until_cursor(editor, &mut font_system.raw);
+            if internal
+                .lazy
+                .as_ref()
+                .is_none_or(|lazy| lazy.detached.is_none())
+            {
+                shape_until_cursor(editor, &mut font_system.raw);
+            }
+
+            if is_edit {
+                sync(internal, window);
+            }
+
+            follow_scroll(internal);
         });
     }
 
     fn move_to(&mut self, cursor: Cursor) {
         self.with_internal_mut(|internal| {
+            if internal.lazy.is_some() {
+                set_cursor(&mut internal.editor, internal.lazy.as_mut(), cursor);
+                return;
+            }
+
             // TODO: Expose `Affinity`
             internal.editor.set_cursor(cosmic_text::Cursor {
                 line: cursor.position.line,
@@ -525,7 +645,17 @@ impl editor::Editor for Editor {
     fn min_bounds(&self) -> Size {
         let internal = self.internal();
 
-        let (bounds, _has_rtl) = text::measure(buffer_from_editor(&internal.editor));
+        let buffer = buffer_from_editor(&internal.editor);
+        let (bounds, _has_rtl) = text::measure(buffer);
+
+        let bounds = if let Some(lazy) = &internal.lazy {
+            Size::new(
+                bounds.width,
+                lazy.rope.line_count() as f32 * buffer.metrics().line_height,
+            )
+        } else {
+            bounds
+        };
 
         bounds * (1.0 / internal.hint_factor)
     }
@@ -625,7 +755,14 @@ impl editor::Editor for Editor {
                 internal.bounds = new_bounds;
             }
 
-            buffer.shape_until_scroll(font_system.raw(), false);
+            follow_scroll(internal);
+
+            buffer_mut_from_editor(&mut internal.editor)
+                .shape_until_scroll(font_system.raw(), false);
+
+            for (range, text) in internal.edits.drain(..) {
+                new_highlighter.edit(range, &text);
+            }
 
             if let Some(topmost_line_changed) = internal.topmost_line_changed.take() {
                 log::trace!(
@@ -636,6 +773,13 @@ impl editor::Editor for Editor {
                 new_highlighter.change_line(topmost_line_changed);
             }
 
+            if let Some(lazy) = &mut internal.lazy
+                && let Some(unhighlighted) = lazy.unhighlighted.take()
+                && unhighlighted < new_highlighter.current_line()
+            {
+                new_highlighter.change_line(unhighlighted);
+            }
+
             internal.editor.shape_as_needed(font_system.raw(), false);
         });
     }
@@ -673,12 +817,21 @@ impl editor::Editor for Editor {
             })
             .unwrap_or(buffer.lines.len().saturating_sub(1));
 
+        let offset = offset(internal);
+        let last_visible_line = offset + last_visible_line;
+
         let current_line = highlighter.current_line();
 
         if current_line > last_visible_line {
             return;
         }
 
+        if let Some(lazy) = &internal.lazy {
+            highlighter.prepare(lazy.rope.lines());
+        } else {
+            highlighter.prepare(buffer.lines.iter().map(cosmic_text::BufferLine::text));
+        }
+
         let editor = self.0.take().expect("Editor should always be initialized");
 
         let mut internal =
@@ -688,14 +841,43 @@ impl editor::Editor for Editor {
 
         let attributes = text::to_attributes(font);
 
-        for line in &mut buffer_mut_from_editor(&mut internal.editor).lines
-            [current_line..=last_visible_line]
-        {
+        internal
+            .underlines
+            .retain(|(line, _range, _underline)| *line < current_line);
+
+        let Internal {
+            editor,
+            underlines,
+            lazy,
+            ..
+        } = &mut internal;
+
+        let buffer = buffer_mut_from_editor(editor);
+
+        for index in current_line..=last_visible_line {
+            let Some(line) = index
+                .checked_sub(offset)
+                .and_then(|line| buffer.lines.get_mut(line))
+            else {
+                // The line is not in the buffer, but the highlighter
+                // still needs to go through it
+                if let Some((line, _ending)) = lazy.as_ref().and_then(|lazy| lazy.rope.line(index))
+                {
+                    let _ = highlighter.highlight_line(line).count();
+                }
+
+                continue;
+            };
+
             let mut list = cosmic_text::AttrsList::new(&attributes);
 
             for (range, highlight) in highlighter.highlight_line(line.text()) {
                 let format = format_highlight(&highlight);
 
+                if let Some(underline) = format.underline {
+                    underlines.push((index, range.clone(), underline));
+                }
+
                 if format.color.is_some() || format.font.is_some() {
                     list.add_span(
                         range,
@@ -745,7 +927,10 @@ impl Default for Internal {
                 },
             )),
             selection: RwLock::new(None),
+            underlines: Vec::new(),
+            lazy: None,
             history: History::new(),
+            edits: Vec::new(),
             font: Font::default(),
             bounds: Size::ZERO,
             topmost_line_changed: None,
@@ -789,6 +974,741 @@ impl PartialEq for Weak {
     }
 }
 
+/// Returns the line of the whole text at the start of the buffer.
+fn offset(internal: &Internal) -> usize {
+    internal.lazy.as_ref().map_or(0, |lazy| lazy.offset)
+}
+
+/// Returns the current cursor of the editor, relative to the whole text.
+fn cursor(internal: &Internal) -> Cursor {
+    if let Some(cursor) = internal.lazy.as_ref().and_then(|lazy| lazy.detached) {
+        return cursor;
+    }
+
+    let offset = offset(internal);
+    let editor = &internal.editor;
+
+    let position = {
+        let cursor = editor.cursor();
+
+        Position {
+            line: offset + cursor.line,
+            column: cursor.index,
+        }
+    };
+
+    let selection = match editor.selection() {
+        cosmic_text::Selection::None => None,
+        cosmic_text::Selection::Normal(cursor)
+        | cosmic_text::Selection::Line(cursor)
+        | cosmic_text::Selection::Word(cursor) => Some(Position {
+            line: offset + cursor.line,
+            column: cursor.index,
+        }),
+    };
+
+    Cursor {
+        position,
+        selection,
+    }
+}
+
+/// Sets the cursor of the editor, relative to the whole text.
+///
+/// The cursor of a lazy editor is detached if it does not fit in its buffer.
+fn set_cursor(editor: &mut cosmic_text::Editor<'static>, lazy: Option<&mut Lazy>, cursor: Cursor) {
+    let is_lazy = lazy.is_some();
+    let offset = lazy.as_ref().map_or(0, |lazy| lazy.offset);
+    let lines = buffer_from_editor(editor).lines.len();
+
+    let local = |position: Position| {
+        (!is_lazy || (offset..offset + lines).contains(&position.line)).then(|| {
+            cosmic_text::Cursor {
+                line: position.line - offset,
+                index: position.column,
+                affinity: cosmic_text::Affinity::Before,
+            }
+        })
+    };
+
+    let position = local(cursor.position);
+    let selection = cursor.selection.map(local);
+
+    editor.set_selection(cosmic_text::Selection::None);
+
+    if let Some(position) = position
+        && selection.is_none_or(|selection| selection.is_some())
+    {
+        editor.set_cursor(position);
+
+        if let Some(Some(selection)) = selection {
+            editor.set_selection(cosmic_text::Selection::Normal(selection));
+        }
+
+        if let Some(lazy) = lazy {
+            lazy.detached = None;
+        }
+    } else {
+        editor.set_cursor(cosmic_text::Cursor {
+            line: 0,
+            index: 0,
+            affinity: cosmic_text::Affinity::Before,
+        });
+
+        if let Some(lazy) = lazy {
+            lazy.detached = Some(cursor);
+        }
+    }
+}
+
+/// Performs the actions of a lazy editor that need special handling.
+///
+/// It returns the action back if it still needs to be performed in the
+/// buffer.
+fn perform_lazy(
+    internal: &mut Internal,
+    action: Action,
+    font_system: &mut cosmic_text::FontSystem,
+) -> Option<Action> {
+    if internal.lazy.is_none() {
+        return Some(action);
+    }
+
+    if let Action::Edit(edit @ (Edit::Undo | Edit::Redo)) = &action {
+        let is_undo = matches!(edit, Edit::Undo);
+
+        match internal.history.next(is_undo) {
+            Some(Change::Rope(replacements)) => {
+                let replacements: Vec<_> = if is_undo {
+                    replacements
+                        .iter()
+                        .rev()
+                        .map(Replacement::reversed)
+                        .collect()
+                } else {
+                    replacements.clone()
+                };
+
+                let _ = if is_undo {
+                    internal.history.undo()
+                } else {
+                    internal.history.redo()
+                };
+
+                for replacement in &replacements {
+                    splice(internal, replacement);
+                }
+            }
+            Some(Change::Buffer(_)) => {
+                // Changes of the buffer always fit in it, and they move the
+                // cursor themselves; so revealing a detached cursor would only
+                // forget them
+                if let Some(lazy) = &mut internal.lazy {
+                    lazy.detached = None;
+                }
+
+                internal.editor.set_selection(cosmic_text::Selection::None);
+
+                return Some(action);
+            }
+            None => {}
+        }
+
+        return None;
+    }
+
+    let Some(lazy) = &internal.lazy else {
+        return Some(action);
+    };
+
+    let is_detached = lazy.detached.is_some();
+    let last_line = lazy.rope.line_count() - 1;
+
+    let end = Position {
+        line: last_line,
+        column: lazy
+            .rope
+            .line(last_line)
+            .map(|(line, _ending)| line.len())
+            .unwrap_or_default(),
+    };
+
+    let current = cursor(internal);
+
+    if is_detached
+        && let Some(selection) = current.selection
+        && let Action::Edit(edit) = &action
+    {
+        let text = match edit {
+            Edit::Insert(c) => Some(c.to_string()),
+            Edit::Paste(text) => Some(String::clone(text)),
+            Edit::Enter => Some(String::from("\n")),
+            Edit::Backspace | Edit::Delete => Some(String::new()),
+            Edit::Indent | Edit::Unindent | Edit::Undo | Edit::Redo => None,
+        };
+
+        if let Some(text) = text {
+            replace(internal, ordered(current.position, selection), &text);
+            return None;
+        }
+    }
+
+    match action {
+        Action::Scroll { lines } => {
+            let scroll = buffer_from_editor(&internal.editor).scroll();
+            let line = (offset(internal) + scroll.line).saturating_add_signed(lines as isize);
+
+            load(
+                internal,
+                cosmic_text::Scroll {
+                    line,
+                    vertical: 0.0,
+                    ..scroll
+                },
+                false,
+            );
+
+            None
+        }
+        Action::Move(motion @ (Motion::DocumentStart | Motion::DocumentEnd))
+        | Action::Select(motion @ (Motion::DocumentStart | Motion::DocumentEnd)) => {
+            let position = if motion == Motion::DocumentStart {
+                Position { line: 0, column: 0 }
+            } else {
+                end
+            };
+
+            let selection = matches!(action, Action::Select(_))
+                .then(|| current.selection.unwrap_or(current.position))
+                .filter(|selection| *selection != position);
+
+            set_cursor(
+                &mut internal.editor,
+                internal.lazy.as_mut(),
+                Cursor {
+                    position,
+                    selection,
+                },
+            );
+
+            reveal(internal, position.line);
+
+            None
+        }
+        Action::SelectAll => {
+            let start = Position { line: 0, column: 0 };
+
+            set_cursor(
+                &mut internal.editor,
+                internal.lazy.as_mut(),
+                Cursor {
+                    position: end,
+                    selection: (end != start).then_some(start),
+                },
+            );
+
+            None
+        }
+        Action::MoveTo(cursor) => {
+            reveal(internal, cursor.position.line);
+
+            Some(action)
+        }
+        Action::Click(_) | Action::Drag(_) => {
+            if let Some(lazy) = &mut internal.lazy {
+                lazy.detached = None;
+            }
+
+            Some(action)
+        }
+        Action::Move(motion) if is_detached => {
+            let collapse = current
+                .selection
+                .filter(|_| !matches!(motion, Motion::Home | Motion::End))
+                .map(|selection| {
+                    let (start, end) = ordered(current.position, selection);
+
+                    match motion.direction() {
+                        Direction::Left => start,
+                        Direction::Right => end,
+                    }
+                });
+
+            let position = collapse.unwrap_or(current.position);
+
+            set_cursor(
+                &mut internal.editor,
+                internal.lazy.as_mut(),
+                Cursor {
+                    position,
+                    selection: None,
+                },
+            );
+
+            reveal(internal, position.line);
+
+            collapse.is_none().then_some(action)
+        }
+        Action::Select(motion) if is_detached => {
+            let anchor = current.selection.unwrap_or(current.position);
+
+            set_cursor(
+                &mut internal.editor,
+                internal.lazy.as_mut(),
+                Cursor {
+                    position: current.position,
+                    selection: None,
+                },
+            );
+
+            reveal(internal, current.position.line);
+
+            internal
+                .editor
+                .action(font_system, cosmic_text::Action::Motion(to_motion(motion)));
+
+            let position = cursor(internal).position;
+
+            set_cursor(
+                &mut internal.editor,
+                internal.lazy.as_mut(),
+                Cursor {
+                    position,
+                    selection: (anchor != position).then_some(anchor),
+                },
+            );
+
+            None
+        }
+        _ if is_detached => {
+            reveal(internal, current.position.line);
+
+            // The selection may still not fit in the buffer
+            let selection = current
+                .selection
+                .map(|selection| clamp(internal, selection));
+
+            set_cursor(
+                &mut internal.editor,
+                internal.lazy.as_mut(),
+                Cursor {
+                    position: current.position,
+                    selection,
+                },
+            );
+
+            Some(action)
+        }
+        _ => Some(action),
+    }
+}
+
+/// Replaces the text between the given positions in the rope of a lazy
+/// editor, placing the cursor at the end of the new text.
+///
+/// The replacement can be undone.
+fn replace(internal: &mut Internal, (start, end): (Position, Position), text: &str) {
+    let Some(lazy) = &internal.lazy else {
+        return;
+    };
+
+    let replacement = Replacement {
+        start,
+        removed: lazy.rope.slice(start, end),
+        inserted: text.to_owned(),
+    };
+
+    splice(internal, &replacement);
+    internal.history.push(Change::Rope(vec![replacement]));
+}
+
+/// Applies a [`Replacement`] to the rope of a lazy editor, placing the
+/// cursor at the end of the inserted text.
+fn splice(internal: &mut Internal, replacement: &Replacement) {
+    let scroll = buffer_from_editor(&internal.editor).scroll();
+    let visible = visible_lines(internal);
+
+    let Some(lazy) = &mut internal.lazy else {
+        return;
+    };
+
+    let Replacement {
+        start,
+        removed,
+        inserted,
+    } = replacement;
+
+    let start = *start;
+    let end = end_of(start, removed);
+    let position = end_of(start, inserted);
+
+    let replacement = lazy
+        .rope
+        .line(start.line)
+        .and_then(|(line, _ending)| line.get(..start.column))
+        .unwrap_or_default()
+        .to_owned()
+        + inserted
+        + lazy
+            .rope
+            .line(end.line)
+            .and_then(|(line, _ending)| line.get(end.column..))
+            .unwrap_or_default();
+
+    lazy.rope.replace(start.line..end.line + 1, &replacement);
+    internal.edits.push((start..end, inserted.clone()));
+
+    lazy.detached = Some(Cursor {
+        position,
+        selection: None,
+    });
+
+    internal.topmost_line_changed = Some(
+        internal
+            .topmost_line_changed
+            .map_or(start.line, |line| line.min(start.line)),
+    );
+
+    load(
+        internal,
+        cosmic_text::Scroll {
+            line: position.line.saturating_sub(visible / 2),
+            vertical: 0.0,
+            ..scroll
+        },
+        true,
+    );
+}
+
+/// Returns the position at the end of the given text, if it started at
+/// the given position.
+fn end_of(start: Position, text: &str) -> Position {
+    let text = Rope::new(text);
+    let last_line = text.line_count() - 1;
+    let last = text
+        .line(last_line)
+        .map(|(line, _ending)| line.len())
+        .unwrap_or_default();
+
+    if last_line == 0 {
+        Position {
+            line: start.line,
+            column: start.column + last,
+        }
+    } else {
+        Position {
+            line: start.line + last_line,
+            column: last,
+        }
+    }
+}
+
+/// Records the edits of the given change of the buffer, whose first line
+/// is at the given offset of the whole text.
+fn record(edits: &mut Vec<(Range<Position>, String)>, change: &cosmic_text::Change, offset: usize) {
+    let position = |cursor: cosmic_text::Cursor| Position {
+        line: offset + cursor.line,
+        column: cursor.index,
+    };
+
+    edits.extend(change.items.iter().map(|item| {
+        let start = position(item.start);
+
+        if item.insert {
+            (start..start, item.text.clone())
+        } else {
+            (start..position(item.end), String::new())
+        }
+    }));
+}
+
+/// Loads the lines of the rope of a lazy editor around the given scroll
+/// into its buffer.
+///
+/// The line of the scroll is relative to the whole text. The buffer is
+/// only reloaded if its lines change, unless forced.
+fn load(internal: &mut Internal, scroll: cosmic_text::Scroll, force: bool) {
+    let cursor = cursor(internal);
+    let visible = visible_lines(internal);
+
+    let Some(lazy) = &mut internal.lazy else {
+        return;
+    };
+
+    let line_count = lazy.rope.line_count();
+    let top = scroll.line.min(line_count - 1);
+    let start = top.saturating_sub(MARGIN);
+    let end = top
+        .saturating_add(visible)
+        .saturating_add(MARGIN)
+        .min(line_count);
+
+    let buffer = buffer_mut_from_editor(&mut internal.editor);
+
+    if force || start != lazy.offset || end - start != buffer.lines.len() {
+        log::trace!("Loading lines {start}..{end} into `Editor`...");
+
+        buffer.set_text(
+            &lazy.rope.get(start..end),
+            &text::to_attributes(internal.font),
+            cosmic_text::Shaping::Advanced,
+            None,
+        );
+
+        internal.history.rebase(lazy.offset, start, end - start);
+
+        lazy.offset = start;
+        lazy.unhighlighted = Some(lazy.unhighlighted.map_or(start, |line| line.min(start)));
+
+        set_cursor(&mut internal.editor, Some(lazy), cursor);
+    }
+
+    buffer_mut_from_editor(&mut internal.editor).set_scroll(cosmic_text::Scroll {
+        line: top - start,
+        ..scroll
+    });
+}
+
+/// Loads the lines around the given line of the whole text into the
+/// buffer of a lazy editor, unless they are already there.
+fn reveal(internal: &mut Internal, line: usize) {
+    let Some(lazy) = &internal.lazy else {
+        return;
+    };
+
+    let buffer = buffer_from_editor(&internal.editor);
+
+    if (lazy.offset..lazy.offset + buffer.lines.len()).contains(&line) {
+        return;
+    }
+
+    let scroll = buffer.scroll();
+
+    load(
+        internal,
+        cosmic_text::Scroll {
+            line: line.saturating_sub(visible_lines(internal) / 2),
+            vertical: 0.0,
+            ..scroll
+        },
+        false,
+    );
+}
+
+/// Loads more lines into the buffer of a lazy editor if its scroll gets
+/// close to the edges.
+fn follow_scroll(internal: &mut Internal) {
+    let Some(lazy) = &internal.lazy else {
+        return;
+    };
+
+    let buffer = buffer_from_editor(&internal.editor);
+    let scroll = buffer.scroll();
+    let lines = buffer.lines.len();
+
+    let is_near_start = lazy.offset > 0 && scroll.line < MARGIN / 2;
+    let is_near_end = lazy.offset + lines < lazy.rope.line_count()
+        && lines.saturating_sub(scroll.line) < visible_lines(internal).saturating_add(MARGIN / 2);
+
+    if is_near_start || is_near_end {
+        let line = lazy.offset + scroll.line;
+
+        load(internal, cosmic_text::Scroll { line, ..scroll }, false);
+    }
+}
+
+/// Writes the buffer of a lazy editor back to its rope, replacing the
+/// given amount of lines.
+fn sync(internal: &mut Internal, lines: usize) {
+    let Some(lazy) = &mut internal.lazy else {
+        return;
+    };
+
+    let mut text = String::new();
+
+    for line in &buffer_from_editor(&internal.editor).lines {
+        text.push_str(line.text());
+        text.push_str(line.ending().as_str());
+    }
+
+    lazy.rope.replace(lazy.offset..lazy.offset + lines, &text);
+}
+
+/// Returns the amount of lines that fit in the bounds of the editor.
+fn visible_lines(internal: &Internal) -> usize {
+    let buffer = buffer_from_editor(&internal.editor);
+
+    (internal.bounds.height * internal.hint_factor / buffer.metrics().line_height).ceil() as usize
+}
+
+/// Clamps the given position of the whole text to the lines in the buffer.
+fn clamp(internal: &Internal, position: Position) -> Position {
+    let offset = offset(internal);
+    let lines = &buffer_from_editor(&internal.editor).lines;
+
+    if position.line < offset {
+        Position {
+            line: offset,
+            column: 0,
+        }
+    } else if position.line >= offset + lines.len() {
+        Position {
+            line: offset + lines.len() - 1,
+            column: lines
+                .last()
+                .map(|line| line.text().len())
+                .unwrap_or_default(),
+        }
+    } else {
+        position
+    }
+}
+
+/// Returns the regions between the given positions of the whole text,
+/// clamped to the lines in the buffer.
+fn clamped_regions(internal: &Internal, start: Position, end: Position) -> Vec<Rectangle> {
+    let offset = offset(internal);
+    let lines = buffer_from_editor(&internal.editor).lines.len();
+    let (start, end) = ordered(start, end);
+
+    if end.line < offset || start.line >= offset + lines {
+        return Vec::new();
+    }
+
+    let cursor = |position: Position| {
+        let position = clamp(internal, position);
+
+        cosmic_text::Cursor {
+            line: position.line - offset,
+            index: position.column,
+            affinity: cosmic_text::Affinity::Before,
+        }
+    };
+
+    regions(internal, cursor(start), cursor(end))
+}
+
+fn ordered(a: Position, b: Position) -> (Position, Position) {
+    if (a.line, a.column) <= (b.line, b.column) {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn caret(internal: &Internal, cursor: cosmic_text::Cursor) -> Point {
+    let buffer = buffer_from_editor(&internal.editor);
+    let scroll = buffer.scroll();
+
+    let line_height = buffer.metrics().line_height;
+
+    let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
+
+    let line = buffer
+        .lines
+        .get(cursor.line)
+        .expect("Cursor line should be present");
+
+    let layout = line.layout_opt().expect("Line layout should be cached");
+
+    let mut lines = layout.iter().enumerate();
+
+    let (visual_line, offset) = lines
+        .find_map(|(i, line)| {
+            let start = line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
+            let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+            let is_cursor_before_start = start > cursor.index;
+
+            let is_cursor_before_end = match cursor.affinity {
+                cosmic_text::Affinity::Before => cursor.index <= end,
+                cosmic_text::Affinity::After => cursor.index < end,
+            };
+
+            if is_cursor_before_start {
+                // Sometimes, the glyph we are looking for is right
+                // between lines. This can happen when a line wraps
+                // on a space.
+                // In that case, we can assume the cursor is at the
+                // end of the previous line.
+                // i is guaranteed to be > 0 because `start` is always
+                // 0 for the first line, so there is no way for the
+                // cursor to be before it.
+                Some((i - 1, layout[i - 1].w))
+            } else if is_cursor_before_end {
+                let offset = line
+                    .glyphs
+                    .iter()
+                    .take_while(|glyph| cursor.index > glyph.start)
+                    .map(|glyph| glyph.w)
+                    .sum();
+
+                Some((i, offset))
+            } else {
+                None
+            }
+        })
+        .unwrap_or((
+            layout.len().saturating_sub(1),
+            layout.last().map(|line| line.w).unwrap_or(0.0),
+        ));
+
+    Point::new(
+        (offset - scroll.horizontal) / internal.hint_factor,
+        ((visual_lines_offset + visual_line as i32) as f32 * line_height - scroll.vertical)
+            / internal.hint_factor,
+    )
+}
+
+fn regions(
+    internal: &Internal,
+    start: cosmic_text::Cursor,
+    end: cosmic_text::Cursor,
+) -> Vec<Rectangle> {
+    let buffer = buffer_from_editor(&internal.editor);
+    let scroll = buffer.scroll();
+
+    let line_height = buffer.metrics().line_height;
+    let selected_lines = end.line - start.line + 1;
+
+    let visual_lines_offset = visual_lines_offset(start.line, buffer);
+
+    buffer
+        .lines
+        .iter()
+        .skip(start.line)
+        .take(selected_lines)
+        .enumerate()
+        .flat_map(|(i, line)| {
+            highlight_line(
+                line,
+                if i == 0 { start.index } else { 0 },
+                if i == selected_lines - 1 {
+                    end.index
+                } else {
+                    line.text().len()
+                },
+            )
+        })
+        .enumerate()
+        .filter_map(|(visual_line, (x, width))| {
+            if width > 0.0 {
+                Some(
+                    Rectangle {
+                        x: x - scroll.horizontal,
+                        width,
+                        y: (visual_line as i32 + visual_lines_offset) as f32 * line_height
+                            - scroll.vertical,
+                        height: line_height,
+                    } * (1.0 / internal.hint_factor),
+                )
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
@@ -918,16 +1838,42 @@ fn shape_until_cursor(
 
 #[derive(Default)]
 struct History {
-    changes: Vec<cosmic_text::Change>,
+    changes: Vec<Change>,
     current: usize,
 }
 
+/// An undoable change of an [`Editor`].
+enum Change {
+    /// A change of the buffer, relative to its first line.
+    Buffer(cosmic_text::Change),
+    /// Some replacements in the rope of a lazy editor, relative to the whole
+    /// text.
+    Rope(Vec<Replacement>),
+}
+
+#[derive(Clone)]
+struct Replacement {
+    start: Position,
+    removed: String,
+    inserted: String,
+}
+
+impl Replacement {
+    fn reversed(&self) -> Self {
+        Self {
+            start: self.start,
+            removed: self.inserted.clone(),
+            inserted: self.removed.clone(),
+        }
+    }
+}
+
 impl History {
     fn new() -> Self {
         Self::default()
     }
 
-    fn undo(&mut self) -> Option<&cosmic_text::Change> {
+    fn undo(&mut self) -> Option<&Change> {
         if self.current == 0 {
             return None;
         }
@@ -936,7 +1882,7 @@ impl History {
         self.changes.get(self.current)
     }
 
-    fn redo(&mut self) -> Option<&cosmic_text::Change> {
+    fn redo(&mut self) -> Option<&Change> {
         if self.current >= self.changes.len() {
             return None;
         }
@@ -946,9 +1892,298 @@ impl History {
         change
     }
 
-    fn push(&mut self, change: cosmic_text::Change) {
+    /// Returns the change that would be undone or redone next.
+    fn next(&self, is_undo: bool) -> Option<&Change> {
+        if is_undo {
+            self.changes.get(self.current.checked_sub(1)?)
+        } else {
+            self.changes.get(self.current)
+        }
+    }
+
+    fn push(&mut self, change: Change) {
         self.changes.truncate(self.current);
         self.changes.push(change);
         self.current += 1;
     }
+
+    /// Moves the changes of the buffer from the given offset to a new one,
+    /// turning the ones that do not fit in the given amount of lines into
+    /// replacements of the whole text.
+    fn rebase(&mut self, from: usize, to: usize, lines: usize) {
+        for change in &mut self.changes {
+            let Change::Buffer(buffer) = change else {
+                continue;
+            };
+
+            let fits = buffer.items.iter().all(|item| {
+                [item.start.line, item.end.line]
+                    .into_iter()
+                    .all(|line| (to..to + lines).contains(&(line + from)))
+            });
+
+            if fits {
+                for item in &mut buffer.items {
+                    item.start.line = item.start.line + from - to;
+                    item.end.line = item.end.line + from - to;
+                }
+            } else {
+                *change = Change::Rope(
+                    buffer
+                        .items
+                        .iter()
+                        .map(|item| {
+                            let start = Position {
+                                line: item.start.line + from,
+                                column: item.start.index,
+                            };
+
+                            if item.insert {
+                                Replacement {
+                                    start,
+                                    removed: String::new(),
+                                    inserted: item.text.clone(),
+                                }
+                            } else {
+                                Replacement {
+                                    start,
+                                    removed: item.text.clone(),
+                                    inserted: String::new(),
+                                }
+                            }
+                        })
+                        .collect(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::Editor as _;
+    use crate::core::text::highlighter::PlainText;
+
+    const LINES: usize = 2_000;
+
+    fn lazy() -> Editor {
+        let text = (0..LINES)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut editor = Editor::with_storage(&text, Storage::Lazy);
+        update(&mut editor);
+
+        editor
+    }
+
+    fn update(editor: &mut Editor) {
+        editor.update(
+            Size::new(200.0, 100.0),
+            Font::MONOSPACE,
+            Pixels(10.0),
+            LineHeight::Absolute(Pixels(10.0)),
+            Wrapping::None,
+            None,
+            &mut PlainText,
+        );
+    }
+
+    fn offset(editor: &Editor) -> usize {
+        super::offset(editor.internal())
+    }
+
+    /// Returns the topmost visible line of the whole text.
+    fn top(editor: &Editor) -> usize {
+        offset(editor) + editor.buffer().scroll().line
+    }
+
+    fn at(line: usize, column: usize) -> Position {
+        Position { line, column }
+    }
+
+    #[test]
+    fn lazy_editor_only_loads_lines_around_the_viewport() {
+        let editor = lazy();
+
+        assert_eq!(editor.storage(), Storage::Lazy);
+        assert_eq!(editor.line_count(), LINES);
+        assert_eq!(offset(&editor), 0);
+        assert!((MARGIN..LINES).contains(&editor.buffer().lines.len()));
+
+        // Lines outside of the buffer are read from the rope
+        assert_eq!(
+            editor.line(LINES - 1).map(|line| line.text.into_owned()),
+            Some(format!("line {}", LINES - 1))
+        );
+    }
+
+    #[test]
+    fn lazy_editor_loads_lines_when_revealing_and_scrolling() {
+        let mut editor = lazy();
+
+        editor.perform(Action::Move(Motion::DocumentEnd));
+
+        assert_eq!(editor.cursor().position, at(LINES - 1, 9));
+        assert!(offset(&editor) > 0);
+        assert!(editor.buffer().lines.len() < LINES);
+
+        editor.perform(Action::Move(Motion::DocumentStart));
+
+        assert_eq!(editor.cursor().position, at(0, 0));
+        assert_eq!(offset(&editor), 0);
+
+        let top = top(&editor);
+
+        editor.perform(Action::Scroll { lines: 1_000 });
+
+        let offset_after_scroll = offset(&editor);
+
+        assert_eq!(self::top(&editor), top + 1_000);
+        assert!(offset_after_scroll > 0);
+
+        editor.perform(Action::Scroll {
+            lines: -(MARGIN as i32),
+        });
+
+        assert_eq!(self::top(&editor), top + 1_000 - MARGIN);
+        assert!(offset(&editor) < offset_after_scroll);
+        assert!(
+            (offset(&editor)..offset(&editor) + editor.buffer().lines.len())
+                .contains(&self::top(&editor))
+        );
+    }
+
+    #[test]
+    fn lazy_editor_follows_the_scroll_of_the_buffer() {
+        let mut editor = lazy();
+        let lines = editor.buffer().lines.len();
+
+        // Moving close to the end of the buffer scrolls it and loads more lines
+        editor.move_to(Cursor {
+            position: at(lines - 5, 0),
+            selection: None,
+        });
+        editor.perform(Action::Move(Motion::Down));
+
+        assert_eq!(editor.cursor().position, at(lines - 4, 0));
+        assert!(offset(&editor) + editor.buffer().lines.len() > lines);
+    }
+
+    #[test]
+    fn lazy_editor_writes_edits_back_to_the_rope() {
+        let mut editor = lazy();
+
+        editor.move_to(Cursor {
+            position: at(5, 6),
+            selection: None,
+        });
+        editor.perform(Action::Edit(Edit::Insert('!')));
+        editor.perform(Action::Edit(Edit::Enter));
+
+        assert_eq!(editor.line_count(), LINES + 1);
+        assert_eq!(editor.cursor().position, at(6, 0));
+
+        // The edits survive reloading the buffer
+        editor.perform(Action::Move(Motion::DocumentEnd));
+        editor.perform(Action::Move(Motion::DocumentStart));
+
+        let text = editor.text();
+        let lines: Vec<_> = text.lines().skip(4).take(3).collect();
+
+        assert_eq!(lines, ["line 4", "line 5!", ""]);
+        assert_eq!(
+            editor.line(LINES).map(|line| line.text.into_owned()),
+            Some(format!("line {}", LINES - 1))
+        );
+
+        editor.perform(Action::Edit(Edit::Undo));
+        editor.perform(Action::Edit(Edit::Undo));
+
+        assert_eq!(editor.line_count(), LINES);
+        assert!(
+            editor
+                .text()
+                .starts_with("line 0\nline 1\nline 2\nline 3\nline 4\nline 5\n")
+        );
+    }
+
+    #[test]
+    fn lazy_editor_detaches_cursor_outside_of_the_buffer() {
+        let mut editor = lazy();
+        let text = editor.text();
+
+        editor.perform(Action::SelectAll);
+
+        let cursor = editor.cursor();
+
+        assert_eq!(cursor.position, at(LINES - 1, 9));
+        assert_eq!(cursor.selection, Some(at(0, 0)));
+        assert_eq!(editor.copy(), Some(text.clone()));
+
+        // Moving collapses the detached selection
+        editor.perform(Action::Move(Motion::Left));
+
+        assert_eq!(editor.cursor().position, at(0, 0));
+        assert_eq!(editor.cursor().selection, None);
+
+        // Replacing a detached selection can be undone
+        editor.perform(Action::Select(Motion::DocumentEnd));
+        editor.perform(Action::Edit(Edit::Paste(Arc::new(String::from("a\nb")))));
+
+        assert_eq!(editor.text(), "a\nb");
+        assert_eq!(editor.cursor().position, at(1, 1));
+
+        editor.perform(Action::Edit(Edit::Undo));
+
+        assert_eq!(editor.text(), text);
+
+        editor.perform(Action::Edit(Edit::Redo));
+
+        assert_eq!(editor.text(), "a\nb");
+    }
+
+    #[test]
+    fn replacing_keeps_the_previous_history() {
+        let mut editor = lazy();
+        let text = editor.text();
+
+        editor.perform(Action::Edit(Edit::Insert('>')));
+
+        editor.perform(Action::Move(Motion::DocumentStart));
+        editor.perform(Action::Select(Motion::DocumentEnd));
+        editor.perform(Action::Edit(Edit::Backspace));
+
+        assert_eq!(editor.text(), "");
+
+        editor.perform(Action::Edit(Edit::Undo));
+
+        assert!(editor.text().starts_with(">line 0\n"));
+
+        editor.perform(Action::Edit(Edit::Undo));
+
+        assert_eq!(editor.text(), text);
+    }
+
+    #[test]
+    fn horizontal_motions_are_visual_in_right_to_left_lines() {
+        let mut editor = Editor::with_text("abc\nשלום");
+        update(&mut editor);
+
+        // Left moves towards the end of a right-to-left line...
+        editor.perform(Action::Move(Motion::Down));
+        editor.perform(Action::Move(Motion::Home));
+        editor.perform(Action::Move(Motion::Left));
+
+        assert_eq!(editor.cursor().position, at(1, "ש".len()));
+
+        // ...and towards the start of a left-to-right one
+        editor.perform(Action::Move(Motion::DocumentStart));
+        editor.perform(Action::Move(Motion::Right));
+        editor.perform(Action::Move(Motion::Left));
+
+        assert_eq!(editor.cursor().position, at(0, 0));
+    }
 }
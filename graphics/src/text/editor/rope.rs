@@ -0,0 +1,300 @@
+use crate::core::text::editor::{LineEnding, Position};
+
+use std::ops::Range;
+
+/// The maximum amount of lines in a [`Chunk`].
+const CHUNK_LINES: usize = 1024;
+
+/// A rope of lines.
+///
+/// The text is split in chunks of contiguous lines, which keeps edits
+/// local to a couple of chunks no matter the size of the text.
+#[derive(Debug, Clone)]
+pub struct Rope {
+    chunks: Vec<Chunk>,
+    line_count: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Chunk {
+    text: String,
+    starts: Vec<usize>,
+}
+
+impl Rope {
+    /// Creates a new [`Rope`] with the given text.
+    pub fn new(text: &str) -> Self {
+        let chunks = chunks(split(text));
+        let line_count = chunks.iter().map(Chunk::len).sum();
+
+        Self { chunks, line_count }
+    }
+
+    /// Returns the amount of lines of the [`Rope`].
+    ///
+    /// There is always at least one line.
+    pub fn line_count(&self) -> usize {
+        self.line_count
+    }
+
+    /// Returns whether the [`Rope`] has no text.
+    pub fn is_empty(&self) -> bool {
+        self.chunks.iter().all(|chunk| chunk.text.is_empty())
+    }
+
+    /// Returns the text and the [`LineEnding`] of the line at the given index.
+    pub fn line(&self, index: usize) -> Option<(&str, LineEnding)> {
+        let (chunk, line) = self.locate(index)?;
+
+        Some(ending(self.chunks[chunk].line(line)))
+    }
+
+    /// Returns an iterator over the text of every line in the [`Rope`].
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.chunks
+            .iter()
+            .flat_map(|chunk| (0..chunk.len()).map(|line| ending(chunk.line(line)).0))
+    }
+
+    /// Returns the whole text of the [`Rope`].
+    pub fn text(&self) -> String {
+        let mut text =
+            String::with_capacity(self.chunks.iter().map(|chunk| chunk.text.len()).sum());
+
+        for chunk in &self.chunks {
+            text.push_str(&chunk.text);
+        }
+
+        text
+    }
+
+    /// Returns the text of the lines in the given range, without the
+    /// ending of the last one.
+    pub fn get(&self, lines: Range<usize>) -> String {
+        let mut text = String::new();
+
+        for index in lines.clone() {
+            let Some((line, ending)) = self.line(index) else {
+                break;
+            };
+
+            text.push_str(line);
+
+            if index + 1 < lines.end {
+                text.push_str(ending.as_str());
+            }
+        }
+
+        text
+    }
+
+    /// Returns the text between the given positions.
+    pub fn slice(&self, start: Position, end: Position) -> String {
+        let text = self.get(start.line..end.line + 1);
+
+        let last = self
+            .line(end.line)
+            .map(|(line, _)| line.len())
+            .unwrap_or_default();
+
+        let from = start.column.min(text.len());
+        let to = text.len() - last.saturating_sub(end.column).min(text.len());
+
+        text.get(from..to.max(from)).unwrap_or_default().to_owned()
+    }
+
+    /// Replaces the lines in the given range with the given text.
+    ///
+    /// The ending of the last line in the range is kept after the text.
+    pub fn replace(&mut self, lines: Range<usize>, text: &str) {
+        let start = lines.start.min(self.line_count - 1);
+        let end = lines.end.clamp(start + 1, self.line_count);
+
+        let (first, first_line) = self.locate(start).expect("Start line should exist");
+        let (last, last_line) = self.locate(end - 1).expect("End line should exist");
+
+        let kept_ending = ending(self.chunks[last].line(last_line)).1;
+
+        let mut replaced = split(text);
+
+        if let Some(line) = replaced.last_mut() {
+            line.push_str(kept_ending.as_str());
+        }
+
+        let new_lines: Vec<String> = (0..first_line)
+            .map(|line| self.chunks[first].line(line).to_owned())
+            .chain(replaced)
+            .chain(
+                (last_line + 1..self.chunks[last].len())
+                    .map(|line| self.chunks[last].line(line).to_owned()),
+            )
+            .collect();
+
+        let removed: usize = self.chunks[first..=last].iter().map(Chunk::len).sum();
+        let added = new_lines.len();
+
+        let _ = self.chunks.splice(first..=last, chunks(new_lines));
+
+        self.line_count = self.line_count - removed + added;
+    }
+
+    fn locate(&self, mut index: usize) -> Option<(usize, usize)> {
+        for (i, chunk) in self.chunks.iter().enumerate() {
+            if index < chunk.len() {
+                return Some((i, index));
+            }
+
+            index -= chunk.len();
+        }
+
+        None
+    }
+}
+
+impl Chunk {
+    fn len(&self) -> usize {
+        self.starts.len()
+    }
+
+    fn line(&self, index: usize) -> &str {
+        let start = self.starts[index];
+        let end = self
+            .starts
+            .get(index + 1)
+            .copied()
+            .unwrap_or(self.text.len());
+
+        &self.text[start..end]
+    }
+}
+
+/// Splits the given text in lines, keeping their endings.
+fn split(text: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut rest = text;
+
+    while let Some(i) = rest.find(['\r', '\n']) {
+        let after = &rest[i..];
+
+        let length = if after.starts_with("\r\n") || after.starts_with("\n\r") {
+            2
+        } else {
+            1
+        };
+
+        lines.push(rest[..i + length].to_owned());
+        rest = &rest[i + length..];
+    }
+
+    lines.push(rest.to_owned());
+    lines
+}
+
+/// Splits the ending of the given line.
+fn ending(line: &str) -> (&str, LineEnding) {
+    for ending in [
+        LineEnding::CrLf,
+        LineEnding::LfCr,
+        LineEnding::Lf,
+        LineEnding::Cr,
+    ] {
+        if let Some(text) = line.strip_suffix(ending.as_str()) {
+            return (text, ending);
+        }
+    }
+
+    (line, LineEnding::None)
+}
+
+fn chunks(lines: impl IntoIterator<Item = String>) -> Vec<Chunk> {
+    let mut chunks = Vec::new();
+    let mut current = Chunk {
+        text: String::new(),
+        starts: Vec::new(),
+    };
+
+    for line in lines {
+        if current.len() == CHUNK_LINES {
+            chunks.push(std::mem::replace(
+                &mut current,
+                Chunk {
+                    text: String::new(),
+                    starts: Vec::new(),
+                },
+            ));
+        }
+
+        current.starts.push(current.text.len());
+        current.text.push_str(&line);
+    }
+
+    if !current.starts.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_and_text() {
+        let text = "LF\nCRLF\r\nCR\rLFCR\n\rNONE";
+        let rope = Rope::new(text);
+
+        assert_eq!(rope.line_count(), 5);
+        assert_eq!(rope.line(0), Some(("LF", LineEnding::Lf)));
+        assert_eq!(rope.line(1), Some(("CRLF", LineEnding::CrLf)));
+        assert_eq!(rope.line(2), Some(("CR", LineEnding::Cr)));
+        assert_eq!(rope.line(3), Some(("LFCR", LineEnding::LfCr)));
+        assert_eq!(rope.line(4), Some(("NONE", LineEnding::None)));
+        assert_eq!(rope.line(5), None);
+        assert_eq!(rope.text(), text);
+
+        let rope = Rope::new("a\n");
+
+        assert_eq!(rope.line_count(), 2);
+        assert_eq!(rope.line(1), Some(("", LineEnding::None)));
+
+        assert!(Rope::new("").is_empty());
+        assert_eq!(Rope::new("").line_count(), 1);
+    }
+
+    #[test]
+    fn replace_across_chunks() {
+        let text: String = (0..3000).map(|i| format!("{i}\n")).collect();
+        let mut rope = Rope::new(&text);
+
+        assert_eq!(rope.line_count(), 3001);
+        assert_eq!(rope.line(2048), Some(("2048", LineEnding::Lf)));
+
+        rope.replace(1000..1100, "a\nb");
+
+        assert_eq!(rope.line_count(), 2903);
+        assert_eq!(rope.line(999), Some(("999", LineEnding::Lf)));
+        assert_eq!(rope.line(1000), Some(("a", LineEnding::Lf)));
+        assert_eq!(rope.line(1001), Some(("b", LineEnding::Lf)));
+        assert_eq!(rope.line(1002), Some(("1100", LineEnding::Lf)));
+
+        assert_eq!(rope.get(999..1002), "999\na\nb");
+        assert_eq!(rope.lines().count(), rope.line_count());
+
+        rope.replace(2902..2903, "end");
+
+        assert_eq!(rope.line(2902), Some(("end", LineEnding::None)));
+        assert!(rope.text().ends_with("2999\nend"));
+    }
+
+    #[test]
+    fn slice() {
+        let rope = Rope::new("hello\nworld\r\nfoo");
+
+        let position = |line, column| Position { line, column };
+
+        assert_eq!(rope.slice(position(0, 1), position(0, 4)), "ell");
+        assert_eq!(rope.slice(position(0, 3), position(1, 2)), "lo\nwo");
+        assert_eq!(rope.slice(position(1, 0), position(2, 3)), "world\r\nfoo");
+    }
+}
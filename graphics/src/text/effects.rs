@@ -0,0 +1,794 @@
+//! Rasterize the effects of some text.
+use crate::core::text::Effects;
+use crate::core::{Color, Gradient, Point, Rectangle, Transformation, Vector};
+use crate::text::{font_system, measure};
+
+use rustc_hash::FxHasher;
+use std::hash::{Hash, Hasher};
+
+/// The [`Effects`] of some text rasterized into an image.
+///
+/// The shadow and the outline of the text are always part of a [`Sprite`],
+/// while its glyphs are only filled by it when the [`Effects`] have a
+/// [`Gradient`]. Otherwise, the glyphs must be drawn on top as usual.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sprite {
+    /// The physical position of the left edge of the [`Sprite`].
+    pub x: i32,
+    /// The physical position of the top edge of the [`Sprite`].
+    pub y: i32,
+    /// The width of the [`Sprite`], in physical pixels.
+    pub width: u32,
+    /// The height of the [`Sprite`], in physical pixels.
+    pub height: u32,
+    /// The pixels of the [`Sprite`] in RGBA, with straight alpha.
+    pub pixels: Vec<u8>,
+}
+
+/// Returns true if the glyphs of some text with the given [`Effects`] are
+/// filled by its [`Sprite`].
+pub fn fills_glyphs(effects: &Effects) -> bool {
+    effects.gradient.is_some()
+}
+
+/// Returns a hash that identifies the [`Sprite`] produced by [`rasterize`]
+/// with the same arguments; so that it can be cached.
+pub fn key(
+    buffer: &cosmic_text::Buffer,
+    position: Point,
+    effects: &Effects,
+    transformation: Transformation,
+    clip_bounds: Rectangle,
+) -> u64 {
+    let mut hasher = FxHasher::default();
+
+    let scale = transformation.scale_factor();
+    let position = position * transformation;
+    let scroll = buffer.scroll();
+
+    for run in buffer.layout_runs() {
+        run.line_y.to_bits().hash(&mut hasher);
+
+        for glyph in run.glyphs {
+            let physical = glyph.physical((position.x - scroll.horizontal, position.y), scale);
+
+            physical.cache_key.hash(&mut hasher);
+            physical.x.hash(&mut hasher);
+            physical.y.hash(&mut hasher);
+        }
+    }
+
+    let mut values = vec![
+        scale,
+        clip_bounds.x,
+        clip_bounds.y,
+        clip_bounds.width,
+        clip_bounds.height,
+    ];
+
+    if let Some(shadow) = effects.shadow {
+        values.extend([1.0, shadow.offset.x, shadow.offset.y, shadow.blur_radius]);
+        values.extend(components(shadow.color));
+    }
+
+    if let Some(outline) = effects.outline {
+        values.extend([2.0, outline.width]);
+        values.extend(components(outline.color));
+    }
+
+    if let Some(Gradient::Linear(linear)) = effects.gradient {
+        values.extend([3.0, linear.angle.0]);
+
+        for stop in linear.stops.iter().flatten() {
+            values.push(stop.offset);
+            values.extend(components(stop.color));
+        }
+    }
+
+    for value in values {
+        value.to_bits().hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+/// Rasterizes the [`Effects`] of the given [`cosmic_text::Buffer`] drawn at
+/// the given position into a [`Sprite`].
+///
+/// The `clip_bounds` are in physical pixels; nothing outside of them is
+/// rasterized. Nothing is returned if the [`Effects`] are not visible.
+///
+/// A blurred shadow is a gaussian blur of the glyphs with a standard
+/// deviation of half its radius; just like in CSS.
+pub fn rasterize(
+    buffer: &cosmic_text::Buffer,
+    position: Point,
+    effects: &Effects,
+    transformation: Transformation,
+    clip_bounds: Rectangle,
+) -> Option<Sprite> {
+    let outline = effects
+        .outline
+        .filter(|outline| outline.width > 0.0 && outline.color.a > 0.0);
+    let shadow = effects.shadow.filter(|shadow| shadow.color.a > 0.0);
+
+    if outline.is_none() && shadow.is_none() && effects.gradient.is_none() {
+        return None;
+    }
+
+    let scale = transformation.scale_factor();
+
+    let radius = outline.map_or(0.0, |outline| outline.width * scale);
+    let sigma = shadow.map_or(0.0, |shadow| shadow.blur_radius.max(0.0) * scale / 2.0);
+    let offset = shadow.map_or((0, 0), |shadow| {
+        let offset = shadow.offset * scale;
+
+        (offset.x.round() as i32, offset.y.round() as i32)
+    });
+
+    let reach = radius.ceil() as i32;
+    let blur = (sigma * 3.0).ceil() as i32;
+
+    let glyphs = glyphs(buffer, position * transformation, scale);
+    let glyph_region = glyphs
+        .iter()
+        .map(|glyph| glyph.region)
+        .reduce(Region::union)?;
+
+    let mut canvas = glyph_region.expand(reach);
+
+    if shadow.is_some() {
+        canvas = canvas.union(glyph_region.expand(reach + blur).translate(offset));
+    }
+
+    let visible = canvas.intersection(Region::enclosing(clip_bounds))?;
+
+    // Glyphs farther than this from the visible region cannot affect it
+    let margin = reach + blur + offset.0.abs().max(offset.1.abs());
+    let work = canvas.intersection(visible.expand(margin))?;
+
+    let mut coverage = Mask::new(work);
+
+    for glyph in &glyphs {
+        coverage.draw(glyph);
+    }
+
+    let outline_mask = outline.map(|_| coverage.dilate(radius));
+
+    let shadow_mask = shadow.map(|_| {
+        let mut mask = outline_mask.as_ref().unwrap_or(&coverage).clone();
+        mask.blur(sigma);
+        mask
+    });
+
+    let gradient = effects.gradient.map(|gradient| match gradient {
+        Gradient::Linear(linear) => {
+            let bounds = Rectangle::new(position, measure(buffer).0) * transformation;
+            let (start, end) = bounds.chord(linear.angle);
+
+            (linear, start, end - start)
+        }
+    });
+
+    let mut pixels = Vec::with_capacity(visible.width() as usize * visible.height() as usize * 4);
+
+    for y in visible.top..visible.bottom {
+        for x in visible.left..visible.right {
+            let mut pixel = [0.0; 4];
+
+            if let (Some(shadow), Some(mask)) = (shadow, &shadow_mask) {
+                over(
+                    &mut pixel,
+                    shadow.color,
+                    mask.get(x - offset.0, y - offset.1),
+                );
+            }
+
+            if let (Some(outline), Some(mask)) = (outline, &outline_mask) {
+                over(&mut pixel, outline.color, mask.get(x, y));
+            }
+
+            if let Some((linear, start, direction)) = gradient {
+                let point = Vector::new(x as f32 + 0.5 - start.x, y as f32 + 0.5 - start.y);
+                let length = direction.x * direction.x + direction.y * direction.y;
+
+                let offset = if length > 0.0 {
+                    (point.x * direction.x + point.y * direction.y) / length
+                } else {
+                    0.0
+                };
+
+                over(&mut pixel, linear.color_at(offset), coverage.get(x, y));
+            }
+
+            pixels.extend(unpremultiply(pixel));
+        }
+    }
+
+    Some(Sprite {
+        x: visible.left,
+        y: visible.top,
+        width: visible.width(),
+        height: visible.height(),
+        pixels,
+    })
+}
+
+/// The coverage of a glyph, in physical pixels.
+struct Glyph {
+    region: Region,
+    coverage: Vec<u8>,
+}
+
+fn glyphs(buffer: &cosmic_text::Buffer, position: Point, scale: f32) -> Vec<Glyph> {
+    let mut font_system = font_system().write().expect("Write font system");
+    let mut swash = cosmic_text::SwashCache::new();
+
+    let scroll = buffer.scroll();
+    let mut glyphs = Vec::new();
+
+    for run in buffer.layout_runs() {
+        for glyph in run.glyphs {
+            let physical = glyph.physical((position.x - scroll.horizontal, position.y), scale);
+
+            let Some(image) = swash.get_image_uncached(font_system.raw(), physical.cache_key)
+            else {
+                continue;
+            };
+
+            let placement = image.placement;
+
+            if placement.width == 0 || placement.height == 0 {
+                continue;
+            }
+
+            let coverage = match image.content {
+                cosmic_text::SwashContent::Mask => image.data,
+                cosmic_text::SwashContent::Color => {
+                    image.data.chunks_exact(4).map(|pixel| pixel[3]).collect()
+                }
+                cosmic_text::SwashContent::SubpixelMask => image
+                    .data
+                    .chunks_exact(4)
+                    .map(|pixel| pixel[0].max(pixel[1]).max(pixel[2]))
+                    .collect(),
+            };
+
+            let left = physical.x + placement.left;
+            let top = physical.y - placement.top + (run.line_y * scale).round() as i32;
+
+            glyphs.push(Glyph {
+                region: Region {
+                    left,
+                    top,
+                    right: left + placement.width as i32,
+                    bottom: top + placement.height as i32,
+                },
+                coverage,
+            });
+        }
+    }
+
+    glyphs
+}
+
+/// A rectangular region of physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Region {
+    left: i32,
+    top: i32,
+    right: i32,
+    bottom: i32,
+}
+
+impl Region {
+    fn enclosing(bounds: Rectangle) -> Self {
+        Self {
+            left: bounds.x.floor() as i32,
+            top: bounds.y.floor() as i32,
+            right: (bounds.x + bounds.width).ceil() as i32,
+            bottom: (bounds.y + bounds.height).ceil() as i32,
+        }
+    }
+
+    fn width(self) -> u32 {
+        (self.right - self.left).max(0) as u32
+    }
+
+    fn height(self) -> u32 {
+        (self.bottom - self.top).max(0) as u32
+    }
+
+    fn contains(self, x: i32, y: i32) -> bool {
+        x >= self.left && x < self.right && y >= self.top && y < self.bottom
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self {
+            left: self.left.min(other.left),
+            top: self.top.min(other.top),
+            right: self.right.max(other.right),
+            bottom: self.bottom.max(other.bottom),
+        }
+    }
+
+    fn intersection(self, other: Self) -> Option<Self> {
+        let region = Self {
+            left: self.left.max(other.left),
+            top: self.top.max(other.top),
+            right: self.right.min(other.right),
+            bottom: self.bottom.min(other.bottom),
+        };
+
+        (region.left < region.right && region.top < region.bottom).then_some(region)
+    }
+
+    fn expand(self, amount: i32) -> Self {
+        Self {
+            left: self.left - amount,
+            top: self.top - amount,
+            right: self.right + amount,
+            bottom: self.bottom + amount,
+        }
+    }
+
+    fn translate(self, (x, y): (i32, i32)) -> Self {
+        Self {
+            left: self.left + x,
+            top: self.top + y,
+            right: self.right + x,
+            bottom: self.bottom + y,
+        }
+    }
+}
+
+/// The coverage of a [`Region`], from `0.0` to `1.0` per pixel.
+#[derive(Debug, Clone)]
+struct Mask {
+    region: Region,
+    values: Vec<f32>,
+}
+
+impl Mask {
+    fn new(region: Region) -> Self {
+        Self {
+            region,
+            values: vec![0.0; region.width() as usize * region.height() as usize],
+        }
+    }
+
+    fn index(&self, x: i32, y: i32) -> usize {
+        (y - self.region.top) as usize * self.region.width() as usize
+            + (x - self.region.left) as usize
+    }
+
+    fn get(&self, x: i32, y: i32) -> f32 {
+        if self.region.contains(x, y) {
+            self.values[self.index(x, y)]
+        } else {
+            0.0
+        }
+    }
+
+    fn draw(&mut self, glyph: &Glyph) {
+        let Some(region) = self.region.intersection(glyph.region) else {
+            return;
+        };
+
+        let width = glyph.region.width() as usize;
+
+        for y in region.top..region.bottom {
+            for x in region.left..region.right {
+                let source =
+                    (y - glyph.region.top) as usize * width + (x - glyph.region.left) as usize;
+                let index = self.index(x, y);
+
+                self.values[index] =
+                    self.values[index].max(f32::from(glyph.coverage[source]) / 255.0);
+            }
+        }
+    }
+
+    /// Grows the coverage by the given radius, anti-aliasing its edges.
+    fn dilate(&self, radius: f32) -> Self {
+        let reach = radius.ceil() as i32;
+
+        let kernel: Vec<_> = (-reach..=reach)
+            .flat_map(|dy| (-reach..=reach).map(move |dx| (dx, dy)))
+            .filter_map(|(dx, dy)| {
+                let distance = ((dx * dx + dy * dy) as f32).sqrt();
+                let weight = (radius + 1.0 - distance).clamp(0.0, 1.0);
+
+                (weight > 0.0).then_some((dx, dy, weight))
+            })
+            .collect();
+
+        let mut dilated = Self::new(self.region);
+
+        for y in self.region.top..self.region.bottom {
+            for x in self.region.left..self.region.right {
+                let value = kernel
+                    .iter()
+                    .map(|(dx, dy, weight)| self.get(x + dx, y + dy) * weight)
+                    .fold(0.0, f32::max);
+
+                let index = dilated.index(x, y);
+                dilated.values[index] = value;
+            }
+        }
+
+        dilated
+    }
+
+    /// Approximates a gaussian blur with the given standard deviation by
+    /// three successive box blurs.
+    fn blur(&mut self, sigma: f32) {
+        if sigma < 0.25 {
+            return;
+        }
+
+        let width = self.region.width() as usize;
+        let height = self.region.height() as usize;
+
+        for size in box_sizes(sigma) {
+            let radius = (size - 1) / 2;
+
+            blur_lines(
+                &mut self.values,
+                height,
+                width,
+                |line, i| line * width + i,
+                radius,
+            );
+            blur_lines(
+                &mut self.values,
+                width,
+                height,
+                |line, i| i * width + line,
+                radius,
+            );
+        }
+    }
+}
+
+/// Returns the sizes of the three box blurs that approximate a gaussian
+/// blur with the given standard deviation.
+fn box_sizes(sigma: f32) -> [usize; 3] {
+    const PASSES: f32 = 3.0;
+
+    let ideal = (12.0 * sigma * sigma / PASSES + 1.0).sqrt();
+
+    let mut lower = ideal.floor() as usize;
+
+    if lower.is_multiple_of(2) {
+        lower = lower.saturating_sub(1).max(1);
+    }
+
+    let upper = lower + 2;
+    let lower_f = lower as f32;
+
+    let lower_passes = ((12.0 * sigma * sigma
+        - PASSES * lower_f * lower_f
+        - 4.0 * PASSES * lower_f
+        - 3.0 * PASSES)
+        / (-4.0 * lower_f - 4.0))
+        .round()
+        .clamp(0.0, PASSES) as usize;
+
+    std::array::from_fn(|i| if i < lower_passes { lower } else { upper })
+}
+
+/// Blurs the given lines of values with a box of the given radius.
+fn blur_lines(
+    values: &mut [f32],
+    lines: usize,
+    length: usize,
+    index: impl Fn(usize, usize) -> usize,
+    radius: usize,
+) {
+    let scale = 1.0 / (2 * radius + 1) as f32;
+    let mut sums = vec![0.0; length + 1];
+
+    for line in 0..lines {
+        for i in 0..length {
+            sums[i + 1] = sums[i] + values[index(line, i)];
+        }
+
+        for i in 0..length {
+            let start = i.saturating_sub(radius);
+            let end = (i + radius + 1).min(length);
+
+            values[index(line, i)] = (sums[end] - sums[start]) * scale;
+        }
+    }
+}
+
+/// Composites the given [`Color`] with the given coverage over a
+/// premultiplied pixel.
+fn over(pixel: &mut [f32; 4], color: Color, coverage: f32) {
+    let alpha = color.a * coverage;
+
+    if alpha <= 0.0 {
+        return;
+    }
+
+    let [r, g, b, a] = *pixel;
+    let remaining = 1.0 - alpha;
+
+    *pixel = [
+        color.r * alpha + r * remaining,
+        color.g * alpha + g * remaining,
+        color.b * alpha + b * remaining,
+        alpha + a * remaining,
+    ];
+}
+
+fn unpremultiply([r, g, b, a]: [f32; 4]) -> [u8; 4] {
+    if a <= 0.0 {
+        return [0; 4];
+    }
+
+    let channel = |value: f32| ((value / a).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    [
+        channel(r),
+        channel(g),
+        channel(b),
+        (a.clamp(0.0, 1.0) * 255.0).round() as u8,
+    ]
+}
+
+fn components(color: Color) -> [f32; 4] {
+    [color.r, color.g, color.b, color.a]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::alignment;
+    use crate::core::text::{self, Alignment, Ellipsis, LineHeight, Outline, Shaping, Wrapping};
+    use crate::core::{Font, Pixels, Shadow, Size, gradient};
+    use crate::text::Paragraph;
+
+    fn icon() -> Paragraph {
+        use crate::core::text::Paragraph as _;
+
+        Paragraph::with_text(text::Text {
+            content: "\u{e800}",
+            bounds: Size::INFINITE,
+            size: Pixels(20.0),
+            line_height: LineHeight::default(),
+            font: Font::new("Iced-Icons"),
+            align_x: Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::None,
+            ellipsis: Ellipsis::default(),
+            hint_factor: None,
+        })
+    }
+
+    fn sprite(effects: Effects, clip_bounds: Rectangle) -> Option<Sprite> {
+        rasterize(
+            icon().buffer(),
+            Point::new(10.0, 10.0),
+            &effects,
+            Transformation::IDENTITY,
+            clip_bounds,
+        )
+    }
+
+    const CLIP_BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    fn mask(width: i32, height: i32, values: &[(i32, i32)]) -> Mask {
+        let mut mask = Mask::new(Region {
+            left: 0,
+            top: 0,
+            right: width,
+            bottom: height,
+        });
+
+        for &(x, y) in values {
+            let index = mask.index(x, y);
+            mask.values[index] = 1.0;
+        }
+
+        mask
+    }
+
+    #[test]
+    fn dilation_grows_coverage_by_the_radius() {
+        let dilated = mask(9, 9, &[(4, 4)]).dilate(2.0);
+
+        assert_eq!(dilated.get(4, 4), 1.0);
+        assert_eq!(dilated.get(6, 4), 1.0);
+        assert_eq!(dilated.get(4, 2), 1.0);
+        assert!(dilated.get(6, 6) < 1.0);
+        assert_eq!(dilated.get(7, 4), 0.0);
+        assert_eq!(dilated.get(4, 8), 0.0);
+    }
+
+    #[test]
+    fn blur_spreads_coverage_and_preserves_its_total() {
+        let mut blurred = mask(41, 41, &[(20, 20)]);
+        blurred.blur(3.0);
+
+        let total: f32 = blurred.values.iter().sum();
+
+        assert!((total - 1.0).abs() < 1e-3);
+        assert!(blurred.get(20, 20) < 1.0);
+        assert!(blurred.get(20, 20) > blurred.get(23, 20));
+        assert!(blurred.get(23, 20) > blurred.get(26, 20));
+        assert_eq!(blurred.get(20, 20 + 15), 0.0);
+    }
+
+    #[test]
+    fn box_sizes_approximate_the_deviation() {
+        for sigma in [1.0, 2.5, 4.0, 10.0] {
+            let variance: f32 = box_sizes(sigma)
+                .into_iter()
+                .map(|size| ((size * size) as f32 - 1.0) / 12.0)
+                .sum();
+
+            assert!(
+                (variance.sqrt() - sigma).abs() < 0.5,
+                "{sigma}: {}",
+                variance.sqrt()
+            );
+        }
+    }
+
+    #[test]
+    fn layers_are_composited_back_to_front() {
+        let mut pixel = [0.0; 4];
+
+        over(&mut pixel, Color::from_rgb(1.0, 0.0, 0.0), 1.0);
+        over(&mut pixel, Color::from_rgba(0.0, 0.0, 1.0, 0.5), 1.0);
+
+        assert_eq!(unpremultiply(pixel), [128, 0, 128, 255]);
+
+        let mut pixel = [0.0; 4];
+
+        over(&mut pixel, Color::WHITE, 0.5);
+
+        assert_eq!(unpremultiply(pixel), [255, 255, 255, 128]);
+    }
+
+    #[test]
+    fn sprites_cover_the_effects_of_the_glyphs() {
+        assert_eq!(sprite(Effects::NONE, CLIP_BOUNDS), None);
+
+        let gradient = gradient::Linear::new(0.0)
+            .add_stop(0.0, Color::BLACK)
+            .add_stop(1.0, Color::WHITE);
+
+        let glyphs = sprite(Effects::default().gradient(gradient), CLIP_BOUNDS).unwrap();
+
+        assert!(glyphs.width > 0 && glyphs.height > 0);
+        assert_eq!(
+            glyphs.pixels.len(),
+            glyphs.width as usize * glyphs.height as usize * 4
+        );
+
+        let outlined = sprite(
+            Effects::default().outline(Outline::new(Color::BLACK, 2.0)),
+            CLIP_BOUNDS,
+        )
+        .unwrap();
+
+        assert_eq!(
+            (outlined.x, outlined.y, outlined.width, outlined.height),
+            (
+                glyphs.x - 2,
+                glyphs.y - 2,
+                glyphs.width + 4,
+                glyphs.height + 4
+            )
+        );
+
+        let shadowed = sprite(
+            Effects::default().shadow(Shadow {
+                color: Color::BLACK,
+                offset: Vector::new(10.0, 0.0),
+                blur_radius: 0.0,
+            }),
+            CLIP_BOUNDS,
+        )
+        .unwrap();
+
+        assert_eq!(
+            (shadowed.x, shadowed.width, shadowed.height),
+            (glyphs.x, glyphs.width + 10, glyphs.height)
+        );
+
+        // Only the shadow is drawn, so the left edge of the glyphs is clear
+        assert!(
+            shadowed
+                .pixels
+                .chunks_exact(4)
+                .step_by(shadowed.width as usize)
+                .all(|pixel| pixel[3] == 0)
+        );
+    }
+
+    #[test]
+    fn gradients_fill_the_glyphs_across_their_bounds() {
+        let gradient = gradient::Linear::new(crate::core::Radians::PI / 2.0)
+            .add_stop(0.0, Color::BLACK)
+            .add_stop(1.0, Color::WHITE);
+
+        let sprite = sprite(Effects::default().gradient(gradient), CLIP_BOUNDS).unwrap();
+
+        let opaque = |column: u32| {
+            sprite
+                .pixels
+                .chunks_exact(4)
+                .skip(column as usize)
+                .step_by(sprite.width as usize)
+                .find(|pixel| pixel[3] == 255)
+                .map(|pixel| pixel[0])
+        };
+
+        let left = (0..sprite.width).find_map(opaque).unwrap();
+        let right = (0..sprite.width).rev().find_map(opaque).unwrap();
+
+        assert!(left < right, "{left} < {right}");
+    }
+
+    #[test]
+    fn sprites_are_clipped() {
+        let effects = Effects::default().outline(Outline::new(Color::BLACK, 2.0));
+
+        let full = sprite(effects, CLIP_BOUNDS).unwrap();
+
+        let clipped = sprite(
+            effects,
+            Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: full.x as f32 + 5.0,
+                height: 100.0,
+            },
+        )
+        .unwrap();
+
+        assert_eq!((clipped.x, clipped.width), (full.x, 5));
+        assert_eq!(
+            sprite(effects, Rectangle::new(Point::ORIGIN, Size::new(5.0, 5.0))),
+            None
+        );
+    }
+
+    #[test]
+    fn keys_change_with_their_inputs() {
+        let paragraph = icon();
+        let effects = Effects::default().outline(Outline::new(Color::BLACK, 2.0));
+
+        let key = |position, effects: Effects| {
+            super::key(
+                paragraph.buffer(),
+                position,
+                &effects,
+                Transformation::IDENTITY,
+                CLIP_BOUNDS,
+            )
+        };
+
+        let origin = key(Point::ORIGIN, effects);
+
+        assert_eq!(origin, key(Point::ORIGIN, effects));
+        assert_ne!(origin, key(Point::new(1.0, 0.0), effects));
+        assert_ne!(
+            origin,
+            key(
+                Point::ORIGIN,
+                Effects::default().outline(Outline::new(Color::BLACK, 3.0))
+            )
+        );
+    }
+}
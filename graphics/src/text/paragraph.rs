@@ -1,6 +1,8 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
+use crate::core::layout;
+use crate::core::text::paragraph::Position;
 use crate::core::text::{Alignment, Ellipsis, Hit, LineHeight, Shaping, Span, Text, Wrapping};
 use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
@@ -8,6 +10,13 @@ use crate::text;
 use std::fmt;
 use std::sync::{self, Arc};
 
+/// The text taking the place of an inline element.
+const INLINE_PLACEHOLDER: &str = "\u{FFFC}";
+
+/// The font size of an inline element placeholder; small enough
+/// for the natural advance of its glyph to be negligible.
+const INLINE_FONT_SIZE: f32 = 0.01;
+
 /// A bunch of text.
 #[derive(Clone, PartialEq)]
 pub struct Paragraph(Arc<Internal>);
@@ -155,6 +164,22 @@ impl core::text::Paragraph for Paragraph {
             text.content.iter().enumerate().map(|(i, span)| {
                 let attrs = text::to_attributes(span.font.unwrap_or(text.font));
 
+                if let Some(size) = span.inline {
+                    let line_height = f32::from(text.line_height.to_absolute(text.size));
+
+                    // An inline element is an invisible, tiny glyph whose
+                    // advance is stretched to the width of the element
+                    let attrs = attrs
+                        .metrics(cosmic_text::Metrics::new(
+                            INLINE_FONT_SIZE,
+                            size.height.max(line_height) * hint_factor,
+                        ))
+                        .letter_spacing(size.width * hint_factor / INLINE_FONT_SIZE)
+                        .color(cosmic_text::Color::rgba(0, 0, 0, 0));
+
+                    return (INLINE_PLACEHOLDER, attrs.metadata(i));
+                }
+
                 let attrs = match (span.size, span.line_height) {
                     (None, None) => attrs,
                     _ => {
@@ -387,6 +412,31 @@ impl core::text::Paragraph for Paragraph {
         bounds
     }
 
+    fn span_baseline(&self, index: usize) -> Option<f32> {
+        let internal = self.internal();
+
+        let run = internal
+            .buffer
+            .layout_runs()
+            .find(|run| run.glyphs.iter().any(|glyph| glyph.metadata == index))?;
+
+        Some(run.line_y / internal.hint_factor)
+    }
+
+    fn baseline(&self) -> Option<f32> {
+        let internal = self.internal();
+        let run = internal.buffer.layout_runs().next()?;
+
+        Some(run.line_y / internal.hint_factor)
+    }
+
+    fn last_baseline(&self) -> Option<f32> {
+        let internal = self.internal();
+        let run = internal.buffer.layout_runs().last()?;
+
+        Some(run.line_y / internal.hint_factor)
+    }
+
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -426,6 +476,66 @@ impl core::text::Paragraph for Paragraph {
             (glyph.y - glyph.y_offset * glyph.font_size) / self.0.hint_factor,
         ))
     }
+
+    fn hit_position(&self, point: Point) -> Option<Position> {
+        let cursor = self
+            .internal()
+            .buffer
+            .hit(point.x * self.0.hint_factor, point.y * self.0.hint_factor)?;
+
+        Some(Position {
+            line: cursor.line,
+            index: cursor.index,
+        })
+    }
+
+    fn range_bounds(&self, start: Position, end: Position) -> Vec<Rectangle> {
+        let internal = self.internal();
+
+        let lines = start.line..=end.line;
+        let start = cosmic_text::Cursor::new(start.line, start.index);
+        let end = cosmic_text::Cursor::new(end.line, end.index);
+
+        internal
+            .buffer
+            .layout_runs()
+            .filter(|run| lines.contains(&run.line_i))
+            .flat_map(|run| {
+                let line_top = run.line_top;
+                let line_height = run.line_height;
+
+                run.highlight(start, end)
+                    .map(move |(x, width)| {
+                        Rectangle::new(Point::new(x, line_top), Size::new(width, line_height))
+                            * (1.0 / internal.hint_factor)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn line(&self, index: usize) -> Option<&str> {
+        self.internal()
+            .buffer
+            .lines
+            .get(index)
+            .map(cosmic_text::BufferLine::text)
+    }
+
+    fn direction(&self) -> layout::Direction {
+        let is_rtl = self
+            .internal()
+            .buffer
+            .layout_runs()
+            .next()
+            .is_some_and(|run| run.rtl);
+
+        if is_rtl {
+            layout::Direction::RightToLeft
+        } else {
+            layout::Direction::LeftToRight
+        }
+    }
 }
 
 impl Default for Paragraph {
@@ -1,28 +1,44 @@
 //! A syntax highlighter for iced.
+//!
+//! You can enable the `tree-sitter` feature for an alternative highlighter
+//! backed by tree-sitter; which parses text incrementally.
 use iced_core as core;
 
+#[cfg(feature = "tree-sitter")]
+pub mod tree_sitter;
+
 use crate::core::Color;
 use crate::core::font::{self, Font};
 use crate::core::text::highlighter::{self, Format};
+use crate::core::theme::palette::{self, Palette};
 
+use std::io;
 use std::ops::Range;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock, RwLock};
 
 use syntect::highlighting;
 use syntect::parsing;
 use two_face::re_exports::syntect;
 
-static SYNTAXES: LazyLock<parsing::SyntaxSet> = LazyLock::new(two_face::syntax::extra_no_newlines);
+static SYNTAXES: LazyLock<RwLock<Syntaxes>> = LazyLock::new(|| {
+    RwLock::new(Syntaxes {
+        set: Arc::new(two_face::syntax::extra_no_newlines()),
+        pending: Vec::new(),
+    })
+});
 
 static THEMES: LazyLock<highlighting::ThemeSet> =
     LazyLock::new(highlighting::ThemeSet::load_defaults);
 
+static CUSTOM_THEMES: RwLock<Vec<Loaded>> = RwLock::new(Vec::new());
+
 const LINES_PER_SNAPSHOT: usize = 50;
 
 /// A syntax highlighter.
 #[derive(Debug)]
 pub struct Highlighter {
-    syntax: &'static parsing::SyntaxReference,
+    syntaxes: Arc<parsing::SyntaxSet>,
+    syntax: usize,
     highlighter: highlighting::Highlighter<'static>,
     caches: Vec<(parsing::ParseState, parsing::ScopeStack)>,
     current_line: usize,
@@ -35,16 +51,15 @@ impl highlighter::Highlighter for Highlighter {
     type Iterator<'a> = Box<dyn Iterator<Item = (Range<usize>, Self::Highlight)> + 'a>;
 
     fn new(settings: &Self::Settings) -> Self {
-        let syntax = SYNTAXES
-            .find_syntax_by_token(&settings.token)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+        let (syntaxes, syntax) = find_syntax(&settings.token);
 
-        let highlighter = highlighting::Highlighter::new(&THEMES.themes[settings.theme.key()]);
+        let highlighter = highlighting::Highlighter::new(settings.theme.definition());
 
-        let parser = parsing::ParseState::new(syntax);
+        let parser = parsing::ParseState::new(&syntaxes.syntaxes()[syntax]);
         let stack = parsing::ScopeStack::new();
 
         Highlighter {
+            syntaxes,
             syntax,
             highlighter,
             caches: vec![(parser, stack)],
@@ -53,11 +68,9 @@ impl highlighter::Highlighter for Highlighter {
     }
 
     fn update(&mut self, new_settings: &Self::Settings) {
-        self.syntax = SYNTAXES
-            .find_syntax_by_token(&new_settings.token)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+        (self.syntaxes, self.syntax) = find_syntax(&new_settings.token);
 
-        self.highlighter = highlighting::Highlighter::new(&THEMES.themes[new_settings.theme.key()]);
+        self.highlighter = highlighting::Highlighter::new(new_settings.theme.definition());
 
         // Restart the highlighter
         self.change_line(0);
@@ -76,7 +89,7 @@ impl highlighter::Highlighter for Highlighter {
 
         let (parser, stack) = self.caches.last().cloned().unwrap_or_else(|| {
             (
-                parsing::ParseState::new(self.syntax),
+                parsing::ParseState::new(&self.syntaxes.syntaxes()[self.syntax]),
                 parsing::ScopeStack::new(),
             )
         });
@@ -95,7 +108,7 @@ impl highlighter::Highlighter for Highlighter {
 
         let (parser, stack) = self.caches.last_mut().expect("Caches must not be empty");
 
-        let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
+        let ops = parser.parse_line(line, &self.syntaxes).unwrap_or_default();
 
         Box::new(scope_iterator(ops, line, stack, &self.highlighter))
     }
@@ -105,6 +118,72 @@ impl highlighter::Highlighter for Highlighter {
     }
 }
 
+/// Registers a new syntax from the contents of a `.sublime-syntax` file.
+///
+/// The syntax will be available to any [`Highlighter`] or [`Stream`]
+/// created afterwards; by its name or any of its file extensions. A syntax
+/// replaces any syntax registered before with the same name.
+///
+/// Syntaxes are linked together lazily; registering many of them at once
+/// only rebuilds the set of syntaxes once.
+pub fn register_syntax(definition: &str) -> Result<(), Error> {
+    let definition = parsing::SyntaxDefinition::load_from_str(definition, false, None)?;
+
+    let mut syntaxes = SYNTAXES.write().expect("Write syntaxes");
+
+    syntaxes
+        .pending
+        .retain(|pending| pending.name != definition.name);
+    syntaxes.pending.push(definition);
+
+    Ok(())
+}
+
+struct Syntaxes {
+    set: Arc<parsing::SyntaxSet>,
+    pending: Vec<parsing::SyntaxDefinition>,
+}
+
+fn syntaxes() -> Arc<parsing::SyntaxSet> {
+    {
+        let syntaxes = SYNTAXES.read().expect("Read syntaxes");
+
+        if syntaxes.pending.is_empty() {
+            return Arc::clone(&syntaxes.set);
+        }
+    }
+
+    let mut syntaxes = SYNTAXES.write().expect("Write syntaxes");
+
+    if !syntaxes.pending.is_empty() {
+        let mut builder = parsing::SyntaxSet::clone(&syntaxes.set).into_builder();
+
+        for definition in syntaxes.pending.drain(..) {
+            builder.add(definition);
+        }
+
+        syntaxes.set = Arc::new(builder.build());
+    }
+
+    Arc::clone(&syntaxes.set)
+}
+
+fn find_syntax(token: &str) -> (Arc<parsing::SyntaxSet>, usize) {
+    let syntaxes = syntaxes();
+
+    let syntax = syntaxes
+        .find_syntax_by_token(token)
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+
+    let index = syntaxes
+        .syntaxes()
+        .iter()
+        .position(|candidate| std::ptr::eq(candidate, syntax))
+        .unwrap_or_default();
+
+    (syntaxes, index)
+}
+
 fn scope_iterator<'a>(
     ops: Vec<(usize, parsing::ScopeStackOp)>,
     line: &str,
@@ -136,7 +215,8 @@ fn scope_iterator<'a>(
 /// It can efficiently highlight an immutable stream of tokens.
 #[derive(Debug)]
 pub struct Stream {
-    syntax: &'static parsing::SyntaxReference,
+    syntaxes: Arc<parsing::SyntaxSet>,
+    syntax: usize,
     highlighter: highlighting::Highlighter<'static>,
     commit: (parsing::ParseState, parsing::ScopeStack),
     state: parsing::ParseState,
@@ -146,16 +226,15 @@ pub struct Stream {
 impl Stream {
     /// Creates a new [`Stream`] highlighter.
     pub fn new(settings: &Settings) -> Self {
-        let syntax = SYNTAXES
-            .find_syntax_by_token(&settings.token)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+        let (syntaxes, syntax) = find_syntax(&settings.token);
 
-        let highlighter = highlighting::Highlighter::new(&THEMES.themes[settings.theme.key()]);
+        let highlighter = highlighting::Highlighter::new(settings.theme.definition());
 
-        let state = parsing::ParseState::new(syntax);
+        let state = parsing::ParseState::new(&syntaxes.syntaxes()[syntax]);
         let stack = parsing::ScopeStack::new();
 
         Self {
+            syntaxes,
             syntax,
             highlighter,
             commit: (state.clone(), stack.clone()),
@@ -172,7 +251,10 @@ impl Stream {
         self.state = self.commit.0.clone();
         self.stack = self.commit.1.clone();
 
-        let ops = self.state.parse_line(line, &SYNTAXES).unwrap_or_default();
+        let ops = self
+            .state
+            .parse_line(line, &self.syntaxes)
+            .unwrap_or_default();
         scope_iterator(ops, line, &mut self.stack, &self.highlighter)
     }
 
@@ -183,7 +265,7 @@ impl Stream {
 
     /// Resets the [`Stream`] highlighter.
     pub fn reset(&mut self) {
-        self.state = parsing::ParseState::new(self.syntax);
+        self.state = parsing::ParseState::new(&self.syntaxes.syntaxes()[self.syntax]);
         self.stack = parsing::ScopeStack::new();
         self.commit = (self.state.clone(), self.stack.clone());
     }
@@ -212,9 +294,7 @@ impl Highlight {
     ///
     /// If `None`, the original text color should be unchanged.
     pub fn color(&self) -> Option<Color> {
-        self.0
-            .foreground
-            .map(|color| Color::from_rgba8(color.r, color.g, color.b, color.a as f32 / 255.0))
+        self.0.foreground.map(from_syntect)
     }
 
     /// Returns the font of this [`Highlight`].
@@ -255,6 +335,7 @@ impl Highlight {
         Format {
             color: self.color(),
             font: self.font(),
+            underline: None,
         }
     }
 }
@@ -268,10 +349,12 @@ pub enum Theme {
     Base16Ocean,
     Base16Eighties,
     InspiredGitHub,
+    /// A [`Custom`] theme loaded at runtime.
+    Custom(Custom),
 }
 
 impl Theme {
-    /// A static slice containing all the available themes.
+    /// A static slice containing all the built-in themes.
     pub const ALL: &'static [Self] = &[
         Self::SolarizedDark,
         Self::Base16Mocha,
@@ -280,6 +363,100 @@ impl Theme {
         Self::InspiredGitHub,
     ];
 
+    /// Loads a [`Custom`] theme with the given name from the contents of
+    /// a `.tmTheme` file.
+    ///
+    /// Loading a theme with the name of an existing [`Custom`] theme
+    /// replaces it.
+    pub fn load(name: impl Into<String>, contents: &str) -> Result<Self, Error> {
+        let definition =
+            highlighting::ThemeSet::load_from_reader(&mut io::Cursor::new(contents.as_bytes()))?;
+
+        let is_dark = definition
+            .settings
+            .background
+            .is_none_or(|background| palette::is_dark(from_syntect(background)));
+
+        Ok(Self::register(name.into(), definition, is_dark))
+    }
+
+    /// Creates a [`Custom`] theme with the given name out of the colors
+    /// of a [`Palette`].
+    ///
+    /// Creating a theme with the name of an existing [`Custom`] theme
+    /// replaces it.
+    pub fn from_palette(name: impl Into<String>, palette: &Palette) -> Self {
+        let background = palette.background.base.color;
+        let text = palette.background.base.text;
+
+        let rules = [
+            (
+                "comment, punctuation.definition.comment",
+                text.mix(background, 0.5),
+                highlighting::FontStyle::ITALIC,
+            ),
+            (
+                "string, constant.character, markup.inserted",
+                palette.success.base.color,
+                highlighting::FontStyle::empty(),
+            ),
+            (
+                "constant.numeric, constant.language, constant.other",
+                palette.warning.base.color,
+                highlighting::FontStyle::empty(),
+            ),
+            (
+                "keyword, storage, markup.heading",
+                palette.primary.base.color,
+                highlighting::FontStyle::empty(),
+            ),
+            (
+                "entity.name.function, support.function, meta.function-call",
+                palette.primary.strong.color,
+                highlighting::FontStyle::empty(),
+            ),
+            (
+                "entity.name.type, entity.name.class, support.type, support.class",
+                palette.success.strong.color,
+                highlighting::FontStyle::empty(),
+            ),
+            (
+                "invalid, markup.deleted",
+                palette.danger.base.color,
+                highlighting::FontStyle::empty(),
+            ),
+        ];
+
+        let scopes = rules
+            .into_iter()
+            .map(|(scope, color, font_style)| highlighting::ThemeItem {
+                scope: scope.parse().expect("Valid scope selector"),
+                style: highlighting::StyleModifier {
+                    foreground: Some(to_syntect(color)),
+                    background: None,
+                    font_style: Some(font_style),
+                },
+            })
+            .collect();
+
+        let name = name.into();
+
+        let definition = highlighting::Theme {
+            name: Some(name.clone()),
+            author: None,
+            settings: highlighting::ThemeSettings {
+                foreground: Some(to_syntect(text)),
+                background: Some(to_syntect(background)),
+                caret: Some(to_syntect(text)),
+                selection: Some(to_syntect(palette.primary.weak.color)),
+                ..highlighting::ThemeSettings::default()
+            },
+            scopes,
+        };
+
+        Self::register(name, definition, palette.is_dark)
+    }
+
     /// Returns `true` if the [`Theme`] is dark, and false otherwise.
     pub fn is_dark(self) -> bool {
         match self {
@@ -287,17 +464,47 @@ impl Theme {
                 true
             }
             Self::InspiredGitHub => false,
+            Self::Custom(custom) => custom.loaded().is_dark,
         }
     }
 
-    fn key(self) -> &'static str {
-        match self {
+    fn register(name: String, definition: highlighting::Theme, is_dark: bool) -> Self {
+        let mut themes = CUSTOM_THEMES.write().expect("Write custom themes");
+
+        let Some(index) = themes.iter().position(|loaded| *loaded.name == name) else {
+            themes.push(Loaded {
+                name: Arc::from(name),
+                definition: Box::leak(Box::new(definition)),
+                is_dark,
+            });
+
+            return Self::Custom(Custom(themes.len() - 1));
+        };
+
+        let loaded = &mut themes[index];
+
+        // Highlighters may still be borrowing the previous definition,
+        // so only changed definitions are stored again
+        if *loaded.definition != definition {
+            loaded.definition = Box::leak(Box::new(definition));
+        }
+
+        loaded.is_dark = is_dark;
+
+        Self::Custom(Custom(index))
+    }
+
+    fn definition(self) -> &'static highlighting::Theme {
+        let key = match self {
             Theme::SolarizedDark => "Solarized (dark)",
             Theme::Base16Mocha => "base16-mocha.dark",
             Theme::Base16Ocean => "base16-ocean.dark",
             Theme::Base16Eighties => "base16-eighties.dark",
             Theme::InspiredGitHub => "InspiredGitHub",
-        }
+            Theme::Custom(custom) => return custom.loaded().definition,
+        };
+
+        &THEMES.themes[key]
     }
 }
 
@@ -309,10 +516,52 @@ impl std::fmt::Display for Theme {
             Theme::Base16Ocean => write!(f, "Ocean"),
             Theme::Base16Eighties => write!(f, "Eighties"),
             Theme::InspiredGitHub => write!(f, "Inspired GitHub"),
+            Theme::Custom(custom) => write!(f, "{}", custom.loaded().name),
         }
     }
 }
 
+/// A highlighting [`Theme`] loaded at runtime.
+///
+/// You can create one with [`Theme::load`] or [`Theme::from_palette`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Custom(usize);
+
+impl Custom {
+    fn loaded(self) -> Loaded {
+        CUSTOM_THEMES.read().expect("Read custom themes")[self.0].clone()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Loaded {
+    name: Arc<str>,
+    definition: &'static highlighting::Theme,
+    is_dark: bool,
+}
+
+/// An error produced when loading syntaxes or themes.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The syntax definition could not be parsed.
+    #[error("the syntax definition is invalid: {0}")]
+    InvalidSyntax(#[from] parsing::ParseSyntaxError),
+
+    /// The theme could not be loaded.
+    #[error("the theme is invalid: {0}")]
+    InvalidTheme(#[from] syntect::LoadingError),
+}
+
+fn from_syntect(color: highlighting::Color) -> Color {
+    Color::from_rgba8(color.r, color.g, color.b, color.a as f32 / 255.0)
+}
+
+fn to_syntect(color: Color) -> highlighting::Color {
+    let [r, g, b, a] = color.into_rgba8();
+
+    highlighting::Color { r, g, b, a }
+}
+
 struct ScopeRangeIterator {
     ops: Vec<(usize, parsing::ScopeStackOp)>,
     line_length: usize,
@@ -347,3 +596,161 @@ impl Iterator for ScopeRangeIterator {
         Some((range, op))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::highlighter::Highlighter as _;
+
+    fn syntax(name: &str, extension: &str, keyword: &str) -> String {
+        format!(
+            "%YAML 1.2
+---
+name: {name}
+file_extensions: [{extension}]
+scope: source.{extension}
+contexts:
+  main:
+    - match: '\\b{keyword}\\b'
+      scope: keyword.control.{extension}
+"
+        )
+    }
+
+    fn tm_theme(keyword: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>name</key>
+    <string>Test</string>
+    <key>settings</key>
+    <array>
+        <dict>
+            <key>settings</key>
+            <dict>
+                <key>background</key>
+                <string>#FFFFFF</string>
+                <key>foreground</key>
+                <string>#000000</string>
+            </dict>
+        </dict>
+        <dict>
+            <key>scope</key>
+            <string>keyword</string>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>{keyword}</string>
+                <key>fontStyle</key>
+                <string>bold</string>
+            </dict>
+        </dict>
+    </array>
+</dict>
+</plist>"#
+        )
+    }
+
+    fn keywords(theme: Theme, token: &str, line: &str) -> Vec<(Range<usize>, Option<Color>)> {
+        let mut highlighter = Highlighter::new(&Settings {
+            theme,
+            token: token.to_owned(),
+        });
+
+        let default = highlighting::Highlighter::new(theme.definition())
+            .style_mod_for_stack(&[])
+            .foreground
+            .map(from_syntect);
+
+        highlighter
+            .highlight_line(line)
+            .map(|(range, highlight)| (range, highlight.color()))
+            .filter(|(_, color)| *color != default)
+            .collect()
+    }
+
+    #[test]
+    fn registered_syntaxes_are_found_by_extension() {
+        register_syntax(&syntax("First", "first", "foo")).expect("Register syntax");
+        register_syntax(&syntax("Second", "second", "bar")).expect("Register syntax");
+
+        assert_eq!(keywords(Theme::Base16Ocean, "first", "foo bar").len(), 1);
+        assert_eq!(keywords(Theme::Base16Ocean, "first", "foo bar")[0].0, 0..3);
+        assert_eq!(keywords(Theme::Base16Ocean, "second", "foo bar")[0].0, 4..7);
+
+        register_syntax(&syntax("First", "first", "bar")).expect("Register syntax");
+
+        assert_eq!(keywords(Theme::Base16Ocean, "first", "foo bar")[0].0, 4..7);
+
+        assert!(register_syntax("contexts: [").is_err());
+    }
+
+    #[test]
+    fn themes_are_loaded_from_tm_theme_files() {
+        register_syntax(&syntax("Themed", "themed", "fn")).expect("Register syntax");
+
+        let theme = Theme::load("Light", &tm_theme("#FF0000")).expect("Load theme");
+
+        assert!(!theme.is_dark());
+        assert_eq!(theme.to_string(), "Light");
+
+        let mut highlighter = Highlighter::new(&Settings {
+            theme,
+            token: "themed".to_owned(),
+        });
+
+        let (_, highlight) = highlighter
+            .highlight_line("fn main() {}")
+            .find(|(range, _)| *range == (0..2))
+            .expect("Highlight keyword");
+
+        assert_eq!(highlight.color(), Some(Color::from_rgb8(0xFF, 0, 0)));
+        assert_eq!(
+            highlight.font().map(|font| font.weight),
+            Some(font::Weight::Bold)
+        );
+
+        assert!(matches!(
+            Theme::load("Invalid", "<plist>"),
+            Err(Error::InvalidTheme(_))
+        ));
+    }
+
+    #[test]
+    fn themes_with_the_same_name_are_replaced() {
+        register_syntax(&syntax("Replaced", "replaced", "fn")).expect("Register syntax");
+
+        let red = Theme::load("Replaced", &tm_theme("#FF0000")).expect("Load theme");
+        let definition = red.definition();
+
+        let count = CUSTOM_THEMES.read().expect("Read custom themes").len();
+
+        assert_eq!(
+            Theme::load("Replaced", &tm_theme("#FF0000")).expect("Load theme"),
+            red
+        );
+        assert!(std::ptr::eq(red.definition(), definition));
+
+        let blue = Theme::load("Replaced", &tm_theme("#0000FF")).expect("Load theme");
+
+        assert_eq!(blue, red);
+        assert_eq!(
+            CUSTOM_THEMES.read().expect("Read custom themes").len(),
+            count
+        );
+        assert_eq!(
+            keywords(blue, "replaced", "fn main() {}")
+                .into_iter()
+                .find(|(range, _)| *range == (0..2))
+                .and_then(|(_, color)| color),
+            Some(Color::from_rgb8(0, 0, 0xFF))
+        );
+
+        let palette = Theme::from_palette("Replaced", &Palette::generate(palette::Seed::DARK));
+
+        assert_eq!(palette, red);
+        assert!(palette.is_dark());
+    }
+}
@@ -0,0 +1,692 @@
+//! Highlight text incrementally with [tree-sitter].
+//!
+//! A tree-sitter [`Highlighter`] parses the whole text into a syntax tree,
+//! reparsing only what changes on every edit. The tree is shared through a
+//! [`Syntax`] handle; which can be used for features like bracket matching
+//! or structural selection.
+//!
+//! The grammar and the highlights query of every [`Language`] must be
+//! provided; most grammar crates ship both of them:
+//!
+//! ```text
+//! let rust = tree_sitter::Language::new(
+//!     "rust",
+//!     tree_sitter_rust::LANGUAGE,
+//!     tree_sitter_rust::HIGHLIGHTS_QUERY,
+//! )?;
+//!
+//! text_editor(&content).highlight_with::<tree_sitter::Highlighter>(
+//!     tree_sitter::Settings::new(rust, Theme::Base16Ocean),
+//!     |highlight, _theme| highlight.to_format(),
+//! )
+//! ```
+//!
+//! [tree-sitter]: https://tree-sitter.github.io
+use crate::core::text::editor::Position;
+use crate::core::text::highlighter;
+use crate::{Highlight, Theme};
+
+use streaming_iterator::StreamingIterator;
+use syntect::highlighting;
+use syntect::parsing::Scope;
+use two_face::re_exports::syntect;
+
+use std::fmt;
+use std::ops::Range;
+use std::sync::{Arc, RwLock};
+
+pub use tree_sitter::{Node, QueryError, Tree};
+
+/// A language that can be highlighted by a tree-sitter [`Highlighter`].
+#[derive(Clone)]
+pub struct Language {
+    name: String,
+    grammar: tree_sitter::Language,
+    highlights: Arc<tree_sitter::Query>,
+}
+
+impl Language {
+    /// Creates a new [`Language`] with the given name, tree-sitter grammar,
+    /// and highlights query.
+    pub fn new(
+        name: impl Into<String>,
+        grammar: impl Into<tree_sitter::Language>,
+        highlights: &str,
+    ) -> Result<Self, QueryError> {
+        let grammar = grammar.into();
+        let highlights = tree_sitter::Query::new(&grammar, highlights)?;
+
+        Ok(Self {
+            name: name.into(),
+            grammar,
+            highlights: Arc::new(highlights),
+        })
+    }
+
+    /// Returns the name of the [`Language`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && Arc::ptr_eq(&self.highlights, &other.highlights)
+    }
+}
+
+impl fmt::Debug for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Language")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The settings of a tree-sitter [`Highlighter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// The [`Language`] to highlight.
+    pub language: Language,
+    /// The [`Theme`] of the [`Highlighter`].
+    pub theme: Theme,
+    /// The [`Syntax`] handle the [`Highlighter`] will keep up to date.
+    pub syntax: Syntax,
+}
+
+impl Settings {
+    /// Creates new [`Settings`] for the given [`Language`] and [`Theme`],
+    /// with a new [`Syntax`] handle.
+    pub fn new(language: Language, theme: Theme) -> Self {
+        Self {
+            language,
+            theme,
+            syntax: Syntax::new(),
+        }
+    }
+
+    /// Sets the [`Syntax`] handle of the [`Settings`].
+    pub fn syntax(mut self, syntax: Syntax) -> Self {
+        self.syntax = syntax;
+        self
+    }
+}
+
+/// A shared handle to the syntax tree of a tree-sitter [`Highlighter`].
+///
+/// Keep a clone of the [`Syntax`] given to the [`Settings`] of a
+/// [`Highlighter`] to query the syntax tree of its text.
+#[derive(Debug, Clone, Default)]
+pub struct Syntax(Arc<RwLock<Option<Tree>>>);
+
+impl Syntax {
+    /// Creates a new empty [`Syntax`] handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the latest syntax [`Tree`], if the text has been parsed.
+    pub fn tree(&self) -> Option<Tree> {
+        self.0.read().expect("Read syntax tree").clone()
+    }
+
+    /// Returns the [`Position`] of the bracket matching the bracket at the
+    /// given [`Position`], if any.
+    pub fn matching_bracket(&self, position: Position) -> Option<Position> {
+        const BRACKETS: &[(&str, &str)] = &[("(", ")"), ("[", "]"), ("{", "}"), ("<", ">")];
+
+        let tree = self.tree()?;
+        let point = to_point(position);
+
+        let node = tree.root_node().descendant_for_point_range(point, point)?;
+
+        let (open, close) = BRACKETS
+            .iter()
+            .find(|(open, close)| node.kind() == *open || node.kind() == *close)?;
+
+        let parent = node.parent()?;
+        let mut cursor = parent.walk();
+        let siblings: Vec<_> = parent.children(&mut cursor).collect();
+
+        let index = siblings
+            .iter()
+            .position(|sibling| sibling.id() == node.id())?;
+
+        let matching = if node.kind() == *open {
+            siblings[index + 1..]
+                .iter()
+                .find(|sibling| sibling.kind() == *close)
+        } else {
+            siblings[..index]
+                .iter()
+                .rev()
+                .find(|sibling| sibling.kind() == *open)
+        }?;
+
+        Some(to_position(matching.start_position()))
+    }
+
+    /// Expands the given selection to the smallest syntax node that
+    /// strictly contains it, if any.
+    ///
+    /// Calling this repeatedly selects larger and larger pieces of
+    /// the syntax tree.
+    pub fn expand_selection(&self, selection: Range<Position>) -> Option<Range<Position>> {
+        let tree = self.tree()?;
+
+        let start = to_point(selection.start);
+        let end = to_point(selection.end);
+
+        let mut node = tree
+            .root_node()
+            .named_descendant_for_point_range(start, end)?;
+
+        while node.start_position() == start && node.end_position() == end {
+            node = node.parent()?;
+        }
+
+        Some(to_position(node.start_position())..to_position(node.end_position()))
+    }
+}
+
+impl PartialEq for Syntax {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A syntax highlighter backed by tree-sitter.
+pub struct Highlighter {
+    parser: tree_sitter::Parser,
+    cursor: tree_sitter::QueryCursor,
+    language: Language,
+    styles: Vec<Option<highlighting::StyleModifier>>,
+    syntax: Syntax,
+    tree: Option<Tree>,
+    text: String,
+    lines: Vec<usize>,
+    current_line: usize,
+    is_dirty: bool,
+    is_edited: bool,
+}
+
+impl highlighter::Highlighter for Highlighter {
+    type Settings = Settings;
+    type Highlight = Highlight;
+
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Self::Highlight)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        let mut parser = tree_sitter::Parser::new();
+
+        // An incompatible grammar simply produces no syntax tree
+        let _ = parser.set_language(&settings.language.grammar);
+
+        Self {
+            parser,
+            cursor: tree_sitter::QueryCursor::new(),
+            language: settings.language.clone(),
+            styles: styles(&settings.language.highlights, settings.theme),
+            syntax: settings.syntax.clone(),
+            tree: None,
+            text: String::new(),
+            lines: Vec::new(),
+            current_line: 0,
+            is_dirty: true,
+            is_edited: false,
+        }
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        if new_settings.language != self.language {
+            *self = Self::new(new_settings);
+            return;
+        }
+
+        self.styles = styles(&self.language.highlights, new_settings.theme);
+
+        if new_settings.syntax != self.syntax {
+            self.syntax = new_settings.syntax.clone();
+            *self.syntax.0.write().expect("Write syntax tree") = self.tree.clone();
+        }
+
+        self.change_line(0);
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = line;
+        self.is_dirty = true;
+    }
+
+    fn edit(&mut self, range: Range<Position>, text: &str) {
+        let Some(tree) = &mut self.tree else {
+            // The whole text will be parsed when preparing
+            return;
+        };
+
+        let (Some(start), Some(old_end)) = (self.offset(range.start), self.offset(range.end))
+        else {
+            // Out of sync; parse everything again
+            self.tree = None;
+            return;
+        };
+
+        // Lines are always joined with a single line feed
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+
+        let new_end = start + text.len();
+        let new_end_position = match text.rfind('\n') {
+            Some(last) => tree_sitter::Point {
+                row: range.start.line + text.matches('\n').count(),
+                column: text.len() - last - 1,
+            },
+            None => tree_sitter::Point {
+                row: range.start.line,
+                column: range.start.column + text.len(),
+            },
+        };
+
+        tree.edit(&tree_sitter::InputEdit {
+            start_byte: start,
+            old_end_byte: old_end,
+            new_end_byte: new_end,
+            start_position: to_point(range.start),
+            old_end_position: to_point(range.end),
+            new_end_position,
+        });
+
+        self.text.replace_range(start..old_end, &text);
+
+        let new_lines = text
+            .match_indices('\n')
+            .map(|(i, _)| start + i + 1)
+            .collect::<Vec<_>>();
+
+        let _ = self
+            .lines
+            .splice(range.start.line + 1..=range.end.line, new_lines);
+
+        for line in &mut self.lines[new_end_position.row + 1..] {
+            *line = *line + new_end - old_end;
+        }
+
+        self.is_edited = true;
+    }
+
+    fn prepare<'a>(&mut self, lines: impl Iterator<Item = &'a str>) {
+        if !self.is_dirty {
+            return;
+        }
+
+        self.is_dirty = false;
+
+        if self.tree.is_some() {
+            if self.is_edited {
+                self.is_edited = false;
+                self.tree = self.parser.parse(&self.text, self.tree.as_ref());
+
+                *self.syntax.0.write().expect("Write syntax tree") = self.tree.clone();
+                return;
+            }
+
+            // Without any edits, only changes from the very first line
+            // may replace the text; like a new font or theme
+            if self.current_line > 0 {
+                return;
+            }
+        }
+
+        let mut text = String::with_capacity(self.text.len());
+        self.lines.clear();
+
+        for line in lines {
+            self.lines.push(text.len());
+            text.push_str(line);
+            text.push('\n');
+        }
+
+        if self.tree.is_some() && text == self.text {
+            return;
+        }
+
+        if let Some(tree) = &mut self.tree {
+            tree.edit(&edit(&self.text, &text));
+        }
+
+        self.tree = self.parser.parse(&text, self.tree.as_ref());
+        self.text = text;
+
+        *self.syntax.0.write().expect("Write syntax tree") = self.tree.clone();
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        let index = self.current_line;
+        self.current_line += 1;
+
+        let (Some(tree), Some(&start)) = (&self.tree, self.lines.get(index)) else {
+            return Vec::new().into_iter();
+        };
+
+        let end = start + line.len();
+        let mut highlights = Vec::new();
+
+        let _ = self.cursor.set_byte_range(start..end);
+
+        let mut captures = self.cursor.captures(
+            &self.language.highlights,
+            tree.root_node(),
+            self.text.as_bytes(),
+        );
+
+        while let Some((match_, index)) = captures.next() {
+            let capture = match_.captures[*index];
+
+            let Some(Some(style)) = self.styles.get(capture.index as usize) else {
+                continue;
+            };
+
+            let range = capture.node.byte_range();
+            let range = range.start.max(start) - start..range.end.min(end) - start;
+
+            if !range.is_empty() {
+                highlights.push((range, Highlight(*style)));
+            }
+        }
+
+        highlights.into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+impl Highlighter {
+    /// Returns the byte offset of the given [`Position`] in the text.
+    fn offset(&self, position: Position) -> Option<usize> {
+        let start = *self.lines.get(position.line)?;
+        let end = self
+            .lines
+            .get(position.line + 1)
+            .map_or(self.text.len().saturating_sub(1), |next| next - 1);
+
+        Some(start + position.column).filter(|offset| *offset <= end)
+    }
+}
+
+impl fmt::Debug for Highlighter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Highlighter")
+            .field("language", &self.language)
+            .field("current_line", &self.current_line)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Computes the style of every capture of a highlights query with the
+/// given [`Theme`].
+fn styles(query: &tree_sitter::Query, theme: Theme) -> Vec<Option<highlighting::StyleModifier>> {
+    let highlighter = highlighting::Highlighter::new(theme.definition());
+
+    query
+        .capture_names()
+        .iter()
+        .map(|name| {
+            let scope = Scope::new(scope(name)?).ok()?;
+
+            Some(highlighter.style_mod_for_stack(&[scope]))
+        })
+        .collect()
+}
+
+/// Maps the name of a tree-sitter capture to a TextMate scope; falling
+/// back to its parent name (e.g. `function.method` to `function`).
+fn scope(capture: &str) -> Option<&'static str> {
+    let mut name = capture;
+
+    loop {
+        let scope = match name {
+            "attribute" => "entity.other.attribute-name",
+            "boolean" | "constant.builtin" => "constant.language",
+            "comment" => "comment",
+            "constant" => "constant",
+            "constructor" | "function" | "function.method" => "entity.name.function",
+            "escape" | "string.escape" => "constant.character.escape",
+            "function.builtin" => "support.function",
+            "function.macro" => "support.macro",
+            "keyword" => "keyword",
+            "label" => "entity.name.label",
+            "module" | "namespace" => "entity.name.namespace",
+            "number" => "constant.numeric",
+            "operator" => "keyword.operator",
+            "property" => "variable.other.member",
+            "punctuation" => "punctuation",
+            "string" => "string",
+            "tag" => "entity.name.tag",
+            "type" => "entity.name.type",
+            "type.builtin" => "storage.type",
+            "variable" => "variable",
+            "variable.builtin" => "variable.language",
+            "variable.parameter" => "variable.parameter",
+            _ => {
+                name = &name[..name.rfind('.')?];
+                continue;
+            }
+        };
+
+        return Some(scope);
+    }
+}
+
+/// Describes the edit that turns the old text into the new one.
+fn edit(old: &str, new: &str) -> tree_sitter::InputEdit {
+    let (old, new) = (old.as_bytes(), new.as_bytes());
+
+    let start = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let suffix = old[start..]
+        .iter()
+        .rev()
+        .zip(new[start..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let old_end = old.len() - suffix;
+    let new_end = new.len() - suffix;
+
+    tree_sitter::InputEdit {
+        start_byte: start,
+        old_end_byte: old_end,
+        new_end_byte: new_end,
+        start_position: point(old, start),
+        old_end_position: point(old, old_end),
+        new_end_position: point(new, new_end),
+    }
+}
+
+fn point(text: &[u8], offset: usize) -> tree_sitter::Point {
+    let before = &text[..offset];
+
+    let row = before.iter().filter(|byte| **byte == b'\n').count();
+    let column = offset
+        - before
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |i| i + 1);
+
+    tree_sitter::Point { row, column }
+}
+
+fn to_point(position: Position) -> tree_sitter::Point {
+    tree_sitter::Point {
+        row: position.line,
+        column: position.column,
+    }
+}
+
+fn to_position(point: tree_sitter::Point) -> Position {
+    Position {
+        line: point.row,
+        column: point.column,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::highlighter::Highlighter as _;
+
+    const TEXT: &[&str] = &["{", "  \"a\": [1, 2]", "}"];
+
+    fn json() -> Language {
+        Language::new(
+            "json",
+            tree_sitter_json::LANGUAGE,
+            tree_sitter_json::HIGHLIGHTS_QUERY,
+        )
+        .expect("Create JSON language")
+    }
+
+    fn position(line: usize, column: usize) -> Position {
+        Position { line, column }
+    }
+
+    fn prepared(syntax: &Syntax) -> Highlighter {
+        let mut highlighter =
+            Highlighter::new(&Settings::new(json(), Theme::Base16Ocean).syntax(syntax.clone()));
+
+        highlighter.prepare(TEXT.iter().copied());
+
+        highlighter
+    }
+
+    fn parse(text: &str) -> Tree {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&json().grammar)
+            .expect("Set JSON grammar");
+
+        parser.parse(text, None).expect("Parse text")
+    }
+
+    fn lines(text: &str) -> Vec<usize> {
+        std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .filter(|start| *start < text.len())
+            .collect()
+    }
+
+    #[test]
+    fn edits_are_parsed_incrementally() {
+        let syntax = Syntax::new();
+        let mut highlighter = prepared(&syntax);
+
+        highlighter.edit(position(1, 12)..position(1, 12), ", 3");
+        highlighter.change_line(1);
+
+        // The edited text is kept by the highlighter; lines are not needed
+        highlighter.prepare(std::iter::empty());
+
+        let text = "{\n  \"a\": [1, 2, 3]\n}\n";
+        let tree = syntax.tree().expect("Syntax tree");
+
+        assert_eq!(highlighter.text, text);
+        assert_eq!(highlighter.lines, lines(text));
+        assert!(!tree.root_node().has_error());
+        assert_eq!(
+            tree.root_node().to_sexp(),
+            parse(text).root_node().to_sexp()
+        );
+
+        highlighter.edit(
+            position(0, 1)..position(2, 0),
+            "\n  \"b\": null,\n  \"c\": true\n",
+        );
+        highlighter.change_line(0);
+        highlighter.prepare(std::iter::empty());
+
+        let text = "{\n  \"b\": null,\n  \"c\": true\n}\n";
+        let tree = syntax.tree().expect("Syntax tree");
+
+        assert_eq!(highlighter.text, text);
+        assert_eq!(highlighter.lines, lines(text));
+        assert_eq!(
+            tree.root_node().to_sexp(),
+            parse(text).root_node().to_sexp()
+        );
+
+        highlighter.change_line(2);
+        highlighter.prepare(std::iter::empty());
+
+        let highlights: Vec<_> = highlighter
+            .highlight_line("  \"c\": true")
+            .map(|(range, _)| range)
+            .collect();
+
+        assert!(highlights.contains(&(2..5)));
+    }
+
+    #[test]
+    fn out_of_sync_edits_parse_everything_again() {
+        let syntax = Syntax::new();
+        let mut highlighter = prepared(&syntax);
+
+        highlighter.edit(position(7, 0)..position(7, 0), "oops");
+        highlighter.change_line(0);
+        highlighter.prepare(["[]"].into_iter());
+
+        assert_eq!(highlighter.text, "[]\n");
+        assert_eq!(
+            syntax.tree().expect("Syntax tree").root_node().to_sexp(),
+            parse("[]\n").root_node().to_sexp()
+        );
+    }
+
+    #[test]
+    fn matching_bracket() {
+        let syntax = Syntax::new();
+        let _highlighter = prepared(&syntax);
+
+        assert_eq!(
+            syntax.matching_bracket(position(0, 0)),
+            Some(position(2, 0))
+        );
+        assert_eq!(
+            syntax.matching_bracket(position(2, 0)),
+            Some(position(0, 0))
+        );
+        assert_eq!(
+            syntax.matching_bracket(position(1, 7)),
+            Some(position(1, 12))
+        );
+        assert_eq!(
+            syntax.matching_bracket(position(1, 12)),
+            Some(position(1, 7))
+        );
+        assert_eq!(syntax.matching_bracket(position(1, 8)), None);
+    }
+
+    #[test]
+    fn expand_selection() {
+        let syntax = Syntax::new();
+        let _highlighter = prepared(&syntax);
+
+        let number = syntax
+            .expand_selection(position(1, 8)..position(1, 8))
+            .expect("Select number");
+        assert_eq!(number, position(1, 8)..position(1, 9));
+
+        let array = syntax.expand_selection(number).expect("Select array");
+        assert_eq!(array, position(1, 7)..position(1, 13));
+
+        let pair = syntax.expand_selection(array).expect("Select pair");
+        assert_eq!(pair, position(1, 2)..position(1, 13));
+
+        let object = syntax.expand_selection(pair).expect("Select object");
+        assert_eq!(object, position(0, 0)..position(2, 1));
+    }
+}
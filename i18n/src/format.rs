@@ -0,0 +1,258 @@
+//! Format numbers, dates, and times for the active locale.
+//!
+//! These helpers are useful to label widgets that display values; like a
+//! `slider` or a `progress_bar`:
+//!
+//! ```
+//! use iced_i18n::format;
+//!
+//! let volume = 0.75;
+//! let label = format::percent(volume, 0);
+//! ```
+//!
+//! Numbers interpolated in translations are formatted in the same way.
+use crate::Locale;
+
+use fluent_bundle::FluentValue;
+use unic_langid::subtags::Region;
+
+use std::sync::RwLock;
+
+static LOCALE: RwLock<Option<Locale>> = RwLock::new(None);
+
+/// Formats a number with the given amount of decimals.
+///
+/// For instance, `1234.5` is formatted as `1,234.50` in `en-US` and
+/// as `1.234,50` in `de-DE` with 2 decimals.
+pub fn number(value: f64, decimals: usize) -> String {
+    localize(&format!("{value:.decimals$}"), &symbols(&locale()), true)
+}
+
+/// Formats an integer.
+pub fn integer(value: i64) -> String {
+    localize(&value.to_string(), &symbols(&locale()), true)
+}
+
+/// Formats a ratio as a percentage with the given amount of decimals.
+///
+/// For instance, `0.25` is formatted as `25%` in `en-US` and as
+/// `25 %` in `fr-FR`.
+pub fn percent(ratio: f64, decimals: usize) -> String {
+    let symbols = symbols(&locale());
+
+    format!(
+        "{}{}",
+        localize(&format!("{:.decimals$}", ratio * 100.0), &symbols, true),
+        symbols.percent
+    )
+}
+
+/// Formats a calendar date in its short numeric form.
+///
+/// For instance, the 7th of March of 2025 is formatted as `3/7/2025` in
+/// `en-US`, as `07/03/2025` in `en-GB`, and as `2025-03-07` in `sv-SE`.
+pub fn date(year: i32, month: u32, day: u32) -> String {
+    date_in(&locale(), year, month, day)
+}
+
+/// Formats a time of the day.
+///
+/// For instance, `15:05` is formatted as `3:05 PM` in `en-US` and as
+/// `15:05` in `de-DE`.
+pub fn time(hour: u32, minute: u32) -> String {
+    time_in(&locale(), hour, minute)
+}
+
+pub(crate) fn locale() -> Locale {
+    LOCALE
+        .read()
+        .expect("Read locale")
+        .clone()
+        .unwrap_or_default()
+}
+
+pub(crate) fn set_locale(locale: Locale) {
+    *LOCALE.write().expect("Write locale") = Some(locale);
+}
+
+pub(crate) fn value<M>(value: &FluentValue<'_>, _memoizer: &M) -> Option<String> {
+    let FluentValue::Number(number) = value else {
+        return None;
+    };
+
+    Some(localize(
+        &number.as_string(),
+        &symbols(&locale()),
+        number.options.use_grouping,
+    ))
+}
+
+struct Symbols {
+    decimal: char,
+    group: &'static str,
+    percent: &'static str,
+}
+
+fn symbols(locale: &Locale) -> Symbols {
+    match subtags(locale) {
+        ("de", Some("CH" | "LI")) => Symbols {
+            decimal: '.',
+            group: "’",
+            percent: "%",
+        },
+        ("es", Some("MX" | "US")) => Symbols {
+            decimal: '.',
+            group: ",",
+            percent: "%",
+        },
+        ("fr", _) => Symbols {
+            decimal: ',',
+            group: "\u{202F}",
+            percent: "\u{202F}%",
+        },
+        ("de" | "es" | "da" | "el" | "id" | "ro" | "sl" | "hr" | "sr", _) => Symbols {
+            decimal: ',',
+            group: ".",
+            percent: "\u{A0}%",
+        },
+        ("it" | "nl" | "pt" | "tr" | "vi", _) => Symbols {
+            decimal: ',',
+            group: ".",
+            percent: "%",
+        },
+        (
+            "ru" | "uk" | "bg" | "cs" | "sk" | "sv" | "fi" | "nb" | "nn" | "no" | "et" | "lt"
+            | "lv" | "hu",
+            _,
+        ) => Symbols {
+            decimal: ',',
+            group: "\u{A0}",
+            percent: "\u{A0}%",
+        },
+        ("pl", _) => Symbols {
+            decimal: ',',
+            group: "\u{A0}",
+            percent: "%",
+        },
+        _ => Symbols {
+            decimal: '.',
+            group: ",",
+            percent: "%",
+        },
+    }
+}
+
+fn subtags(locale: &Locale) -> (&str, Option<&str>) {
+    (
+        locale.language.as_str(),
+        locale.region.as_ref().map(Region::as_str),
+    )
+}
+
+fn localize(number: &str, symbols: &Symbols, grouping: bool) -> String {
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(number) => ("-", number),
+        None => ("", number),
+    };
+
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+
+    if !integer.bytes().all(|byte| byte.is_ascii_digit()) {
+        return format!("{sign}{number}");
+    }
+
+    let mut result = String::from(sign);
+
+    for (i, digit) in integer.chars().enumerate() {
+        if grouping && i > 0 && (integer.len() - i) % 3 == 0 {
+            result.push_str(symbols.group);
+        }
+
+        result.push(digit);
+    }
+
+    if let Some(fraction) = fraction {
+        result.push(symbols.decimal);
+        result.push_str(fraction);
+    }
+
+    result
+}
+
+fn date_in(locale: &Locale, year: i32, month: u32, day: u32) -> String {
+    match subtags(locale) {
+        ("en", Some("CA")) | ("sv" | "lt", _) => {
+            format!("{year:04}-{month:02}-{day:02}")
+        }
+        ("en", None | Some("US" | "PH")) => format!("{month}/{day}/{year}"),
+        ("ja" | "zh", _) => format!("{year}/{month:02}/{day:02}"),
+        ("ko" | "hu", _) => format!("{year}. {month:02}. {day:02}."),
+        ("nl", _) => format!("{day:02}-{month:02}-{year}"),
+        (
+            "de" | "ru" | "uk" | "pl" | "cs" | "sk" | "fi" | "nb" | "nn" | "no" | "da" | "tr"
+            | "ro" | "et" | "lv" | "bg" | "hr" | "sl" | "sr",
+            _,
+        ) => format!("{day:02}.{month:02}.{year}"),
+        ("und", _) => format!("{year:04}-{month:02}-{day:02}"),
+        _ => format!("{day:02}/{month:02}/{year}"),
+    }
+}
+
+fn time_in(locale: &Locale, hour: u32, minute: u32) -> String {
+    match subtags(locale) {
+        ("en", Some("GB" | "IE")) => format!("{hour:02}:{minute:02}"),
+        ("en", _) => {
+            let period = if hour < 12 { "AM" } else { "PM" };
+            let hour = match hour % 12 {
+                0 => 12,
+                hour => hour,
+            };
+
+            format!("{hour}:{minute:02} {period}")
+        }
+        _ => format!("{hour:02}:{minute:02}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locale(tag: &str) -> Locale {
+        tag.parse().unwrap()
+    }
+
+    fn format(tag: &str, number: &str) -> String {
+        localize(number, &symbols(&locale(tag)), true)
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(format("en-US", "1234567.891"), "1,234,567.891");
+        assert_eq!(format("de-DE", "1234567.891"), "1.234.567,891");
+        assert_eq!(format("fr-FR", "-1234.5"), "-1\u{202F}234,5");
+        assert_eq!(format("de-CH", "1234"), "1’234");
+        assert_eq!(format("en-US", "123"), "123");
+        assert_eq!(format("en-US", "inf"), "inf");
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(date_in(&locale("en-US"), 2025, 3, 7), "3/7/2025");
+        assert_eq!(date_in(&locale("en-GB"), 2025, 3, 7), "07/03/2025");
+        assert_eq!(date_in(&locale("de-DE"), 2025, 3, 7), "07.03.2025");
+        assert_eq!(date_in(&locale("sv-SE"), 2025, 3, 7), "2025-03-07");
+        assert_eq!(date_in(&locale("ja-JP"), 2025, 3, 7), "2025/03/07");
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(time_in(&locale("en-US"), 0, 5), "12:05 AM");
+        assert_eq!(time_in(&locale("en-US"), 15, 5), "3:05 PM");
+        assert_eq!(time_in(&locale("en-GB"), 15, 5), "15:05");
+        assert_eq!(time_in(&locale("fr-FR"), 9, 30), "09:30");
+    }
+}
@@ -0,0 +1,520 @@
+//! Localize [`iced`] applications with [Fluent].
+//!
+//! Translations are written as Fluent resources and added to a global catalog,
+//! either embedded in the binary with [`add`] or read from disk with [`load`].
+//! Then, messages can be looked up with [`translate`] or the [`t!`] macro,
+//! which produce a `String` that can be passed directly to any widget taking
+//! some text.
+//!
+//! The active locale can be changed at any time with [`set_locale`]. Since
+//! the `view` of an application is rebuilt after every `update`, switching
+//! the locale while handling a message re-renders the whole user interface
+//! in the new language.
+//!
+//! Plurals and other selectors are handled by Fluent itself, while numbers
+//! and dates can be formatted for the active locale with the [`format`] module.
+//!
+//! # Example
+//! ```
+//! use iced_i18n as i18n;
+//! use iced_i18n::t;
+//!
+//! i18n::add("en-US", r#"
+//! hello = Hello, { $name }!
+//! emails = { $count ->
+//!     [one] You have one new email.
+//!    *[other] You have { $count } new emails.
+//! }
+//! "#)?;
+//!
+//! i18n::add("es-ES", r#"
+//! hello = ¡Hola, { $name }!
+//! emails = { $count ->
+//!     [one] Tienes un correo nuevo.
+//!    *[other] Tienes { $count } correos nuevos.
+//! }
+//! "#)?;
+//!
+//! assert_eq!(t!("hello", name = "Ferris"), "Hello, Ferris!");
+//!
+//! i18n::set_locale("es-ES")?;
+//!
+//! assert_eq!(t!("hello", name = "Ferris"), "¡Hola, Ferris!");
+//! assert_eq!(t!("emails", count = 1), "Tienes un correo nuevo.");
+//! assert_eq!(t!("emails", count = 12000), "Tienes 12.000 correos nuevos.");
+//! # Ok::<(), i18n::Error>(())
+//! ```
+//!
+//! [`iced`]: https://github.com/iced-rs/iced
+//! [Fluent]: https://projectfluent.org
+#![cfg_attr(docsrs, feature(doc_cfg))]
+pub mod format;
+
+pub use fluent_bundle::{FluentArgs, FluentValue};
+pub use unic_langid::LanguageIdentifier as Locale;
+
+use fluent_bundle::FluentResource;
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_langneg::NegotiationStrategy;
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+static CATALOG: RwLock<Catalog> = RwLock::new(Catalog::new());
+
+/// Looks up a message in the active locale.
+///
+/// Named arguments can be given after the message identifier. They can be
+/// anything convertible into a [`FluentValue`]; like numbers or strings.
+///
+/// ```
+/// # use iced_i18n::t;
+/// # let unread = 3;
+/// let title = t!("inbox-title");
+/// let subtitle = t!("inbox-unread", count = unread, folder = "Inbox");
+/// ```
+///
+/// See [`translate`] for the details.
+#[macro_export]
+macro_rules! t {
+    ($id:expr $(,)?) => {
+        $crate::translate($id, None)
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut arguments = $crate::FluentArgs::new();
+        $(arguments.set(stringify!($name), $value);)+
+
+        $crate::translate($id, Some(&arguments))
+    }};
+}
+
+/// Adds the given Fluent resource to the translations of a locale.
+///
+/// Messages already present in the locale will be overridden. Translations
+/// can be embedded in the binary by combining this with [`include_str!`].
+///
+/// The first locale added becomes the fallback locale, unless one is set
+/// explicitly with [`set_fallback`].
+pub fn add(locale: &str, source: impl Into<String>) -> Result<(), Error> {
+    let locale = parse(locale)?;
+
+    let resource = FluentResource::try_new(source.into()).map_err(|(_resource, errors)| {
+        Error::InvalidResource {
+            locale: locale.to_string(),
+            errors: errors.iter().map(ToString::to_string).collect(),
+        }
+    })?;
+
+    let mut catalog = CATALOG.write().expect("Write catalog");
+    catalog.bundle(locale).add_resource_overriding(resource);
+    catalog.negotiate();
+
+    Ok(())
+}
+
+/// Loads the Fluent resources found at the given path.
+///
+/// If the path is a directory, each of its subdirectories is expected to be
+/// named after a locale and contain `.ftl` files; like `locales/en-US/main.ftl`.
+/// Files directly inside the directory are named after their locale instead;
+/// like `locales/en-US.ftl`.
+///
+/// If the path is a file, it is loaded in the same way as a file directly
+/// inside a directory.
+pub fn load(path: impl AsRef<Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+
+    if !path.is_dir() {
+        return load_file(path, None);
+    }
+
+    let mut entries = read_dir(path)?;
+    entries.sort();
+
+    for entry in entries {
+        if entry.is_dir() {
+            let Some(locale) = entry.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            let mut files = read_dir(&entry)?;
+            files.sort();
+
+            for file in files {
+                load_file(&file, Some(locale))?;
+            }
+        } else {
+            load_file(&entry, None)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Sets the active locale.
+///
+/// The locale is negotiated against the available translations; so asking
+/// for `es-MX` will use the `es-ES` translations if those are the closest
+/// ones. Messages missing in the active locale are looked up in the
+/// fallback locale.
+pub fn set_locale(locale: &str) -> Result<(), Error> {
+    let locale = parse(locale)?;
+
+    let mut catalog = CATALOG.write().expect("Write catalog");
+    catalog.requested = Some(locale);
+    catalog.negotiate();
+
+    Ok(())
+}
+
+/// Sets the fallback locale; used when a message is missing in the active
+/// locale.
+pub fn set_fallback(locale: &str) -> Result<(), Error> {
+    let locale = parse(locale)?;
+
+    let mut catalog = CATALOG.write().expect("Write catalog");
+    catalog.fallback = Some(locale);
+    catalog.negotiate();
+
+    Ok(())
+}
+
+/// Returns the active locale.
+///
+/// This is the locale that was negotiated last; or the fallback locale, if
+/// none was set.
+pub fn locale() -> Locale {
+    format::locale()
+}
+
+/// Returns all the locales with translations available.
+pub fn locales() -> Vec<Locale> {
+    let catalog = CATALOG.read().expect("Read catalog");
+
+    catalog.locales.clone()
+}
+
+/// Returns true if a message with the given identifier exists in the
+/// active locale or in any of its fallbacks.
+pub fn contains(id: &str) -> bool {
+    let catalog = CATALOG.read().expect("Read catalog");
+    let (message, attribute) = split(id);
+
+    catalog.chain.iter().any(|&index| {
+        catalog.bundles[index]
+            .get_message(message)
+            .is_some_and(|message| match attribute {
+                Some(attribute) => message.get_attribute(attribute).is_some(),
+                None => message.value().is_some(),
+            })
+    })
+}
+
+/// Translates the message with the given identifier in the active locale,
+/// using the given arguments.
+///
+/// Attributes of a message can be looked up with a dot; like `login.placeholder`.
+///
+/// If the message is missing, the identifier itself is returned and a
+/// warning is logged.
+pub fn translate(id: &str, arguments: Option<&FluentArgs<'_>>) -> String {
+    let catalog = CATALOG.read().expect("Read catalog");
+    let (message, attribute) = split(id);
+
+    for bundle in catalog.chain.iter().map(|&index| &catalog.bundles[index]) {
+        let Some(message) = bundle.get_message(message) else {
+            continue;
+        };
+
+        let pattern = match attribute {
+            Some(attribute) => message
+                .get_attribute(attribute)
+                .map(|attribute| attribute.value()),
+            None => message.value(),
+        };
+
+        let Some(pattern) = pattern else {
+            continue;
+        };
+
+        let mut errors = Vec::new();
+        let translation = bundle.format_pattern(pattern, arguments, &mut errors);
+
+        for error in errors {
+            log::warn!("Error translating \"{id}\": {error}");
+        }
+
+        return translation.into_owned();
+    }
+
+    log::warn!("Missing translation: {id}");
+
+    id.to_owned()
+}
+
+/// An error that can happen when loading translations.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The locale identifier is not valid.
+    #[error("invalid locale: {0}")]
+    InvalidLocale(String),
+    /// The Fluent resource contains syntax errors.
+    #[error("invalid resource for {locale}: {}", errors.join("; "))]
+    InvalidResource {
+        /// The locale of the resource.
+        locale: String,
+        /// The syntax errors found.
+        errors: Vec<String>,
+    },
+    /// An I/O operation failed.
+    #[error("I/O error: {0}")]
+    Io(Arc<io::Error>),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+struct Catalog {
+    bundles: Vec<FluentBundle<FluentResource>>,
+    locales: Vec<Locale>,
+    requested: Option<Locale>,
+    fallback: Option<Locale>,
+    chain: Vec<usize>,
+}
+
+impl Catalog {
+    const fn new() -> Self {
+        Self {
+            bundles: Vec::new(),
+            locales: Vec::new(),
+            requested: None,
+            fallback: None,
+            chain: Vec::new(),
+        }
+    }
+
+    fn bundle(&mut self, locale: Locale) -> &mut FluentBundle<FluentResource> {
+        let index = if let Some(index) = self
+            .locales
+            .iter()
+            .position(|candidate| *candidate == locale)
+        {
+            index
+        } else {
+            let mut bundle = FluentBundle::new_concurrent(vec![locale.clone()]);
+            bundle.set_use_isolating(false);
+            bundle.set_formatter(Some(format::value));
+
+            self.bundles.push(bundle);
+            self.locales.push(locale);
+
+            self.bundles.len() - 1
+        };
+
+        &mut self.bundles[index]
+    }
+
+    fn negotiate(&mut self) {
+        let fallback = self
+            .fallback
+            .as_ref()
+            .and_then(|fallback| {
+                fluent_langneg::negotiate_languages(
+                    &[fallback],
+                    &self.locales,
+                    None,
+                    NegotiationStrategy::Lookup,
+                )
+                .first()
+                .copied()
+            })
+            .or_else(|| self.locales.first());
+
+        let negotiated = fluent_langneg::negotiate_languages(
+            self.requested.as_slice(),
+            &self.locales,
+            fallback,
+            NegotiationStrategy::Filtering,
+        );
+
+        self.chain = negotiated
+            .into_iter()
+            .filter_map(|locale| {
+                self.locales
+                    .iter()
+                    .position(|candidate| candidate == locale)
+            })
+            .collect();
+
+        format::set_locale(
+            self.chain
+                .first()
+                .map(|&index| self.locales[index].clone())
+                .unwrap_or_default(),
+        );
+    }
+}
+
+fn parse(locale: &str) -> Result<Locale, Error> {
+    locale
+        .parse()
+        .map_err(|_| Error::InvalidLocale(locale.to_owned()))
+}
+
+fn split(id: &str) -> (&str, Option<&str>) {
+    match id.split_once('.') {
+        Some((message, attribute)) => (message, Some(attribute)),
+        None => (id, None),
+    }
+}
+
+fn read_dir(path: &Path) -> Result<Vec<std::path::PathBuf>, Error> {
+    fs::read_dir(path)?.map(|entry| Ok(entry?.path())).collect()
+}
+
+fn load_file(path: &Path, locale: Option<&str>) -> Result<(), Error> {
+    if path.extension().is_none_or(|extension| extension != "ftl") {
+        return Ok(());
+    }
+
+    let Some(locale) = locale.or_else(|| path.file_stem().and_then(|stem| stem.to_str())) else {
+        return Ok(());
+    };
+
+    add(locale, fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::{Mutex, MutexGuard, PoisonError};
+
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    /// Clears the global catalog; keeping other tests away from it until
+    /// the returned guard is dropped.
+    fn reset() -> MutexGuard<'static, ()> {
+        let guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+        *CATALOG.write().expect("Write catalog") = Catalog::new();
+        format::set_locale(Locale::default());
+
+        guard
+    }
+
+    #[test]
+    fn translate_messages_and_attributes() {
+        let _guard = reset();
+
+        add(
+            "en-US",
+            "hello = Hello, { $name }!\nlogin = Log in\n    .placeholder = Username\n",
+        )
+        .unwrap();
+
+        assert_eq!(t!("hello", name = "Ferris"), "Hello, Ferris!");
+        assert_eq!(t!("login"), "Log in");
+        assert_eq!(t!("login.placeholder"), "Username");
+
+        assert!(contains("login.placeholder"));
+        assert!(!contains("login.tooltip"));
+        assert_eq!(t!("login.tooltip"), "login.tooltip");
+        assert_eq!(t!("missing"), "missing");
+
+        // Later resources override existing messages
+        add("en-US", "login = Sign in").unwrap();
+
+        assert_eq!(t!("login"), "Sign in");
+
+        assert!(matches!(
+            add("en-US", "broken = { $"),
+            Err(Error::InvalidResource { .. })
+        ));
+        assert!(matches!(
+            add("not a locale", ""),
+            Err(Error::InvalidLocale(_))
+        ));
+    }
+
+    #[test]
+    fn fallback_chains() {
+        let _guard = reset();
+
+        add(
+            "en-US",
+            "greeting = Hello\nfarewell = Goodbye\nthanks = Thanks",
+        )
+        .unwrap();
+        add("es-ES", "greeting = Hola").unwrap();
+        add("de-DE", "greeting = Hallo\nfarewell = Tschüss").unwrap();
+
+        // The first locale added is the fallback
+        assert_eq!(locale(), parse("en-US").unwrap());
+        assert_eq!(t!("greeting"), "Hello");
+
+        // The closest available locale is negotiated
+        set_locale("es-MX").unwrap();
+
+        assert_eq!(locale(), parse("es-ES").unwrap());
+        assert_eq!(format::number(1234.5, 1), "1.234,5");
+        assert_eq!(t!("greeting"), "Hola");
+        assert_eq!(t!("farewell"), "Goodbye");
+
+        set_fallback("de-DE").unwrap();
+
+        assert_eq!(t!("greeting"), "Hola");
+        assert_eq!(t!("farewell"), "Tschüss");
+        assert_eq!(t!("thanks"), "thanks");
+
+        // Locales without translations fall back entirely
+        set_locale("ja-JP").unwrap();
+
+        assert_eq!(locale(), parse("de-DE").unwrap());
+        assert_eq!(t!("greeting"), "Hallo");
+    }
+
+    #[test]
+    fn load_directories_and_files() {
+        let _guard = reset();
+
+        let root = std::env::temp_dir().join(format!("iced_i18n_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(root.join("en-US")).unwrap();
+        fs::write(root.join("en-US").join("main.ftl"), "title = Inbox").unwrap();
+        fs::write(root.join("en-US").join("extra.ftl"), "empty = No emails").unwrap();
+        fs::write(root.join("es-ES.ftl"), "title = Bandeja de entrada").unwrap();
+        fs::write(root.join("README.md"), "Not a resource").unwrap();
+        fs::write(root.join("fr-FR.ftl"), "title = Boîte de réception").unwrap();
+
+        load(&root).unwrap();
+
+        assert_eq!(
+            locales(),
+            ["en-US", "es-ES", "fr-FR"].map(|locale| parse(locale).unwrap())
+        );
+        assert_eq!(t!("title"), "Inbox");
+        assert_eq!(t!("empty"), "No emails");
+
+        set_locale("es").unwrap();
+
+        assert_eq!(t!("title"), "Bandeja de entrada");
+        assert_eq!(t!("empty"), "No emails");
+
+        // Single files are named after their locale
+        fs::write(root.join("es-ES.ftl"), "empty = Sin correos").unwrap();
+        load(root.join("es-ES.ftl")).unwrap();
+
+        assert_eq!(t!("empty"), "Sin correos");
+
+        assert!(matches!(load(root.join("missing.ftl")), Err(Error::Io(_))));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -10,6 +10,7 @@ mod preset;
 
 pub use preset::Preset;
 
+use crate::core::layout;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::theme;
@@ -562,14 +563,23 @@ impl<T> Renderer for T where
 pub struct Instance<P: Program> {
     program: P,
     state: P::State,
+    direction: layout::Direction,
 }
 
 impl<P: Program> Instance<P> {
     /// Creates a new [`Instance`] of the given [`Program`].
     pub fn new(program: P) -> (Self, Task<P::Message>) {
         let (state, task) = program.boot();
-
-        (Self { program, state }, task)
+        let direction = program.settings().direction;
+
+        (
+            Self {
+                program,
+                state,
+                direction,
+            },
+            task,
+        )
     }
 
     /// Returns the current title of the [`Instance`].
@@ -606,4 +616,9 @@ impl<P: Program> Instance<P> {
     pub fn scale_factor(&self, window: window::Id) -> f32 {
         self.program.scale_factor(&self.state, window)
     }
+
+    /// Returns the layout [`Direction`](layout::Direction) of the [`Instance`].
+    pub fn direction(&self) -> layout::Direction {
+        self.direction
+    }
 }
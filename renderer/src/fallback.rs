@@ -131,6 +131,21 @@ where
         );
     }
 
+    fn fill_paragraph_with_effects(
+        &mut self,
+        text: &Self::Paragraph,
+        position: Point,
+        color: Color,
+        effects: &core::text::Effects,
+        clip_bounds: Rectangle,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.fill_paragraph_with_effects(text, position, color, effects, clip_bounds)
+        );
+    }
+
     fn fill_editor(
         &mut self,
         editor: &Self::Editor,
@@ -340,6 +355,22 @@ where
         delegate!(self, compositor, compositor.list_fonts())
     }
 
+    fn set_font_fallback(&mut self, fallback: font::Fallback) {
+        delegate!(self, compositor, compositor.set_font_fallback(fallback));
+    }
+
+    fn find_font(&mut self, name: &str) -> Result<font::Family, font::Error> {
+        delegate!(self, compositor, compositor.find_font(name))
+    }
+
+    fn resolve_fonts(
+        &mut self,
+        text: &str,
+        font: font::Font,
+    ) -> Result<Vec<font::Run>, font::Error> {
+        delegate!(self, compositor, compositor.resolve_fonts(text, font))
+    }
+
     fn information(&self) -> compositor::Information {
         delegate!(self, compositor, compositor.information())
     }
@@ -1,6 +1,6 @@
 //! Load and use fonts.
 use crate::core::Pixels;
-use crate::core::font::{Error, Family, Font};
+use crate::core::font::{Error, Fallback, Family, Font, Run};
 use crate::futures::futures::channel::oneshot;
 use crate::task::{self, Task};
 
@@ -23,6 +23,30 @@ pub enum Action {
         channel: oneshot::Sender<Result<Vec<Family>, Error>>,
     },
 
+    /// Finds an available font family by name.
+    Find {
+        /// The name of the family to find.
+        name: String,
+        /// The channel to send back the found family.
+        channel: oneshot::Sender<Result<Family, Error>>,
+    },
+
+    /// Sets the fallback chains used for the characters missing in a font.
+    SetFallback {
+        /// The new [`Fallback`] chains.
+        fallback: Fallback,
+    },
+
+    /// Resolves the font faces that render some text.
+    Resolve {
+        /// The text to resolve.
+        text: String,
+        /// The [`Font`] of the text.
+        font: Font,
+        /// The channel to send back the runs of the text.
+        channel: oneshot::Sender<Result<Vec<Run>, Error>>,
+    },
+
     /// Sets the new font defaults for the running application.
     SetDefaults {
         /// The new default [`Font`].
@@ -37,6 +61,16 @@ impl fmt::Debug for Action {
         match self {
             Self::Load { .. } => f.write_str("Load"),
             Self::List { .. } => f.write_str("List"),
+            Self::Find { name, .. } => f.debug_struct("Find").field("name", name).finish(),
+            Self::SetFallback { fallback } => f
+                .debug_struct("SetFallback")
+                .field("fallback", fallback)
+                .finish(),
+            Self::Resolve { text, font, .. } => f
+                .debug_struct("Resolve")
+                .field("text", text)
+                .field("font", font)
+                .finish(),
             Self::SetDefaults { font, text_size } => f
                 .debug_struct("SetDefaults")
                 .field("font", font)
@@ -61,6 +95,40 @@ pub fn list() -> Task<Result<Vec<Family>, Error>> {
     task::oneshot(|channel| crate::Action::Font(Action::List { channel }))
 }
 
+/// Finds an available font family by name, ignoring case; including the
+/// system fonts.
+///
+/// The returned [`Font`] uses the exact name of the family.
+pub fn find(name: impl Into<String>) -> Task<Result<Font, Error>> {
+    task::oneshot(|channel| {
+        crate::Action::Font(Action::Find {
+            name: name.into(),
+            channel,
+        })
+    })
+    .map(|result| result.map(Font::with_family))
+}
+
+/// Sets the [`Fallback`] chains used for the characters missing in a
+/// [`Font`]; like CJK, emoji or Arabic.
+pub fn set_fallback<Message>(fallback: Fallback) -> Task<Message> {
+    task::effect(crate::Action::Font(Action::SetFallback { fallback }))
+}
+
+/// Resolves the font faces that render the given text with the given
+/// [`Font`].
+///
+/// This is useful to debug missing glyphs rendered as boxes.
+pub fn resolve(text: impl Into<String>, font: Font) -> Task<Result<Vec<Run>, Error>> {
+    task::oneshot(|channel| {
+        crate::Action::Font(Action::Resolve {
+            text: text.into(),
+            font,
+            channel,
+        })
+    })
+}
+
 /// Sets a new default [`Font`] and text size for the running application.
 pub fn set_defaults<Message>(font: Font, text_size: impl Into<Pixels>) -> Task<Message> {
     task::effect(crate::Action::Font(Action::SetDefaults {
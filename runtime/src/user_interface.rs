@@ -29,6 +29,7 @@ pub struct UserInterface<'a, Message, Theme, Renderer> {
     state: widget::Tree,
     overlay: Option<Overlay>,
     bounds: Size,
+    direction: layout::Direction,
 }
 
 struct Overlay {
@@ -118,9 +119,24 @@ where
             state,
             overlay: None,
             bounds,
+            direction: layout::Direction::LeftToRight,
         }
     }
 
+    /// Sets the layout [`Direction`](layout::Direction) of the [`UserInterface`].
+    ///
+    /// If the [`Direction`](layout::Direction) is
+    /// [`RightToLeft`](layout::Direction::RightToLeft), the whole layout
+    /// will be mirrored horizontally.
+    pub fn direction(mut self, direction: layout::Direction) -> Self {
+        if direction != self.direction {
+            self.base = std::mem::take(&mut self.base).directed(direction);
+            self.direction = direction;
+        }
+
+        self
+    }
+
     /// Updates the [`UserInterface`] by processing each provided [`Event`].
     ///
     /// It returns __messages__ that may have been produced as a result of user
@@ -204,6 +220,7 @@ where
         let mut clipboard = Clipboard::new();
         let mut has_layout_changed = false;
         let viewport = Rectangle::with_size(self.bounds);
+        let direction = self.direction;
 
         let mut maybe_overlay = self
             .root
@@ -215,7 +232,7 @@ where
                 &viewport,
                 Vector::ZERO,
             )
-            .map(overlay::Nested::new);
+            .map(|overlay| overlay::Nested::new(overlay).directed(direction));
 
         let (base_cursor, overlay_statuses, overlay_interaction) = if maybe_overlay.is_some() {
             let bounds = self.bounds;
@@ -244,11 +261,15 @@ where
                         shell::Diff::Skip => {}
                     }
 
-                    self.base = self.root.as_widget_mut().layout(
-                        &mut self.state,
-                        renderer,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
-                    );
+                    self.base = self
+                        .root
+                        .as_widget_mut()
+                        .layout(
+                            &mut self.state,
+                            renderer,
+                            &layout::Limits::new(Size::ZERO, self.bounds),
+                        )
+                        .directed(self.direction);
 
                     maybe_overlay = self
                         .root
@@ -260,7 +281,7 @@ where
                             &viewport,
                             Vector::ZERO,
                         )
-                        .map(overlay::Nested::new);
+                        .map(|overlay| overlay::Nested::new(overlay).directed(direction));
 
                     if maybe_overlay.is_none() {
                         break;
@@ -354,11 +375,15 @@ where
                         shell::Diff::Skip => {}
                     }
 
-                    self.base = self.root.as_widget_mut().layout(
-                        &mut self.state,
-                        renderer,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
-                    );
+                    self.base = self
+                        .root
+                        .as_widget_mut()
+                        .layout(
+                            &mut self.state,
+                            renderer,
+                            &layout::Limits::new(Size::ZERO, self.bounds),
+                        )
+                        .directed(self.direction);
 
                     if let Some(mut overlay) = self
                         .root
@@ -370,7 +395,7 @@ where
                             &viewport,
                             Vector::ZERO,
                         )
-                        .map(overlay::Nested::new)
+                        .map(|overlay| overlay::Nested::new(overlay).directed(direction))
                     {
                         let layout = overlay.layout(renderer, self.bounds);
                         let interaction =
@@ -503,6 +528,7 @@ where
         cursor: mouse::Cursor,
     ) {
         let viewport = Rectangle::with_size(self.bounds);
+        let direction = self.direction;
         renderer.reset(viewport);
 
         let base_cursor = match &self.overlay {
@@ -544,7 +570,7 @@ where
                 &viewport,
                 Vector::ZERO,
             )
-            .map(overlay::Nested::new);
+            .map(|overlay| overlay::Nested::new(overlay).directed(direction));
 
         if let Some(mut overlay) = overlay {
             overlay.draw(renderer, theme, style, Layout::new(layout), cursor);
@@ -554,6 +580,7 @@ where
     /// Applies a [`widget::Operation`] to the [`UserInterface`].
     pub fn operate(&mut self, renderer: &Renderer, operation: &mut dyn widget::Operation) {
         let viewport = Rectangle::with_size(self.bounds);
+        let direction = self.direction;
 
         self.root.as_widget_mut().operate(
             &mut self.state,
@@ -572,7 +599,7 @@ where
                 &viewport,
                 Vector::ZERO,
             )
-            .map(overlay::Nested::new)
+            .map(|overlay| overlay::Nested::new(overlay).directed(direction))
         {
             if self.overlay.is_none() {
                 self.overlay = Some(Overlay {
@@ -591,8 +618,23 @@ where
 
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
+    ///
+    /// Any widget that caches its layout—like a `memo`—may reuse it if its
+    /// limits have not changed. Use [`invalidate_layouts`](Self::invalidate_layouts)
+    /// beforehand to force a full relayout.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        let direction = self.direction;
+
+        Self::build(self.root, bounds, Cache { state: self.state }, renderer).direction(direction)
+    }
+
+    /// Invalidates the layout caches of the widgets of the [`UserInterface`].
+    ///
+    /// This should be called whenever the layout of widgets can change for
+    /// reasons outside of their control; like when fonts are loaded or the
+    /// default text settings of a renderer change.
+    pub fn invalidate_layouts(&mut self) {
+        layout::cache::invalidate(&mut self.state);
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
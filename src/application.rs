@@ -37,8 +37,8 @@ use crate::shell;
 use crate::theme;
 use crate::window;
 use crate::{
-    Backend, Element, Executor, Font, Never, Preset, Result, Settings, Size, Subscription, Task,
-    Theme,
+    Backend, Direction, Element, Executor, Font, Never, Preset, Result, Settings, Size,
+    Subscription, Task, Theme,
 };
 
 use iced_debug as debug;
@@ -238,6 +238,22 @@ impl<P: Program> Application<P> {
         }
     }
 
+    /// Sets the layout [`Direction`] of the [`Application`].
+    ///
+    /// Use [`Direction::RightToLeft`] to mirror the whole user interface for
+    /// right-to-left languages; like Arabic or Hebrew. The direction of
+    /// specific parts of the interface can be changed with the
+    /// [`directional`](crate::widget::directional) widget.
+    pub fn direction(self, direction: Direction) -> Self {
+        Self {
+            settings: Settings {
+                direction,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Adds a font to the list of fonts that will be loaded at the start of the [`Application`].
     pub fn font(mut self, font: impl Into<Cow<'static, [u8]>>) -> Self {
         self.settings.fonts.push(font.into());
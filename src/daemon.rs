@@ -5,7 +5,9 @@ use crate::program::{self, Program};
 use crate::shell;
 use crate::theme;
 use crate::window;
-use crate::{Element, Executor, Font, Preset, Result, Settings, Subscription, Task, Theme};
+use crate::{
+    Direction, Element, Executor, Font, Preset, Result, Settings, Subscription, Task, Theme,
+};
 
 use iced_debug as debug;
 
@@ -173,6 +175,22 @@ impl<P: Program> Daemon<P> {
         }
     }
 
+    /// Sets the layout [`Direction`] of the [`Daemon`].
+    ///
+    /// Use [`Direction::RightToLeft`] to mirror the whole user interface for
+    /// right-to-left languages; like Arabic or Hebrew. The direction of
+    /// specific parts of the interface can be changed with the
+    /// [`directional`](crate::widget::directional) widget.
+    pub fn direction(self, direction: Direction) -> Self {
+        Self {
+            settings: Settings {
+                direction,
+                ..self.settings
+            },
+            ..self
+        }
+    }
+
     /// Adds a font to the list of fonts that will be loaded at the start of the [`Daemon`].
     pub fn font(mut self, font: impl Into<Cow<'static, [u8]>>) -> Self {
         self.settings.fonts.push(font.into());
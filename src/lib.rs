@@ -508,6 +508,12 @@ compile_error!(
 #[cfg(feature = "highlighter")]
 pub use iced_highlighter as highlighter;
 
+#[cfg(feature = "i18n")]
+pub use iced_i18n as i18n;
+
+#[cfg(feature = "vector")]
+pub use iced_vector as vector;
+
 #[cfg(feature = "wgpu-bare")]
 pub use iced_renderer::wgpu::wgpu;
 
@@ -526,6 +532,7 @@ pub use crate::core::animation;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
+pub use crate::core::layout::Direction;
 pub use crate::core::padding;
 pub use crate::core::theme;
 pub use crate::core::{
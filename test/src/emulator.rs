@@ -186,7 +186,8 @@ impl<P: Program + 'static> Emulator<P> {
                         self.size,
                         self.cache.take().unwrap(),
                         &mut self.renderer,
-                    );
+                    )
+                    .direction(program.settings().direction);
 
                     let mut operation = Some(operation);
 
@@ -298,7 +299,8 @@ impl<P: Program + 'static> Emulator<P> {
             self.size,
             self.cache.take().unwrap(),
             &mut self.renderer,
-        );
+        )
+        .direction(program.settings().direction);
 
         let mut messages = Vec::new();
 
@@ -478,7 +480,8 @@ impl<P: Program + 'static> Emulator<P> {
             self.size,
             self.cache.take().unwrap(),
             &mut self.renderer,
-        );
+        )
+        .direction(program.settings().direction);
 
         // TODO: Nested redraws!
         let _ = user_interface.update(
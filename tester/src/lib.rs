@@ -717,6 +717,8 @@ impl<P: Program + 'static> Tester<P> {
                                             }
                                             _ => None,
                                         },
+                                        selection: None,
+                                        effects: text::Effects::NONE,
                                     })
                                     .into()
                             }),
@@ -882,6 +884,8 @@ where
             text(label).size(14).style(|theme: &core::Theme| {
                 text::Style {
                     color: Some(theme.palette().background.weak.text),
+                    selection: None,
+                    effects: text::Effects::NONE,
                 }
             }),
             space::horizontal(),
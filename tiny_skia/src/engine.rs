@@ -295,6 +295,7 @@ impl Engine {
                 paragraph,
                 position,
                 color,
+                effects,
                 clip_bounds: local_clip_bounds,
                 transformation: local_transformation,
             } => {
@@ -305,8 +306,9 @@ impl Engine {
                     return;
                 };
 
-                let physical_bounds =
-                    Rectangle::new(*position, paragraph.min_bounds) * transformation;
+                let physical_bounds = effects
+                    .bounds(Rectangle::new(*position, paragraph.min_bounds))
+                    * transformation;
 
                 if !clip_bounds.intersects(&physical_bounds) {
                     return;
@@ -324,9 +326,11 @@ impl Engine {
                     paragraph,
                     *position,
                     *color,
+                    effects,
                     pixels,
                     clip_mask,
                     transformation,
+                    clip_bounds,
                 );
             }
             Text::Editor {
@@ -574,6 +578,7 @@ impl Engine {
                 self.vector_pipeline.draw(
                     &svg.handle,
                     svg.color,
+                    &svg.stylesheet,
                     *bounds,
                     svg.opacity,
                     _pixels,
@@ -159,6 +159,11 @@ impl geometry::frame::Backend for Frame {
     fn fill_text(&mut self, text: impl Into<geometry::Text>) {
         let text = text.into();
 
+        if !text.effects.is_none() {
+            text.draw_effects(self);
+            return;
+        }
+
         let (scale_x, scale_y) = self.transform.get_scale();
 
         if !self.transform.has_skew() && scale_x == scale_y && scale_x > 0.0 && scale_y > 0.0 {
@@ -1,5 +1,6 @@
 use crate::Primitive;
 use crate::core::renderer::Quad;
+use crate::core::text::Effects;
 use crate::core::{self, Background, Color, Point, Rectangle, Svg, Transformation};
 use crate::graphics::damage;
 use crate::graphics::layer;
@@ -35,6 +36,7 @@ impl Layer {
         paragraph: &Paragraph,
         position: Point,
         color: Color,
+        effects: Effects,
         clip_bounds: Rectangle,
         transformation: Transformation,
     ) {
@@ -42,6 +44,7 @@ impl Layer {
             paragraph: paragraph.downgrade(),
             position,
             color,
+            effects,
             clip_bounds,
             transformation,
         };
@@ -1,8 +1,9 @@
 use crate::core::alignment;
-use crate::core::text::{Alignment, Ellipsis, Shaping, Wrapping};
+use crate::core::text::{Alignment, Effects, Ellipsis, Shaping, Wrapping};
 use crate::core::{Color, Font, Pixels, Point, Rectangle, Transformation};
 use crate::graphics::text::cache::{self, Cache};
 use crate::graphics::text::editor;
+use crate::graphics::text::effects;
 use crate::graphics::text::font_system;
 use crate::graphics::text::paragraph;
 
@@ -41,14 +42,30 @@ impl Pipeline {
         paragraph: &paragraph::Weak,
         position: Point,
         color: Color,
+        effects: &Effects,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
         transformation: Transformation,
+        clip_bounds: Rectangle,
     ) {
         let Some(paragraph) = paragraph.upgrade() else {
             return;
         };
 
+        if let Some(sprite) = effects::rasterize(
+            paragraph.buffer(),
+            position,
+            effects,
+            transformation,
+            clip_bounds,
+        ) {
+            draw_sprite(&sprite, pixels, clip_mask);
+        }
+
+        if effects::fills_glyphs(effects) {
+            return;
+        }
+
         let mut font_system = font_system().write().expect("Write font system");
 
         draw(
@@ -234,6 +251,40 @@ fn draw(
     }
 }
 
+fn draw_sprite(
+    sprite: &effects::Sprite,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    let Some(size) = tiny_skia::IntSize::from_wh(sprite.width, sprite.height) else {
+        return;
+    };
+
+    let data = sprite
+        .pixels
+        .chunks_exact(4)
+        .flat_map(|pixel| {
+            let color =
+                tiny_skia::ColorU8::from_rgba(pixel[2], pixel[1], pixel[0], pixel[3]).premultiply();
+
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+
+    let Some(pixmap) = tiny_skia::Pixmap::from_vec(data, size) else {
+        return;
+    };
+
+    pixels.draw_pixmap(
+        sprite.x,
+        sprite.y,
+        pixmap.as_ref(),
+        &tiny_skia::PixmapPaint::default(),
+        tiny_skia::Transform::identity(),
+        clip_mask,
+    );
+}
+
 fn from_color(color: cosmic_text::Color) -> Color {
     let [r, g, b, a] = color.as_rgba();
 
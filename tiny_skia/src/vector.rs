@@ -1,4 +1,4 @@
-use crate::core::svg::{Data, Handle};
+use crate::core::svg::{Data, Handle, Stylesheet};
 use crate::core::{Color, Rectangle, Size};
 
 use resvg::usvg;
@@ -35,6 +35,7 @@ impl Pipeline {
         &mut self,
         handle: &Handle,
         color: Option<Color>,
+        stylesheet: &Stylesheet,
         bounds: Rectangle,
         opacity: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
@@ -44,6 +45,7 @@ impl Pipeline {
         if let Some(image) = self.cache.borrow_mut().draw(
             handle,
             color,
+            stylesheet,
             Size::new(
                 (bounds.width * transform.sx) as u32,
                 (bounds.height * transform.sy) as u32,
@@ -70,8 +72,8 @@ impl Pipeline {
 
 #[derive(Default)]
 struct Cache {
-    trees: FxHashMap<u64, Option<resvg::usvg::Tree>>,
-    tree_hits: FxHashSet<u64>,
+    trees: FxHashMap<TreeKey, Option<resvg::usvg::Tree>>,
+    tree_hits: FxHashSet<TreeKey>,
     rasters: FxHashMap<RasterKey, tiny_skia::Pixmap>,
     raster_hits: FxHashSet<RasterKey>,
     #[cfg(feature = "svg-text")]
@@ -79,15 +81,24 @@ struct Cache {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct RasterKey {
+struct TreeKey {
     id: u64,
+    stylesheet: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct RasterKey {
+    tree: TreeKey,
     color: Option<[u8; 4]>,
     size: Size<u32>,
 }
 
 impl Cache {
-    fn load(&mut self, handle: &Handle) -> Option<&usvg::Tree> {
-        let id = handle.id();
+    fn load(&mut self, handle: &Handle, stylesheet: &Stylesheet) -> Option<&usvg::Tree> {
+        let key = TreeKey {
+            id: handle.id(),
+            stylesheet: stylesheet.id(),
+        };
 
         // TODO: Reuse `cosmic-text` font database
         #[cfg(feature = "svg-text")]
@@ -105,10 +116,11 @@ impl Cache {
                 .as_ref()
                 .expect("fontdb must be initialized")
                 .clone(),
+            style_sheet: (!stylesheet.is_empty()).then(|| stylesheet.as_str().to_owned()),
             ..usvg::Options::default()
         };
 
-        if let hash_map::Entry::Vacant(entry) = self.trees.entry(id) {
+        if let hash_map::Entry::Vacant(entry) = self.trees.entry(key) {
             let svg = match handle.data() {
                 Data::Path(path) => fs::read_to_string(path)
                     .ok()
@@ -119,12 +131,12 @@ impl Cache {
             let _ = entry.insert(svg);
         }
 
-        let _ = self.tree_hits.insert(id);
-        self.trees.get(&id).unwrap().as_ref()
+        let _ = self.tree_hits.insert(key);
+        self.trees.get(&key).unwrap().as_ref()
     }
 
     fn viewport_dimensions(&mut self, handle: &Handle) -> Option<Size<u32>> {
-        let tree = self.load(handle)?;
+        let tree = self.load(handle, &Stylesheet::default())?;
         let size = tree.size();
 
         Some(Size::new(size.width() as u32, size.height() as u32))
@@ -134,6 +146,7 @@ impl Cache {
         &mut self,
         handle: &Handle,
         color: Option<Color>,
+        stylesheet: &Stylesheet,
         size: Size<u32>,
     ) -> Option<tiny_skia::PixmapRef<'_>> {
         if size.width == 0 || size.height == 0 {
@@ -141,14 +154,17 @@ impl Cache {
         }
 
         let key = RasterKey {
-            id: handle.id(),
+            tree: TreeKey {
+                id: handle.id(),
+                stylesheet: stylesheet.id(),
+            },
             color: color.map(Color::into_rgba8),
             size,
         };
 
         #[allow(clippy::map_entry)]
         if !self.rasters.contains_key(&key) {
-            let tree = self.load(handle)?;
+            let tree = self.load(handle, stylesheet)?;
 
             let mut image = tiny_skia::Pixmap::new(size.width, size.height)?;
 
@@ -223,3 +239,40 @@ impl std::fmt::Debug for Cache {
             .finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::svg::Selector;
+
+    const SQUARE: &[u8] = br##"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="4">
+        <rect id="square" width="4" height="4" fill="#000000"/>
+    </svg>"##;
+
+    #[test]
+    fn stylesheet_changes_rasterize_again() {
+        let handle = Handle::from_memory(SQUARE);
+        let size = Size::new(4, 4);
+        let stylesheet = Stylesheet::new().fill(Selector::id("square"), Color::WHITE);
+
+        let mut cache = Cache::default();
+
+        let original = cache
+            .draw(&handle, None, &Stylesheet::default(), size)
+            .and_then(|pixmap| pixmap.pixel(1, 1))
+            .expect("Rasterize original SVG");
+
+        let styled = cache
+            .draw(&handle, None, &stylesheet, size)
+            .and_then(|pixmap| pixmap.pixel(1, 1))
+            .expect("Rasterize styled SVG");
+
+        assert_ne!(original, styled);
+        assert_eq!(cache.trees.len(), 2);
+        assert_eq!(cache.rasters.len(), 2);
+
+        let _ = cache.draw(&handle, None, &stylesheet.clone(), size);
+
+        assert_eq!(cache.rasters.len(), 2);
+    }
+}
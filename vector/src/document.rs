@@ -0,0 +1,304 @@
+//! Build and write vector documents.
+use crate::core::{Color, Rectangle, Size};
+use crate::graphics::geometry::{self, Path, Style, fill};
+use crate::{pdf, svg};
+
+/// A vector document made of [`Primitive`]s.
+///
+/// A [`Document`] can be written as an SVG or PDF file.
+#[derive(Debug, Clone)]
+pub struct Document {
+    size: Size,
+    background: Option<Color>,
+    primitives: Vec<Primitive>,
+}
+
+impl Document {
+    /// Creates a new empty [`Document`] with the given [`Size`].
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            background: None,
+            primitives: Vec::new(),
+        }
+    }
+
+    /// Sets the background [`Color`] of the [`Document`].
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Returns the [`Size`] of the [`Document`].
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the background [`Color`] of the [`Document`], if any.
+    pub fn background_color(&self) -> Option<Color> {
+        self.background
+    }
+
+    /// Returns the [`Primitive`]s of the [`Document`].
+    pub fn primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+
+    /// Adds a [`Primitive`] to the [`Document`].
+    pub fn push(&mut self, primitive: Primitive) {
+        self.primitives.push(primitive);
+    }
+
+    /// Adds all the given [`Primitive`]s to the [`Document`].
+    pub fn extend(&mut self, primitives: impl IntoIterator<Item = Primitive>) {
+        self.primitives.extend(primitives);
+    }
+
+    /// Writes the [`Document`] as an SVG file.
+    pub fn to_svg(&self) -> String {
+        svg::write(self)
+    }
+
+    /// Writes the [`Document`] as a single-page PDF file.
+    pub fn to_pdf(&self) -> Vec<u8> {
+        pdf::write(self)
+    }
+}
+
+/// A vector primitive of a [`Document`].
+///
+/// All coordinates are absolute; any transformations have already
+/// been applied.
+#[derive(Debug, Clone)]
+pub enum Primitive {
+    /// A filled [`Path`].
+    Fill {
+        /// The [`Path`] to fill.
+        path: Path,
+        /// The [`Style`] of the fill.
+        style: Style,
+        /// The fill [`Rule`](fill::Rule) of the [`Path`].
+        rule: fill::Rule,
+    },
+    /// A stroked [`Path`].
+    Stroke {
+        /// The [`Path`] to stroke.
+        path: Path,
+        /// The [`Style`] of the stroke.
+        style: Style,
+        /// The properties of the stroke.
+        stroke: Stroke,
+    },
+    /// A raster image.
+    #[cfg(feature = "image")]
+    Image {
+        /// The image to draw.
+        image: crate::core::Image,
+        /// The bounds of the image.
+        bounds: Rectangle,
+    },
+    /// A group of primitives clipped to some bounds.
+    Group {
+        /// The clip bounds of the group.
+        clip_bounds: Rectangle,
+        /// The primitives of the group.
+        primitives: Vec<Primitive>,
+    },
+}
+
+/// The properties of a stroke.
+#[derive(Debug, Clone)]
+pub struct Stroke {
+    /// The width of the stroke.
+    pub width: f32,
+    /// The shape of the endpoints of the stroke.
+    pub line_cap: geometry::LineCap,
+    /// The shape of the corners of the stroke.
+    pub line_join: geometry::LineJoin,
+    /// The alternating lengths of the dashes and gaps of the stroke.
+    ///
+    /// The stroke is solid if empty.
+    pub dash: Vec<f32>,
+    /// The offset of the dash pattern.
+    pub dash_offset: f32,
+}
+
+impl Stroke {
+    pub(crate) fn new(stroke: &geometry::Stroke<'_>, scale: f32) -> Self {
+        Self {
+            width: stroke.width * scale,
+            line_cap: stroke.line_cap,
+            line_join: stroke.line_join,
+            dash: stroke
+                .line_dash
+                .segments
+                .iter()
+                .map(|segment| segment * scale)
+                .collect(),
+            dash_offset: stroke.line_dash.offset as f32 * scale,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Point, Transformation};
+    use crate::graphics::gradient::{self, Gradient};
+    use crate::transform;
+
+    fn sample() -> Document {
+        let mut document = Document::new(Size::new(20.0, 10.0));
+
+        document.push(Primitive::Fill {
+            path: Path::rectangle(Point::new(1.0, 1.0), Size::new(4.0, 4.0)),
+            style: Style::Solid(Color::from_rgba(1.0, 0.0, 0.0, 0.5)),
+            rule: fill::Rule::EvenOdd,
+        });
+
+        document.push(transform::primitive(
+            Primitive::Stroke {
+                path: Path::line(Point::ORIGIN, Point::new(1.0, 1.0)),
+                style: Style::Solid(Color::BLACK),
+                stroke: Stroke {
+                    width: 1.0,
+                    line_cap: geometry::LineCap::Round,
+                    line_join: geometry::LineJoin::Miter,
+                    dash: vec![1.0, 0.5],
+                    dash_offset: 0.0,
+                },
+            },
+            &transform::from_transformation(
+                Transformation::translate(10.0, 2.0) * Transformation::scale(2.0),
+            ),
+        ));
+
+        document.push(Primitive::Group {
+            clip_bounds: Rectangle::new(Point::new(0.0, 5.0), Size::new(20.0, 5.0)),
+            primitives: vec![Primitive::Fill {
+                path: Path::rectangle(Point::new(0.0, 5.0), Size::new(20.0, 5.0)),
+                style: Style::Gradient(Gradient::Linear(
+                    gradient::Linear::new(Point::new(0.0, 5.0), Point::new(20.0, 5.0))
+                        .add_stop(0.0, Color::from_rgba(0.0, 0.0, 1.0, 0.0))
+                        .add_stop(1.0, Color::from_rgb(0.0, 0.0, 1.0)),
+                )),
+                rule: fill::Rule::NonZero,
+            }],
+        });
+
+        document
+    }
+
+    #[test]
+    fn svg() {
+        assert_eq!(
+            sample().to_svg(),
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10" viewBox="0 0 20 10">
+<path d="M 1 1 L 5 1 L 5 5 L 1 5 Z" fill="#ff0000" fill-opacity="0.5" fill-rule="evenodd"/>
+<path d="M 10 2 L 12 4" fill="none" stroke="#000000" stroke-width="2" stroke-linecap="round" stroke-dasharray="2 1" stroke-dashoffset="0"/>
+<clipPath id="clip1"><rect x="0" y="5" width="20" height="5"/></clipPath>
+<g clip-path="url(#clip1)">
+<linearGradient id="gradient2" gradientUnits="userSpaceOnUse" x1="0" y1="5" x2="20" y2="5">
+<stop offset="0" stop-color="#0000ff" stop-opacity="0"/>
+<stop offset="1" stop-color="#0000ff"/>
+</linearGradient>
+<path d="M 0 5 L 20 5 L 20 10 L 0 10 Z" fill="url(#gradient2)"/>
+</g>
+</svg>
+"##
+        );
+    }
+
+    #[test]
+    fn pdf() {
+        let pdf = sample().to_pdf();
+        let header = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n";
+
+        assert!(pdf.starts_with(header));
+
+        let pdf = std::str::from_utf8(&pdf[header.len()..]).expect("PDF must be ASCII");
+
+        assert_eq!(
+            pdf,
+            "1 0 obj\n\
+            << /Type /Catalog /Pages 2 0 R >>\n\
+            endobj\n\
+            2 0 obj\n\
+            << /Type /Pages /Kids [3 0 R] /Count 1 >>\n\
+            endobj\n\
+            3 0 obj\n\
+            << /Type /Page /Parent 2 0 R /MediaBox [0 0 20 10] /Resources << \
+            /ExtGState << /GS0 << /ca 0.5 /CA 0.5 >> \
+            /SM0 << /SMask << /Type /Mask /S /Luminosity /G 5 0 R >> >> >> \
+            /Pattern << /P0 << /PatternType 2 /Matrix [1 0 0 -1 0 10] \
+            /Shading << /ShadingType 2 /ColorSpace /DeviceRGB /Coords [0 5 20 5] \
+            /Extend [true true] /Function << /FunctionType 2 /Domain [0 1] \
+            /C0 [0 0 1] /C1 [0 0 1] /N 1 >> >> >> >> >> /Contents 4 0 R >>\n\
+            endobj\n\
+            4 0 obj\n\
+            << /Length 202 >>\n\
+            stream\n\
+            1 0 0 -1 0 10 cm\n\
+            q\n/GS0 gs\n1 0 0 rg\n1 1 m\n5 1 l\n5 5 l\n1 5 l\nh\nf*\nQ\n\
+            q\n0 0 0 RG\n2 w 1 J 0 j\n[2 1] 0 d\n10 2 m\n12 4 l\nS\nQ\n\
+            q\n0 5 20 5 re W n\n\
+            q\n/SM0 gs\n/Pattern cs /P0 scn\n0 5 m\n20 5 l\n20 10 l\n0 10 l\nh\nf\nQ\n\
+            Q\n\n\
+            endstream\n\
+            endobj\n\
+            5 0 obj\n\
+            << /Type /XObject /Subtype /Form /BBox [0 0 20 10] \
+            /Group << /S /Transparency /CS /DeviceGray >> \
+            /Resources << /Shading << /Sh0 << /ShadingType 2 /ColorSpace /DeviceGray \
+            /Coords [0 5 20 5] /Extend [true true] /Function << /FunctionType 2 \
+            /Domain [0 1] /C0 [0] /C1 [1] /N 1 >> >> >> >> /Length 7 >>\n\
+            stream\n\
+            /Sh0 sh\n\
+            endstream\n\
+            endobj\n\
+            xref\n\
+            0 6\n\
+            0000000000 65535 f \n\
+            0000000015 00000 n \n\
+            0000000064 00000 n \n\
+            0000000121 00000 n \n\
+            0000000558 00000 n \n\
+            0000000811 00000 n \n\
+            trailer\n\
+            << /Size 6 /Root 1 0 R >>\n\
+            startxref\n\
+            1149\n\
+            %%EOF\n"
+        );
+    }
+
+    #[test]
+    fn pdf_xref_offsets() {
+        let pdf = sample().to_pdf();
+        let text = String::from_utf8_lossy(&pdf);
+
+        let startxref: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|rest| rest.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .expect("PDF must have a startxref");
+
+        let xref = std::str::from_utf8(&pdf[startxref..]).expect("xref must be ASCII");
+
+        assert!(xref.starts_with("xref\n"));
+
+        let offsets: Vec<usize> = xref
+            .lines()
+            .skip(3)
+            .take_while(|line| line.ends_with(" n "))
+            .map(|line| line[..10].parse().expect("offset must be a number"))
+            .collect();
+
+        assert_eq!(offsets.len(), 5);
+
+        for (i, &offset) in offsets.iter().enumerate() {
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj\n", i + 1).as_bytes()));
+        }
+    }
+}
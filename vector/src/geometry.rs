@@ -0,0 +1,208 @@
+//! Draw 2D geometry as vector primitives.
+use crate::Primitive;
+use crate::core::{self, Point, Radians, Rectangle, Size, Svg, Vector};
+use crate::document::Stroke;
+use crate::graphics::cache::{self, Cached};
+use crate::graphics::geometry::fill::Fill;
+use crate::graphics::geometry::path::lyon_path::math;
+use crate::graphics::geometry::{self, Path};
+use crate::transform::{self, Transform};
+
+use std::sync::Arc;
+
+/// Some vector geometry recorded by a [`Frame`].
+#[derive(Debug, Clone)]
+pub struct Geometry {
+    pub(crate) primitives: Arc<[Primitive]>,
+    pub(crate) clip_bounds: Rectangle,
+}
+
+impl Cached for Geometry {
+    type Cache = Self;
+
+    fn load(cache: &Self) -> Self {
+        cache.clone()
+    }
+
+    fn cache(self, _group: cache::Group, _previous: Option<Self>) -> Self {
+        self
+    }
+}
+
+/// A frame that records vector [`Primitive`]s.
+#[derive(Debug)]
+pub struct Frame {
+    clip_bounds: Rectangle,
+    transform: Transform,
+    stack: Vec<Transform>,
+    primitives: Vec<Primitive>,
+}
+
+impl Frame {
+    /// Creates a new [`Frame`] with the given clip bounds.
+    pub fn new(bounds: Rectangle) -> Self {
+        Self {
+            clip_bounds: bounds,
+            transform: Transform::identity(),
+            stack: Vec::new(),
+            primitives: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, primitive: Primitive) {
+        self.primitives
+            .push(transform::primitive(primitive, &self.transform));
+    }
+}
+
+impl geometry::frame::Backend for Frame {
+    type Geometry = Geometry;
+
+    fn width(&self) -> f32 {
+        self.clip_bounds.width
+    }
+
+    fn height(&self) -> f32 {
+        self.clip_bounds.height
+    }
+
+    fn size(&self) -> Size {
+        self.clip_bounds.size()
+    }
+
+    fn center(&self) -> Point {
+        Point::new(self.clip_bounds.width / 2.0, self.clip_bounds.height / 2.0)
+    }
+
+    fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        let fill = fill.into();
+
+        self.push(Primitive::Fill {
+            path: path.clone(),
+            style: fill.style,
+            rule: fill.rule,
+        });
+    }
+
+    fn fill_rectangle(&mut self, top_left: Point, size: Size, fill: impl Into<Fill>) {
+        self.fill(&Path::rectangle(top_left, size), fill);
+    }
+
+    fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<geometry::Stroke<'a>>) {
+        let stroke = stroke.into();
+
+        self.push(Primitive::Stroke {
+            path: path.clone(),
+            style: stroke.style,
+            stroke: Stroke::new(&stroke, 1.0),
+        });
+    }
+
+    fn stroke_rectangle<'a>(
+        &mut self,
+        top_left: Point,
+        size: Size,
+        stroke: impl Into<geometry::Stroke<'a>>,
+    ) {
+        self.stroke(&Path::rectangle(top_left, size), stroke);
+    }
+
+    fn fill_text(&mut self, text: impl Into<geometry::Text>) {
+        let text = text.into();
+
+        if !text.effects.is_none() {
+            text.draw_effects(self);
+            return;
+        }
+
+        text.draw_with(|path, color| self.fill(&path, color));
+    }
+
+    fn stroke_text<'a>(
+        &mut self,
+        text: impl Into<geometry::Text>,
+        stroke: impl Into<geometry::Stroke<'a>>,
+    ) {
+        let text = text.into();
+        let stroke = stroke.into();
+
+        text.draw_with(|path, _color| self.stroke(&path, stroke));
+    }
+
+    fn push_transform(&mut self) {
+        self.stack.push(self.transform);
+    }
+
+    fn pop_transform(&mut self) {
+        self.transform = self.stack.pop().expect("Pop transform");
+    }
+
+    fn draft(&mut self, clip_bounds: Rectangle) -> Self {
+        Self::new(clip_bounds)
+    }
+
+    fn paste(&mut self, frame: Self) {
+        self.primitives.push(Primitive::Group {
+            clip_bounds: frame.clip_bounds,
+            primitives: frame.primitives,
+        });
+    }
+
+    fn translate(&mut self, translation: Vector) {
+        self.transform = self
+            .transform
+            .pre_translate(math::Vector::new(translation.x, translation.y));
+    }
+
+    fn rotate(&mut self, angle: impl Into<Radians>) {
+        self.transform = self
+            .transform
+            .pre_rotate(math::Angle::radians(angle.into().0));
+    }
+
+    fn scale(&mut self, scale: f32) {
+        self.scale_nonuniform(Vector { x: scale, y: scale });
+    }
+
+    fn scale_nonuniform(&mut self, scale: impl Into<Vector>) {
+        let scale = scale.into();
+
+        self.transform = self.transform.pre_scale(scale.x, scale.y);
+    }
+
+    fn into_geometry(self) -> Geometry {
+        Geometry {
+            primitives: Arc::from(self.primitives),
+            clip_bounds: self.clip_bounds,
+        }
+    }
+
+    fn draw_image(&mut self, bounds: Rectangle, image: impl Into<core::Image>) {
+        #[cfg(feature = "image")]
+        self.push(Primitive::Image {
+            image: image.into(),
+            bounds,
+        });
+
+        #[cfg(not(feature = "image"))]
+        {
+            let _ = (bounds, image);
+
+            log::warn!("Images are not supported without the `image` feature");
+        }
+    }
+
+    fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
+        #[cfg(feature = "svg")]
+        for primitive in crate::vector::primitives(&svg.into(), bounds) {
+            self.push(primitive);
+        }
+
+        #[cfg(not(feature = "svg"))]
+        {
+            let _ = (bounds, svg);
+
+            log::warn!("SVGs are not supported without the `svg` feature");
+        }
+    }
+}
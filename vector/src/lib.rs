@@ -0,0 +1,506 @@
+//! A vector renderer for [`iced`] that exports drawings to SVG and PDF.
+//!
+//! Instead of rasterizing, the [`Renderer`] records everything it is asked to
+//! draw as vector [`Primitive`]s. Then, the recorded primitives can be turned
+//! into a [`Document`] and written as an SVG or PDF file.
+//!
+//! The [`Renderer`] supports quads, text, and geometry; so it can be used to
+//! export both the [`Frame`] of a `Canvas` and entire widget trees—as long as
+//! they are generic over their `Renderer`. Text is exported as glyph outlines,
+//! so the resulting files do not depend on any fonts.
+//!
+//! # Example
+//! ```no_run
+//! use iced_vector::Renderer;
+//! use iced_vector::core::renderer::Settings;
+//! use iced_vector::core::{Color, Point, Size};
+//! use iced_vector::graphics::geometry::{Frame, Path};
+//!
+//! let mut renderer = Renderer::new(Settings::default());
+//! let size = Size::new(200.0, 200.0);
+//!
+//! let mut frame = Frame::new(&renderer, size);
+//! frame.fill(&Path::circle(Point::new(100.0, 100.0), 50.0), Color::BLACK);
+//!
+//! let document = renderer.export(frame.into_geometry(), size);
+//!
+//! std::fs::write("circle.svg", document.to_svg()).unwrap();
+//! std::fs::write("circle.pdf", document.to_pdf()).unwrap();
+//! ```
+//!
+//! [`iced`]: https://github.com/iced-rs/iced
+//! [`Frame`]: graphics::geometry::Frame
+#![cfg_attr(docsrs, feature(doc_cfg))]
+pub mod document;
+pub mod geometry;
+
+mod number;
+mod pdf;
+mod svg;
+mod transform;
+
+#[cfg(feature = "image")]
+mod raster;
+
+#[cfg(feature = "svg")]
+mod vector;
+
+pub use iced_graphics as graphics;
+pub use iced_graphics::core;
+
+pub use document::{Document, Primitive};
+pub use geometry::Geometry;
+
+use crate::core::Renderer as _;
+use crate::core::layout::{self, Layout};
+use crate::core::renderer;
+use crate::core::text::Paragraph as _;
+use crate::core::theme;
+use crate::core::widget::Tree;
+use crate::core::{
+    Background, Color, Element, Font, Pixels, Point, Rectangle, Size, Transformation, mouse,
+};
+use crate::graphics::geometry::{Path, Style, fill};
+use crate::graphics::gradient::{self, Gradient};
+use crate::graphics::text::{Editor, Paragraph};
+
+/// A renderer that records vector [`Primitive`]s.
+#[derive(Debug)]
+pub struct Renderer {
+    settings: renderer::Settings,
+    scale: Option<renderer::Scale>,
+    layers: Vec<Layer>,
+    transformations: Vec<Transformation>,
+}
+
+#[derive(Debug)]
+struct Layer {
+    bounds: Rectangle,
+    primitives: Vec<Primitive>,
+}
+
+impl Renderer {
+    /// Creates a new [`Renderer`] with the given [`renderer::Settings`].
+    pub fn new(settings: renderer::Settings) -> Self {
+        Self {
+            settings,
+            scale: None,
+            layers: vec![Layer {
+                bounds: Rectangle::INFINITE,
+                primitives: Vec::new(),
+            }],
+            transformations: vec![Transformation::IDENTITY],
+        }
+    }
+
+    /// Returns a [`Document`] of the given [`Size`] containing all the
+    /// primitives recorded so far; resetting the [`Renderer`].
+    pub fn document(&mut self, size: Size) -> Document {
+        while self.layers.len() > 1 {
+            self.end_layer();
+        }
+
+        let mut document = Document::new(size);
+        document.extend(self.layers[0].primitives.drain(..));
+
+        self.transformations.truncate(1);
+
+        document
+    }
+
+    /// Draws the given [`Geometry`] into a new [`Document`] of the given [`Size`].
+    pub fn export(&mut self, geometry: Geometry, size: Size) -> Document {
+        use crate::graphics::geometry::Renderer as _;
+
+        self.reset(Rectangle::with_size(size));
+        self.draw_geometry(geometry);
+
+        self.document(size)
+    }
+
+    /// Lays out and draws the given [`Element`] into a new [`Document`] of the given [`Size`].
+    ///
+    /// The background of the [`Document`] will be filled with the base style of the
+    /// given `Theme`.
+    pub fn render<'a, Message, Theme>(
+        &mut self,
+        element: impl Into<Element<'a, Message, Theme, Self>>,
+        size: Size,
+        theme: &Theme,
+    ) -> Document
+    where
+        Theme: theme::Base,
+    {
+        let mut element = element.into();
+        let mut tree = Tree::empty();
+        tree.diff(element.as_widget_mut());
+
+        let node =
+            element
+                .as_widget_mut()
+                .layout(&mut tree, self, &layout::Limits::new(Size::ZERO, size));
+
+        let style = theme.base();
+        let viewport = Rectangle::with_size(size);
+
+        self.reset(viewport);
+
+        element.as_widget().draw(
+            &tree,
+            self,
+            theme,
+            &renderer::Style {
+                text_color: style.text_color,
+            },
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &viewport,
+        );
+
+        self.document(size).background(style.background_color)
+    }
+
+    fn transformation(&self) -> Transformation {
+        self.transformations
+            .last()
+            .copied()
+            .unwrap_or(Transformation::IDENTITY)
+    }
+
+    fn push(&mut self, primitive: Primitive) {
+        let transform = transform::from_transformation(self.transformation());
+
+        self.layers
+            .last_mut()
+            .expect("Renderer must have a layer")
+            .primitives
+            .push(transform::primitive(primitive, &transform));
+    }
+
+    fn push_clipped(
+        &mut self,
+        primitives: Vec<Primitive>,
+        bounds: Rectangle,
+        clip_bounds: Rectangle,
+    ) {
+        if bounds.intersection(&clip_bounds) == Some(bounds) {
+            for primitive in primitives {
+                self.push(primitive);
+            }
+        } else {
+            self.push(Primitive::Group {
+                clip_bounds,
+                primitives,
+            });
+        }
+    }
+
+    fn fill_buffer(
+        &mut self,
+        buffer: &graphics::text::cosmic_text::Buffer,
+        position: Point,
+        color: Color,
+        bounds: Rectangle,
+        clip_bounds: Rectangle,
+    ) {
+        let mut glyphs = Vec::new();
+
+        graphics::geometry::Text::draw_buffer(buffer, position, color, |path, color| {
+            glyphs.push(Primitive::Fill {
+                path,
+                style: Style::Solid(color),
+                rule: fill::Rule::NonZero,
+            });
+        });
+
+        self.push_clipped(glyphs, bounds, clip_bounds);
+    }
+}
+
+impl core::Renderer for Renderer {
+    fn start_layer(&mut self, bounds: Rectangle) {
+        self.layers.push(Layer {
+            bounds: transform::bounds(
+                bounds,
+                &transform::from_transformation(self.transformation()),
+            ),
+            primitives: Vec::new(),
+        });
+    }
+
+    fn end_layer(&mut self) {
+        if self.layers.len() < 2 {
+            return;
+        }
+
+        let layer = self.layers.pop().expect("Pop layer");
+
+        self.layers
+            .last_mut()
+            .expect("Renderer must have a layer")
+            .primitives
+            .push(Primitive::Group {
+                clip_bounds: layer.bounds,
+                primitives: layer.primitives,
+            });
+    }
+
+    fn start_transformation(&mut self, transformation: Transformation) {
+        self.transformations
+            .push(self.transformation() * transformation);
+    }
+
+    fn end_transformation(&mut self) {
+        if self.transformations.len() > 1 {
+            let _ = self.transformations.pop();
+        }
+    }
+
+    fn fill_quad(&mut self, quad: renderer::Quad, background: impl Into<Background>) {
+        // TODO: Shadows
+        let bounds = quad.bounds;
+        let border = quad.border;
+
+        let style = match background.into() {
+            Background::Color(color) => Style::Solid(color),
+            Background::Gradient(core::Gradient::Linear(linear)) => {
+                let (start, end) = bounds.chord(linear.angle);
+
+                Style::Gradient(Gradient::Linear(
+                    gradient::Linear::new(start, end).add_stops(linear.stops.into_iter().flatten()),
+                ))
+            }
+        };
+
+        if !matches!(style, Style::Solid(color) if color.a == 0.0) {
+            self.push(Primitive::Fill {
+                path: Path::rounded_rectangle(bounds.position(), bounds.size(), border.radius),
+                style,
+                rule: fill::Rule::NonZero,
+            });
+        }
+
+        if border.width > 0.0 && border.color.a > 0.0 {
+            let width = border
+                .width
+                .min(bounds.width / 2.0)
+                .min(bounds.height / 2.0);
+            let inner = bounds.shrink(width);
+
+            let inner_radius = core::border::Radius {
+                top_left: (border.radius.top_left - width).max(0.0),
+                top_right: (border.radius.top_right - width).max(0.0),
+                bottom_right: (border.radius.bottom_right - width).max(0.0),
+                bottom_left: (border.radius.bottom_left - width).max(0.0),
+            };
+
+            let path = Path::new(|builder| {
+                builder.rounded_rectangle(bounds.position(), bounds.size(), border.radius);
+                builder.rounded_rectangle(inner.position(), inner.size(), inner_radius);
+            });
+
+            self.push(Primitive::Fill {
+                path,
+                style: Style::Solid(border.color),
+                rule: fill::Rule::EvenOdd,
+            });
+        }
+    }
+
+    fn allocate_image(
+        &mut self,
+        _handle: &core::image::Handle,
+        callback: impl FnOnce(Result<core::image::Allocation, core::image::Error>) + Send + 'static,
+    ) {
+        #[cfg(feature = "image")]
+        callback(core::image::Renderer::load_image(self, _handle));
+
+        #[cfg(not(feature = "image"))]
+        callback(Err(core::image::Error::Unsupported));
+    }
+
+    fn hint(&mut self, scale: renderer::Scale) {
+        self.scale = Some(scale);
+    }
+
+    fn scale(&self) -> Option<renderer::Scale> {
+        self.scale
+    }
+
+    fn reset(&mut self, _new_bounds: Rectangle) {
+        self.layers.truncate(1);
+        self.layers[0].primitives.clear();
+        self.transformations.truncate(1);
+    }
+
+    fn settings(&self) -> renderer::Settings {
+        self.settings
+    }
+}
+
+impl core::text::Renderer for Renderer {
+    type Font = Font;
+    type Paragraph = Paragraph;
+    type Editor = Editor;
+
+    const ICON_FONT: Font = Font::new("Iced-Icons");
+    const CHECKMARK_ICON: char = '\u{f00c}';
+    const ARROW_DOWN_ICON: char = '\u{e800}';
+    const ICED_LOGO: char = '\u{e801}';
+    const SCROLL_UP_ICON: char = '\u{e802}';
+    const SCROLL_DOWN_ICON: char = '\u{e803}';
+    const SCROLL_LEFT_ICON: char = '\u{e804}';
+    const SCROLL_RIGHT_ICON: char = '\u{e805}';
+
+    fn default_font(&self) -> Self::Font {
+        self.settings.default_font
+    }
+
+    fn default_size(&self) -> Pixels {
+        self.settings.default_text_size
+    }
+
+    fn fill_paragraph(
+        &mut self,
+        paragraph: &Self::Paragraph,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        self.fill_buffer(
+            paragraph.buffer(),
+            position,
+            color,
+            Rectangle::new(position, paragraph.min_bounds()),
+            clip_bounds,
+        );
+    }
+
+    fn fill_editor(
+        &mut self,
+        editor: &Self::Editor,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        use crate::core::text::Editor as _;
+
+        self.fill_buffer(
+            editor.buffer(),
+            position,
+            color,
+            Rectangle::new(position, editor.bounds()),
+            clip_bounds,
+        );
+    }
+
+    fn fill_text(
+        &mut self,
+        text: core::Text,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        use crate::core::alignment;
+        use crate::core::text::Alignment;
+
+        let paragraph = Paragraph::with_text(text.as_ref());
+        let size = paragraph.min_bounds();
+
+        let x = match text.align_x {
+            Alignment::Default | Alignment::Left | Alignment::Justified => position.x,
+            Alignment::Center => position.x - size.width / 2.0,
+            Alignment::Right => position.x - size.width,
+        };
+
+        let y = match text.align_y {
+            alignment::Vertical::Top => position.y,
+            alignment::Vertical::Center => position.y - size.height / 2.0,
+            alignment::Vertical::Bottom => position.y - size.height,
+        };
+
+        let position = Point::new(x, y);
+
+        self.fill_buffer(
+            paragraph.buffer(),
+            position,
+            color,
+            Rectangle::new(position, size),
+            clip_bounds,
+        );
+    }
+}
+
+impl graphics::text::Renderer for Renderer {
+    fn fill_raw(&mut self, raw: graphics::text::Raw) {
+        let Some(buffer) = raw.buffer.upgrade() else {
+            return;
+        };
+
+        let (size, _) = graphics::text::measure(&buffer);
+
+        self.fill_buffer(
+            &buffer,
+            raw.position,
+            raw.color,
+            Rectangle::new(raw.position, size),
+            raw.clip_bounds,
+        );
+    }
+}
+
+impl graphics::geometry::Renderer for Renderer {
+    type Geometry = Geometry;
+    type Frame = geometry::Frame;
+
+    fn new_frame(&self, bounds: Rectangle) -> Self::Frame {
+        geometry::Frame::new(bounds)
+    }
+
+    fn draw_geometry(&mut self, geometry: Self::Geometry) {
+        self.push(Primitive::Group {
+            clip_bounds: geometry.clip_bounds,
+            primitives: geometry.primitives.to_vec(),
+        });
+    }
+}
+
+#[cfg(feature = "image")]
+impl core::image::Renderer for Renderer {
+    type Handle = core::image::Handle;
+
+    fn load_image(
+        &self,
+        handle: &Self::Handle,
+    ) -> Result<core::image::Allocation, core::image::Error> {
+        let image = graphics::image::load(handle)?;
+
+        #[allow(unsafe_code)]
+        Ok(unsafe { core::image::allocate(handle, Size::new(image.width(), image.height())) })
+    }
+
+    fn measure_image(&self, handle: &Self::Handle) -> Option<Size<u32>> {
+        let image = raster::load(handle)?;
+
+        Some(Size::new(image.width(), image.height()))
+    }
+
+    fn draw_image(&mut self, image: core::Image, bounds: Rectangle, clip_bounds: Rectangle) {
+        self.push_clipped(
+            vec![Primitive::Image { image, bounds }],
+            bounds,
+            clip_bounds,
+        );
+    }
+}
+
+#[cfg(feature = "svg")]
+impl core::svg::Renderer for Renderer {
+    fn measure_svg(&self, handle: &core::svg::Handle) -> Size<u32> {
+        vector::measure(handle)
+    }
+
+    fn draw_svg(&mut self, svg: core::Svg, bounds: Rectangle, clip_bounds: Rectangle) {
+        self.push_clipped(vector::primitives(&svg, bounds), bounds, clip_bounds);
+    }
+}
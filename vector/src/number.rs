@@ -0,0 +1,40 @@
+use std::fmt;
+
+/// A number formatted compactly with, at most, three decimals.
+#[derive(Debug, Clone, Copy)]
+pub struct Number(pub f32);
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.0.is_finite() {
+            return f.write_str("0");
+        }
+
+        let number = format!("{:.3}", self.0);
+        let number = number.trim_end_matches('0').trim_end_matches('.');
+
+        f.write_str(if number == "-0" { "0" } else { number })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format() {
+        let tests = [
+            (0.0, "0"),
+            (10.0, "10"),
+            (0.5, "0.5"),
+            (-1.25, "-1.25"),
+            (1.0 / 3.0, "0.333"),
+            (-0.0001, "0"),
+            (f32::NAN, "0"),
+        ];
+
+        for (number, expected) in tests {
+            assert_eq!(Number(number).to_string(), expected);
+        }
+    }
+}
@@ -0,0 +1,523 @@
+use crate::core::Color;
+use crate::core::gradient::ColorStop;
+use crate::document::{Document, Primitive, Stroke};
+use crate::graphics::Gradient;
+use crate::graphics::geometry::path::lyon_path;
+use crate::graphics::geometry::{LineCap, LineJoin, Path, Style, fill};
+use crate::graphics::gradient::Linear;
+use crate::number::Number;
+
+use std::fmt::Write;
+
+pub fn write(document: &Document) -> Vec<u8> {
+    let size = document.size();
+
+    let mut writer = Writer {
+        width: size.width,
+        height: size.height,
+        content: String::new(),
+        states: Vec::new(),
+        masks: Vec::new(),
+        patterns: Vec::new(),
+        images: Vec::new(),
+        objects: Vec::new(),
+    };
+
+    // PDF coordinates start at the bottom-left corner of the page
+    let _ = writeln!(writer.content, "1 0 0 -1 0 {} cm", Number(size.height));
+
+    if let Some(background) = document.background_color() {
+        writer.content.push_str("q\n");
+        writer.alpha(background.a);
+        writer.color(background, "rg");
+
+        let _ = writeln!(
+            writer.content,
+            "0 0 {} {} re f",
+            Number(size.width),
+            Number(size.height)
+        );
+
+        writer.content.push_str("Q\n");
+    }
+
+    for primitive in document.primitives() {
+        writer.primitive(primitive);
+    }
+
+    writer.finish()
+}
+
+struct Writer {
+    width: f32,
+    height: f32,
+    content: String,
+    states: Vec<(String, f32)>,
+    masks: Vec<(String, usize)>,
+    patterns: Vec<String>,
+    images: Vec<String>,
+    objects: Vec<Vec<u8>>,
+}
+
+impl Writer {
+    fn primitive(&mut self, primitive: &Primitive) {
+        match primitive {
+            Primitive::Fill { path, style, rule } => {
+                self.content.push_str("q\n");
+                self.paint(style, "rg", "cs", "scn");
+
+                if self.path(path) {
+                    self.content.push_str(match rule {
+                        fill::Rule::NonZero => "f\n",
+                        fill::Rule::EvenOdd => "f*\n",
+                    });
+                }
+
+                self.content.push_str("Q\n");
+            }
+            Primitive::Stroke {
+                path,
+                style,
+                stroke,
+            } => {
+                self.content.push_str("q\n");
+                self.paint(style, "RG", "CS", "SCN");
+                self.stroke(stroke);
+
+                if self.path(path) {
+                    self.content.push_str("S\n");
+                }
+
+                self.content.push_str("Q\n");
+            }
+            #[cfg(feature = "image")]
+            Primitive::Image { image, bounds } => {
+                self.image(image, *bounds);
+            }
+            Primitive::Group {
+                clip_bounds,
+                primitives,
+            } => {
+                self.content.push_str("q\n");
+
+                if clip_bounds.width.is_finite() && clip_bounds.height.is_finite() {
+                    let _ = writeln!(
+                        self.content,
+                        "{} {} {} {} re W n",
+                        Number(clip_bounds.x),
+                        Number(clip_bounds.y),
+                        Number(clip_bounds.width.max(0.0)),
+                        Number(clip_bounds.height.max(0.0)),
+                    );
+                }
+
+                for primitive in primitives {
+                    self.primitive(primitive);
+                }
+
+                self.content.push_str("Q\n");
+            }
+        }
+    }
+
+    fn paint(&mut self, style: &Style, color: &str, space: &str, pattern: &str) {
+        match style {
+            Style::Solid(solid) => {
+                self.alpha(solid.a);
+                self.color(*solid, color);
+            }
+            Style::Gradient(Gradient::Linear(linear)) => {
+                if linear.stops.iter().flatten().any(|stop| stop.color.a < 1.0) {
+                    self.mask(linear);
+                }
+
+                let name = format!("P{}", self.patterns.len());
+
+                self.patterns.push(format!(
+                    "/{name} << /PatternType 2 /Matrix [1 0 0 -1 0 {}] /Shading {} >>",
+                    Number(self.height),
+                    shading(linear, "DeviceRGB", |color| vec![color.r, color.g, color.b]),
+                ));
+
+                let _ = writeln!(self.content, "/Pattern {space} /{name} {pattern}");
+            }
+        }
+    }
+
+    /// Sets a soft mask with the opacity of the color stops of the
+    /// given [`Linear`] gradient.
+    fn mask(&mut self, linear: &Linear) {
+        // The mask is painted in the coordinate system in effect when
+        // it is set; so, unlike patterns, it needs no flipping
+        let group = self.object(stream(
+            &format!(
+                "/Type /XObject /Subtype /Form /BBox [0 0 {} {}] \
+                /Group << /S /Transparency /CS /DeviceGray >> \
+                /Resources << /Shading << /Sh0 {} >> >>",
+                Number(self.width),
+                Number(self.height),
+                shading(linear, "DeviceGray", |color| vec![color.a]),
+            ),
+            b"/Sh0 sh",
+        ));
+
+        let name = format!("SM{}", self.masks.len());
+        let _ = writeln!(self.content, "/{name} gs");
+
+        self.masks.push((name, group));
+    }
+
+    fn color(&mut self, color: Color, operator: &str) {
+        let _ = writeln!(
+            self.content,
+            "{} {} {} {operator}",
+            Number(color.r),
+            Number(color.g),
+            Number(color.b)
+        );
+    }
+
+    fn alpha(&mut self, alpha: f32) {
+        if alpha >= 1.0 {
+            return;
+        }
+
+        let index = if let Some(index) = self
+            .states
+            .iter()
+            .position(|(_, existing)| *existing == alpha)
+        {
+            index
+        } else {
+            let name = format!("GS{}", self.states.len());
+            self.states.push((name, alpha));
+
+            self.states.len() - 1
+        };
+
+        let _ = writeln!(self.content, "/{} gs", self.states[index].0);
+    }
+
+    fn stroke(&mut self, stroke: &Stroke) {
+        let _ = writeln!(
+            self.content,
+            "{} w {} J {} j",
+            Number(stroke.width),
+            match stroke.line_cap {
+                LineCap::Butt => 0,
+                LineCap::Round => 1,
+                LineCap::Square => 2,
+            },
+            match stroke.line_join {
+                LineJoin::Miter => 0,
+                LineJoin::Round => 1,
+                LineJoin::Bevel => 2,
+            },
+        );
+
+        if !stroke.dash.is_empty() {
+            self.content.push('[');
+
+            for (i, length) in stroke.dash.iter().enumerate() {
+                let separator = if i == 0 { "" } else { " " };
+                let _ = write!(self.content, "{separator}{}", Number(*length));
+            }
+
+            let _ = writeln!(self.content, "] {} d", Number(stroke.dash_offset));
+        }
+    }
+
+    /// Writes the construction operators of the given [`Path`].
+    ///
+    /// Returns `false` if the [`Path`] is empty.
+    fn path(&mut self, path: &Path) -> bool {
+        let mut is_empty = true;
+
+        for event in path.raw() {
+            is_empty = false;
+
+            let _ = match event {
+                lyon_path::Event::Begin { at } => {
+                    writeln!(self.content, "{} {} m", Number(at.x), Number(at.y))
+                }
+                lyon_path::Event::Line { to, .. } => {
+                    writeln!(self.content, "{} {} l", Number(to.x), Number(to.y))
+                }
+                lyon_path::Event::Quadratic { from, ctrl, to } => {
+                    // PDF has no quadratic curves, so we elevate them
+                    let ctrl1 = from + (ctrl - from) * (2.0 / 3.0);
+                    let ctrl2 = to + (ctrl - to) * (2.0 / 3.0);
+
+                    writeln!(
+                        self.content,
+                        "{} {} {} {} {} {} c",
+                        Number(ctrl1.x),
+                        Number(ctrl1.y),
+                        Number(ctrl2.x),
+                        Number(ctrl2.y),
+                        Number(to.x),
+                        Number(to.y),
+                    )
+                }
+                lyon_path::Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => writeln!(
+                    self.content,
+                    "{} {} {} {} {} {} c",
+                    Number(ctrl1.x),
+                    Number(ctrl1.y),
+                    Number(ctrl2.x),
+                    Number(ctrl2.y),
+                    Number(to.x),
+                    Number(to.y),
+                ),
+                lyon_path::Event::End { close: true, .. } => writeln!(self.content, "h"),
+                lyon_path::Event::End { close: false, .. } => Ok(()),
+            };
+        }
+
+        !is_empty
+    }
+
+    #[cfg(feature = "image")]
+    fn image(&mut self, image: &crate::core::Image, bounds: crate::core::Rectangle) {
+        use crate::core::image::FilterMethod;
+
+        let Some(buffer) = crate::raster::load(&image.handle) else {
+            return;
+        };
+
+        let (width, height) = buffer.dimensions();
+        let pixels = buffer.as_raw();
+
+        let mut color = Vec::with_capacity(pixels.len() / 4 * 3);
+        let mut alpha = Vec::with_capacity(pixels.len() / 4);
+
+        for pixel in pixels.chunks_exact(4) {
+            color.extend_from_slice(&pixel[..3]);
+            alpha.push(pixel[3]);
+        }
+
+        let interpolate = image.filter_method == FilterMethod::Linear;
+
+        let mask = self.object(stream(
+            &format!(
+                "/Type /XObject /Subtype /Image /Width {width} /Height {height} \
+                /ColorSpace /DeviceGray /BitsPerComponent 8 /Interpolate {interpolate}"
+            ),
+            &alpha,
+        ));
+
+        let object = self.object(stream(
+            &format!(
+                "/Type /XObject /Subtype /Image /Width {width} /Height {height} \
+                /ColorSpace /DeviceRGB /BitsPerComponent 8 /Interpolate {interpolate} \
+                /SMask {mask} 0 R"
+            ),
+            &color,
+        ));
+
+        let name = format!("Im{}", self.images.len());
+        self.images.push(format!("/{name} {object} 0 R"));
+
+        // Map the unit square of the image to its (rotated) bounds,
+        // flipping it vertically since our y axis points down
+        let (sin, cos) = image.rotation.0.sin_cos();
+        let center = bounds.center();
+        let (w, h) = (bounds.width, bounds.height);
+
+        self.content.push_str("q\n");
+        self.alpha(image.opacity);
+
+        let _ = writeln!(
+            self.content,
+            "{} {} {} {} {} {} cm /{name} Do",
+            Number(w * cos),
+            Number(w * sin),
+            Number(h * sin),
+            Number(-h * cos),
+            Number(center.x - cos * w / 2.0 - sin * h / 2.0),
+            Number(center.y - sin * w / 2.0 + cos * h / 2.0),
+        );
+
+        self.content.push_str("Q\n");
+    }
+
+    /// Adds a new object to the file and returns its number.
+    fn object(&mut self, object: Vec<u8>) -> usize {
+        // The catalog, the page tree, the page and its contents come first
+        const FIRST_OBJECT: usize = 5;
+
+        self.objects.push(object);
+
+        FIRST_OBJECT + self.objects.len() - 1
+    }
+
+    fn finish(self) -> Vec<u8> {
+        let mut resources = String::new();
+
+        if !self.states.is_empty() || !self.masks.is_empty() {
+            resources.push_str(" /ExtGState <<");
+
+            for (name, alpha) in &self.states {
+                let alpha = Number(*alpha);
+                let _ = write!(resources, " /{name} << /ca {alpha} /CA {alpha} >>");
+            }
+
+            for (name, group) in &self.masks {
+                let _ = write!(
+                    resources,
+                    " /{name} << /SMask << /Type /Mask /S /Luminosity /G {group} 0 R >> >>"
+                );
+            }
+
+            resources.push_str(" >>");
+        }
+
+        if !self.patterns.is_empty() {
+            let _ = write!(resources, " /Pattern << {} >>", self.patterns.join(" "));
+        }
+
+        if !self.images.is_empty() {
+            let _ = write!(resources, " /XObject << {} >>", self.images.join(" "));
+        }
+
+        let mut objects = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                /Resources <<{resources} >> /Contents 4 0 R >>",
+                Number(self.width),
+                Number(self.height),
+            )
+            .into_bytes(),
+            stream("", self.content.as_bytes()),
+        ];
+
+        objects.extend(self.objects);
+
+        let mut file = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(file.len());
+
+            file.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+            file.extend_from_slice(object);
+            file.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref = file.len();
+        let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+
+        for offset in offsets {
+            let _ = writeln!(trailer, "{offset:010} 00000 n ");
+        }
+
+        let _ = write!(
+            trailer,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        );
+
+        file.extend_from_slice(trailer.as_bytes());
+        file
+    }
+}
+
+/// Returns an axial shading of the given [`Linear`] gradient in the given
+/// color space.
+fn shading(linear: &Linear, space: &str, components: fn(Color) -> Vec<f32>) -> String {
+    format!(
+        "<< /ShadingType 2 /ColorSpace /{space} /Coords [{} {} {} {}] \
+        /Extend [true true] /Function {} >>",
+        Number(linear.start.x),
+        Number(linear.start.y),
+        Number(linear.end.x),
+        Number(linear.end.y),
+        function(&linear.stops, components),
+    )
+}
+
+/// Returns a PDF function that interpolates the given color stops.
+///
+/// The output values of the function are the `components` of each color.
+fn function(stops: &[Option<ColorStop>], components: fn(Color) -> Vec<f32>) -> String {
+    let mut stops: Vec<ColorStop> = stops.iter().flatten().copied().collect();
+
+    let (Some(first), Some(last)) = (stops.first().copied(), stops.last().copied()) else {
+        let black = components(Color::BLACK);
+
+        return interpolation(&black, &black);
+    };
+
+    // Extend the first and last colors to the edges of the domain
+    if first.offset > 0.0 {
+        stops.insert(
+            0,
+            ColorStop {
+                offset: 0.0,
+                color: first.color,
+            },
+        );
+    }
+
+    if last.offset < 1.0 || stops.len() == 1 {
+        stops.push(ColorStop {
+            offset: 1.0,
+            color: last.color,
+        });
+    }
+
+    let functions: Vec<String> = stops
+        .windows(2)
+        .map(|pair| interpolation(&components(pair[0].color), &components(pair[1].color)))
+        .collect();
+
+    if let [function] = functions.as_slice() {
+        return function.clone();
+    }
+
+    let bounds: Vec<String> = stops[1..stops.len() - 1]
+        .iter()
+        .map(|stop| Number(stop.offset).to_string())
+        .collect();
+
+    format!(
+        "<< /FunctionType 3 /Domain [0 1] /Functions [{}] /Bounds [{}] /Encode [{}] >>",
+        functions.join(" "),
+        bounds.join(" "),
+        vec!["0 1"; functions.len()].join(" "),
+    )
+}
+
+fn interpolation(from: &[f32], to: &[f32]) -> String {
+    format!(
+        "<< /FunctionType 2 /Domain [0 1] /C0 [{}] /C1 [{}] /N 1 >>",
+        numbers(from),
+        numbers(to),
+    )
+}
+
+fn numbers(numbers: &[f32]) -> String {
+    numbers
+        .iter()
+        .map(|number| Number(*number).to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn stream(dictionary: &str, data: &[u8]) -> Vec<u8> {
+    let separator = if dictionary.is_empty() { "" } else { " " };
+
+    let mut stream = format!(
+        "<< {dictionary}{separator}/Length {} >>\nstream\n",
+        data.len()
+    )
+    .into_bytes();
+
+    stream.extend_from_slice(data);
+    stream.extend_from_slice(b"\nendstream");
+    stream
+}
@@ -0,0 +1,59 @@
+use crate::core::image;
+use crate::graphics;
+
+pub use graphics::image::Buffer;
+
+/// Loads the pixels of the image with the given [`image::Handle`].
+pub fn load(handle: &image::Handle) -> Option<Buffer> {
+    graphics::image::load(handle)
+        .inspect_err(|error| log::warn!("Failed to load image: {error}"))
+        .ok()
+}
+
+/// Encodes the image with the given [`image::Handle`] as a PNG file.
+pub fn encode_png(handle: &image::Handle) -> Option<Vec<u8>> {
+    let buffer = load(handle)?;
+    let mut png = Vec::new();
+
+    let mut encoder = png::Encoder::new(&mut png, buffer.width(), buffer.height());
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let result = encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(buffer.as_raw()));
+
+    if let Err(error) = result {
+        log::warn!("Failed to encode image: {error}");
+        return None;
+    }
+
+    Some(png)
+}
+
+/// Encodes the given bytes in Base64.
+pub fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+
+    output
+}
@@ -0,0 +1,250 @@
+use crate::core::Color;
+use crate::document::{Document, Primitive, Stroke};
+use crate::graphics::Gradient;
+use crate::graphics::geometry::{LineCap, LineJoin, Style, fill};
+use crate::number::Number;
+
+use std::fmt::Write;
+
+pub fn write(document: &Document) -> String {
+    let size = document.size();
+
+    let mut writer = Writer {
+        output: String::new(),
+        next_id: 0,
+    };
+
+    let _ = writeln!(
+        writer.output,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#,
+        width = Number(size.width),
+        height = Number(size.height),
+    );
+
+    if let Some(background) = document.background_color() {
+        let _ = writeln!(
+            writer.output,
+            r#"<rect width="100%" height="100%" fill="{}"{}/>"#,
+            Hex(background),
+            Opacity("fill-opacity", background.a),
+        );
+    }
+
+    for primitive in document.primitives() {
+        writer.primitive(primitive);
+    }
+
+    writer.output.push_str("</svg>\n");
+    writer.output
+}
+
+struct Writer {
+    output: String,
+    next_id: usize,
+}
+
+impl Writer {
+    fn primitive(&mut self, primitive: &Primitive) {
+        match primitive {
+            Primitive::Fill { path, style, rule } => {
+                let paint = self.paint(style);
+
+                let _ = writeln!(
+                    self.output,
+                    r#"<path d="{}" fill="{paint}"{}{}/>"#,
+                    path.to_svg(),
+                    Opacity("fill-opacity", opacity(style)),
+                    match rule {
+                        fill::Rule::NonZero => "",
+                        fill::Rule::EvenOdd => r#" fill-rule="evenodd""#,
+                    },
+                );
+            }
+            Primitive::Stroke {
+                path,
+                style,
+                stroke,
+            } => {
+                let paint = self.paint(style);
+
+                let _ = write!(
+                    self.output,
+                    r#"<path d="{}" fill="none" stroke="{paint}"{} stroke-width="{}""#,
+                    path.to_svg(),
+                    Opacity("stroke-opacity", opacity(style)),
+                    Number(stroke.width),
+                );
+
+                self.stroke(stroke);
+                self.output.push_str("/>\n");
+            }
+            #[cfg(feature = "image")]
+            Primitive::Image { image, bounds } => {
+                self.image(image, *bounds);
+            }
+            Primitive::Group {
+                clip_bounds,
+                primitives,
+            } => {
+                if clip_bounds.width.is_infinite() || clip_bounds.height.is_infinite() {
+                    self.output.push_str("<g>\n");
+                } else {
+                    let id = self.id("clip");
+
+                    let _ = writeln!(
+                        self.output,
+                        r#"<clipPath id="{id}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+                        Number(clip_bounds.x),
+                        Number(clip_bounds.y),
+                        Number(clip_bounds.width.max(0.0)),
+                        Number(clip_bounds.height.max(0.0)),
+                    );
+
+                    let _ = writeln!(self.output, r#"<g clip-path="url(#{id})">"#);
+                }
+
+                for primitive in primitives {
+                    self.primitive(primitive);
+                }
+
+                self.output.push_str("</g>\n");
+            }
+        }
+    }
+
+    fn paint(&mut self, style: &Style) -> String {
+        match style {
+            Style::Solid(color) => Hex(*color).to_string(),
+            Style::Gradient(Gradient::Linear(linear)) => {
+                let id = self.id("gradient");
+
+                let _ = writeln!(
+                    self.output,
+                    r#"<linearGradient id="{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}">"#,
+                    Number(linear.start.x),
+                    Number(linear.start.y),
+                    Number(linear.end.x),
+                    Number(linear.end.y),
+                );
+
+                for stop in linear.stops.iter().flatten() {
+                    let _ = writeln!(
+                        self.output,
+                        r#"<stop offset="{}" stop-color="{}"{}/>"#,
+                        Number(stop.offset),
+                        Hex(stop.color),
+                        Opacity("stop-opacity", stop.color.a),
+                    );
+                }
+
+                self.output.push_str("</linearGradient>\n");
+
+                format!("url(#{id})")
+            }
+        }
+    }
+
+    fn stroke(&mut self, stroke: &Stroke) {
+        match stroke.line_cap {
+            LineCap::Butt => {}
+            LineCap::Square => self.output.push_str(r#" stroke-linecap="square""#),
+            LineCap::Round => self.output.push_str(r#" stroke-linecap="round""#),
+        }
+
+        match stroke.line_join {
+            LineJoin::Miter => {}
+            LineJoin::Round => self.output.push_str(r#" stroke-linejoin="round""#),
+            LineJoin::Bevel => self.output.push_str(r#" stroke-linejoin="bevel""#),
+        }
+
+        if !stroke.dash.is_empty() {
+            self.output.push_str(r#" stroke-dasharray=""#);
+
+            for (i, length) in stroke.dash.iter().enumerate() {
+                let separator = if i == 0 { "" } else { " " };
+                let _ = write!(self.output, "{separator}{}", Number(*length));
+            }
+
+            let _ = write!(
+                self.output,
+                r#"" stroke-dashoffset="{}""#,
+                Number(stroke.dash_offset)
+            );
+        }
+    }
+
+    #[cfg(feature = "image")]
+    fn image(&mut self, image: &crate::core::Image, bounds: crate::core::Rectangle) {
+        let Some(png) = crate::raster::encode_png(&image.handle) else {
+            return;
+        };
+
+        let _ = write!(
+            self.output,
+            r#"<image x="{}" y="{}" width="{}" height="{}" preserveAspectRatio="none""#,
+            Number(bounds.x),
+            Number(bounds.y),
+            Number(bounds.width),
+            Number(bounds.height),
+        );
+
+        if image.rotation.0 != 0.0 {
+            let center = bounds.center();
+
+            let _ = write!(
+                self.output,
+                r#" transform="rotate({} {} {})""#,
+                Number(image.rotation.0.to_degrees()),
+                Number(center.x),
+                Number(center.y),
+            );
+        }
+
+        if image.filter_method == crate::core::image::FilterMethod::Nearest {
+            self.output
+                .push_str(r#" style="image-rendering:pixelated""#);
+        }
+
+        let _ = writeln!(
+            self.output,
+            r#"{} href="data:image/png;base64,{}"/>"#,
+            Opacity("opacity", image.opacity),
+            crate::raster::base64(&png),
+        );
+    }
+
+    fn id(&mut self, prefix: &str) -> String {
+        self.next_id += 1;
+
+        format!("{prefix}{}", self.next_id)
+    }
+}
+
+fn opacity(style: &Style) -> f32 {
+    match style {
+        Style::Solid(color) => color.a,
+        Style::Gradient(_) => 1.0,
+    }
+}
+
+struct Hex(Color);
+
+impl std::fmt::Display for Hex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [r, g, b, _a] = self.0.into_rgba8();
+
+        write!(f, "#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+struct Opacity(&'static str, f32);
+
+impl std::fmt::Display for Opacity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.1 < 1.0 {
+            write!(f, r#" {}="{}""#, self.0, Number(self.1))
+        } else {
+            Ok(())
+        }
+    }
+}
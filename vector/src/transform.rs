@@ -0,0 +1,109 @@
+use crate::Primitive;
+use crate::core::{Point, Rectangle, Transformation};
+use crate::document::Stroke;
+use crate::graphics::Gradient;
+use crate::graphics::geometry::Style;
+use crate::graphics::geometry::path::lyon_path::math;
+
+pub type Transform = math::Transform;
+
+pub fn from_transformation(transformation: Transformation) -> Transform {
+    let scale = transformation.scale_factor();
+    let translation = transformation.translation();
+
+    Transform::scale(scale, scale).then_translate(math::Vector::new(translation.x, translation.y))
+}
+
+pub fn primitive(primitive: Primitive, transform: &Transform) -> Primitive {
+    if *transform == Transform::identity() {
+        return primitive;
+    }
+
+    match primitive {
+        Primitive::Fill { path, style, rule } => Primitive::Fill {
+            path: path.transform(transform),
+            style: self::style(style, transform),
+            rule,
+        },
+        Primitive::Stroke {
+            path,
+            style,
+            stroke,
+        } => {
+            let scale = self::scale(transform);
+
+            Primitive::Stroke {
+                path: path.transform(transform),
+                style: self::style(style, transform),
+                stroke: Stroke {
+                    width: stroke.width * scale,
+                    dash: stroke.dash.iter().map(|length| length * scale).collect(),
+                    dash_offset: stroke.dash_offset * scale,
+                    ..stroke
+                },
+            }
+        }
+        #[cfg(feature = "image")]
+        Primitive::Image { mut image, bounds } => {
+            let (bounds, rotation) = Rectangle::with_vertices(
+                point(Point::new(bounds.x, bounds.y), transform),
+                point(Point::new(bounds.x + bounds.width, bounds.y), transform),
+                point(Point::new(bounds.x, bounds.y + bounds.height), transform),
+            );
+
+            image.rotation += rotation;
+
+            Primitive::Image { image, bounds }
+        }
+        Primitive::Group {
+            clip_bounds,
+            primitives,
+        } => Primitive::Group {
+            clip_bounds: self::bounds(clip_bounds, transform),
+            primitives: primitives
+                .into_iter()
+                .map(|primitive| self::primitive(primitive, transform))
+                .collect(),
+        },
+    }
+}
+
+pub fn point(point: Point, transform: &Transform) -> Point {
+    let point = transform.transform_point(math::Point::new(point.x, point.y));
+
+    Point::new(point.x, point.y)
+}
+
+pub fn bounds(bounds: Rectangle, transform: &Transform) -> Rectangle {
+    if bounds.width.is_infinite() || bounds.height.is_infinite() {
+        return bounds;
+    }
+
+    let bounds = transform.outer_transformed_box(&math::Box2D::new(
+        math::Point::new(bounds.x, bounds.y),
+        math::Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+    ));
+
+    Rectangle {
+        x: bounds.min.x,
+        y: bounds.min.y,
+        width: bounds.width(),
+        height: bounds.height(),
+    }
+}
+
+fn scale(transform: &Transform) -> f32 {
+    transform.determinant().abs().sqrt()
+}
+
+fn style(style: Style, transform: &Transform) -> Style {
+    match style {
+        Style::Solid(color) => Style::Solid(color),
+        Style::Gradient(Gradient::Linear(mut linear)) => {
+            linear.start = point(linear.start, transform);
+            linear.end = point(linear.end, transform);
+
+            Style::Gradient(Gradient::Linear(linear))
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::Primitive;
+use crate::core::svg::{Data, Handle, Stylesheet};
+use crate::core::{Color, Point, Rectangle, Size, Svg};
+use crate::document::Stroke;
+use crate::graphics::geometry::path::lyon_path::math;
+use crate::graphics::geometry::{LineCap, LineJoin, Path, Style, fill};
+use crate::graphics::gradient::{self, Gradient};
+use crate::transform::{self, Transform};
+
+use resvg::usvg;
+use std::fs;
+
+/// Returns the viewport dimensions of the SVG with the given [`Handle`].
+pub fn measure(handle: &Handle) -> Size<u32> {
+    load(handle, &Stylesheet::default())
+        .map(|tree| {
+            let size = tree.size();
+
+            Size::new(size.width() as u32, size.height() as u32)
+        })
+        .unwrap_or(Size::new(0, 0))
+}
+
+/// Converts the given [`Svg`] into primitives that fill the given bounds.
+pub fn primitives(svg: &Svg, bounds: Rectangle) -> Vec<Primitive> {
+    let Some(tree) = load(&svg.handle, &svg.stylesheet) else {
+        return Vec::new();
+    };
+
+    let size = tree.size();
+    let center = bounds.center();
+
+    let fit = Transform::scale(bounds.width / size.width(), bounds.height / size.height())
+        .then_translate(math::Vector::new(bounds.x, bounds.y))
+        .then_translate(math::Vector::new(-center.x, -center.y))
+        .then_rotate(math::Angle::radians(svg.rotation.0))
+        .then_translate(math::Vector::new(center.x, center.y));
+
+    let mut primitives = Vec::new();
+
+    group(
+        tree.root(),
+        &Paint {
+            color: svg.color,
+            opacity: svg.opacity,
+        },
+        &mut primitives,
+    );
+
+    primitives
+        .into_iter()
+        .map(|primitive| transform::primitive(primitive, &fit))
+        .collect()
+}
+
+fn load(handle: &Handle, stylesheet: &Stylesheet) -> Option<usvg::Tree> {
+    let options = usvg::Options {
+        style_sheet: (!stylesheet.is_empty()).then(|| stylesheet.as_str().to_owned()),
+        ..usvg::Options::default()
+    };
+
+    match handle.data() {
+        Data::Path(path) => fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| usvg::Tree::from_str(&contents, &options).ok()),
+        Data::Bytes(bytes) => usvg::Tree::from_data(bytes, &options).ok(),
+    }
+}
+
+/// The paint overrides of an [`Svg`].
+struct Paint {
+    color: Option<Color>,
+    opacity: f32,
+}
+
+fn group(group: &usvg::Group, paint: &Paint, primitives: &mut Vec<Primitive>) {
+    // TODO: Clip paths, masks, and filters
+    let paint = Paint {
+        opacity: paint.opacity * group.opacity().get(),
+        ..*paint
+    };
+
+    for node in group.children() {
+        match node {
+            usvg::Node::Group(group) => self::group(group, &paint, primitives),
+            usvg::Node::Path(path) if path.is_visible() => self::path(path, &paint, primitives),
+            usvg::Node::Text(text) => self::group(text.flattened(), &paint, primitives),
+            usvg::Node::Path(_) | usvg::Node::Image(_) => {}
+        }
+    }
+}
+
+fn path(path: &usvg::Path, paint: &Paint, primitives: &mut Vec<Primitive>) {
+    let transform = into_transform(path.abs_transform());
+
+    let data = Path::new(|builder| {
+        for segment in path.data().segments() {
+            match segment {
+                usvg::tiny_skia_path::PathSegment::MoveTo(p) => {
+                    builder.move_to(Point::new(p.x, p.y));
+                }
+                usvg::tiny_skia_path::PathSegment::LineTo(p) => {
+                    builder.line_to(Point::new(p.x, p.y));
+                }
+                usvg::tiny_skia_path::PathSegment::QuadTo(control, p) => {
+                    builder
+                        .quadratic_curve_to(Point::new(control.x, control.y), Point::new(p.x, p.y));
+                }
+                usvg::tiny_skia_path::PathSegment::CubicTo(control_a, control_b, p) => {
+                    builder.bezier_curve_to(
+                        Point::new(control_a.x, control_a.y),
+                        Point::new(control_b.x, control_b.y),
+                        Point::new(p.x, p.y),
+                    );
+                }
+                usvg::tiny_skia_path::PathSegment::Close => {
+                    builder.close();
+                }
+            }
+        }
+    });
+
+    let fill = path.fill().and_then(|fill| {
+        Some(Primitive::Fill {
+            path: data.clone(),
+            style: style(fill.paint(), fill.opacity().get(), paint)?,
+            rule: match fill.rule() {
+                usvg::FillRule::NonZero => fill::Rule::NonZero,
+                usvg::FillRule::EvenOdd => fill::Rule::EvenOdd,
+            },
+        })
+    });
+
+    let stroke = path.stroke().and_then(|stroke| {
+        Some(Primitive::Stroke {
+            path: data.clone(),
+            style: style(stroke.paint(), stroke.opacity().get(), paint)?,
+            stroke: Stroke {
+                width: stroke.width().get(),
+                line_cap: match stroke.linecap() {
+                    usvg::LineCap::Butt => LineCap::Butt,
+                    usvg::LineCap::Round => LineCap::Round,
+                    usvg::LineCap::Square => LineCap::Square,
+                },
+                line_join: match stroke.linejoin() {
+                    usvg::LineJoin::Miter | usvg::LineJoin::MiterClip => LineJoin::Miter,
+                    usvg::LineJoin::Round => LineJoin::Round,
+                    usvg::LineJoin::Bevel => LineJoin::Bevel,
+                },
+                dash: stroke.dasharray().map(<[f32]>::to_vec).unwrap_or_default(),
+                dash_offset: stroke.dashoffset(),
+            },
+        })
+    });
+
+    let ordered = match path.paint_order() {
+        usvg::PaintOrder::FillAndStroke => [fill, stroke],
+        usvg::PaintOrder::StrokeAndFill => [stroke, fill],
+    };
+
+    primitives.extend(
+        ordered
+            .into_iter()
+            .flatten()
+            .map(|primitive| transform::primitive(primitive, &transform)),
+    );
+}
+
+fn style(paint: &usvg::Paint, opacity: f32, overrides: &Paint) -> Option<Style> {
+    let opacity = opacity * overrides.opacity;
+
+    let style = match paint {
+        usvg::Paint::Color(color) => Style::Solid(Color {
+            a: opacity,
+            ..overrides
+                .color
+                .unwrap_or(Color::from_rgb8(color.red, color.green, color.blue))
+        }),
+        usvg::Paint::LinearGradient(linear) => {
+            let transform = into_transform(linear.transform());
+
+            let start = transform::point(Point::new(linear.x1(), linear.y1()), &transform);
+            let end = transform::point(Point::new(linear.x2(), linear.y2()), &transform);
+
+            let gradient =
+                linear
+                    .stops()
+                    .iter()
+                    .fold(gradient::Linear::new(start, end), |gradient, stop| {
+                        let color = overrides.color.unwrap_or(Color::from_rgb8(
+                            stop.color().red,
+                            stop.color().green,
+                            stop.color().blue,
+                        ));
+
+                        gradient.add_stop(
+                            stop.offset().get(),
+                            Color {
+                                a: stop.opacity().get() * opacity,
+                                ..color
+                            },
+                        )
+                    });
+
+            Style::Gradient(Gradient::Linear(gradient))
+        }
+        usvg::Paint::RadialGradient(radial) => {
+            // TODO: Radial gradients
+            let stop = radial.stops().last()?;
+
+            let color = overrides.color.unwrap_or(Color::from_rgb8(
+                stop.color().red,
+                stop.color().green,
+                stop.color().blue,
+            ));
+
+            Style::Solid(Color {
+                a: stop.opacity().get() * opacity,
+                ..color
+            })
+        }
+        usvg::Paint::Pattern(_) => {
+            // TODO: Patterns
+            return None;
+        }
+    };
+
+    Some(style)
+}
+
+fn into_transform(transform: usvg::Transform) -> Transform {
+    Transform::new(
+        transform.sx,
+        transform.ky,
+        transform.kx,
+        transform.sy,
+        transform.tx,
+        transform.ty,
+    )
+}
@@ -272,6 +272,11 @@ impl geometry::frame::Backend for Frame {
     fn fill_text(&mut self, text: impl Into<geometry::Text>) {
         let text = text.into();
 
+        if !text.effects.is_none() {
+            text.draw_effects(self);
+            return;
+        }
+
         let (scale_x, scale_y) = self.transforms.current.scale();
 
         if self.transforms.current.is_scale_translation()
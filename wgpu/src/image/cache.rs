@@ -184,7 +184,9 @@ impl Cache {
     #[cfg(feature = "svg")]
     pub fn measure_svg(&mut self, handle: &core::svg::Handle) -> Size<u32> {
         // TODO: Concurrency
-        self.vector.load(handle).viewport_dimensions()
+        self.vector
+            .load(handle, &core::svg::Stylesheet::default())
+            .viewport_dimensions()
     }
 
     #[cfg(feature = "image")]
@@ -257,11 +259,21 @@ impl Cache {
         belt: &mut wgpu::util::StagingBelt,
         handle: &core::svg::Handle,
         color: Option<core::Color>,
+        stylesheet: &core::svg::Stylesheet,
         size: Size<u32>,
     ) -> Option<(&atlas::Entry, &Arc<wgpu::BindGroup>)> {
         // TODO: Concurrency
         self.vector
-            .upload(device, encoder, belt, handle, color, size, &mut self.atlas)
+            .upload(
+                device,
+                encoder,
+                belt,
+                handle,
+                color,
+                stylesheet,
+                size,
+                &mut self.atlas,
+            )
             .map(|entry| (entry, self.atlas.bind_group()))
     }
 
@@ -293,6 +293,7 @@ impl State {
                         belt,
                         &svg.handle,
                         svg.color,
+                        &svg.stylesheet,
                         Size::new(bounds.width as u32, bounds.height as u32),
                     ) {
                         match atlas.as_mut() {
@@ -35,22 +35,25 @@ impl Svg {
 /// Caches svg vector and raster data
 #[derive(Debug, Default)]
 pub struct Cache {
-    svgs: FxHashMap<u64, Svg>,
-    rasterized: FxHashMap<(u64, u32, u32, ColorFilter), atlas::Entry>,
-    svg_hits: FxHashSet<u64>,
-    rasterized_hits: FxHashSet<(u64, u32, u32, ColorFilter)>,
+    svgs: FxHashMap<(u64, StylesheetId), Svg>,
+    rasterized: FxHashMap<(u64, StylesheetId, u32, u32, ColorFilter), atlas::Entry>,
+    svg_hits: FxHashSet<(u64, StylesheetId)>,
+    rasterized_hits: FxHashSet<(u64, StylesheetId, u32, u32, ColorFilter)>,
     should_trim: bool,
     #[cfg(feature = "svg-text")]
     fontdb: Option<Arc<usvg::fontdb::Database>>,
 }
 
 type ColorFilter = Option<[u8; 4]>;
+type StylesheetId = u64;
 
 impl Cache {
     /// Load svg
-    pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
-        if self.svgs.contains_key(&handle.id()) {
-            return self.svgs.get(&handle.id()).unwrap();
+    pub fn load(&mut self, handle: &svg::Handle, stylesheet: &svg::Stylesheet) -> &Svg {
+        let key = (handle.id(), stylesheet.id());
+
+        if self.svgs.contains_key(&key) {
+            return self.svgs.get(&key).unwrap();
         }
 
         // TODO: Reuse `cosmic-text` font database
@@ -69,6 +72,7 @@ impl Cache {
                 .as_ref()
                 .expect("fontdb must be initialized")
                 .clone(),
+            style_sheet: (!stylesheet.is_empty()).then(|| stylesheet.as_str().to_owned()),
             ..usvg::Options::default()
         };
 
@@ -86,8 +90,8 @@ impl Cache {
 
         self.should_trim = true;
 
-        let _ = self.svgs.insert(handle.id(), svg);
-        self.svgs.get(&handle.id()).unwrap()
+        let _ = self.svgs.insert(key, svg);
+        self.svgs.get(&key).unwrap()
     }
 
     /// Load svg and upload raster data
@@ -98,26 +102,28 @@ impl Cache {
         belt: &mut wgpu::util::StagingBelt,
         handle: &svg::Handle,
         color: Option<Color>,
+        stylesheet: &svg::Stylesheet,
         size: Size<u32>,
         atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
         let id = handle.id();
+        let stylesheet_id = stylesheet.id();
 
         let color = color.map(Color::into_rgba8);
-        let key = (id, size.width, size.height, color);
+        let key = (id, stylesheet_id, size.width, size.height, color);
 
         // TODO: Optimize!
         // We currently rerasterize the SVG when its size changes. This is slow
         // as heck. A GPU rasterizer like `pathfinder` may perform better.
         // It would be cool to be able to smooth resize the `svg` example.
         if self.rasterized.contains_key(&key) {
-            let _ = self.svg_hits.insert(id);
+            let _ = self.svg_hits.insert((id, stylesheet_id));
             let _ = self.rasterized_hits.insert(key);
 
             return self.rasterized.get(&key);
         }
 
-        match self.load(handle) {
+        match self.load(handle, stylesheet) {
             Svg::Loaded(tree) => {
                 // TODO: Optimize!
                 // We currently rerasterize the SVG when its size changes. This is slow
@@ -172,7 +178,7 @@ impl Cache {
 
                 log::debug!("allocating {id} {}x{}", size.width, size.height);
 
-                let _ = self.svg_hits.insert(id);
+                let _ = self.svg_hits.insert((id, stylesheet_id));
                 let _ = self.rasterized_hits.insert(key);
                 let _ = self.rasterized.insert(key, allocation);
                 self.should_trim = true;
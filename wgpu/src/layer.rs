@@ -1,3 +1,4 @@
+use crate::core::text::Effects;
 use crate::core::{self, Background, Color, Point, Rectangle, Svg, Transformation, renderer};
 use crate::graphics;
 use crate::graphics::Mesh;
@@ -64,6 +65,7 @@ impl Layer {
         paragraph: &Paragraph,
         position: Point,
         color: Color,
+        effects: Effects,
         clip_bounds: Rectangle,
         transformation: Transformation,
     ) {
@@ -71,6 +73,7 @@ impl Layer {
             paragraph: paragraph.downgrade(),
             position,
             color,
+            effects,
             clip_bounds,
             transformation,
         };
@@ -753,7 +753,27 @@ impl core::text::Renderer for Renderer {
     ) {
         let (layer, transformation) = self.layers.current_mut();
 
-        layer.draw_paragraph(text, position, color, clip_bounds, transformation);
+        layer.draw_paragraph(
+            text,
+            position,
+            color,
+            core::text::Effects::NONE,
+            clip_bounds,
+            transformation,
+        );
+    }
+
+    fn fill_paragraph_with_effects(
+        &mut self,
+        text: &Self::Paragraph,
+        position: Point,
+        color: Color,
+        effects: &core::text::Effects,
+        clip_bounds: Rectangle,
+    ) {
+        let (layer, transformation) = self.layers.current_mut();
+
+        layer.draw_paragraph(text, position, color, *effects, clip_bounds, transformation);
     }
 
     fn fill_editor(
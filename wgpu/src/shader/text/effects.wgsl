@@ -0,0 +1,35 @@
+struct Globals {
+    transform: mat4x4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> globals: Globals;
+@group(0) @binding(1) var u_sampler: sampler;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+    @location(0) position: vec2<f32>,
+    @location(1) size: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn effects_vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    let corner = vertex_position(input.vertex_index);
+
+    out.position = globals.transform * vec4<f32>(input.position + corner * input.size, 0.0, 1.0);
+    out.uv = corner;
+
+    return out;
+}
+
+@fragment
+fn effects_fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    return premultiply(textureSample(u_texture, u_sampler, input.uv));
+}
@@ -1,3 +1,5 @@
+mod effects;
+
 use crate::core::alignment;
 use crate::core::text::Alignment;
 use crate::core::{Rectangle, Size, Transformation, Vector};
@@ -253,11 +255,16 @@ impl Storage {
     }
 }
 
-pub struct Viewport(cryoglyph::Viewport);
+pub struct Viewport {
+    raw: cryoglyph::Viewport,
+    projection: Transformation,
+}
 
 impl Viewport {
     pub fn update(&mut self, queue: &wgpu::Queue, resolution: Size<u32>) {
-        self.0.update(
+        self.projection = Transformation::orthographic(resolution.width, resolution.height);
+
+        self.raw.update(
             queue,
             cryoglyph::Resolution {
                 width: resolution.width,
@@ -272,6 +279,7 @@ pub struct Pipeline {
     format: wgpu::TextureFormat,
     cache: cryoglyph::Cache,
     atlas: Arc<RwLock<cryoglyph::TextAtlas>>,
+    effects: effects::Pipeline,
 }
 
 impl Pipeline {
@@ -284,11 +292,15 @@ impl Pipeline {
             format,
             cache,
             atlas: Arc::new(RwLock::new(atlas)),
+            effects: effects::Pipeline::new(device, format),
         }
     }
 
     pub fn create_viewport(&self, device: &wgpu::Device) -> Viewport {
-        Viewport(cryoglyph::Viewport::new(device, &self.cache))
+        Viewport {
+            raw: cryoglyph::Viewport::new(device, &self.cache),
+            projection: Transformation::IDENTITY,
+        }
     }
 
     pub fn trim(&self) {
@@ -299,8 +311,10 @@ impl Pipeline {
 #[derive(Default)]
 pub struct State {
     renderers: Vec<cryoglyph::TextRenderer>,
+    effects: Vec<effects::Layer>,
     prepare_layer: usize,
     cache: BufferCache,
+    sprites: effects::Cache,
     storage: Storage,
 }
 
@@ -337,11 +351,27 @@ impl State {
                         ));
                     }
 
+                    if self.effects.len() <= self.prepare_layer {
+                        self.effects
+                            .push(effects::Layer::new(device, &pipeline.effects));
+                    }
+
+                    self.effects[self.prepare_layer].prepare(
+                        &pipeline.effects,
+                        device,
+                        queue,
+                        &mut self.sprites,
+                        text,
+                        layer_bounds * layer_transformation,
+                        layer_transformation * *transformation,
+                        viewport.projection,
+                    );
+
                     let renderer = &mut self.renderers[self.prepare_layer];
                     let result = prepare(
                         device,
                         queue,
-                        &viewport.0,
+                        &viewport.raw,
                         encoder,
                         renderer,
                         &mut atlas,
@@ -369,7 +399,7 @@ impl State {
                     self.storage.prepare(
                         device,
                         queue,
-                        &viewport.0,
+                        &viewport.raw,
                         encoder,
                         pipeline.format,
                         &pipeline.cache,
@@ -401,8 +431,14 @@ impl State {
                 Item::Group { .. } => {
                     let renderer = &self.renderers[start + layer_count];
 
+                    self.effects[start + layer_count].render(
+                        &pipeline.effects,
+                        &self.sprites,
+                        render_pass,
+                    );
+
                     renderer
-                        .render(&atlas, &viewport.0, render_pass)
+                        .render(&atlas, &viewport.raw, render_pass)
                         .expect("Render text");
 
                     layer_count += 1;
@@ -411,7 +447,7 @@ impl State {
                     if let Some((atlas, upload)) = self.storage.get(cache) {
                         upload
                             .renderer
-                            .render(atlas, &viewport.0, render_pass)
+                            .render(atlas, &viewport.raw, render_pass)
                             .expect("Render cached text");
                     }
                 }
@@ -423,6 +459,7 @@ impl State {
 
     pub fn trim(&mut self) {
         self.cache.trim();
+        self.sprites.trim();
         self.storage.trim();
 
         self.prepare_layer = 0;
@@ -501,6 +538,7 @@ fn prepare(
                 Text::Paragraph {
                     position,
                     color,
+                    effects,
                     clip_bounds,
                     transformation,
                     ..
@@ -511,6 +549,11 @@ fn prepare(
                         return None;
                     };
 
+                    // The gradient is already painted over the glyphs
+                    if crate::graphics::text::effects::fills_glyphs(effects) {
+                        return None;
+                    }
+
                     (
                         paragraph.buffer(),
                         paragraph.hint_factor(),
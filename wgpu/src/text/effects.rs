@@ -0,0 +1,418 @@
+//! Draw the rasterized effects of text.
+use crate::Buffer;
+use crate::core::{Rectangle, Transformation};
+use crate::graphics::color;
+use crate::graphics::text::Text;
+use crate::graphics::text::effects::{self, Sprite};
+
+use bytemuck::{Pod, Zeroable};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use std::mem;
+
+const INITIAL_INSTANCES: usize = 16;
+
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    raw: wgpu::RenderPipeline,
+    constants_layout: wgpu::BindGroupLayout,
+    texture_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            min_filter: wgpu::FilterMode::Nearest,
+            mag_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::MipmapFilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let constants_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("iced_wgpu.text.effects.constants_layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64),
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("iced_wgpu.text.effects.texture_layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("iced_wgpu.text.effects.pipeline"),
+            bind_group_layouts: &[Some(&constants_layout), Some(&texture_layout)],
+            immediate_size: 0,
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("iced_wgpu.text.effects.shader"),
+            source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(concat!(
+                include_str!("../shader/vertex.wgsl"),
+                "\n",
+                include_str!("../shader/color.wgsl"),
+                "\n",
+                include_str!("../shader/text/effects.wgsl"),
+            ))),
+        });
+
+        let raw = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("iced_wgpu.text.effects.pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("effects_vs_main"),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: mem::size_of::<Instance>() as u64,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &wgpu::vertex_attr_array!(
+                        // Position
+                        0 => Float32x2,
+                        // Size
+                        1 => Float32x2,
+                    ),
+                }],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("effects_fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                front_face: wgpu::FrontFace::Cw,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview_mask: None,
+            cache: None,
+        });
+
+        Self {
+            raw,
+            constants_layout,
+            texture_layout,
+            sampler,
+        }
+    }
+}
+
+/// The uploaded [`Sprite`]s of some text, kept while they are drawn.
+#[derive(Default)]
+pub struct Cache {
+    entries: FxHashMap<u64, Option<Entry>>,
+    recently_used: FxHashSet<u64>,
+}
+
+struct Entry {
+    instance: Instance,
+    bind_group: wgpu::BindGroup,
+    _texture: wgpu::Texture,
+}
+
+impl Cache {
+    fn upload(
+        &mut self,
+        pipeline: &Pipeline,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        key: u64,
+        rasterize: impl FnOnce() -> Option<Sprite>,
+    ) -> Option<Instance> {
+        let _ = self.recently_used.insert(key);
+
+        self.entries
+            .entry(key)
+            .or_insert_with(|| {
+                let sprite = rasterize()?;
+
+                Some(Entry::new(pipeline, device, queue, &sprite))
+            })
+            .as_ref()
+            .map(|entry| entry.instance)
+    }
+
+    pub fn trim(&mut self) {
+        self.entries
+            .retain(|key, _| self.recently_used.contains(key));
+
+        self.recently_used.clear();
+    }
+}
+
+impl Entry {
+    fn new(
+        pipeline: &Pipeline,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        sprite: &Sprite,
+    ) -> Self {
+        let extent = wgpu::Extent3d {
+            width: sprite.width,
+            height: sprite.height,
+            depth_or_array_layers: 1,
+        };
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu.text.effects.texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: if color::GAMMA_CORRECTION {
+                wgpu::TextureFormat::Rgba8UnormSrgb
+            } else {
+                wgpu::TextureFormat::Rgba8Unorm
+            },
+            usage: wgpu::TextureUsages::COPY_DST | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::default(),
+            },
+            &sprite.pixels,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(sprite.width * 4),
+                rows_per_image: Some(sprite.height),
+            },
+            extent,
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu.text.effects.texture_bind_group"),
+            layout: &pipeline.texture_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Self {
+            instance: Instance {
+                position: [sprite.x as f32, sprite.y as f32],
+                size: [sprite.width as f32, sprite.height as f32],
+            },
+            bind_group,
+            _texture: texture,
+        }
+    }
+}
+
+/// The [`Sprite`]s drawn behind a group of text.
+#[derive(Debug)]
+pub struct Layer {
+    constants: wgpu::BindGroup,
+    uniforms: wgpu::Buffer,
+    instances: Buffer<Instance>,
+    sprites: Vec<u64>,
+}
+
+impl Layer {
+    pub fn new(device: &wgpu::Device, pipeline: &Pipeline) -> Self {
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu.text.effects.uniforms"),
+            size: mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu.text.effects.constants"),
+            layout: &pipeline.constants_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniforms.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&pipeline.sampler),
+                },
+            ],
+        });
+
+        let instances = Buffer::new(
+            device,
+            "iced_wgpu.text.effects.instances",
+            INITIAL_INSTANCES,
+            wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        );
+
+        Self {
+            constants,
+            uniforms,
+            instances,
+            sprites: Vec::new(),
+        }
+    }
+
+    /// Rasterizes and uploads the [`Sprite`]s of the given text, unless
+    /// they are already cached.
+    pub fn prepare(
+        &mut self,
+        pipeline: &Pipeline,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        cache: &mut Cache,
+        text: &[Text],
+        layer_bounds: Rectangle,
+        layer_transformation: Transformation,
+        projection: Transformation,
+    ) {
+        use crate::core::text::Paragraph as _;
+
+        self.sprites.clear();
+
+        let mut instances = Vec::new();
+
+        for text in text {
+            let Text::Paragraph {
+                paragraph,
+                position,
+                effects,
+                clip_bounds,
+                transformation,
+                ..
+            } = text
+            else {
+                continue;
+            };
+
+            if effects.is_none() {
+                continue;
+            }
+
+            let Some(paragraph) = paragraph.upgrade() else {
+                continue;
+            };
+
+            let transformation = layer_transformation * *transformation;
+
+            let Some(clip_bounds) = layer_bounds.intersection(&(*clip_bounds * transformation))
+            else {
+                continue;
+            };
+
+            // Hinted paragraphs are laid out at a larger scale
+            let (position, transformation) = match paragraph.hint_factor() {
+                Some(hint_factor) => (
+                    *position * Transformation::scale(hint_factor),
+                    transformation * Transformation::scale(1.0 / hint_factor),
+                ),
+                None => (*position, transformation),
+            };
+
+            let buffer = paragraph.buffer();
+            let key = effects::key(buffer, position, effects, transformation, clip_bounds);
+
+            let instance = cache.upload(pipeline, device, queue, key, || {
+                effects::rasterize(buffer, position, effects, transformation, clip_bounds)
+            });
+
+            if let Some(instance) = instance {
+                instances.push(instance);
+                self.sprites.push(key);
+            }
+        }
+
+        if instances.is_empty() {
+            return;
+        }
+
+        queue.write_buffer(
+            &self.uniforms,
+            0,
+            bytemuck::bytes_of(&Uniforms {
+                transform: projection.into(),
+            }),
+        );
+
+        let _ = self.instances.resize(device, instances.len());
+        queue.write_buffer(&self.instances.raw, 0, bytemuck::cast_slice(&instances));
+    }
+
+    pub fn render<'a>(
+        &'a self,
+        pipeline: &'a Pipeline,
+        cache: &'a Cache,
+        render_pass: &mut wgpu::RenderPass<'a>,
+    ) {
+        if self.sprites.is_empty() {
+            return;
+        }
+
+        render_pass.set_pipeline(&pipeline.raw);
+        render_pass.set_bind_group(0, &self.constants, &[]);
+        render_pass.set_vertex_buffer(0, self.instances.slice(..));
+
+        for (i, key) in self.sprites.iter().enumerate() {
+            if let Some(Some(entry)) = cache.entries.get(key) {
+                let i = i as u32;
+
+                render_pass.set_bind_group(1, &entry.bind_group, &[]);
+                render_pass.draw(0..6, i..i + 1);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+struct Instance {
+    position: [f32; 2],
+    size: [f32; 2],
+}
+
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+struct Uniforms {
+    transform: [f32; 16],
+}
@@ -48,9 +48,12 @@
 //!     canvas(Circle { radius: 50.0 }).into()
 //! }
 //! ```
+pub mod scene;
+
 mod program;
 
 pub use program::Program;
+pub use scene::Scene;
 
 pub use crate::Action;
 pub use crate::core::event::Event;
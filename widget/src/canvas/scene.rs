@@ -0,0 +1,752 @@
+//! Retain shapes in a [`Scene`] and route events to them.
+//!
+//! A [`Scene`] is an optional retained-mode layer on top of a [`Canvas`].
+//! Instead of drawing everything in [`Program::draw`] and hit testing shapes
+//! manually in [`Program::update`], you register [`Shape`]s with an id and let
+//! the [`Scene`] figure out which one is under the cursor.
+//!
+//! Each [`Shape`] is cached separately. Changing a single [`Shape`] will only
+//! redraw that [`Shape`].
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::canvas;
+//! use iced::widget::canvas::{scene, Path, Scene};
+//! use iced::{Color, Length, Point};
+//!
+//! struct State {
+//!     scene: Scene<usize>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Scene(scene::Event<usize>),
+//! }
+//!
+//! fn new() -> State {
+//!     let mut scene = Scene::new();
+//!
+//!     scene.insert(
+//!         0,
+//!         scene::Shape::new(Path::circle(Point::new(50.0, 50.0), 20.0))
+//!             .fill(Color::BLACK),
+//!     );
+//!
+//!     State { scene }
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     canvas(state.scene.on_event(Message::Scene))
+//!         .width(Length::Fill)
+//!         .height(Length::Fill)
+//!         .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Scene(scene::Event::Dragged { id, delta, .. }) => {
+//!             let _ = state.scene.update(&id, |shape| {
+//!                 shape.path = shape.path.transform(
+//!                     &canvas::path::lyon_path::math::Transform::translation(
+//!                         delta.x, delta.y,
+//!                     ),
+//!                 );
+//!             });
+//!         }
+//!         Message::Scene(_) => {}
+//!     }
+//! }
+//! ```
+//!
+//! [`Canvas`]: crate::Canvas
+use crate::Action;
+use crate::canvas::{self, Cache, Fill, Geometry, Path, Program, Stroke};
+use crate::core::mouse;
+use crate::core::{Point, Rectangle, Vector};
+use crate::graphics::geometry;
+
+/// A set of retained [`Shape`]s identified by some `Id`.
+///
+/// [`Shape`]s are drawn and hit tested in ascending order of
+/// [`Shape::z_index`]; ties are resolved by insertion order.
+pub struct Scene<Id, Renderer = crate::Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    entries: Vec<Entry<Id, Renderer>>,
+}
+
+struct Entry<Id, Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    id: Id,
+    shape: Shape,
+    bounds: Rectangle,
+    cache: Cache<Renderer>,
+}
+
+impl<Id, Renderer> Scene<Id, Renderer>
+where
+    Id: PartialEq,
+    Renderer: geometry::Renderer,
+{
+    /// Creates a new empty [`Scene`].
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Inserts a [`Shape`] with the given `Id` into the [`Scene`].
+    ///
+    /// If a [`Shape`] with the same `Id` already exists, it is replaced.
+    pub fn insert(&mut self, id: Id, shape: Shape) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.bounds = shape.bounds();
+            entry.shape = shape;
+            entry.cache.clear();
+        } else {
+            self.entries.push(Entry {
+                id,
+                bounds: shape.bounds(),
+                shape,
+                cache: Cache::new(),
+            });
+        }
+
+        self.sort();
+    }
+
+    /// Updates the [`Shape`] with the given `Id` using the provided closure.
+    ///
+    /// Only the geometry of the updated [`Shape`] will be redrawn.
+    ///
+    /// Returns `false` if the [`Scene`] does not contain the `Id`.
+    pub fn update(&mut self, id: &Id, f: impl FnOnce(&mut Shape)) -> bool {
+        let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == *id) else {
+            return false;
+        };
+
+        let z_index = entry.shape.z_index;
+
+        f(&mut entry.shape);
+        entry.bounds = entry.shape.bounds();
+        entry.cache.clear();
+
+        if entry.shape.z_index != z_index {
+            self.sort();
+        }
+
+        true
+    }
+
+    /// Removes the [`Shape`] with the given `Id` from the [`Scene`].
+    pub fn remove(&mut self, id: &Id) -> Option<Shape> {
+        let index = self.entries.iter().position(|entry| entry.id == *id)?;
+
+        Some(self.entries.remove(index).shape)
+    }
+
+    /// Removes all the [`Shape`]s of the [`Scene`].
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the [`Shape`] with the given `Id`, if any.
+    pub fn get(&self, id: &Id) -> Option<&Shape> {
+        self.entries
+            .iter()
+            .find(|entry| entry.id == *id)
+            .map(|entry| &entry.shape)
+    }
+
+    /// Returns an iterator over the `Id` and [`Shape`] pairs of the [`Scene`],
+    /// from bottom to top.
+    pub fn iter(&self) -> impl Iterator<Item = (&Id, &Shape)> {
+        self.entries.iter().map(|entry| (&entry.id, &entry.shape))
+    }
+
+    /// Returns the number of [`Shape`]s in the [`Scene`].
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the [`Scene`] contains no [`Shape`]s.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the `Id` of the topmost interactive [`Shape`] containing
+    /// the given [`Point`], if any.
+    pub fn shape_at(&self, point: Point) -> Option<&Id> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| {
+                entry.shape.is_interactive
+                    && entry.bounds.contains(point)
+                    && entry.shape.contains(point)
+            })
+            .map(|entry| &entry.id)
+    }
+
+    /// Turns the [`Scene`] into an interactive [`Program`] that produces
+    /// messages using the given closure.
+    pub fn on_event<'a, Message>(
+        &'a self,
+        on_event: impl Fn(Event<Id>) -> Message + 'a,
+    ) -> Interactive<'a, Id, Message, Renderer> {
+        Interactive {
+            scene: self,
+            on_event: Box::new(on_event),
+        }
+    }
+
+    fn sort(&mut self) {
+        // Stable, so insertion order is kept for equal indices
+        self.entries.sort_by_key(|entry| entry.shape.z_index);
+    }
+
+    fn draw_shapes(&self, renderer: &Renderer, bounds: Rectangle) -> Vec<Geometry<Renderer>> {
+        self.draw_shapes_with(renderer, bounds, |frame, shape| {
+            if let Some(fill) = shape.fill {
+                frame.fill(&shape.path, fill);
+            }
+
+            if let Some(stroke) = shape.stroke {
+                frame.stroke(&shape.path, stroke);
+            }
+        })
+    }
+
+    fn draw_shapes_with(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        draw: impl Fn(&mut canvas::Frame<Renderer>, &Shape),
+    ) -> Vec<Geometry<Renderer>> {
+        self.entries
+            .iter()
+            .map(|entry| {
+                entry
+                    .cache
+                    .draw(renderer, bounds.size(), |frame| draw(frame, &entry.shape))
+            })
+            .collect()
+    }
+
+    fn interaction(
+        &self,
+        state: &State<Id>,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        let id = match &state.pressed {
+            Some(pressed) => Some(&pressed.id),
+            None => cursor
+                .position_in(bounds)
+                .and_then(|position| self.shape_at(position)),
+        };
+
+        id.and_then(|id| self.get(id))
+            .map(|shape| shape.interaction)
+            .unwrap_or_default()
+    }
+}
+
+impl<Id, Renderer> Scene<Id, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: geometry::Renderer,
+{
+    fn route(
+        &self,
+        state: &mut State<Id>,
+        event: &canvas::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<Event<Id>> {
+        let canvas::Event::Mouse(event) = event else {
+            return None;
+        };
+
+        match event {
+            mouse::Event::CursorMoved { .. } => {
+                if let Some(pressed) = &mut state.pressed {
+                    // Keep dragging even if the cursor leaves the canvas
+                    let position = cursor.position_from(bounds.position())?;
+                    let delta = position - pressed.last_position;
+
+                    pressed.last_position = position;
+
+                    return Some(Event::Dragged {
+                        id: pressed.id.clone(),
+                        position,
+                        delta,
+                    });
+                }
+
+                let hovered = cursor
+                    .position_in(bounds)
+                    .and_then(|position| self.shape_at(position))
+                    .cloned();
+
+                if hovered == state.hovered {
+                    return None;
+                }
+
+                state.hovered = hovered.clone();
+
+                Some(Event::Hovered(hovered))
+            }
+            mouse::Event::CursorLeft => {
+                if state.pressed.is_some() || state.hovered.is_none() {
+                    return None;
+                }
+
+                state.hovered = None;
+
+                Some(Event::Hovered(None))
+            }
+            mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                let position = cursor.position_in(bounds)?;
+                let id = self.shape_at(position)?.clone();
+
+                state.pressed = Some(Pressed {
+                    id: id.clone(),
+                    last_position: position,
+                });
+
+                Some(Event::Pressed { id, position })
+            }
+            mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                let pressed = state.pressed.take()?;
+                let position = cursor
+                    .position_from(bounds.position())
+                    .unwrap_or(pressed.last_position);
+
+                Some(Event::Released {
+                    id: pressed.id,
+                    position,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<Id, Renderer> Default for Scene<Id, Renderer>
+where
+    Id: PartialEq,
+    Renderer: geometry::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Id, Renderer> std::fmt::Debug for Scene<Id, Renderer>
+where
+    Id: std::fmt::Debug,
+    Renderer: geometry::Renderer,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.entries.iter().map(|entry| (&entry.id, &entry.shape)))
+            .finish()
+    }
+}
+
+impl<Id, Message, Theme, Renderer> Program<Message, Theme, Renderer> for Scene<Id, Renderer>
+where
+    Id: Clone + PartialEq + 'static,
+    Renderer: geometry::Renderer,
+{
+    type State = State<Id>;
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        self.draw_shapes(renderer, bounds)
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        self.interaction(state, bounds, cursor)
+    }
+}
+
+/// An interactive [`Scene`] that produces messages.
+///
+/// This is the [`Program`] returned by [`Scene::on_event`].
+pub struct Interactive<'a, Id, Message, Renderer = crate::Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    scene: &'a Scene<Id, Renderer>,
+    on_event: Box<dyn Fn(Event<Id>) -> Message + 'a>,
+}
+
+impl<Id, Message, Theme, Renderer> Program<Message, Theme, Renderer>
+    for Interactive<'_, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq + 'static,
+    Renderer: geometry::Renderer,
+{
+    type State = State<Id>;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &canvas::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<Action<Message>> {
+        let event = self.scene.route(state, event, bounds, cursor)?;
+        let is_capturing = !matches!(event, Event::Hovered(_));
+
+        let action = Action::publish((self.on_event)(event));
+
+        Some(if is_capturing {
+            action.and_capture()
+        } else {
+            action
+        })
+    }
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        self.scene.draw_shapes(renderer, bounds)
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        self.scene.interaction(state, bounds, cursor)
+    }
+}
+
+/// A retained shape of a [`Scene`].
+#[derive(Debug, Clone)]
+pub struct Shape {
+    /// The [`Path`] of the [`Shape`].
+    pub path: Path,
+
+    /// The [`Fill`] of the [`Shape`], if any.
+    pub fill: Option<Fill>,
+
+    /// The [`Stroke`] of the [`Shape`], if any.
+    pub stroke: Option<Stroke<'static>>,
+
+    /// The z-index of the [`Shape`].
+    ///
+    /// [`Shape`]s with a higher z-index are drawn on top and
+    /// receive events first.
+    pub z_index: i32,
+
+    /// The [`mouse::Interaction`] of the [`Shape`] when hovered or pressed.
+    pub interaction: mouse::Interaction,
+
+    /// Whether the [`Shape`] can be hovered, pressed, and dragged.
+    ///
+    /// Non-interactive shapes let events pass through to the shapes below.
+    pub is_interactive: bool,
+}
+
+impl Shape {
+    /// Creates a new [`Shape`] with the given [`Path`].
+    ///
+    /// By default, a [`Shape`] is interactive but neither filled
+    /// nor stroked.
+    pub fn new(path: Path) -> Self {
+        Self {
+            path,
+            fill: None,
+            stroke: None,
+            z_index: 0,
+            interaction: mouse::Interaction::default(),
+            is_interactive: true,
+        }
+    }
+
+    /// Sets the [`Fill`] of the [`Shape`].
+    pub fn fill(mut self, fill: impl Into<Fill>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Sets the [`Stroke`] of the [`Shape`].
+    pub fn stroke(mut self, stroke: impl Into<Stroke<'static>>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Sets the z-index of the [`Shape`].
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Sets the [`mouse::Interaction`] of the [`Shape`].
+    pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
+        self.interaction = interaction;
+        self
+    }
+
+    /// Sets whether the [`Shape`] is interactive.
+    pub fn interactive(mut self, is_interactive: bool) -> Self {
+        self.is_interactive = is_interactive;
+        self
+    }
+
+    /// Returns true if the given [`Point`] hits the [`Shape`]; taking
+    /// into account both its [`Fill`] and its [`Stroke`].
+    pub fn contains(&self, point: Point) -> bool {
+        let in_fill = self
+            .fill
+            .is_some_and(|fill| self.path.contains(point, fill.rule));
+
+        in_fill
+            || self
+                .stroke
+                .is_some_and(|stroke| self.path.stroke_contains(point, stroke.width))
+    }
+
+    /// Returns the [`Rectangle`] containing every [`Point`] that can hit
+    /// the [`Shape`].
+    fn bounds(&self) -> Rectangle {
+        let bounds = self.path.bounds();
+
+        match self.stroke {
+            Some(stroke) => bounds.expand(stroke.width / 2.0),
+            None => bounds,
+        }
+    }
+}
+
+/// An interaction with the [`Shape`]s of a [`Scene`].
+///
+/// All positions are relative to the bounds of the [`Canvas`].
+///
+/// [`Canvas`]: crate::Canvas
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<Id> {
+    /// The hovered [`Shape`] has changed.
+    Hovered(Option<Id>),
+
+    /// A [`Shape`] was pressed.
+    Pressed {
+        /// The `Id` of the pressed [`Shape`].
+        id: Id,
+        /// The position of the cursor.
+        position: Point,
+    },
+
+    /// A pressed [`Shape`] was dragged.
+    Dragged {
+        /// The `Id` of the dragged [`Shape`].
+        id: Id,
+        /// The new position of the cursor.
+        position: Point,
+        /// The movement of the cursor since the last event.
+        delta: Vector,
+    },
+
+    /// A pressed [`Shape`] was released.
+    Released {
+        /// The `Id` of the released [`Shape`].
+        id: Id,
+        /// The position of the cursor.
+        position: Point,
+    },
+}
+
+/// The internal state of a [`Scene`] in a [`Canvas`].
+///
+/// [`Canvas`]: crate::Canvas
+#[derive(Debug)]
+pub struct State<Id> {
+    hovered: Option<Id>,
+    pressed: Option<Pressed<Id>>,
+}
+
+#[derive(Debug)]
+struct Pressed<Id> {
+    id: Id,
+    last_position: Point,
+}
+
+impl<Id> Default for State<Id> {
+    fn default() -> Self {
+        Self {
+            hovered: None,
+            pressed: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Event, Scene, Shape, State};
+    use crate::canvas::{self, Path, Stroke};
+    use crate::core::mouse;
+    use crate::core::{Color, Point, Rectangle, Size, Vector};
+
+    use std::cell::Cell;
+
+    fn square(x: f32, y: f32) -> Shape {
+        Shape::new(Path::rectangle(Point::new(x, y), Size::new(10.0, 10.0))).fill(Color::BLACK)
+    }
+
+    fn mouse(event: mouse::Event) -> canvas::Event {
+        canvas::Event::Mouse(event)
+    }
+
+    #[test]
+    fn topmost_shape_receives_hits() {
+        let mut scene = Scene::<&str, ()>::new();
+
+        scene.insert("top", square(5.0, 5.0).z_index(1));
+        scene.insert("bottom", square(0.0, 0.0));
+        scene.insert("ghost", square(0.0, 0.0).z_index(2).interactive(false));
+
+        assert_eq!(scene.shape_at(Point::new(7.0, 7.0)), Some(&"top"));
+        assert_eq!(scene.shape_at(Point::new(2.0, 2.0)), Some(&"bottom"));
+        assert_eq!(scene.shape_at(Point::new(20.0, 20.0)), None);
+
+        let _ = scene.update(&"bottom", |shape| shape.z_index = 3);
+
+        assert_eq!(scene.shape_at(Point::new(7.0, 7.0)), Some(&"bottom"));
+    }
+
+    #[test]
+    fn stroke_extends_hit_bounds() {
+        let mut scene = Scene::<&str, ()>::new();
+
+        scene.insert(
+            "line",
+            Shape::new(Path::line(Point::new(0.0, 10.0), Point::new(20.0, 10.0)))
+                .stroke(Stroke::default().with_width(4.0)),
+        );
+
+        assert_eq!(scene.shape_at(Point::new(10.0, 11.5)), Some(&"line"));
+        assert_eq!(scene.shape_at(Point::new(10.0, 13.0)), None);
+
+        let _ = scene.update(&"line", |shape| {
+            shape.stroke = Some(Stroke::default().with_width(8.0));
+        });
+
+        assert_eq!(scene.shape_at(Point::new(10.0, 13.0)), Some(&"line"));
+    }
+
+    #[test]
+    fn press_drag_and_release() {
+        let mut scene = Scene::<&str, ()>::new();
+        scene.insert("square", square(0.0, 0.0));
+
+        let bounds = Rectangle::new(Point::new(100.0, 100.0), Size::new(50.0, 50.0));
+        let mut state = State::default();
+
+        let mut route = |event, x, y| {
+            let cursor = mouse::Cursor::Available(Point::new(x, y));
+
+            scene.route(&mut state, &mouse(event), bounds, cursor)
+        };
+
+        let moved = mouse::Event::CursorMoved {
+            position: Point::ORIGIN,
+        };
+        let pressed = mouse::Event::ButtonPressed(mouse::Button::Left);
+        let released = mouse::Event::ButtonReleased(mouse::Button::Left);
+
+        assert_eq!(
+            route(moved, 105.0, 105.0),
+            Some(Event::Hovered(Some("square")))
+        );
+        assert_eq!(route(moved, 106.0, 105.0), None);
+        assert_eq!(
+            route(pressed, 105.0, 105.0),
+            Some(Event::Pressed {
+                id: "square",
+                position: Point::new(5.0, 5.0)
+            })
+        );
+
+        // Dragging continues outside of the shape and the canvas
+        assert_eq!(
+            route(moved, 200.0, 105.0),
+            Some(Event::Dragged {
+                id: "square",
+                position: Point::new(100.0, 5.0),
+                delta: Vector::new(95.0, 0.0)
+            })
+        );
+        assert_eq!(
+            route(released, 200.0, 110.0),
+            Some(Event::Released {
+                id: "square",
+                position: Point::new(100.0, 10.0)
+            })
+        );
+        assert_eq!(route(released, 200.0, 110.0), None);
+
+        // Pressing outside of any shape is ignored
+        assert_eq!(route(moved, 130.0, 130.0), Some(Event::Hovered(None)));
+        assert_eq!(route(pressed, 130.0, 130.0), None);
+        assert_eq!(route(moved, 131.0, 130.0), None);
+    }
+
+    #[test]
+    fn only_changed_shapes_are_redrawn() {
+        let mut scene = Scene::<&str, ()>::new();
+        scene.insert("a", square(0.0, 0.0));
+        scene.insert("b", square(20.0, 0.0));
+
+        let bounds = Rectangle::with_size(Size::new(100.0, 100.0));
+        let draw = |scene: &Scene<&str, ()>| {
+            let drawn = Cell::new(0);
+
+            let _ = scene.draw_shapes_with(&(), bounds, |_frame, _shape| {
+                drawn.set(drawn.get() + 1);
+            });
+
+            drawn.get()
+        };
+
+        assert_eq!(draw(&scene), 2);
+        assert_eq!(draw(&scene), 0);
+
+        let _ = scene.update(&"a", |shape| shape.z_index = 1);
+        assert_eq!(draw(&scene), 1);
+
+        scene.insert("b", square(30.0, 0.0));
+        assert_eq!(draw(&scene), 1);
+
+        scene.insert("c", square(40.0, 0.0));
+        assert_eq!(draw(&scene), 1);
+
+        let _ = scene.remove(&"a");
+        assert_eq!(draw(&scene), 0);
+    }
+}
@@ -0,0 +1,1407 @@
+//! Charts plot series of data as lines, areas, bars, or scatter points.
+//!
+//! A [`Chart`] draws its axes, grid, and legend in a cached layer that is
+//! only redrawn when its bounds, view, or style change. Series are drawn in
+//! a separate layer that is only redrawn when their [`Data`] changes—and
+//! large series are decimated to a few points per pixel.
+//!
+//! An interactive [`Chart`] can be panned by dragging and zoomed with the
+//! mouse wheel or a pinch gesture. Double clicking resets its view.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::chart::{self, Axis, Series};
+//! use iced::Length;
+//!
+//! struct State {
+//!    temperature: chart::Data,
+//!    humidity: chart::Data,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     chart::Chart::new([
+//!         Series::line("Temperature", &state.temperature),
+//!         Series::area("Humidity", &state.humidity),
+//!     ])
+//!     .x_axis(Axis::time().label("Time"))
+//!     .y_axis(Axis::linear().label("Value"))
+//!     .width(Length::Fill)
+//!     .height(300)
+//!     .into()
+//! }
+//! ```
+pub mod axis;
+pub mod series;
+
+pub use axis::{Axis, Scale};
+pub use series::{Data, Kind, Series};
+
+use crate::Renderer;
+use crate::canvas::{self, Frame, Path, Stroke, Text};
+use crate::core::alignment;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer::{self, Renderer as _};
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Theme, Vector,
+    Widget,
+};
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// A widget that plots series of [`Data`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::chart::{self, Series};
+///
+/// struct State {
+///    sales: chart::Data,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     chart::Chart::new([Series::bar("Sales", &state.sales)]).into()
+/// }
+/// ```
+pub struct Chart<'a, Message, Theme = crate::Theme>
+where
+    Theme: Catalog,
+{
+    series: Vec<Series<'a>>,
+    x_axis: Axis,
+    y_axis: Axis,
+    width: Length,
+    height: Length,
+    text_size: Pixels,
+    legend: bool,
+    tooltip: bool,
+    interactive: bool,
+    on_hover: Option<Box<dyn Fn(Option<Hover>) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> Chart<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    /// The default text size of a [`Chart`].
+    pub const DEFAULT_TEXT_SIZE: Pixels = Pixels(12.0);
+
+    /// Creates a new [`Chart`] with the given [`Series`].
+    pub fn new(series: impl IntoIterator<Item = Series<'a>>) -> Self {
+        Self {
+            series: series.into_iter().collect(),
+            x_axis: Axis::default(),
+            y_axis: Axis::default(),
+            width: Length::Fill,
+            height: Length::Fixed(300.0),
+            text_size: Self::DEFAULT_TEXT_SIZE,
+            legend: true,
+            tooltip: true,
+            interactive: true,
+            on_hover: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a [`Series`] to the [`Chart`].
+    pub fn push(mut self, series: Series<'a>) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Sets the horizontal [`Axis`] of the [`Chart`].
+    pub fn x_axis(mut self, axis: Axis) -> Self {
+        self.x_axis = axis;
+        self
+    }
+
+    /// Sets the vertical [`Axis`] of the [`Chart`].
+    pub fn y_axis(mut self, axis: Axis) -> Self {
+        self.y_axis = axis;
+        self
+    }
+
+    /// Sets the width of the [`Chart`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Chart`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the size of the labels of the [`Chart`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into();
+        self
+    }
+
+    /// Sets whether the legend of the [`Chart`] is shown.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Sets whether a tooltip is shown when hovering a point of the [`Chart`].
+    pub fn tooltip(mut self, tooltip: bool) -> Self {
+        self.tooltip = tooltip;
+        self
+    }
+
+    /// Sets whether the [`Chart`] can be panned and zoomed.
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Sets the message that should be produced when the hovered point
+    /// of the [`Chart`] changes.
+    pub fn on_hover(mut self, on_hover: impl Fn(Option<Hover>) -> Message + 'a) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// Sets the style of the [`Chart`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Chart`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn view(&self, state: &State) -> View {
+        state.view.unwrap_or_else(|| {
+            let fingerprint = self.data_fingerprint();
+            let mut fit = state.fit.borrow_mut();
+
+            match *fit {
+                Some((last, view)) if last == fingerprint => view,
+                _ => {
+                    let view = self.fit();
+                    *fit = Some((fingerprint, view));
+
+                    view
+                }
+            }
+        })
+    }
+
+    fn fit(&self) -> View {
+        let mut x = Range::EMPTY;
+        let mut y = Range::EMPTY;
+
+        for series in &self.series {
+            for &(px, py) in series.data.points() {
+                if let (Some(px), Some(py)) =
+                    (self.x_axis.scale.project(px), self.y_axis.scale.project(py))
+                {
+                    x.include(px);
+                    y.include(py);
+                }
+            }
+        }
+
+        let has_bars = self.series.iter().any(|series| series.kind == Kind::Bar);
+        let has_fill = has_bars || self.series.iter().any(|series| series.kind == Kind::Area);
+
+        if has_fill && self.y_axis.scale == Scale::Linear {
+            y.include(0.0);
+        }
+
+        let x = fixed(&self.x_axis).unwrap_or_else(|| x.padded(if has_bars { 0.05 } else { 0.0 }));
+        let y = fixed(&self.y_axis).unwrap_or_else(|| y.padded(0.05));
+
+        View { x, y }
+    }
+
+    fn plot(&self, size: Size, view: View) -> Plot {
+        const PADDING: f32 = 10.0;
+        const SPACING: f32 = 6.0;
+
+        let text_size = self.text_size.0;
+        let line_height = text_size * 1.3;
+
+        let x_ticks = self.x_axis.generate(view.x.start, view.x.end);
+        let y_ticks = self.y_axis.generate(view.y.start, view.y.end);
+
+        let y_labels = y_ticks
+            .iter()
+            .map(|tick| estimate_width(&tick.label, text_size))
+            .fold(0.0, f32::max);
+
+        let left = PADDING
+            + y_labels
+            + SPACING
+            + if self.y_axis.label.is_some() {
+                line_height + SPACING
+            } else {
+                0.0
+            };
+
+        let bottom = PADDING
+            + line_height
+            + SPACING
+            + if self.x_axis.label.is_some() {
+                line_height + SPACING
+            } else {
+                0.0
+            };
+
+        let right = PADDING
+            + x_ticks
+                .last()
+                .map(|tick| estimate_width(&tick.label, text_size) / 2.0)
+                .unwrap_or(0.0);
+
+        let top = PADDING + text_size / 2.0;
+
+        Plot {
+            area: Rectangle {
+                x: left,
+                y: top,
+                width: (size.width - left - right).max(1.0),
+                height: (size.height - top - bottom).max(1.0),
+            },
+            view,
+            x_ticks,
+            y_ticks,
+        }
+    }
+
+    fn axes_fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        for axis in [&self.x_axis, &self.y_axis] {
+            axis.scale.hash(&mut hasher);
+            axis.label.hash(&mut hasher);
+            axis.ticks.hash(&mut hasher);
+            axis.format.map(|format| format as usize).hash(&mut hasher);
+            axis.range
+                .as_ref()
+                .map(|range| (range.start().to_bits(), range.end().to_bits()))
+                .hash(&mut hasher);
+        }
+
+        self.text_size.0.to_bits().hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    fn data_fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.axes_fingerprint().hash(&mut hasher);
+
+        for series in &self.series {
+            series.data.revision().hash(&mut hasher);
+            series.kind.hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    fn series_fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.data_fingerprint().hash(&mut hasher);
+        self.legend.hash(&mut hasher);
+
+        for series in &self.series {
+            series.label.hash(&mut hasher);
+            series.width.to_bits().hash(&mut hasher);
+            series.color.map(Color::into_rgba8).hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    fn color(&self, index: usize, style: &Style) -> Color {
+        self.series[index]
+            .color
+            .unwrap_or(style.palette[index % style.palette.len()])
+    }
+
+    fn draw_background(&self, frame: &mut Frame, plot: &Plot, style: &Style) {
+        let area = plot.area;
+        let text_size = Pixels(self.text_size.0);
+        let projection = plot.projection();
+
+        if let Some(background) = style.background {
+            frame.fill_rectangle(Point::ORIGIN, frame.size(), background);
+        }
+
+        for tick in &plot.x_ticks {
+            let x = projection.x(tick.position);
+
+            frame.stroke(
+                &Path::line(Point::new(x, area.y), Point::new(x, area.y + area.height)),
+                Stroke::default().with_color(style.grid).with_width(1.0),
+            );
+
+            frame.fill_text(Text {
+                content: tick.label.clone(),
+                position: Point::new(x, area.y + area.height + 6.0),
+                color: style.text,
+                size: text_size,
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Top,
+                ..Text::default()
+            });
+        }
+
+        for tick in &plot.y_ticks {
+            let y = projection.y(tick.position);
+
+            frame.stroke(
+                &Path::line(Point::new(area.x, y), Point::new(area.x + area.width, y)),
+                Stroke::default().with_color(style.grid).with_width(1.0),
+            );
+
+            frame.fill_text(Text {
+                content: tick.label.clone(),
+                position: Point::new(area.x - 6.0, y),
+                color: style.text,
+                size: text_size,
+                align_x: text::Alignment::Right,
+                align_y: alignment::Vertical::Center,
+                ..Text::default()
+            });
+        }
+
+        frame.stroke(
+            &Path::new(|builder| {
+                builder.move_to(Point::new(area.x, area.y));
+                builder.line_to(Point::new(area.x, area.y + area.height));
+                builder.line_to(Point::new(area.x + area.width, area.y + area.height));
+            }),
+            Stroke::default().with_color(style.axis).with_width(1.0),
+        );
+
+        if let Some(label) = &self.x_axis.label {
+            frame.fill_text(Text {
+                content: label.clone(),
+                position: Point::new(area.center_x(), frame.height() - 10.0),
+                color: style.text,
+                size: text_size,
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Bottom,
+                ..Text::default()
+            });
+        }
+
+        if let Some(label) = &self.y_axis.label {
+            frame.with_save(|frame| {
+                frame.translate(Vector::new(10.0, area.center_y()));
+                frame.rotate(-std::f32::consts::FRAC_PI_2);
+
+                frame.fill_text(Text {
+                    content: label.clone(),
+                    position: Point::ORIGIN,
+                    color: style.text,
+                    size: text_size,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Top,
+                    ..Text::default()
+                });
+            });
+        }
+    }
+
+    fn draw_series(&self, frame: &mut Frame, plot: &Plot, style: &Style) {
+        let projection = plot.projection();
+        let x_scale = self.x_axis.scale;
+        let y_scale = self.y_axis.scale;
+
+        let baseline = {
+            let zero = y_scale
+                .project(0.0)
+                .filter(|zero| plot.view.y.contains(*zero))
+                .unwrap_or(if plot.view.y.start > 0.0 {
+                    plot.view.y.start
+                } else {
+                    plot.view.y.end.min(0.0)
+                });
+
+            projection.y(zero)
+        };
+
+        let bars: Vec<usize> = (0..self.series.len())
+            .filter(|index| self.series[*index].kind == Kind::Bar)
+            .collect();
+
+        for (index, series) in self.series.iter().enumerate() {
+            let color = self.color(index, style);
+            let visible = series.data.visible(
+                x_scale.unproject(plot.view.x.start),
+                x_scale.unproject(plot.view.x.end),
+            );
+
+            let segments = segments(
+                series.data.points()[visible]
+                    .iter()
+                    .map(|&(x, y)| Some((x_scale.project(x)?, y_scale.project(y)?)))
+                    .map(|point| point.map(|(x, y)| projection.point(x, y))),
+            );
+
+            match series.kind {
+                Kind::Line => {
+                    for segment in segments {
+                        stroke_polyline(frame, &series::decimate(segment), color, series.width);
+                    }
+                }
+                Kind::Area => {
+                    for segment in segments {
+                        let points = series::decimate(segment);
+
+                        if let (Some(first), Some(last)) = (points.first(), points.last()) {
+                            let area = Path::new(|builder| {
+                                builder.move_to(Point::new(first.x, baseline));
+
+                                for point in &points {
+                                    builder.line_to(*point);
+                                }
+
+                                builder.line_to(Point::new(last.x, baseline));
+                                builder.close();
+                            });
+
+                            frame.fill(&area, color.scale_alpha(0.3));
+                        }
+
+                        stroke_polyline(frame, &points, color, series.width);
+                    }
+                }
+                Kind::Bar => {
+                    let points: Vec<Point> = segments.into_iter().flatten().collect();
+
+                    let slot = match (points.first(), points.last()) {
+                        (Some(first), Some(last)) if points.len() > 1 => {
+                            (last.x - first.x).abs() / (points.len() - 1) as f32
+                        }
+                        _ => plot.area.width * 0.1,
+                    };
+
+                    let group = slot * series.width.clamp(0.0, 1.0);
+                    let width = group / bars.len().max(1) as f32;
+                    let position = bars.iter().position(|bar| *bar == index).unwrap_or(0);
+                    let offset = -group / 2.0 + position as f32 * width;
+
+                    let path = Path::new(|builder| {
+                        if width < 1.0 {
+                            for point in series::decimate(points) {
+                                builder.rectangle(
+                                    Point::new(point.x.floor(), point.y.min(baseline)),
+                                    Size::new(1.0, (point.y - baseline).abs()),
+                                );
+                            }
+                        } else {
+                            for point in points {
+                                builder.rectangle(
+                                    Point::new(point.x + offset, point.y.min(baseline)),
+                                    Size::new(width, (point.y - baseline).abs()),
+                                );
+                            }
+                        }
+                    });
+
+                    frame.fill(&path, color);
+                }
+                Kind::Scatter => {
+                    let radius = series.width;
+                    let mut occupied = HashSet::new();
+
+                    let dots = Path::new(|builder| {
+                        for point in segments.into_iter().flatten() {
+                            if occupied.insert((point.x as i32, point.y as i32)) {
+                                builder.circle(point, radius);
+                            }
+                        }
+                    });
+
+                    frame.fill(&dots, color);
+                }
+            }
+        }
+    }
+
+    fn draw_legend(&self, frame: &mut Frame, plot: &Plot, style: &Style) {
+        const SWATCH: f32 = 10.0;
+        const PADDING: f32 = 8.0;
+
+        let entries: Vec<_> = self
+            .series
+            .iter()
+            .enumerate()
+            .filter(|(_, series)| !series.label.is_empty())
+            .collect();
+
+        if !self.legend || entries.is_empty() {
+            return;
+        }
+
+        let text_size = self.text_size.0;
+        let line_height = (text_size * 1.3).max(SWATCH);
+
+        let width = entries
+            .iter()
+            .map(|(_, series)| estimate_width(&series.label, text_size))
+            .fold(0.0, f32::max)
+            + SWATCH
+            + PADDING * 3.0;
+
+        let height = entries.len() as f32 * line_height + PADDING * 2.0;
+
+        let top_left = Point::new(
+            plot.area.x + plot.area.width - width - PADDING,
+            plot.area.y + PADDING,
+        );
+
+        frame.fill_rectangle(
+            top_left,
+            Size::new(width, height),
+            style.tooltip.scale_alpha(0.85),
+        );
+
+        for (row, (index, series)) in entries.into_iter().enumerate() {
+            let y = top_left.y + PADDING + row as f32 * line_height + line_height / 2.0;
+
+            frame.fill_rectangle(
+                Point::new(top_left.x + PADDING, y - SWATCH / 2.0),
+                Size::new(SWATCH, SWATCH),
+                self.color(index, style),
+            );
+
+            frame.fill_text(Text {
+                content: series.label.clone(),
+                position: Point::new(top_left.x + PADDING * 2.0 + SWATCH, y),
+                color: style.text,
+                size: self.text_size,
+                align_y: alignment::Vertical::Center,
+                ..Text::default()
+            });
+        }
+    }
+
+    fn draw_tooltip(
+        &self,
+        frame: &mut Frame,
+        plot: &Plot,
+        style: &Style,
+        hover: Hover,
+        bounds: Size,
+    ) {
+        const PADDING: f32 = 6.0;
+
+        let Some(series) = self.series.get(hover.series) else {
+            return;
+        };
+
+        let projection = plot.projection();
+        let color = self.color(hover.series, style);
+
+        let (Some(x), Some(y)) = (
+            self.x_axis.scale.project(hover.x),
+            self.y_axis.scale.project(hover.y),
+        ) else {
+            return;
+        };
+
+        let point = projection.point(x, y);
+
+        if !plot.area.contains(point) {
+            return;
+        }
+
+        frame.stroke(
+            &Path::line(
+                Point::new(point.x, plot.area.y),
+                Point::new(point.x, plot.area.y + plot.area.height),
+            ),
+            Stroke::default()
+                .with_color(style.axis.scale_alpha(0.5))
+                .with_width(1.0),
+        );
+
+        frame.fill(&Path::circle(point, 4.0), color);
+        frame.stroke(
+            &Path::circle(point, 4.0),
+            Stroke::default().with_color(style.tooltip).with_width(1.5),
+        );
+
+        let lines = [
+            series.label.clone(),
+            format!(
+                "{}, {}",
+                self.x_axis.describe(hover.x),
+                self.y_axis.describe(hover.y)
+            ),
+        ];
+
+        let text_size = self.text_size.0;
+        let line_height = text_size * 1.3;
+
+        let size = Size::new(
+            lines
+                .iter()
+                .map(|line| estimate_width(line, text_size))
+                .fold(0.0, f32::max)
+                + PADDING * 2.0,
+            line_height * lines.len() as f32 + PADDING * 2.0,
+        );
+
+        let x = if point.x + 12.0 + size.width > bounds.width {
+            point.x - 12.0 - size.width
+        } else {
+            point.x + 12.0
+        };
+
+        let y = (point.y - size.height / 2.0).clamp(0.0, (bounds.height - size.height).max(0.0));
+        let top_left = Point::new(x.max(0.0), y);
+
+        frame.fill_rectangle(top_left, size, style.tooltip);
+        frame.stroke_rectangle(
+            top_left,
+            size,
+            Stroke::default().with_color(color).with_width(1.0),
+        );
+
+        for (i, line) in lines.into_iter().enumerate() {
+            frame.fill_text(Text {
+                content: line,
+                position: Point::new(
+                    top_left.x + PADDING,
+                    top_left.y + PADDING + i as f32 * line_height,
+                ),
+                color: style.text,
+                size: self.text_size,
+                ..Text::default()
+            });
+        }
+    }
+
+    fn hover(&self, plot: &Plot, cursor: Point) -> Option<Hover> {
+        const DISTANCE: f32 = 16.0;
+
+        if !plot.area.contains(cursor) {
+            return None;
+        }
+
+        let projection = plot.projection();
+        let x_scale = self.x_axis.scale;
+        let y_scale = self.y_axis.scale;
+
+        let mut closest: Option<(f32, Hover)> = None;
+
+        for (index, series) in self.series.iter().enumerate() {
+            let points = series.data.points();
+            let visible = series.data.visible(
+                x_scale.unproject(plot.view.x.start),
+                x_scale.unproject(plot.view.x.end),
+            );
+
+            let candidates = if series.data.is_sorted() && series.kind != Kind::Scatter {
+                let target = x_scale.unproject(projection.inverse_x(cursor.x));
+                let next = points.partition_point(|(x, _)| *x < target);
+
+                next.saturating_sub(1)..(next + 1).min(points.len())
+            } else {
+                visible
+            };
+
+            for i in candidates {
+                let (x, y) = points[i];
+
+                let (Some(px), Some(py)) = (x_scale.project(x), y_scale.project(y)) else {
+                    continue;
+                };
+
+                let point = projection.point(px, py);
+
+                let is_close = match series.kind {
+                    Kind::Scatter => point.distance(cursor) <= series.width + DISTANCE / 2.0,
+                    Kind::Line | Kind::Area | Kind::Bar => (point.x - cursor.x).abs() <= DISTANCE,
+                };
+
+                let distance = point.distance(cursor);
+
+                if is_close && closest.is_none_or(|(closest, _)| distance < closest) {
+                    closest = Some((
+                        distance,
+                        Hover {
+                            series: index,
+                            index: i,
+                            x,
+                            y,
+                        },
+                    ));
+                }
+            }
+        }
+
+        closest.map(|(_, hover)| hover)
+    }
+
+    fn set_hovered(
+        &self,
+        state: &mut State,
+        hovered: Option<Hover>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if state.hovered == hovered {
+            return;
+        }
+
+        state.hovered = hovered;
+
+        if let Some(on_hover) = &self.on_hover {
+            shell.publish(on_hover(hovered));
+        }
+
+        if self.tooltip {
+            shell.request_redraw();
+        }
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for Chart<'_, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let view = self.view(state);
+        let plot = self.plot(bounds.size(), view);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(last) = state.drag {
+                    state.view = Some(plot.pan(*position - last));
+                    state.drag = Some(*position);
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+
+                let hovered = cursor
+                    .position_in(bounds)
+                    .and_then(|position| self.hover(&plot, position));
+
+                self.set_hovered(state, hovered, shell);
+            }
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                state.drag = None;
+                self.set_hovered(state, None, shell);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if self.interactive => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return;
+                };
+
+                if !plot.area.contains(position) {
+                    return;
+                }
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+
+                if click.kind() == mouse::click::Kind::Double {
+                    state.view = None;
+                    state.drag = None;
+
+                    shell.request_redraw();
+                } else {
+                    state.drag = cursor.position();
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if self.interactive => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return;
+                };
+
+                if !plot.area.contains(position) {
+                    return;
+                }
+
+                let lines = match *delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 20.0,
+                };
+
+                state.view = Some(plot.zoom(position, 0.9f64.powf(f64::from(lines))));
+
+                let hovered = self.hover(&self.plot(bounds.size(), self.view(state)), position);
+                self.set_hovered(state, hovered, shell);
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) if self.interactive => {
+                let position = *position - Vector::new(bounds.x, bounds.y);
+
+                if !plot.area.contains(position) || state.fingers.len() >= 2 {
+                    return;
+                }
+
+                state.fingers.push((*id, position));
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let position = *position - Vector::new(bounds.x, bounds.y);
+
+                let Some(finger) = state.fingers.iter().position(|(finger, _)| finger == id) else {
+                    return;
+                };
+
+                let previous = state.fingers[finger].1;
+                state.fingers[finger].1 = position;
+
+                let view = match state.fingers.as_slice() {
+                    [_] => plot.pan(position - previous),
+                    [(_, a), (_, b)] => {
+                        let other = if finger == 0 { *b } else { *a };
+
+                        let old_center = midpoint(previous, other);
+                        let new_center = midpoint(position, other);
+
+                        let old_distance = previous.distance(other);
+                        let new_distance = position.distance(other);
+
+                        let panned = self.plot(bounds.size(), plot.pan(new_center - old_center));
+
+                        if new_distance > 1.0 {
+                            panned.zoom(new_center, f64::from(old_distance / new_distance))
+                        } else {
+                            panned.view
+                        }
+                    }
+                    _ => return,
+                };
+
+                state.view = Some(view);
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                let count = state.fingers.len();
+                state.fingers.retain(|(finger, _)| finger != id);
+
+                if state.fingers.len() != count {
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if bounds.width < 1.0 || bounds.height < 1.0 {
+            return;
+        }
+
+        let style = theme.style(&self.class);
+        let view = self.view(state);
+        let plot = self.plot(bounds.size(), view);
+
+        {
+            let mut keys = state.keys.borrow_mut();
+
+            let background = Key {
+                size: bounds.size(),
+                view,
+                style,
+                fingerprint: self.axes_fingerprint(),
+            };
+
+            if keys.background.as_ref() != Some(&background) {
+                state.background.clear();
+                keys.background = Some(background);
+            }
+
+            let series = Key {
+                size: bounds.size(),
+                view,
+                style,
+                fingerprint: self.series_fingerprint(),
+            };
+
+            if keys.series.as_ref() != Some(&series) {
+                state.series.clear();
+                keys.series = Some(series);
+            }
+        }
+
+        let background = state.background.draw(renderer, bounds.size(), |frame| {
+            self.draw_background(frame, &plot, &style);
+        });
+
+        let series = state.series.draw(renderer, bounds.size(), |frame| {
+            frame.with_clip(plot.area, |frame| {
+                self.draw_series(frame, &plot, &style);
+            });
+
+            self.draw_legend(frame, &plot, &style);
+        });
+
+        let tooltip = state.hovered.filter(|_| self.tooltip).map(|hover| {
+            let mut frame = Frame::new(renderer, bounds.size());
+            self.draw_tooltip(&mut frame, &plot, &style, hover, bounds.size());
+
+            frame.into_geometry()
+        });
+
+        renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+            use crate::graphics::geometry::Renderer as _;
+
+            renderer.draw_geometry(background);
+            renderer.draw_geometry(series);
+
+            if let Some(tooltip) = tooltip {
+                renderer.draw_geometry(tooltip);
+            }
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if !self.interactive {
+            return mouse::Interaction::default();
+        }
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let bounds = layout.bounds();
+        let plot = self.plot(bounds.size(), self.view(state));
+
+        if cursor
+            .position_in(bounds)
+            .is_some_and(|position| plot.area.contains(position))
+        {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme> From<Chart<'a, Message, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    fn from(chart: Chart<'a, Message, Theme>) -> Self {
+        Self::new(chart)
+    }
+}
+
+/// A hovered point of a [`Chart`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hover {
+    /// The index of the hovered [`Series`].
+    pub series: usize,
+    /// The index of the hovered point in the [`Data`] of the [`Series`].
+    pub index: usize,
+    /// The `x` value of the hovered point.
+    pub x: f64,
+    /// The `y` value of the hovered point.
+    pub y: f64,
+}
+
+#[derive(Default)]
+struct State {
+    view: Option<View>,
+    drag: Option<Point>,
+    fingers: Vec<(touch::Finger, Point)>,
+    last_click: Option<mouse::Click>,
+    hovered: Option<Hover>,
+    fit: RefCell<Option<(u64, View)>>,
+    keys: RefCell<Keys>,
+    background: canvas::Cache,
+    series: canvas::Cache,
+}
+
+#[derive(Default)]
+struct Keys {
+    background: Option<Key>,
+    series: Option<Key>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Key {
+    size: Size,
+    view: View,
+    style: Style,
+    fingerprint: u64,
+}
+
+/// The visible region of a [`Chart`], in projected coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct View {
+    x: Range,
+    y: Range,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Range {
+    start: f64,
+    end: f64,
+}
+
+impl Range {
+    const EMPTY: Self = Self {
+        start: f64::INFINITY,
+        end: f64::NEG_INFINITY,
+    };
+
+    fn include(&mut self, value: f64) {
+        self.start = self.start.min(value);
+        self.end = self.end.max(value);
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        (self.start..=self.end).contains(&value)
+    }
+
+    fn span(&self) -> f64 {
+        self.end - self.start
+    }
+
+    fn padded(self, amount: f64) -> Self {
+        if self.start > self.end {
+            return Self {
+                start: 0.0,
+                end: 1.0,
+            };
+        }
+
+        let span = self.span();
+
+        if span <= f64::EPSILON * self.start.abs().max(1.0) {
+            let offset = (self.start.abs() * 0.1).max(0.5);
+
+            return Self {
+                start: self.start - offset,
+                end: self.end + offset,
+            };
+        }
+
+        Self {
+            start: self.start - span * amount,
+            end: self.end + span * amount,
+        }
+    }
+}
+
+fn fixed(axis: &Axis) -> Option<Range> {
+    let range = axis.range.as_ref()?;
+
+    let start = axis.scale.project(*range.start())?;
+    let end = axis.scale.project(*range.end())?;
+
+    (start < end).then_some(Range { start, end })
+}
+
+struct Plot {
+    area: Rectangle,
+    view: View,
+    x_ticks: Vec<axis::Tick>,
+    y_ticks: Vec<axis::Tick>,
+}
+
+impl Plot {
+    fn projection(&self) -> Projection {
+        Projection {
+            area: self.area,
+            view: self.view,
+        }
+    }
+
+    fn pan(&self, delta: Vector) -> View {
+        let x = f64::from(delta.x / self.area.width) * self.view.x.span();
+        let y = f64::from(delta.y / self.area.height) * self.view.y.span();
+
+        View {
+            x: Range {
+                start: self.view.x.start - x,
+                end: self.view.x.end - x,
+            },
+            y: Range {
+                start: self.view.y.start + y,
+                end: self.view.y.end + y,
+            },
+        }
+    }
+
+    fn zoom(&self, center: Point, factor: f64) -> View {
+        let projection = self.projection();
+
+        let zoom = |range: Range, center: f64| {
+            let span = range.span() * factor;
+
+            if span <= f64::EPSILON * center.abs().max(1.0) * 1e3 || !span.is_finite() {
+                return range;
+            }
+
+            Range {
+                start: center + (range.start - center) * factor,
+                end: center + (range.end - center) * factor,
+            }
+        };
+
+        View {
+            x: zoom(self.view.x, projection.inverse_x(center.x)),
+            y: zoom(self.view.y, projection.inverse_y(center.y)),
+        }
+    }
+}
+
+struct Projection {
+    area: Rectangle,
+    view: View,
+}
+
+impl Projection {
+    const LIMIT: f32 = 1e6;
+
+    fn x(&self, x: f64) -> f32 {
+        let ratio = (x - self.view.x.start) / self.view.x.span();
+
+        (self.area.x + ratio as f32 * self.area.width).clamp(-Self::LIMIT, Self::LIMIT)
+    }
+
+    fn y(&self, y: f64) -> f32 {
+        let ratio = (y - self.view.y.start) / self.view.y.span();
+
+        (self.area.y + self.area.height - ratio as f32 * self.area.height)
+            .clamp(-Self::LIMIT, Self::LIMIT)
+    }
+
+    fn point(&self, x: f64, y: f64) -> Point {
+        Point::new(self.x(x), self.y(y))
+    }
+
+    fn inverse_x(&self, x: f32) -> f64 {
+        self.view.x.start + f64::from((x - self.area.x) / self.area.width) * self.view.x.span()
+    }
+
+    fn inverse_y(&self, y: f32) -> f64 {
+        self.view.y.start
+            + f64::from((self.area.y + self.area.height - y) / self.area.height)
+                * self.view.y.span()
+    }
+}
+
+/// Splits a sequence of points into contiguous segments at the points
+/// that cannot be displayed.
+fn segments(points: impl Iterator<Item = Option<Point>>) -> Vec<Vec<Point>> {
+    let mut segments = vec![Vec::new()];
+
+    for point in points {
+        match point {
+            Some(point) => segments.last_mut().expect("Last segment").push(point),
+            None => segments.push(Vec::new()),
+        }
+    }
+
+    segments.retain(|segment| !segment.is_empty());
+    segments
+}
+
+fn stroke_polyline(frame: &mut Frame, points: &[Point], color: Color, width: f32) {
+    let Some((first, rest)) = points.split_first() else {
+        return;
+    };
+
+    let line = Path::new(|builder| {
+        builder.move_to(*first);
+
+        for point in rest {
+            builder.line_to(*point);
+        }
+    });
+
+    frame.stroke(
+        &line,
+        Stroke {
+            line_join: canvas::LineJoin::Round,
+            ..Stroke::default().with_color(color).with_width(width)
+        },
+    );
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+/// Estimates the width of a label without shaping it.
+fn estimate_width(label: &str, text_size: f32) -> f32 {
+    label.chars().count() as f32 * text_size * 0.6
+}
+
+/// The appearance of a [`Chart`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The background [`Color`] of the [`Chart`], if any.
+    pub background: Option<Color>,
+    /// The [`Color`] of the labels of the [`Chart`].
+    pub text: Color,
+    /// The [`Color`] of the axes of the [`Chart`].
+    pub axis: Color,
+    /// The [`Color`] of the grid lines of the [`Chart`].
+    pub grid: Color,
+    /// The background [`Color`] of the legend and the tooltips of the [`Chart`].
+    pub tooltip: Color,
+    /// The colors used for [`Series`] without an explicit [`Color`].
+    pub palette: [Color; 6],
+}
+
+/// The theme catalog of a [`Chart`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Chart`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Chart`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.palette();
+
+    Style {
+        background: None,
+        text: palette.background.base.text,
+        axis: palette.background.strong.color,
+        grid: palette.background.weak.color,
+        tooltip: palette.background.base.color,
+        palette: [
+            palette.primary.base.color,
+            palette.success.base.color,
+            palette.danger.base.color,
+            palette.warning.base.color,
+            palette.secondary.strong.color,
+            palette.primary.weak.color,
+        ],
+    }
+}
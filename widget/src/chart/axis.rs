@@ -0,0 +1,546 @@
+//! Map data values to the edges of a [`Chart`] and label them.
+//!
+//! [`Chart`]: super::Chart
+use std::ops::RangeInclusive;
+
+/// An axis of a [`Chart`].
+///
+/// [`Chart`]: super::Chart
+#[derive(Debug, Clone)]
+pub struct Axis {
+    pub(crate) scale: Scale,
+    pub(crate) label: Option<String>,
+    pub(crate) range: Option<RangeInclusive<f64>>,
+    pub(crate) ticks: usize,
+    pub(crate) format: Option<fn(f64) -> String>,
+}
+
+impl Axis {
+    /// The default amount of ticks an [`Axis`] will try to produce.
+    pub const DEFAULT_TICKS: usize = 6;
+
+    /// Creates a new [`Axis`] with the given [`Scale`].
+    pub fn new(scale: Scale) -> Self {
+        Self {
+            scale,
+            label: None,
+            range: None,
+            ticks: Self::DEFAULT_TICKS,
+            format: None,
+        }
+    }
+
+    /// Creates a new [`Axis`] with a [`Scale::Linear`].
+    pub fn linear() -> Self {
+        Self::new(Scale::Linear)
+    }
+
+    /// Creates a new [`Axis`] with a [`Scale::Logarithmic`].
+    pub fn logarithmic() -> Self {
+        Self::new(Scale::Logarithmic)
+    }
+
+    /// Creates a new [`Axis`] with a [`Scale::Time`].
+    pub fn time() -> Self {
+        Self::new(Scale::Time)
+    }
+
+    /// Sets the label of the [`Axis`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the initial range of the [`Axis`].
+    ///
+    /// By default, the range is computed to fit all the data of a [`Chart`].
+    ///
+    /// [`Chart`]: super::Chart
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Sets the amount of ticks the [`Axis`] will try to produce.
+    ///
+    /// The actual amount of ticks may differ slightly, since ticks are
+    /// always placed at round values.
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks.max(1);
+        self
+    }
+
+    /// Sets the function used to format the tick labels of the [`Axis`].
+    pub fn format(mut self, format: fn(f64) -> String) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Returns the [`Scale`] of the [`Axis`].
+    pub fn scale(&self) -> Scale {
+        self.scale
+    }
+
+    /// Generates the ticks of the [`Axis`] between the given projected
+    /// bounds.
+    pub(crate) fn generate(&self, start: f64, end: f64) -> Vec<Tick> {
+        let (start, end) = (start.min(end), start.max(end));
+
+        if !start.is_finite() || !end.is_finite() || end - start <= f64::EPSILON {
+            return Vec::new();
+        }
+
+        let mut ticks = match self.scale {
+            Scale::Linear => linear(start, end, self.ticks),
+            Scale::Logarithmic => logarithmic(start, end, self.ticks),
+            Scale::Time => time(start, end, self.ticks),
+        };
+
+        if let Some(format) = self.format {
+            for tick in &mut ticks {
+                tick.label = format(self.scale.unproject(tick.position));
+            }
+        }
+
+        ticks
+    }
+
+    /// Formats a data value of the [`Axis`] with full precision.
+    pub(crate) fn describe(&self, value: f64) -> String {
+        if let Some(format) = self.format {
+            return format(value);
+        }
+
+        match self.scale {
+            Scale::Linear | Scale::Logarithmic => {
+                let precision = if value == 0.0 || !value.is_finite() {
+                    0
+                } else {
+                    (3.0 - value.abs().log10().floor()).clamp(0.0, 6.0) as usize
+                };
+
+                let label = format_number(value, precision);
+
+                if label.contains('.') && !label.contains('e') {
+                    label.trim_end_matches('0').trim_end_matches('.').to_owned()
+                } else {
+                    label
+                }
+            }
+            Scale::Time => {
+                let time = value.floor() as i64;
+                let (year, month, day) = civil(time.div_euclid(DAY));
+                let (hour, minute, second) = clock(time);
+
+                format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}")
+            }
+        }
+    }
+}
+
+impl Default for Axis {
+    fn default() -> Self {
+        Self::linear()
+    }
+}
+
+/// The scale of an [`Axis`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Scale {
+    /// Values are spaced evenly.
+    #[default]
+    Linear,
+
+    /// Values are spaced by orders of magnitude.
+    ///
+    /// Non-positive values cannot be displayed and are skipped.
+    Logarithmic,
+
+    /// Values are seconds since the Unix epoch, displayed in UTC.
+    Time,
+}
+
+impl Scale {
+    /// Projects a data value into the linear space of the [`Scale`].
+    ///
+    /// Returns `None` if the value cannot be displayed.
+    pub fn project(self, value: f64) -> Option<f64> {
+        match self {
+            Scale::Linear | Scale::Time => value.is_finite().then_some(value),
+            Scale::Logarithmic => (value > 0.0 && value.is_finite()).then(|| value.log10()),
+        }
+    }
+
+    /// Turns a projected value back into a data value.
+    pub fn unproject(self, position: f64) -> f64 {
+        match self {
+            Scale::Linear | Scale::Time => position,
+            Scale::Logarithmic => 10f64.powf(position),
+        }
+    }
+}
+
+/// A labeled tick of an [`Axis`].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Tick {
+    /// The projected position of the tick.
+    pub position: f64,
+    /// The label of the tick.
+    pub label: String,
+}
+
+fn linear(start: f64, end: f64, count: usize) -> Vec<Tick> {
+    let step = nice_step(end - start, count);
+    let precision = precision(step);
+
+    steps(start, end, step)
+        .map(|value| Tick {
+            position: value,
+            label: format_number(value, precision),
+        })
+        .collect()
+}
+
+fn logarithmic(start: f64, end: f64, count: usize) -> Vec<Tick> {
+    let first = start.floor() as i32;
+    let last = end.ceil() as i32;
+    let decades = (last - first).max(1) as usize;
+
+    if decades > count {
+        let stride = decades.div_ceil(count) as i32;
+
+        return (first..=last)
+            .filter(|exponent| exponent.rem_euclid(stride) == 0)
+            .map(f64::from)
+            .filter(|position| (start..=end).contains(position))
+            .map(|position| Tick {
+                position,
+                label: format_power(position as i32),
+            })
+            .collect();
+    }
+
+    let multiples: &[f64] = if decades * 3 <= count {
+        &[1.0, 2.0, 5.0]
+    } else {
+        &[1.0]
+    };
+
+    (first..=last)
+        .flat_map(|exponent| {
+            multiples.iter().map(move |multiple| {
+                let value = multiple * 10f64.powi(exponent);
+
+                Tick {
+                    position: value.log10(),
+                    label: if *multiple == 1.0 {
+                        format_power(exponent)
+                    } else {
+                        format_number(value, (-exponent).max(0) as usize)
+                    },
+                }
+            })
+        })
+        .filter(|tick| tick.position >= start - 1e-9 && tick.position <= end + 1e-9)
+        .collect()
+}
+
+const SECOND: i64 = 1;
+const MINUTE: i64 = 60 * SECOND;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Interval {
+    Seconds(i64),
+    Months(i64),
+}
+
+impl Interval {
+    const ALL: &[Interval] = &[
+        Interval::Seconds(SECOND),
+        Interval::Seconds(2 * SECOND),
+        Interval::Seconds(5 * SECOND),
+        Interval::Seconds(10 * SECOND),
+        Interval::Seconds(15 * SECOND),
+        Interval::Seconds(30 * SECOND),
+        Interval::Seconds(MINUTE),
+        Interval::Seconds(2 * MINUTE),
+        Interval::Seconds(5 * MINUTE),
+        Interval::Seconds(10 * MINUTE),
+        Interval::Seconds(15 * MINUTE),
+        Interval::Seconds(30 * MINUTE),
+        Interval::Seconds(HOUR),
+        Interval::Seconds(2 * HOUR),
+        Interval::Seconds(3 * HOUR),
+        Interval::Seconds(6 * HOUR),
+        Interval::Seconds(12 * HOUR),
+        Interval::Seconds(DAY),
+        Interval::Seconds(2 * DAY),
+        Interval::Seconds(7 * DAY),
+        Interval::Months(1),
+        Interval::Months(3),
+        Interval::Months(6),
+        Interval::Months(12),
+    ];
+
+    fn approximate_seconds(self) -> f64 {
+        match self {
+            Interval::Seconds(seconds) => seconds as f64,
+            Interval::Months(months) => months as f64 * 30.44 * DAY as f64,
+        }
+    }
+}
+
+fn time(start: f64, end: f64, count: usize) -> Vec<Tick> {
+    let span = end - start;
+    let target = span / count as f64;
+
+    if target < 1.0 {
+        return linear(start, end, count);
+    }
+
+    let interval = Interval::ALL
+        .iter()
+        .copied()
+        .find(|interval| interval.approximate_seconds() >= target);
+
+    match interval {
+        Some(Interval::Seconds(seconds)) => {
+            let format: fn(i64) -> String = if seconds < MINUTE {
+                |time| {
+                    let (hour, minute, second) = clock(time);
+                    format!("{hour:02}:{minute:02}:{second:02}")
+                }
+            } else if seconds < DAY {
+                |time| {
+                    let (hour, minute, _) = clock(time);
+                    format!("{hour:02}:{minute:02}")
+                }
+            } else {
+                |time| {
+                    let (year, month, day) = civil(time.div_euclid(DAY));
+                    format!("{year:04}-{month:02}-{day:02}")
+                }
+            };
+
+            steps(start, end, seconds as f64)
+                .map(|position| Tick {
+                    position,
+                    label: format(position as i64),
+                })
+                .collect()
+        }
+        Some(Interval::Months(months)) => {
+            let (year, month, _) = civil((start as i64).div_euclid(DAY));
+            let mut index = year * 12 + i64::from(month) - 1;
+            index -= index.rem_euclid(months);
+
+            let mut ticks = Vec::new();
+
+            loop {
+                let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u32 + 1);
+                let position = (days_from_civil(year, month, 1) * DAY) as f64;
+
+                if position > end {
+                    break;
+                }
+
+                if position >= start {
+                    ticks.push(Tick {
+                        position,
+                        label: if months < 12 {
+                            format!("{year:04}-{month:02}")
+                        } else {
+                            format!("{year:04}")
+                        },
+                    });
+                }
+
+                index += months;
+            }
+
+            ticks
+        }
+        None => {
+            let years = nice_step(span / (365.25 * DAY as f64), count).max(1.0);
+            let (first, _, _) = civil((start as i64).div_euclid(DAY));
+            let mut year = (first as f64 / years).floor() * years;
+            let mut ticks = Vec::new();
+
+            loop {
+                let position = (days_from_civil(year as i64, 1, 1) * DAY) as f64;
+
+                if position > end {
+                    break;
+                }
+
+                if position >= start {
+                    ticks.push(Tick {
+                        position,
+                        label: format!("{year:04}"),
+                    });
+                }
+
+                year += years;
+            }
+
+            ticks
+        }
+    }
+}
+
+fn steps(start: f64, end: f64, step: f64) -> impl Iterator<Item = f64> {
+    let first = (start / step - 1e-9).ceil() as i64;
+    let last = (end / step + 1e-9).floor() as i64;
+
+    (first..=last).map(move |i| i as f64 * step)
+}
+
+/// Returns a round step that splits the given span into roughly
+/// `count` pieces.
+fn nice_step(span: f64, count: usize) -> f64 {
+    let raw = span / count.max(1) as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    nice * magnitude
+}
+
+fn precision(step: f64) -> usize {
+    (-step.log10().floor()).max(0.0) as usize
+}
+
+fn format_number(value: f64, precision: usize) -> String {
+    let magnitude = value.abs();
+
+    if magnitude != 0.0 && !(1e-6..1e9).contains(&magnitude) {
+        return format!("{value:e}");
+    }
+
+    let label = format!("{value:.precision$}");
+
+    if label.starts_with('-') && label.trim_start_matches(['-', '0', '.']).is_empty() {
+        label[1..].to_owned()
+    } else {
+        label
+    }
+}
+
+fn format_power(exponent: i32) -> String {
+    if (-3..=6).contains(&exponent) {
+        format_number(10f64.powi(exponent), (-exponent).max(0) as usize)
+    } else {
+        format!("1e{exponent}")
+    }
+}
+
+fn clock(time: i64) -> (i64, i64, i64) {
+    let seconds = time.rem_euclid(DAY);
+
+    (seconds / HOUR, seconds % HOUR / MINUTE, seconds % MINUTE)
+}
+
+/// Converts days since the Unix epoch into a `(year, month, day)` date.
+fn civil(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Converts a `(year, month, day)` date into days since the Unix epoch.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (i64::from(month) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(ticks: Vec<Tick>) -> Vec<String> {
+        ticks.into_iter().map(|tick| tick.label).collect()
+    }
+
+    #[test]
+    fn linear_ticks() {
+        let axis = Axis::linear().ticks(5);
+
+        assert_eq!(
+            labels(axis.generate(0.0, 10.0)),
+            ["0", "2", "4", "6", "8", "10"]
+        );
+
+        assert_eq!(labels(axis.generate(-0.3, 0.3)), ["-0.2", "0.0", "0.2"]);
+
+        assert!(axis.generate(1.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn logarithmic_ticks() {
+        let axis = Axis::logarithmic().ticks(6);
+
+        assert_eq!(
+            labels(axis.generate(0.0, 2.0)),
+            ["1", "2", "5", "10", "20", "50", "100"]
+        );
+
+        assert_eq!(
+            labels(axis.generate(-12.0, 12.0)),
+            ["1e-12", "1e-8", "1e-4", "1", "10000", "1e8", "1e12"]
+        );
+    }
+
+    #[test]
+    fn time_ticks() {
+        let axis = Axis::time().ticks(4);
+
+        // 2024-03-01T00:00:00Z to 2024-03-01T02:00:00Z
+        assert_eq!(
+            labels(axis.generate(1_709_251_200.0, 1_709_258_400.0)),
+            ["00:00", "00:30", "01:00", "01:30", "02:00"]
+        );
+
+        // 2023-11-15 to 2024-05-15
+        assert_eq!(
+            labels(axis.generate(1_700_006_400.0, 1_715_731_200.0)),
+            ["2024-01", "2024-04"]
+        );
+    }
+
+    #[test]
+    fn civil_dates() {
+        for days in [-719_468, -1, 0, 11_016, 19_783, 2_932_896] {
+            let (year, month, day) = civil(days);
+
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+
+        assert_eq!(civil(0), (1970, 1, 1));
+        assert_eq!(civil(19_782), (2024, 2, 29));
+    }
+}
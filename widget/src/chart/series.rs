@@ -0,0 +1,279 @@
+//! Plot data in a [`Chart`] as lines, areas, bars, or scatter points.
+//!
+//! [`Chart`]: super::Chart
+use crate::core::{Color, Point};
+
+use std::sync::atomic::{self, AtomicU64};
+
+/// The points of a [`Series`].
+///
+/// [`Data`] keeps track of its changes, so a [`Chart`] only redraws
+/// a [`Series`] when its [`Data`] is modified.
+///
+/// [`Chart`]: super::Chart
+#[derive(Debug, Clone)]
+pub struct Data {
+    points: Vec<(f64, f64)>,
+    is_sorted: bool,
+    revision: u64,
+}
+
+impl Data {
+    /// Creates a new [`Data`] from the given `(x, y)` points.
+    pub fn new(points: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        let is_sorted = points.is_sorted_by(|a, b| a.0 <= b.0);
+
+        Self {
+            points,
+            is_sorted,
+            revision: next_revision(),
+        }
+    }
+
+    /// Returns the points of the [`Data`].
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Returns the amount of points in the [`Data`].
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns true if the [`Data`] has no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Appends a point to the [`Data`].
+    pub fn push(&mut self, x: f64, y: f64) {
+        self.is_sorted &= self.points.last().is_none_or(|last| last.0 <= x);
+        self.points.push((x, y));
+        self.revision = next_revision();
+    }
+
+    /// Appends multiple points to the [`Data`].
+    pub fn extend(&mut self, points: impl IntoIterator<Item = (f64, f64)>) {
+        for (x, y) in points {
+            self.is_sorted &= self.points.last().is_none_or(|last| last.0 <= x);
+            self.points.push((x, y));
+        }
+
+        self.revision = next_revision();
+    }
+
+    /// Removes the first `amount` points of the [`Data`].
+    ///
+    /// This is useful to keep a sliding window over a stream of data.
+    pub fn truncate_front(&mut self, amount: usize) {
+        let _ = self.points.drain(..amount.min(self.points.len()));
+        self.revision = next_revision();
+    }
+
+    /// Removes all the points of the [`Data`].
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.is_sorted = true;
+        self.revision = next_revision();
+    }
+
+    pub(crate) fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Returns the range of indices whose `x` falls within the given
+    /// bounds, including one extra point on each side.
+    ///
+    /// Unsorted data is never culled.
+    pub(crate) fn visible(&self, start: f64, end: f64) -> std::ops::Range<usize> {
+        if !self.is_sorted {
+            return 0..self.points.len();
+        }
+
+        let first = self.points.partition_point(|(x, _)| *x < start);
+        let last = self.points.partition_point(|(x, _)| *x <= end);
+
+        first.saturating_sub(1)..(last + 1).min(self.points.len())
+    }
+
+    pub(crate) fn is_sorted(&self) -> bool {
+        self.is_sorted
+    }
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl FromIterator<(f64, f64)> for Data {
+    fn from_iter<T: IntoIterator<Item = (f64, f64)>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+fn next_revision() -> u64 {
+    static REVISION: AtomicU64 = AtomicU64::new(0);
+
+    REVISION.fetch_add(1, atomic::Ordering::Relaxed)
+}
+
+/// A set of [`Data`] plotted in a [`Chart`].
+///
+/// [`Chart`]: super::Chart
+#[derive(Debug, Clone)]
+pub struct Series<'a> {
+    pub(crate) label: String,
+    pub(crate) data: &'a Data,
+    pub(crate) kind: Kind,
+    pub(crate) color: Option<Color>,
+    pub(crate) width: f32,
+}
+
+impl<'a> Series<'a> {
+    /// Creates a new [`Series`] of the given [`Kind`].
+    pub fn new(kind: Kind, label: impl Into<String>, data: &'a Data) -> Self {
+        Self {
+            label: label.into(),
+            data,
+            kind,
+            color: None,
+            width: match kind {
+                Kind::Line | Kind::Area => 2.0,
+                Kind::Bar => 0.8,
+                Kind::Scatter => 3.0,
+            },
+        }
+    }
+
+    /// Creates a new [`Kind::Line`] series.
+    pub fn line(label: impl Into<String>, data: &'a Data) -> Self {
+        Self::new(Kind::Line, label, data)
+    }
+
+    /// Creates a new [`Kind::Area`] series.
+    pub fn area(label: impl Into<String>, data: &'a Data) -> Self {
+        Self::new(Kind::Area, label, data)
+    }
+
+    /// Creates a new [`Kind::Bar`] series.
+    pub fn bar(label: impl Into<String>, data: &'a Data) -> Self {
+        Self::new(Kind::Bar, label, data)
+    }
+
+    /// Creates a new [`Kind::Scatter`] series.
+    pub fn scatter(label: impl Into<String>, data: &'a Data) -> Self {
+        Self::new(Kind::Scatter, label, data)
+    }
+
+    /// Sets the [`Color`] of the [`Series`].
+    ///
+    /// By default, a color of the palette of the [`Chart`] is used.
+    ///
+    /// [`Chart`]: super::Chart
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the width of the [`Series`].
+    ///
+    /// - For lines and areas, this is the width of the stroke.
+    /// - For bars, this is the fraction of the available space each bar takes.
+    /// - For scatter plots, this is the radius of each point.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+/// The kind of a [`Series`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// Points connected by straight lines.
+    Line,
+    /// A line with the region below it filled.
+    Area,
+    /// A bar for each point.
+    Bar,
+    /// A dot for each point.
+    Scatter,
+}
+
+/// Reduces the given screen points to at most four points per pixel column;
+/// keeping the first, lowest, highest, and last point of each column.
+///
+/// The resulting polyline is visually identical to the original one.
+pub(crate) fn decimate(points: impl IntoIterator<Item = Point>) -> Vec<Point> {
+    let mut output = Vec::new();
+    let mut column: Option<Column> = None;
+
+    for point in points {
+        let x = point.x.floor();
+
+        match &mut column {
+            Some(current) if current.x == x => current.add(point),
+            _ => {
+                if let Some(column) = column.take() {
+                    column.flush(&mut output);
+                }
+
+                column = Some(Column::new(x, point));
+            }
+        }
+    }
+
+    if let Some(column) = column {
+        column.flush(&mut output);
+    }
+
+    output
+}
+
+struct Column {
+    x: f32,
+    first: Point,
+    min: Point,
+    max: Point,
+    last: Point,
+}
+
+impl Column {
+    fn new(x: f32, point: Point) -> Self {
+        Self {
+            x,
+            first: point,
+            min: point,
+            max: point,
+            last: point,
+        }
+    }
+
+    fn add(&mut self, point: Point) {
+        if point.y < self.min.y {
+            self.min = point;
+        }
+
+        if point.y > self.max.y {
+            self.max = point;
+        }
+
+        self.last = point;
+    }
+
+    fn flush(self, output: &mut Vec<Point>) {
+        let (low, high) = if self.min.x <= self.max.x {
+            (self.min, self.max)
+        } else {
+            (self.max, self.min)
+        };
+
+        for point in [self.first, low, high, self.last] {
+            if output.last() != Some(&point) {
+                output.push(point);
+            }
+        }
+    }
+}
@@ -401,7 +401,7 @@ where
         let mut y = 0.0;
 
         let align_factor = match self.column.align {
-            Alignment::Start => 0.0,
+            Alignment::Start | Alignment::Baseline | Alignment::LastBaseline => 0.0,
             Alignment::Center => 2.0,
             Alignment::End => 1.0,
         };
@@ -0,0 +1,1083 @@
+//! Diff views display the differences between two texts.
+//!
+//! Lines are compared as a whole first; then, the words of every pair of
+//! modified lines are compared to highlight what actually changed.
+//!
+//! You can enable the `highlighter` feature for syntax highlighting.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! #
+//! use iced::widget::diff_view;
+//! use iced::Theme;
+//!
+//! struct State {
+//!    diff: diff_view::Content,
+//! }
+//!
+//! enum Message {
+//!     Diff(diff_view::Action),
+//! }
+//!
+//! impl State {
+//!     pub fn new() -> Self {
+//!         Self {
+//!             diff: diff_view::Content::new("Hello, world!", "Hello, iced!"),
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         diff_view(&self.diff, Theme::TokyoNight)
+//!             .map(Message::Diff)
+//!             .into()
+//!     }
+//!
+//!     fn update(state: &mut State, message: Message) {
+//!         match message {
+//!             Message::Diff(action) => {
+//!                 state.diff.perform(action);
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::font::Font;
+use crate::core::text::Highlighter;
+use crate::core::text::highlighter::Format;
+use crate::core::theme::palette;
+use crate::core::{self, Color, Element, Length, Pixels, Theme};
+use crate::{column, container, mouse_area, rich_text, row, scrollable, span, text};
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+pub use core::text::Highlight;
+
+/// The differences between two texts.
+#[derive(Debug, Clone, Default)]
+pub struct Content {
+    rows: Vec<Row>,
+    expanded: HashSet<usize>,
+}
+
+impl Content {
+    /// Computes the differences between the `old` and the `new` text.
+    pub fn new(old: &str, new: &str) -> Self {
+        Self {
+            rows: rows(old, new),
+            expanded: HashSet::new(),
+        }
+    }
+
+    /// Returns the [`Row`]s of the [`Content`].
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// Returns the amount of lines added in the new text.
+    pub fn additions(&self) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| matches!(row.kind(), Kind::Added | Kind::Modified))
+            .count()
+    }
+
+    /// Returns the amount of lines removed from the old text.
+    pub fn deletions(&self) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| matches!(row.kind(), Kind::Removed | Kind::Modified))
+            .count()
+    }
+
+    /// Returns whether both texts are the same.
+    pub fn is_empty(&self) -> bool {
+        self.rows.iter().all(|row| row.kind() == Kind::Unchanged)
+    }
+
+    /// Performs an [`Action`] on the [`Content`].
+    pub fn perform(&mut self, action: Action) {
+        match action {
+            Action::Toggle(hunk) => {
+                if !self.expanded.remove(&hunk) {
+                    let _ = self.expanded.insert(hunk);
+                }
+            }
+            Action::ExpandAll => {
+                self.expanded.extend(0..self.rows.len());
+            }
+            Action::CollapseAll => {
+                self.expanded.clear();
+            }
+        }
+    }
+
+    /// Highlights both texts of the [`Content`] with the given
+    /// [`Highlighter`] and a strategy to turn its highlights into some
+    /// text format.
+    ///
+    /// Any previous highlighting is replaced.
+    pub fn highlight_with<H: Highlighter>(
+        &mut self,
+        settings: &H::Settings,
+        to_format: impl Fn(&H::Highlight) -> Format<Font>,
+    ) {
+        fn highlight<H: Highlighter>(
+            lines: Vec<&mut Line>,
+            settings: &H::Settings,
+            to_format: &impl Fn(&H::Highlight) -> Format<Font>,
+        ) {
+            let mut highlighter = H::new(settings);
+            highlighter.prepare(lines.iter().map(|line| line.text.as_str()));
+
+            for line in lines {
+                line.highlights = highlighter
+                    .highlight_line(&line.text)
+                    .map(|(range, highlight)| (range, to_format(&highlight)))
+                    .collect();
+            }
+        }
+
+        highlight::<H>(
+            self.rows
+                .iter_mut()
+                .filter_map(|row| row.old.as_mut())
+                .collect(),
+            settings,
+            &to_format,
+        );
+
+        highlight::<H>(
+            self.rows
+                .iter_mut()
+                .filter_map(|row| row.new.as_mut())
+                .collect(),
+            settings,
+            &to_format,
+        );
+    }
+
+    /// Highlights both texts of the [`Content`] with the built-in
+    /// highlighter for the given syntax and [`Theme`].
+    ///
+    /// [`Theme`]: iced_highlighter::Theme
+    #[cfg(feature = "highlighter")]
+    pub fn highlight(&mut self, syntax: &str, theme: iced_highlighter::Theme) {
+        self.highlight_with::<iced_highlighter::Highlighter>(
+            &iced_highlighter::Settings {
+                theme,
+                token: syntax.to_owned(),
+            },
+            iced_highlighter::Highlight::to_format,
+        );
+    }
+}
+
+/// An interaction with a [`Content`] produced by [`view`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Expands or collapses the hunk of unchanged lines starting at the
+    /// given row.
+    Toggle(usize),
+    /// Expands every hunk of unchanged lines.
+    ExpandAll,
+    /// Collapses every hunk of unchanged lines.
+    CollapseAll,
+}
+
+/// A pair of lines displayed at the same height in a side-by-side diff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    /// The line of the old text, if any.
+    pub old: Option<Line>,
+    /// The line of the new text, if any.
+    pub new: Option<Line>,
+}
+
+impl Row {
+    /// Returns the [`Kind`] of change of the [`Row`].
+    pub fn kind(&self) -> Kind {
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) if old.text == new.text => Kind::Unchanged,
+            (Some(_), Some(_)) => Kind::Modified,
+            (Some(_), None) => Kind::Removed,
+            (None, _) => Kind::Added,
+        }
+    }
+}
+
+/// The kind of change of a [`Row`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// The line is present in both texts.
+    Unchanged,
+    /// The line is only present in the new text.
+    Added,
+    /// The line is only present in the old text.
+    Removed,
+    /// The line has been replaced with a different one.
+    Modified,
+}
+
+/// A line of text in a [`Row`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
+    /// The number of the line in its text, starting at 1.
+    pub number: usize,
+    /// The text of the line, without its ending.
+    pub text: String,
+    /// The byte ranges of the text that changed, if the line was modified.
+    pub changes: Vec<Range<usize>>,
+    highlights: Vec<(Range<usize>, Format<Font>)>,
+}
+
+impl Line {
+    fn new(number: usize, text: &str) -> Self {
+        Self {
+            number,
+            text: text.to_owned(),
+            changes: Vec::new(),
+            highlights: Vec::new(),
+        }
+    }
+
+    /// Returns the [`rich_text()`] spans ready to be used for the given
+    /// change [`Highlight`].
+    pub fn spans(&self, change: Highlight) -> Vec<text::Span<'_, (), Font>> {
+        let mut bounds: Vec<usize> = self
+            .highlights
+            .iter()
+            .map(|(range, _)| range)
+            .chain(&self.changes)
+            .flat_map(|range| [range.start, range.end])
+            .chain([0, self.text.len()])
+            .filter(|bound| *bound <= self.text.len())
+            .collect();
+
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        bounds
+            .windows(2)
+            .filter_map(|window| {
+                let (start, end) = (window[0], window[1]);
+                let fragment = self.text.get(start..end)?;
+
+                let format = self
+                    .highlights
+                    .iter()
+                    .rev()
+                    .find(|(range, _)| range.contains(&start))
+                    .map(|(_, format)| *format);
+
+                let is_changed = self.changes.iter().any(|range| range.contains(&start));
+
+                let span = span(fragment)
+                    .color_maybe(format.and_then(|format| format.color))
+                    .font_maybe(format.and_then(|format| format.font));
+
+                Some(if is_changed {
+                    span.background(change.background).border(change.border)
+                } else {
+                    span
+                })
+            })
+            .collect()
+    }
+}
+
+/// The way of laying out the texts of a [`Content`] in [`view`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// The old text on the left and the new text on the right.
+    #[default]
+    SideBySide,
+    /// Both texts interleaved in a single column.
+    Unified,
+}
+
+/// Configuration controlling diff rendering in [`view`].
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    /// The text size.
+    pub text_size: Pixels,
+    /// The [`Mode`] of the diff.
+    pub mode: Mode,
+    /// The amount of unchanged lines shown around every change.
+    ///
+    /// The rest of unchanged lines are collapsed.
+    pub context: usize,
+    /// The styling of the diff.
+    pub style: Style,
+}
+
+impl Settings {
+    /// Creates new [`Settings`] with default text size and the given [`Style`].
+    pub fn with_style(style: impl Into<Style>) -> Self {
+        Self::with_text_size(14, style)
+    }
+
+    /// Creates new [`Settings`] with the given text size in [`Pixels`].
+    pub fn with_text_size(text_size: impl Into<Pixels>, style: impl Into<Style>) -> Self {
+        Self {
+            text_size: text_size.into(),
+            mode: Mode::default(),
+            context: 3,
+            style: style.into(),
+        }
+    }
+}
+
+impl From<&Theme> for Settings {
+    fn from(theme: &Theme) -> Self {
+        Self::with_style(Style::from(theme))
+    }
+}
+
+impl From<Theme> for Settings {
+    fn from(theme: Theme) -> Self {
+        Self::with_style(Style::from(theme))
+    }
+}
+
+/// The text styling of a diff rendering in [`view`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Font`] of the lines.
+    pub font: Font,
+    /// The [`Color`] of the line numbers.
+    pub line_number_color: Color,
+    /// The [`Highlight`] of the changed words of an added line.
+    pub added_highlight: Highlight,
+    /// The [`Highlight`] of the changed words of a removed line.
+    pub removed_highlight: Highlight,
+}
+
+impl Style {
+    /// Creates a new [`Style`] from the given [`palette::Seed`].
+    pub fn from_palette(seed: palette::Seed) -> Self {
+        Self {
+            font: Font::MONOSPACE,
+            line_number_color: seed.text.scale_alpha(0.5),
+            added_highlight: Highlight {
+                background: seed.success.scale_alpha(0.4).into(),
+                border: core::Border::default(),
+            },
+            removed_highlight: Highlight {
+                background: seed.danger.scale_alpha(0.4).into(),
+                border: core::Border::default(),
+            },
+        }
+    }
+}
+
+impl From<palette::Seed> for Style {
+    fn from(seed: palette::Seed) -> Self {
+        Self::from_palette(seed)
+    }
+}
+
+impl From<&Theme> for Style {
+    fn from(theme: &Theme) -> Self {
+        Self::from_palette(theme.seed())
+    }
+}
+
+impl From<Theme> for Style {
+    fn from(theme: Theme) -> Self {
+        Self::from_palette(theme.seed())
+    }
+}
+
+/// Displays the differences of a [`Content`].
+///
+/// Both texts share a single vertical scrollbar, so their lines are
+/// always aligned. Hunks of unchanged lines can be expanded and collapsed
+/// by clicking on them; which produces an [`Action`] that must be
+/// performed on the [`Content`].
+pub fn view<'a, Theme, Renderer>(
+    content: &'a Content,
+    settings: impl Into<Settings>,
+) -> Element<'a, Action, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    let settings = settings.into();
+
+    let digits = content
+        .rows
+        .iter()
+        .flat_map(|row| [&row.old, &row.new])
+        .flatten()
+        .map(|line| line.number)
+        .max()
+        .unwrap_or(1)
+        .ilog10()
+        + 1;
+
+    let mut lines = Vec::new();
+
+    for (range, is_hidden) in hunks(&content.rows, settings.context) {
+        let is_expanded = content.expanded.contains(&range.start);
+
+        if is_hidden {
+            lines.push(hunk(settings, range.clone(), is_expanded));
+
+            if !is_expanded {
+                continue;
+            }
+        }
+
+        let rows = &content.rows[range];
+
+        match settings.mode {
+            Mode::SideBySide => {
+                lines.extend(rows.iter().map(|row| {
+                    let (old, new) = match row.kind() {
+                        Kind::Unchanged => (Kind::Unchanged, Kind::Unchanged),
+                        _ => (Kind::Removed, Kind::Added),
+                    };
+
+                    row![
+                        side(settings, digits, row.old.as_ref(), old),
+                        side(settings, digits, row.new.as_ref(), new),
+                    ]
+                    .into()
+                }));
+            }
+            Mode::Unified => {
+                if rows.iter().all(|row| row.kind() == Kind::Unchanged) {
+                    lines.extend(rows.iter().map(|row| {
+                        unified(
+                            settings,
+                            digits,
+                            row.old.as_ref().map(|line| line.number),
+                            row.new.as_ref(),
+                            Kind::Unchanged,
+                        )
+                    }));
+                } else {
+                    lines.extend(rows.iter().filter_map(|row| {
+                        let old = row.old.as_ref()?;
+
+                        Some(unified(
+                            settings,
+                            digits,
+                            Some(old.number),
+                            Some(old),
+                            Kind::Removed,
+                        ))
+                    }));
+
+                    lines.extend(rows.iter().filter_map(|row| {
+                        let new = row.new.as_ref()?;
+
+                        Some(unified(settings, digits, None, Some(new), Kind::Added))
+                    }));
+                }
+            }
+        }
+    }
+
+    scrollable(column(lines))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
+/// Displays a bar that expands or collapses the hidden unchanged lines in
+/// the given range of rows.
+pub fn hunk<'a, Theme, Renderer>(
+    settings: Settings,
+    rows: Range<usize>,
+    is_expanded: bool,
+) -> Element<'a, Action, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    let amount = rows.len();
+
+    let label = if is_expanded {
+        format!("Hide {amount} unchanged lines")
+    } else {
+        format!("⋯ {amount} unchanged lines")
+    };
+
+    mouse_area(
+        container(
+            text(label)
+                .size(settings.text_size)
+                .font(settings.style.font),
+        )
+        .width(Length::Fill)
+        .padding([settings.text_size.0 / 4.0, settings.text_size.0])
+        .class(Theme::diff_hunk()),
+    )
+    .on_press(Action::Toggle(rows.start))
+    .interaction(core::mouse::Interaction::Pointer)
+    .into()
+}
+
+fn side<'a, Theme, Renderer>(
+    settings: Settings,
+    digits: u32,
+    line: Option<&'a Line>,
+    kind: Kind,
+) -> Element<'a, Action, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    let Some(line) = line else {
+        return container(text(""))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .class(Theme::diff_line(Kind::Unchanged))
+            .into();
+    };
+
+    unified(settings, digits, None, Some(line), kind)
+}
+
+fn unified<'a, Theme, Renderer>(
+    settings: Settings,
+    digits: u32,
+    old_number: Option<usize>,
+    line: Option<&'a Line>,
+    kind: Kind,
+) -> Element<'a, Action, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    let number = |number: Option<usize>| {
+        rich_text([
+            span::<(), Font>(number.map(|number| number.to_string()).unwrap_or_default())
+                .color(settings.style.line_number_color),
+        ])
+        .size(settings.text_size)
+        .font(settings.style.font)
+        .width(settings.text_size * 0.6 * digits as f32 + settings.text_size)
+        .align_x(alignment::Horizontal::Right)
+    };
+
+    let sign = match kind {
+        Kind::Added => "+",
+        Kind::Removed => "-",
+        Kind::Unchanged | Kind::Modified => " ",
+    };
+
+    let change = match kind {
+        Kind::Removed => settings.style.removed_highlight,
+        _ => settings.style.added_highlight,
+    };
+
+    let mut numbers = row![];
+
+    if settings.mode == Mode::Unified {
+        numbers = numbers.push(number(if kind == Kind::Added {
+            None
+        } else {
+            old_number
+        }));
+    }
+
+    numbers = numbers.push(number(
+        if kind == Kind::Removed && settings.mode == Mode::Unified {
+            None
+        } else {
+            line.map(|line| line.number)
+        },
+    ));
+
+    container(
+        row![
+            numbers,
+            text(sign)
+                .size(settings.text_size)
+                .font(settings.style.font),
+            rich_text(line.map(|line| line.spans(change)).unwrap_or_default())
+                .size(settings.text_size)
+                .font(settings.style.font)
+                .width(Length::Fill),
+        ]
+        .spacing(settings.text_size.0 / 2.0),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .class(Theme::diff_line(kind))
+    .into()
+}
+
+/// Splits the given rows in ranges, marking the unchanged ones that should
+/// be hidden when collapsed.
+fn hunks(rows: &[Row], context: usize) -> Vec<(Range<usize>, bool)> {
+    let mut hunks = Vec::new();
+    let mut start = 0;
+
+    while start < rows.len() {
+        let is_unchanged = rows[start].kind() == Kind::Unchanged;
+
+        let end = rows[start..]
+            .iter()
+            .position(|row| (row.kind() == Kind::Unchanged) != is_unchanged)
+            .map_or(rows.len(), |length| start + length);
+
+        let leading = if start == 0 { 0 } else { context };
+        let trailing = if end == rows.len() { 0 } else { context };
+
+        if is_unchanged && end - start > leading + trailing + 1 {
+            hunks.push((start..start + leading, false));
+            hunks.push((start + leading..end - trailing, true));
+            hunks.push((end - trailing..end, false));
+        } else {
+            hunks.push((start..end, false));
+        }
+
+        start = end;
+    }
+
+    hunks.retain(|(range, _)| !range.is_empty());
+    hunks
+}
+
+fn rows(old: &str, new: &str) -> Vec<Row> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let mut rows = Vec::new();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+
+    let (mut i, mut j) = (0, 0);
+
+    let flush = |rows: &mut Vec<Row>, removed: &mut Vec<Line>, added: &mut Vec<Line>| {
+        let mut removed = removed.drain(..);
+        let mut added = added.drain(..);
+
+        loop {
+            let (mut old, mut new) = (removed.next(), added.next());
+
+            if old.is_none() && new.is_none() {
+                break;
+            }
+
+            if let (Some(old), Some(new)) = (&mut old, &mut new) {
+                (old.changes, new.changes) = changes(&old.text, &new.text);
+            }
+
+            rows.push(Row { old, new });
+        }
+    };
+
+    for operation in diff(&old, &new) {
+        match operation {
+            Operation::Equal => {
+                flush(&mut rows, &mut removed, &mut added);
+
+                rows.push(Row {
+                    old: Some(Line::new(i + 1, old[i])),
+                    new: Some(Line::new(j + 1, new[j])),
+                });
+
+                i += 1;
+                j += 1;
+            }
+            Operation::Delete => {
+                removed.push(Line::new(i + 1, old[i]));
+                i += 1;
+            }
+            Operation::Insert => {
+                added.push(Line::new(j + 1, new[j]));
+                j += 1;
+            }
+        }
+    }
+
+    flush(&mut rows, &mut removed, &mut added);
+
+    rows
+}
+
+/// Computes the byte ranges of the words that changed between two lines.
+///
+/// Lines that barely have anything in common are considered replaced as a
+/// whole; in which case no ranges are returned.
+fn changes(old: &str, new: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let old_words: Vec<(usize, &str)> = old.split_word_bound_indices().collect();
+    let new_words: Vec<(usize, &str)> = new.split_word_bound_indices().collect();
+
+    let old_tokens: Vec<&str> = old_words.iter().map(|(_, word)| *word).collect();
+    let new_tokens: Vec<&str> = new_words.iter().map(|(_, word)| *word).collect();
+
+    let mut old_changes: Vec<Range<usize>> = Vec::new();
+    let mut new_changes: Vec<Range<usize>> = Vec::new();
+    let mut common = 0;
+
+    let push = |changes: &mut Vec<Range<usize>>, (start, word): (usize, &str)| {
+        let end = start + word.len();
+
+        match changes.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => changes.push(start..end),
+        }
+    };
+
+    let (mut i, mut j) = (0, 0);
+
+    for operation in diff(&old_tokens, &new_tokens) {
+        match operation {
+            Operation::Equal => {
+                common += old_tokens[i].len();
+                i += 1;
+                j += 1;
+            }
+            Operation::Delete => {
+                push(&mut old_changes, old_words[i]);
+                i += 1;
+            }
+            Operation::Insert => {
+                push(&mut new_changes, new_words[j]);
+                j += 1;
+            }
+        }
+    }
+
+    if common * 2 < old.len().max(new.len()) {
+        return (Vec::new(), Vec::new());
+    }
+
+    (old_changes, new_changes)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Computes the shortest sequence of operations that turns `old` into
+/// `new`, using the linear space variant of Myers' algorithm.
+fn diff<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Operation> {
+    let mut operations = Vec::with_capacity(old.len().max(new.len()));
+
+    diff_into(old, new, &mut operations);
+
+    operations
+}
+
+fn diff_into<T: PartialEq>(old: &[T], new: &[T], operations: &mut Vec<Operation>) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    operations.extend(std::iter::repeat_n(Operation::Equal, prefix));
+
+    if a.is_empty() || b.is_empty() {
+        operations.extend(std::iter::repeat_n(Operation::Delete, a.len()));
+        operations.extend(std::iter::repeat_n(Operation::Insert, b.len()));
+    } else {
+        let (x, y) = middle(a, b);
+
+        diff_into(&a[..x], &b[..y], operations);
+        diff_into(&a[x..], &b[y..], operations);
+    }
+
+    operations.extend(std::iter::repeat_n(Operation::Equal, suffix));
+}
+
+/// Finds a point in the middle of a shortest edit path between `a` and
+/// `b`; searching forwards and backwards at the same time until both
+/// searches overlap.
+///
+/// Both `a` and `b` must not be empty, and their first and last elements
+/// must differ. The point is never at either end, so splitting at it
+/// always produces smaller problems.
+fn middle<T: PartialEq>(a: &[T], b: &[T]) -> (usize, usize) {
+    let n = a.len() as isize;
+    let m = b.len() as isize;
+    let delta = n - m;
+    let max = (n + m + 1) / 2;
+
+    // The furthest `x` reached in every diagonal `k`, indexed by `k + max`;
+    // backwards, `x` is counted from the end
+    let length = 2 * max + 2;
+
+    let mut forward = vec![-1; length as usize];
+    let mut backward = forward.clone();
+
+    forward[max as usize + 1] = 0;
+    backward[max as usize + 1] = 0;
+
+    let is_odd = delta % 2 != 0;
+
+    // Diagonals that went past the end of the texts are skipped
+    let (mut forward_start, mut forward_end) = (0, 0);
+    let (mut backward_start, mut backward_end) = (0, 0);
+
+    for d in 0..=max {
+        for k in (-d + forward_start..=d - forward_end).step_by(2) {
+            let index = (k + max) as usize;
+
+            let mut x = if k == -d || (k != d && forward[index - 1] < forward[index + 1]) {
+                forward[index + 1]
+            } else {
+                forward[index - 1] + 1
+            };
+
+            let mut y = x - k;
+
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            forward[index] = x;
+
+            if x > n {
+                forward_end += 2;
+            } else if y > m {
+                forward_start += 2;
+            } else if is_odd {
+                let reverse = max + delta - k;
+
+                if (0..length).contains(&reverse)
+                    && backward[reverse as usize] != -1
+                    && x >= n - backward[reverse as usize]
+                {
+                    return (x as usize, y as usize);
+                }
+            }
+        }
+
+        for k in (-d + backward_start..=d - backward_end).step_by(2) {
+            let index = (k + max) as usize;
+
+            let mut x = if k == -d || (k != d && backward[index - 1] < backward[index + 1]) {
+                backward[index + 1]
+            } else {
+                backward[index - 1] + 1
+            };
+
+            let mut y = x - k;
+
+            while x < n && y < m && a[(n - x - 1) as usize] == b[(m - y - 1) as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            backward[index] = x;
+
+            if x > n {
+                backward_end += 2;
+            } else if y > m {
+                backward_start += 2;
+            } else if !is_odd {
+                let reverse = max + delta - k;
+
+                if (0..length).contains(&reverse) && forward[reverse as usize] != -1 {
+                    let forward_x = forward[reverse as usize];
+
+                    if forward_x >= n - x {
+                        return (forward_x as usize, (forward_x - (reverse - max)) as usize);
+                    }
+                }
+            }
+        }
+    }
+
+    // The searches always overlap; otherwise, everything is deleted and
+    // inserted
+    (a.len(), 0)
+}
+
+/// The theme catalog of a diff view.
+pub trait Catalog: container::Catalog + scrollable::Catalog + text::Catalog {
+    /// The styling class of a line of the given [`Kind`].
+    ///
+    /// Modified lines are styled as a removed line in the old text and as
+    /// an added line in the new one.
+    fn diff_line<'a>(kind: Kind) -> <Self as container::Catalog>::Class<'a>;
+
+    /// The styling class of a hunk of hidden unchanged lines.
+    fn diff_hunk<'a>() -> <Self as container::Catalog>::Class<'a>;
+}
+
+impl Catalog for Theme {
+    fn diff_line<'a>(kind: Kind) -> <Self as container::Catalog>::Class<'a> {
+        Box::new(move |theme: &Theme| {
+            let palette = theme.palette();
+
+            let background = match kind {
+                Kind::Unchanged => None,
+                Kind::Added | Kind::Modified => Some(palette.success.base.color.scale_alpha(0.15)),
+                Kind::Removed => Some(palette.danger.base.color.scale_alpha(0.15)),
+            };
+
+            container::Style {
+                background: background.map(Into::into),
+                ..container::Style::default()
+            }
+        })
+    }
+
+    fn diff_hunk<'a>() -> <Self as container::Catalog>::Class<'a> {
+        Box::new(|theme: &Theme| {
+            let palette = theme.palette();
+
+            container::Style {
+                background: Some(palette.primary.weak.color.scale_alpha(0.2).into()),
+                text_color: Some(palette.background.weak.text),
+                ..container::Style::default()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(content: &Content) -> Vec<Kind> {
+        content.rows().iter().map(Row::kind).collect()
+    }
+
+    #[test]
+    fn line_diff() {
+        let content = Content::new("a\nb\nc\nd", "a\nc\nx\nd\ne");
+
+        assert_eq!(
+            kinds(&content),
+            [
+                Kind::Unchanged,
+                Kind::Removed,
+                Kind::Unchanged,
+                Kind::Added,
+                Kind::Unchanged,
+                Kind::Added,
+            ]
+        );
+
+        assert_eq!(content.additions(), 2);
+        assert_eq!(content.deletions(), 1);
+        assert!(Content::new("same\n", "same").is_empty());
+    }
+
+    #[test]
+    fn modified_lines() {
+        let content = Content::new("let x = 1;\nfoo", "let y = 1;\nbar");
+        let rows = content.rows();
+
+        assert_eq!(kinds(&content), [Kind::Modified, Kind::Modified]);
+
+        let (old, new) = (rows[0].old.as_ref().unwrap(), rows[0].new.as_ref().unwrap());
+
+        assert_eq!(old.changes, vec![Range { start: 4, end: 5 }]);
+        assert_eq!(new.changes, vec![Range { start: 4, end: 5 }]);
+
+        // Completely different lines are not highlighted word by word
+        assert!(rows[1].new.as_ref().unwrap().changes.is_empty());
+    }
+
+    #[test]
+    fn shortest_edits() {
+        fn apply(old: &[u8], new: &[u8], operations: &[Operation]) -> Vec<u8> {
+            let (mut i, mut j) = (0, 0);
+            let mut result = Vec::new();
+
+            for operation in operations {
+                match operation {
+                    Operation::Equal => {
+                        assert_eq!(old[i], new[j]);
+                        result.push(old[i]);
+                        i += 1;
+                        j += 1;
+                    }
+                    Operation::Delete => i += 1,
+                    Operation::Insert => {
+                        result.push(new[j]);
+                        j += 1;
+                    }
+                }
+            }
+
+            assert_eq!((i, j), (old.len(), new.len()));
+
+            result
+        }
+
+        fn distance(old: &[u8], new: &[u8]) -> usize {
+            let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+
+            for i in 1..=old.len() {
+                for j in 1..=new.len() {
+                    lcs[i][j] = if old[i - 1] == new[j - 1] {
+                        lcs[i - 1][j - 1] + 1
+                    } else {
+                        lcs[i - 1][j].max(lcs[i][j - 1])
+                    };
+                }
+            }
+
+            old.len() + new.len() - 2 * lcs[old.len()][new.len()]
+        }
+
+        let mut seed = 42u32;
+        let mut random = |limit: u32| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+
+            (seed % limit) as u8
+        };
+
+        for _ in 0..500 {
+            let old: Vec<u8> = (0..random(12)).map(|_| random(3)).collect();
+            let new: Vec<u8> = (0..random(12)).map(|_| random(3)).collect();
+
+            let operations = diff(&old, &new);
+            let edits = operations
+                .iter()
+                .filter(|operation| **operation != Operation::Equal)
+                .count();
+
+            assert_eq!(apply(&old, &new, &operations), new);
+            assert_eq!(edits, distance(&old, &new), "{old:?} -> {new:?}");
+        }
+    }
+
+    #[test]
+    fn collapsed_hunks() {
+        let old: String = (0..20).map(|i| format!("{i}\n")).collect();
+        let new = old.replace("10\n", "ten\n");
+
+        let content = Content::new(&old, &new);
+
+        assert_eq!(
+            hunks(content.rows(), 3),
+            [
+                (0..7, true),
+                (7..10, false),
+                (10..11, false),
+                (11..14, false),
+                (14..20, true),
+            ]
+        );
+    }
+}
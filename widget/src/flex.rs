@@ -0,0 +1,329 @@
+//! Constrain the size of an element and control how it grows or shrinks
+//! inside a flex layout.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{container, flex, row};
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     row![
+//!         flex(container("Sidebar")).basis(200).shrink(0.0),
+//!         flex(container("Content")).grow(1).min_width(300),
+//!         flex(container("Thumbnail")).aspect_ratio(16.0 / 9.0),
+//!     ]
+//!     .into()
+//! }
+//! # struct State;
+//! ```
+use crate::core::layout::{self, flex};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size, Vector, Widget};
+
+/// A widget that constrains the size of its contents and defines how they
+/// grow or shrink inside a flex layout, like a `row` or a `column`.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{flex, row, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     row![
+///         flex(text("I take twice the space")).grow(2),
+///         flex(text("than me!")).grow(1),
+///     ]
+///     .into()
+/// }
+/// # struct State;
+/// ```
+pub struct Flex<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    item: Option<flex::Item>,
+    min_width: Option<f32>,
+    max_width: Option<f32>,
+    min_height: Option<f32>,
+    max_height: Option<f32>,
+    aspect_ratio: Option<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> Flex<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Flex`] wrapping the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            item: None,
+            min_width: None,
+            max_width: None,
+            min_height: None,
+            max_height: None,
+            aspect_ratio: None,
+        }
+    }
+
+    /// Sets the initial size of the [`Flex`] in the main axis of its layout.
+    ///
+    /// By default, the intrinsic size of its contents is used.
+    pub fn basis(mut self, basis: impl Into<Pixels>) -> Self {
+        self.item_mut().basis = Some(basis.into().0);
+        self
+    }
+
+    /// Sets the portion of the remaining space of its layout that the
+    /// [`Flex`] takes, relative to its siblings.
+    ///
+    /// By default, a [`Flex`] does not grow.
+    pub fn grow(mut self, grow: u16) -> Self {
+        self.item_mut().grow = grow;
+        self
+    }
+
+    /// Sets how much the [`Flex`] shrinks when the items of its layout
+    /// overflow, relative to its siblings.
+    ///
+    /// By default, it is `1.0`. A value of `0.0` prevents it from shrinking.
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.item_mut().shrink = shrink.max(0.0);
+        self
+    }
+
+    /// Sets the minimum width of the [`Flex`].
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = Some(min_width.into().0);
+        self
+    }
+
+    /// Sets the maximum width of the [`Flex`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = Some(max_width.into().0);
+        self
+    }
+
+    /// Sets the minimum height of the [`Flex`].
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min_height = Some(min_height.into().0);
+        self
+    }
+
+    /// Sets the maximum height of the [`Flex`].
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.max_height = Some(max_height.into().0);
+        self
+    }
+
+    /// Sets the aspect ratio of the [`Flex`]; that is, its width divided
+    /// by its height.
+    ///
+    /// The width of the [`Flex`] is resolved first, and its height is
+    /// derived from it.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = (ratio > 0.0 && ratio.is_finite()).then_some(ratio);
+        self
+    }
+
+    fn item_mut(&mut self) -> &mut flex::Item {
+        self.item.get_or_insert_with(flex::Item::default)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Flex<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        self.content.as_widget_mut().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        let size = self.content.as_widget().size();
+
+        let bound = |length: Length, min: Option<f32>, max: Option<f32>| {
+            let length = min.map_or(length, |min| length.min(min));
+
+            max.map_or(length, |max| length.max(max))
+        };
+
+        Size::new(
+            bound(size.width, self.min_width, self.max_width),
+            bound(size.height, self.min_height, self.max_height),
+        )
+    }
+
+    fn flex(&self) -> Option<flex::Item> {
+        self.item
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size();
+        let limits = limits.width(size.width).height(size.height);
+
+        let Some(ratio) = self.aspect_ratio else {
+            let content = self.content.as_widget_mut().layout(tree, renderer, &limits);
+            let size = limits.resolve(size.width, size.height, content.size());
+
+            return layout::Node::with_children(size, vec![content]).with_children_baselines();
+        };
+
+        let min = limits.min();
+        let max = limits.max();
+
+        let width = if max.width.is_finite()
+            && !limits.compression().width
+            && !matches!(size.width, Length::Fixed(_))
+        {
+            max.width
+        } else {
+            let intrinsic = self
+                .content
+                .as_widget_mut()
+                .layout(tree, renderer, &limits.loose())
+                .size();
+
+            limits.resolve_width(size.width, intrinsic.width)
+        };
+
+        let height = (width / ratio).min(max.height).max(min.height);
+        let width = (height * ratio).min(width).max(min.width);
+
+        let size = Size::new(width, height);
+
+        let content = self.content.as_widget_mut().layout(
+            tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, size),
+        );
+
+        layout::Node::with_children(size, vec![content]).with_children_baselines()
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            tree,
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            tree,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            tree,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            tree,
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            tree,
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Flex<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(flex: Flex<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(flex)
+    }
+}
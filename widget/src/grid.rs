@@ -1,10 +1,17 @@
 //! Distribute content on a grid.
+mod tracks;
+
+pub use crate::core::layout::grid::{Area, Areas, Placement};
+pub use tracks::{Cell, Tracks, cell, tracks};
+
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::{Operation, Tree};
-use crate::core::{Element, Event, Length, Pixels, Rectangle, Shell, Size, Vector, Widget};
+use crate::core::{
+    Alignment, Element, Event, Length, Pixels, Rectangle, Shell, Size, Vector, Widget,
+};
 
 /// A container that distributes its contents on a responsive grid.
 pub struct Grid<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
@@ -12,6 +19,7 @@ pub struct Grid<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
     columns: Constraint,
     width: Option<Pixels>,
     height: Sizing,
+    align_y: Alignment,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
 }
 
@@ -50,6 +58,7 @@ where
             columns: Constraint::Amount(3),
             width: None,
             height: Sizing::AspectRatio(1.0),
+            align_y: Alignment::Start,
             children,
         }
     }
@@ -78,6 +87,15 @@ where
         self
     }
 
+    /// Sets the vertical alignment of the contents of each cell of the [`Grid`].
+    ///
+    /// With [`Alignment::Baseline`] or [`Alignment::LastBaseline`], the
+    /// contents of every row of the [`Grid`] share the same baseline.
+    pub fn align_y(mut self, align: impl Into<Alignment>) -> Self {
+        self.align_y = align.into();
+        self
+    }
+
     /// Sets the amount of columns in the [`Grid`].
     pub fn columns(mut self, column: usize) -> Self {
         self.columns = Constraint::Amount(column);
@@ -205,7 +223,7 @@ where
         let mut nodes = Vec::with_capacity(self.children.len());
         let mut x = 0.0;
         let mut y = 0.0;
-        let mut row_height = 0.0f32;
+        let mut row_start = 0;
 
         for (i, (child, tree)) in self.children.iter_mut().zip(&mut tree.children).enumerate() {
             let node = child
@@ -213,27 +231,23 @@ where
                 .layout(tree, renderer, &cell_limits)
                 .move_to((x, y));
 
-            let size = node.size();
-
-            x += size.width + self.spacing;
-            row_height = row_height.max(size.height);
+            x += node.size().width + self.spacing;
+            nodes.push(node);
 
             if (i + 1) % cells_per_row == 0 {
-                y += cell_height.unwrap_or(row_height) + self.spacing;
+                y += align_row(&mut nodes[row_start..], self.align_y, cell_height) + self.spacing;
                 x = 0.0;
-                row_height = 0.0;
+                row_start = i + 1;
             }
-
-            nodes.push(node);
         }
 
         if x == 0.0 {
             y -= self.spacing;
         } else {
-            y += cell_height.unwrap_or(row_height);
+            y += align_row(&mut nodes[row_start..], self.align_y, cell_height);
         }
 
-        layout::Node::with_children(Size::new(available.width, y), nodes)
+        layout::Node::with_children(Size::new(available.width, y), nodes).with_children_baselines()
     }
 
     fn operate(
@@ -356,6 +370,46 @@ where
     }
 }
 
+/// Aligns the nodes of a row of a [`Grid`] vertically and returns the
+/// height of the row.
+fn align_row(nodes: &mut [layout::Node], align: Alignment, cell_height: Option<f32>) -> f32 {
+    if align.is_baseline() {
+        let baseline = |node: &layout::Node| {
+            match align {
+                Alignment::LastBaseline => node.last_baseline(),
+                _ => node.baseline(),
+            }
+            .unwrap_or(node.size().height)
+        };
+
+        let ascent = nodes.iter().map(baseline).fold(0.0, f32::max);
+
+        let descent = nodes
+            .iter()
+            .map(|node| node.size().height - baseline(node))
+            .fold(0.0, f32::max);
+
+        for node in nodes {
+            node.translate_mut(Vector::new(0.0, ascent - baseline(node)));
+        }
+
+        return cell_height.unwrap_or(ascent + descent);
+    }
+
+    let row_height = cell_height.unwrap_or_else(|| {
+        nodes
+            .iter()
+            .map(|node| node.size().height)
+            .fold(0.0, f32::max)
+    });
+
+    for node in nodes {
+        node.align_mut(Alignment::Start, align, Size::new(0.0, row_height));
+    }
+
+    row_height
+}
+
 /// The sizing strategy of a [`Grid`].
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Sizing {
@@ -387,3 +441,116 @@ impl From<Length> for Sizing {
 pub fn aspect_ratio(width: impl Into<Pixels>, height: impl Into<Pixels>) -> Sizing {
     Sizing::AspectRatio(width.into().0 / height.into().0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+
+    /// A widget with the metrics of some lines of text of the given size.
+    struct Label {
+        size: f32,
+        lines: usize,
+    }
+
+    impl Widget<(), (), ()> for Label {
+        fn size(&self) -> Size<Length> {
+            Size {
+                width: Length::Shrink,
+                height: Length::Shrink,
+            }
+        }
+
+        fn layout(
+            &mut self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            let line_height = self.size * 1.25;
+            let first = self.size;
+
+            layout::Node::new(limits.resolve(
+                Length::Shrink,
+                Length::Shrink,
+                Size::new(self.size * 2.0, line_height * self.lines as f32),
+            ))
+            .with_baselines(first, first + line_height * (self.lines - 1) as f32)
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn label(size: f32, lines: usize) -> Element<'static, (), (), ()> {
+        Element::new(Label { size, lines })
+    }
+
+    #[test]
+    fn baselines_are_shared_across_font_sizes() {
+        let layout = |align: Alignment| {
+            let mut grid: Grid<'_, (), (), ()> = Grid::new()
+                .columns(3)
+                .spacing(10)
+                .height(Length::Shrink)
+                .align_y(align)
+                .push(label(10.0, 3))
+                .push(label(20.0, 1))
+                .push(Space::new().height(12))
+                .push(label(20.0, 1))
+                .push(label(10.0, 1))
+                .push(Space::new().height(12));
+
+            let mut tree = Tree::new(&grid as &dyn Widget<(), (), ()>);
+            grid.diff(&mut tree);
+
+            grid.layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(300.0, 300.0)),
+            )
+        };
+
+        let baselines = |node: &layout::Node, align: Alignment| {
+            node.children()
+                .iter()
+                .map(|child| {
+                    let baseline = match align {
+                        Alignment::LastBaseline => child.last_baseline(),
+                        _ => child.baseline(),
+                    };
+
+                    // Children without a baseline are aligned by their bottom edge
+                    child.bounds().y + baseline.unwrap_or(child.size().height)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first = layout(Alignment::Baseline);
+
+        assert_eq!(
+            baselines(&first, Alignment::Baseline),
+            [20.0, 20.0, 20.0, 77.5, 77.5, 77.5]
+        );
+        assert_eq!(first.baseline(), Some(20.0));
+        assert_eq!(first.last_baseline(), Some(77.5));
+
+        let last = layout(Alignment::LastBaseline);
+
+        assert_eq!(
+            baselines(&last, Alignment::LastBaseline),
+            [35.0, 35.0, 35.0, 70.0, 70.0, 70.0]
+        );
+        assert_eq!(last.baseline(), Some(10.0));
+        assert_eq!(last.last_baseline(), Some(70.0));
+    }
+}
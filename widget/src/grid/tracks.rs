@@ -0,0 +1,437 @@
+use crate::core::alignment;
+use crate::core::layout::grid::{Area, Areas, Placement};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Alignment, Element, Event, Length, Padding, Pixels, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A container that distributes its contents on a grid of explicit row and
+/// column tracks; like a CSS grid.
+///
+/// Contents can span multiple rows and columns, be placed in named [`Areas`],
+/// or flow automatically into the first free cells of the grid.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{grid, text};
+/// use iced::Length;
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     grid::tracks([Length::Fixed(200.0), Length::Fill], [Length::Shrink, Length::Fill])
+///         .areas(["sidebar header", "sidebar main"])
+///         .push_cell(grid::cell(text("Header")).area("header"))
+///         .push_cell(grid::cell(text("Sidebar")).area("sidebar"))
+///         .push_cell(grid::cell(text("Main")).area("main"))
+///         .spacing(10)
+///         .into()
+/// }
+/// ```
+pub struct Tracks<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    areas: Areas,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    column_spacing: f32,
+    row_spacing: f32,
+    align_x: Alignment,
+    align_y: Alignment,
+    slots: Vec<Slot>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+struct Slot {
+    placement: Placement,
+    align_x: Option<Alignment>,
+    align_y: Option<Alignment>,
+}
+
+impl<'a, Message, Theme, Renderer> Tracks<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Tracks`] grid with the given column and row tracks.
+    ///
+    /// Contents placed beyond the given tracks produce new tracks that
+    /// [`Shrink`](Length::Shrink) to fit them.
+    pub fn new(
+        columns: impl IntoIterator<Item = impl Into<Length>>,
+        rows: impl IntoIterator<Item = impl Into<Length>>,
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().map(Into::into).collect(),
+            rows: rows.into_iter().map(Into::into).collect(),
+            areas: Areas::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::ZERO,
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            align_x: Alignment::Start,
+            align_y: Alignment::Start,
+            slots: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the named [`Areas`] of the [`Tracks`] grid, described by rows of
+    /// whitespace-separated names.
+    ///
+    /// See [`Areas::parse`] for more details.
+    pub fn areas<'b>(mut self, rows: impl IntoIterator<Item = &'b str>) -> Self {
+        self.areas = Areas::parse(rows);
+        self
+    }
+
+    /// Sets the spacing _between_ both the rows and the columns of the
+    /// [`Tracks`] grid.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        let amount = amount.into().0;
+
+        self.column_spacing = amount;
+        self.row_spacing = amount;
+        self
+    }
+
+    /// Sets the spacing _between_ the columns of the [`Tracks`] grid.
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the spacing _between_ the rows of the [`Tracks`] grid.
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Tracks`] grid.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Tracks`] grid.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tracks`] grid.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the default horizontal alignment of the contents of every
+    /// [`Cell`] of the [`Tracks`] grid.
+    pub fn align_x(mut self, align: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = Alignment::from(align.into());
+        self
+    }
+
+    /// Sets the default vertical alignment of the contents of every
+    /// [`Cell`] of the [`Tracks`] grid.
+    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = Alignment::from(align.into());
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Tracks`] grid, placed in the first free
+    /// cell.
+    pub fn push(self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.push_cell(Cell::new(child))
+    }
+
+    /// Adds a [`Cell`] to the [`Tracks`] grid.
+    pub fn push_cell(mut self, cell: Cell<'a, Message, Theme, Renderer>) -> Self {
+        self.slots.push(Slot {
+            placement: cell.placement,
+            align_x: cell.align_x,
+            align_y: cell.align_y,
+        });
+
+        self.children.push(cell.content);
+        self
+    }
+
+    /// Extends the [`Tracks`] grid with the given [`Cell`]s.
+    pub fn extend(
+        self,
+        cells: impl IntoIterator<Item = Cell<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        cells.into_iter().fold(self, Self::push_cell)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tracks<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let areas = layout::grid::place(
+            self.slots.iter().map(|slot| &slot.placement),
+            &self.areas,
+            self.columns.len(),
+        );
+
+        let cells: Vec<_> = areas
+            .into_iter()
+            .zip(&self.slots)
+            .map(|(area, slot)| layout::grid::Cell {
+                area,
+                align_x: slot.align_x.unwrap_or(self.align_x),
+                align_y: slot.align_y.unwrap_or(self.align_y),
+            })
+            .collect();
+
+        layout::grid::resolve(
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            self.padding,
+            &self.columns,
+            &self.rows,
+            self.column_spacing,
+            self.row_spacing,
+            &cells,
+            &mut self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget_mut()
+                .update(tree, event, layout, cursor, renderer, shell, viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(viewport) = layout.bounds().intersection(viewport) {
+            for ((child, tree), layout) in self
+                .children
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .filter(|(_, layout)| layout.bounds().intersects(&viewport))
+            {
+                child
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, &viewport);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tracks<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(tracks: Tracks<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(tracks)
+    }
+}
+
+/// Some content placed in a [`Tracks`] grid.
+pub struct Cell<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    placement: Placement,
+    align_x: Option<Alignment>,
+    align_y: Option<Alignment>,
+}
+
+impl<'a, Message, Theme, Renderer> Cell<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Cell`] with the given content, placed in the first
+    /// free cell of the grid.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            placement: Placement::default(),
+            align_x: None,
+            align_y: None,
+        }
+    }
+
+    /// Places the [`Cell`] at the given row and column, keeping its span.
+    pub fn at(mut self, row: usize, column: usize) -> Self {
+        let (rows, columns) = self.span_of();
+
+        self.placement = Placement::Area(Area::new(row, column).span(rows, columns));
+        self
+    }
+
+    /// Sets the amount of rows and columns spanned by the [`Cell`].
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.placement = match self.placement {
+            Placement::Area(area) => Placement::Area(area.span(rows, columns)),
+            _ => Placement::Auto { rows, columns },
+        };
+
+        self
+    }
+
+    /// Places the [`Cell`] in the named area of the grid with the given name.
+    ///
+    /// See [`Tracks::areas`].
+    pub fn area(mut self, name: impl Into<String>) -> Self {
+        self.placement = Placement::Named(name.into());
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Cell`].
+    pub fn align_x(mut self, align: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = Some(Alignment::from(align.into()));
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Cell`].
+    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = Some(Alignment::from(align.into()));
+        self
+    }
+
+    fn span_of(&self) -> (usize, usize) {
+        match self.placement {
+            Placement::Auto { rows, columns } => (rows, columns),
+            Placement::Area(area) => (area.rows, area.columns),
+            Placement::Named(_) => (1, 1),
+        }
+    }
+}
+
+/// Creates a new [`Tracks`] grid with the given column and row tracks.
+pub fn tracks<'a, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = impl Into<Length>>,
+    rows: impl IntoIterator<Item = impl Into<Length>>,
+) -> Tracks<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    Tracks::new(columns, rows)
+}
+
+/// Creates a new [`Cell`] with the given content, to be placed in a
+/// [`Tracks`] grid.
+pub fn cell<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Cell<'a, Message, Theme, Renderer> {
+    Cell::new(content)
+}
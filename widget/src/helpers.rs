@@ -25,7 +25,10 @@ use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
 use crate::transition::{self, Transition};
 use crate::vertical_slider::{self, VerticalSlider};
-use crate::{Column, Grid, MouseArea, Pin, Responsive, Row, Sensor, Space, Stack, Themer};
+use crate::{
+    Column, Directional, Flex, Grid, Masonry, Memo, MouseArea, Pin, Responsive, Row, SelectionArea,
+    Sensor, Space, Stack, Themer,
+};
 
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
@@ -207,7 +210,7 @@ macro_rules! rich_text {
         $crate::text::Rich::new()
     );
     ($($x:expr),+ $(,)?) => (
-        $crate::text::Rich::from_iter([$($crate::text::Span::from($x)),+])
+        $crate::text::Rich::from_iter([$($crate::text::Inline::from($x)),+])
     );
 }
 
@@ -555,6 +558,19 @@ where
     Grid::with_children(children)
 }
 
+/// Creates a new [`Masonry`] from an iterator.
+///
+/// A [`Masonry`] distributes its children on columns, placing each child at
+/// the bottom of the shortest column.
+pub fn masonry<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Masonry<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Masonry::with_children(children)
+}
+
 /// Creates a new [`Stack`] with the given children.
 ///
 /// [`Stack`]: crate::Stack
@@ -1193,10 +1209,50 @@ pub fn span<'a, Link, Font>(text: impl text::IntoFragment<'a>) -> text::Span<'a,
     text::Span::new(text)
 }
 
+/// Creates a new [`Inline`] element to be used in some [`Rich`] text.
+///
+/// [`Inline`]: text::Inline
+/// [`Rich`]: text::Rich
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::*; }
+/// # pub type State = ();
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{button, inline, rich_text, span};
+/// use iced::never;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     rich_text![
+///         span("Press "),
+///         inline(button("Save").on_press(Message::Save)),
+///         span(" to keep your changes."),
+///     ]
+///     .on_link_click(never)
+///     .into()
+/// }
+/// ```
+pub fn inline<'a, Link, Message, Theme, Renderer>(
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> text::Inline<'a, Link, Message, Theme, Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    text::Inline::Element(element.into())
+}
+
 #[cfg(feature = "markdown")]
 #[doc(inline)]
 pub use crate::markdown::view as markdown;
 
+#[doc(inline)]
+pub use crate::diff_view::view as diff_view;
+
 /// Creates a new [`Checkbox`].
 ///
 /// # Example
@@ -1938,6 +1994,43 @@ where
     crate::QRCode::new(data)
 }
 
+/// Creates a new [`Chart`] widget with the given [`Series`].
+///
+/// Charts plot series of data as lines, areas, bars, or scatter points.
+///
+/// [`Chart`]: crate::Chart
+/// [`Series`]: crate::chart::Series
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::widget::chart;
+///
+/// struct State {
+///    prices: chart::Data,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     chart([chart::Series::line("Price", &state.prices)]).into()
+/// }
+/// ```
+#[cfg(feature = "chart")]
+pub fn chart<'a, Message, Theme>(
+    series: impl IntoIterator<Item = crate::chart::Series<'a>>,
+) -> crate::Chart<'a, Message, Theme>
+where
+    Theme: crate::chart::Catalog + 'a,
+{
+    crate::Chart::new(series)
+}
+
 /// Creates a new [`Shader`].
 ///
 /// [`Shader`]: crate::Shader
@@ -1959,6 +2052,32 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates a new [`SelectionArea`], letting users select and copy the
+/// text of its contents as a whole.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, selection_area, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(log: &[String]) -> Element<'_, Message> {
+///     selection_area(column(log.iter().map(|line| text(line).selectable(true).into()))).into()
+/// }
+/// ```
+pub fn selection_area<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> SelectionArea<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    SelectionArea::new(content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, Theme, Renderer>(
     theme: Option<Theme>,
@@ -1971,6 +2090,71 @@ where
     Themer::new(theme, content)
 }
 
+/// A widget that lays out its contents in the given [`Direction`].
+///
+/// This is useful to mirror the user interface for right-to-left languages;
+/// like Arabic or Hebrew.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::core::layout::Direction; }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// #
+/// use iced::Direction;
+/// use iced::widget::{directional, row, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     directional(
+///         Direction::RightToLeft,
+///         row![text("مرحبا"), text("بالعالم")].spacing(10),
+///     )
+///     .into()
+/// }
+/// # struct State;
+/// ```
+///
+/// [`Direction`]: core::layout::Direction
+pub fn directional<'a, Message, Theme, Renderer>(
+    direction: core::layout::Direction,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Directional<'a, Message, Theme, Renderer> {
+    Directional::new(direction, content)
+}
+
+/// Creates a new [`Flex`] wrapping the given content.
+///
+/// A [`Flex`] constrains the size of its contents and defines how they grow
+/// or shrink inside a flex layout, like a [`Row`] or a [`Column`].
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{flex, row, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     row![
+///         flex(text("Fixed")).basis(100).shrink(0.0),
+///         flex(text("Flexible")).grow(1).max_width(400),
+///     ]
+///     .into()
+/// }
+/// # struct State;
+/// ```
+pub fn flex<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Flex<'a, Message, Theme, Renderer> {
+    Flex::new(content)
+}
+
 /// Creates a [`PaneGrid`] with the given [`pane_grid::State`] and view function.
 ///
 /// Pane grids let your users split regions of your application and organize layout dynamically.
@@ -2030,6 +2214,40 @@ where
     Float::new(content)
 }
 
+/// Creates a new [`Memo`] widget with the given data dependency and content.
+///
+/// The layout of the content will be reused as long as the hash of the
+/// dependency and the layout limits stay the same.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use iced_widget::*; } }
+/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced::widget::{column, memo, text};
+///
+/// enum Message {
+///     // ...
+/// }
+///
+/// struct State {
+///     items: Vec<String>,
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     memo(
+///         &state.items,
+///         column(state.items.iter().map(|item| text(item).into())),
+///     )
+///     .into()
+/// }
+/// ```
+pub fn memo<'a, Message, Theme, Renderer>(
+    dependency: impl std::hash::Hash,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Memo<'a, Message, Theme, Renderer> {
+    Memo::new(dependency, content)
+}
+
 /// Creates a new [`Responsive`] widget with a closure that produces its
 /// contents.
 ///
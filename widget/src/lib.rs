@@ -11,9 +11,12 @@ pub use core::widget::{Id, Void};
 
 mod action;
 mod column;
+mod directional;
+mod memo;
 mod mouse_area;
 mod pin;
 mod responsive;
+mod selection_area;
 mod stack;
 mod themer;
 
@@ -21,9 +24,12 @@ pub mod button;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
+pub mod diff_view;
+pub mod flex;
 pub mod float;
 pub mod grid;
 pub mod keyed;
+pub mod masonry;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
@@ -65,10 +71,18 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use directional::Directional;
+#[doc(no_inline)]
+pub use flex::Flex;
+#[doc(no_inline)]
 pub use float::Float;
 #[doc(no_inline)]
 pub use grid::Grid;
 #[doc(no_inline)]
+pub use masonry::Masonry;
+#[doc(no_inline)]
+pub use memo::Memo;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
 pub use pane_grid::PaneGrid;
@@ -89,6 +103,8 @@ pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selection_area::SelectionArea;
+#[doc(no_inline)]
 pub use sensor::Sensor;
 #[doc(no_inline)]
 pub use slider::Slider;
@@ -146,6 +162,13 @@ pub mod qr_code;
 #[doc(no_inline)]
 pub use qr_code::QRCode;
 
+#[cfg(feature = "chart")]
+pub mod chart;
+
+#[cfg(feature = "chart")]
+#[doc(no_inline)]
+pub use chart::Chart;
+
 #[cfg(feature = "markdown")]
 pub mod markdown;
 
@@ -48,7 +48,9 @@ use crate::core::border;
 use crate::core::font::{self, Font};
 use crate::core::padding;
 use crate::core::theme::palette;
-use crate::core::{self, Color, Element, Length, Padding, Pixels, Theme, color};
+use crate::core::widget::Id;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::{self, Color, Element, Length, Padding, Pixels, Rectangle, Theme, Vector, color};
 use crate::{checkbox, column, container, rich_text, row, rule, scrollable, span, text};
 
 use std::borrow::BorrowMut;
@@ -60,17 +62,25 @@ use std::rc::Rc;
 use std::sync::Arc;
 
 pub use core::text::Highlight;
-pub use pulldown_cmark::HeadingLevel;
+pub use pulldown_cmark::{BlockQuoteKind, HeadingLevel};
 
 /// A [`String`] representing a [URI] in a Markdown document
 ///
 /// [URI]: https://en.wikipedia.org/wiki/Uniform_Resource_Identifier
 pub type Uri = String;
 
+/// A [`String`] identifying a heading in a Markdown document; like
+/// `getting-started`.
+///
+/// Anchors are generated from the text of a heading, in the same way
+/// GitHub does.
+pub type Anchor = String;
+
 /// A bunch of Markdown that has been parsed.
 #[derive(Debug, Default)]
 pub struct Content {
     items: Vec<Item>,
+    sources: Vec<Range<usize>>,
     incomplete: HashMap<usize, Section>,
     state: State,
 }
@@ -78,6 +88,7 @@ pub struct Content {
 #[derive(Debug)]
 struct Section {
     content: String,
+    offset: usize,
     broken_links: HashSet<String>,
 }
 
@@ -94,6 +105,18 @@ impl Content {
         content
     }
 
+    /// Sets the [`Theme`] used to highlight the code blocks of the
+    /// [`Content`].
+    ///
+    /// Any code already parsed keeps its original highlighting.
+    ///
+    /// [`Theme`]: iced_highlighter::Theme
+    #[cfg(feature = "highlighter")]
+    pub fn highlight(mut self, theme: iced_highlighter::Theme) -> Self {
+        self.state.theme = Some(theme);
+        self
+    }
+
     /// Pushes more Markdown into the [`Content`]; parsing incrementally!
     ///
     /// This is specially useful when you have long streams of Markdown; like
@@ -114,7 +137,13 @@ impl Content {
         };
 
         // Pop the last item
-        let _ = self.items.pop();
+        if let Some(mut item) = self.items.pop() {
+            self.state.forget(&mut item);
+        }
+
+        let _ = self.sources.pop();
+
+        let offset = self.state.offset;
 
         // Re-parse last item and new text
         for (item, source, broken_links) in parse_with(&mut self.state, input) {
@@ -122,13 +151,20 @@ impl Content {
                 let _ = self.incomplete.insert(
                     self.items.len(),
                     Section {
-                        content: source.to_owned(),
+                        content: input[source.start - offset..source.end - offset].to_owned(),
+                        offset: source.start,
                         broken_links,
                     },
                 );
             }
 
             self.items.push(item);
+            self.sources.push(source);
+        }
+
+        // Skip input that produced no items
+        if self.state.leftover.is_empty() {
+            self.state.offset = offset + input.len();
         }
 
         self.state.leftover.push_str(&leftover[input.len()..]);
@@ -149,15 +185,35 @@ impl Content {
                 if broken_links_before != section.broken_links.len() {
                     let mut state = State {
                         leftover: String::new(),
+                        offset: section.offset,
                         references: self.state.references.clone(),
                         images: HashSet::new(),
+                        anchors: HashMap::new(),
+                        #[cfg(feature = "highlighter")]
+                        theme: self.state.theme,
                         #[cfg(feature = "highlighter")]
                         highlighter: None,
                     };
 
-                    if let Some((item, _source, _broken_links)) =
+                    if let Some((mut item, _source, _broken_links)) =
                         parse_with(&mut state, &section.content).next()
                     {
+                        // Keep the anchors of the original headings, since
+                        // they were deduplicated against the whole document
+                        let mut anchors = Vec::new();
+
+                        visit_headings(&mut self.items[*index], &mut |text| {
+                            anchors.push(text.anchor.take());
+                        });
+
+                        let mut anchors = anchors.into_iter();
+
+                        visit_headings(&mut item, &mut |text| {
+                            if let Some(original) = anchors.next() {
+                                text.anchor = original;
+                            }
+                        });
+
                         self.items[*index] = item;
                     }
 
@@ -177,6 +233,12 @@ impl Content {
         &self.items
     }
 
+    /// Returns the byte range in the Markdown source of the top-level
+    /// [`Item`] with the given index, if any.
+    pub fn source(&self, index: usize) -> Option<Range<usize>> {
+        self.sources.get(index).cloned()
+    }
+
     /// Returns the URLs of the Markdown images present in the [`Content`].
     pub fn images(&self) -> &HashSet<Uri> {
         &self.state.images
@@ -187,6 +249,8 @@ impl Content {
 #[derive(Debug, Clone)]
 pub enum Item {
     /// A heading.
+    ///
+    /// The [`Anchor`] of a heading is available through [`Text::anchor`].
     Heading(pulldown_cmark::HeadingLevel, Text),
     /// A paragraph.
     Paragraph(Text),
@@ -219,6 +283,19 @@ pub enum Item {
     },
     /// A quote.
     Quote(Vec<Item>),
+    /// A GitHub-style admonition; like `> [!NOTE]`.
+    Admonition(BlockQuoteKind, Vec<Item>),
+    /// The definition of a footnote.
+    Footnote {
+        /// The label of the footnote.
+        label: String,
+        /// The contents of the footnote.
+        items: Vec<Item>,
+    },
+    /// A block of display math; like `$$ e^{i\pi} + 1 = 0 $$`.
+    ///
+    /// The math is not typeset; its raw source is kept instead.
+    Math(String),
     /// A horizontal separator.
     Rule,
     /// A table.
@@ -250,6 +327,7 @@ pub struct Row {
 #[derive(Debug, Clone)]
 pub struct Text {
     spans: Vec<Span>,
+    anchor: Option<Anchor>,
     last_style: Cell<Option<Style>>,
     last_styled_spans: RefCell<Arc<[text::Span<'static, Uri>]>>,
 }
@@ -258,6 +336,7 @@ impl Text {
     fn new(spans: Vec<Span>) -> Self {
         Self {
             spans,
+            anchor: None,
             last_style: Cell::default(),
             last_styled_spans: RefCell::default(),
         }
@@ -277,6 +356,22 @@ impl Text {
 
         self.last_styled_spans.borrow().clone()
     }
+
+    /// Returns the [`Anchor`] of the [`Text`], if it is the text of a heading.
+    pub fn anchor(&self) -> Option<&str> {
+        self.anchor.as_deref()
+    }
+
+    fn plain(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| match span {
+                Span::Standard { text, .. } => text.as_str(),
+                #[cfg(feature = "highlighter")]
+                Span::Highlight { text, .. } => text.as_str(),
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -288,6 +383,7 @@ enum Span {
         strong: bool,
         emphasis: bool,
         code: bool,
+        script: Option<Script>,
     },
     #[cfg(feature = "highlighter")]
     Highlight {
@@ -307,31 +403,35 @@ impl Span {
                 strong,
                 emphasis,
                 code,
+                script,
             } => {
                 let span = span(text.clone()).strikethrough(*strikethrough);
 
                 let span = if *code {
-                    span.font(style.inline_code_font)
+                    span.font(script_font(style.inline_code_font, *script))
                         .color(style.inline_code_color)
                         .background(style.inline_code_highlight.background)
                         .border(style.inline_code_highlight.border)
                         .padding(style.inline_code_padding)
                 } else if *strong || *emphasis {
-                    span.font(Font {
-                        weight: if *strong {
-                            font::Weight::Bold
-                        } else {
-                            font::Weight::Normal
-                        },
-                        style: if *emphasis {
-                            font::Style::Italic
-                        } else {
-                            font::Style::Normal
+                    span.font(script_font(
+                        Font {
+                            weight: if *strong {
+                                font::Weight::Bold
+                            } else {
+                                font::Weight::Normal
+                            },
+                            style: if *emphasis {
+                                font::Style::Italic
+                            } else {
+                                font::Style::Normal
+                            },
+                            ..style.font
                         },
-                        ..style.font
-                    })
+                        *script,
+                    ))
                 } else {
-                    span.font(style.font)
+                    span.font(script_font(style.font, *script))
                 };
 
                 if let Some(link) = link.as_ref() {
@@ -362,6 +462,9 @@ pub enum Bullet {
         items: Vec<Item>,
         /// Whether the task is done or not.
         done: bool,
+        /// The byte range of the task marker (i.e. `[ ]` or `[x]`) in the
+        /// Markdown source.
+        marker: Range<usize>,
     },
 }
 
@@ -426,31 +529,116 @@ pub fn parse(markdown: &str) -> impl Iterator<Item = Item> + '_ {
 #[derive(Debug, Default)]
 struct State {
     leftover: String,
+    offset: usize,
     references: HashMap<String, String>,
     images: HashSet<Uri>,
+    anchors: HashMap<Anchor, usize>,
+    #[cfg(feature = "highlighter")]
+    theme: Option<iced_highlighter::Theme>,
     #[cfg(feature = "highlighter")]
     highlighter: Option<Highlighter>,
 }
 
+impl State {
+    /// Generates a unique [`Anchor`] for a heading with the given [`Text`].
+    fn anchor(&mut self, text: &Text) -> Anchor {
+        let slug = slugify(&text.plain());
+        let count = self.anchors.entry(slug.clone()).or_default();
+
+        let anchor = if *count == 0 {
+            slug
+        } else {
+            format!("{slug}-{count}")
+        };
+
+        *count += 1;
+        anchor
+    }
+
+    /// Releases the anchors of the headings in the given [`Item`].
+    fn forget(&mut self, item: &mut Item) {
+        visit_headings(item, &mut |text| {
+            if let Some(count) = self.anchors.get_mut(&slugify(&text.plain())) {
+                *count = count.saturating_sub(1);
+            }
+        });
+    }
+}
+
+/// Turns the given title into an [`Anchor`]; like GitHub does.
+fn slugify(title: &str) -> Anchor {
+    title
+        .trim()
+        .chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                Some(c)
+            } else if c.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Calls the given function for every heading in the [`Item`]; including
+/// nested ones.
+fn visit_headings(item: &mut Item, f: &mut impl FnMut(&mut Text)) {
+    match item {
+        Item::Heading(_, text) => f(text),
+        Item::List { bullets, .. } => {
+            for bullet in bullets {
+                let (Bullet::Point { items } | Bullet::Task { items, .. }) = bullet;
+
+                for item in items {
+                    visit_headings(item, f);
+                }
+            }
+        }
+        Item::Quote(items) | Item::Admonition(_, items) | Item::Footnote { items, .. } => {
+            for item in items {
+                visit_headings(item, f);
+            }
+        }
+        Item::Table { columns, rows } => {
+            let headers = columns.iter_mut().map(|column| &mut column.header);
+            let cells = rows.iter_mut().flat_map(|row| row.cells.iter_mut());
+
+            for item in headers.chain(cells).flatten() {
+                visit_headings(item, f);
+            }
+        }
+        Item::Paragraph(_)
+        | Item::CodeBlock { .. }
+        | Item::Image { .. }
+        | Item::Rule
+        | Item::Math(_) => {}
+    }
+}
+
 #[cfg(feature = "highlighter")]
 #[derive(Debug)]
 struct Highlighter {
     lines: Vec<(String, Vec<Span>)>,
     language: String,
+    theme: iced_highlighter::Theme,
     parser: iced_highlighter::Stream,
     current: usize,
 }
 
 #[cfg(feature = "highlighter")]
 impl Highlighter {
-    pub fn new(language: &str) -> Self {
+    pub fn new(language: &str, theme: iced_highlighter::Theme) -> Self {
         Self {
             lines: Vec::new(),
             parser: iced_highlighter::Stream::new(&iced_highlighter::Settings {
-                theme: iced_highlighter::Theme::Base16Ocean,
+                theme,
                 token: language.to_owned(),
             }),
             language: language.to_owned(),
+            theme,
             current: 0,
         }
     }
@@ -512,10 +700,15 @@ impl Highlighter {
 fn parse_with<'a>(
     mut state: impl BorrowMut<State> + 'a,
     markdown: &'a str,
-) -> impl Iterator<Item = (Item, &'a str, HashSet<String>)> + 'a {
+) -> impl Iterator<Item = (Item, Range<usize>, HashSet<String>)> + 'a {
     enum Scope {
         List(List),
         Quote(Vec<Item>),
+        Admonition(BlockQuoteKind, Vec<Item>),
+        Footnote {
+            label: String,
+            items: Vec<Item>,
+        },
         Table {
             alignment: Vec<pulldown_cmark::Alignment>,
             columns: Vec<Column>,
@@ -538,6 +731,9 @@ fn parse_with<'a>(
     let mut strong = false;
     let mut emphasis = false;
     let mut strikethrough = false;
+    let mut keyboard = false;
+    let mut script: Option<Script> = None;
+    let mut math = None;
     let mut metadata = false;
     let mut code_block = false;
     let mut link = None;
@@ -553,7 +749,12 @@ fn parse_with<'a>(
             | pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
             | pulldown_cmark::Options::ENABLE_TABLES
             | pulldown_cmark::Options::ENABLE_STRIKETHROUGH
-            | pulldown_cmark::Options::ENABLE_TASKLISTS,
+            | pulldown_cmark::Options::ENABLE_TASKLISTS
+            | pulldown_cmark::Options::ENABLE_MATH
+            | pulldown_cmark::Options::ENABLE_GFM
+            // GFM footnotes drop references to definitions that have not
+            // been parsed yet, which breaks incremental parsing
+            | pulldown_cmark::Options::ENABLE_OLD_FOOTNOTES,
         {
             let references = state.borrow().references.clone();
             let broken_links = broken_links.clone();
@@ -574,6 +775,7 @@ fn parse_with<'a>(
         },
     );
 
+    let offset = state.borrow().offset;
     let references = &mut state.borrow_mut().references;
 
     for reference in parser.reference_definitions().iter() {
@@ -586,7 +788,9 @@ fn parse_with<'a>(
                 Scope::List(list) => {
                     list.bullets.last_mut().expect("item context").push(item);
                 }
-                Scope::Quote(items) => {
+                Scope::Quote(items)
+                | Scope::Admonition(_, items)
+                | Scope::Footnote { items, .. } => {
                     items.push(item);
                 }
                 Scope::Table { current, .. } => {
@@ -597,10 +801,11 @@ fn parse_with<'a>(
             None
         } else {
             state.leftover = markdown[source.start..].to_owned();
+            state.offset = offset + source.start;
 
             Some((
                 item,
-                &markdown[source.start..source.end],
+                offset + source.start..offset + source.end,
                 broken_links.take(),
             ))
         }
@@ -660,7 +865,26 @@ fn parse_with<'a>(
 
                 None
             }
-            pulldown_cmark::Tag::BlockQuote(_kind) if !metadata => {
+            pulldown_cmark::Tag::BlockQuote(kind) if !metadata => {
+                let prev = if spans.is_empty() {
+                    None
+                } else {
+                    produce(
+                        state.borrow_mut(),
+                        &mut stack,
+                        Item::Paragraph(Text::new(spans.drain(..).collect())),
+                        source,
+                    )
+                };
+
+                stack.push(match kind {
+                    Some(kind) => Scope::Admonition(kind, Vec::new()),
+                    None => Scope::Quote(Vec::new()),
+                });
+
+                prev
+            }
+            pulldown_cmark::Tag::FootnoteDefinition(label) if !metadata => {
                 let prev = if spans.is_empty() {
                     None
                 } else {
@@ -672,7 +896,10 @@ fn parse_with<'a>(
                     )
                 };
 
-                stack.push(Scope::Quote(Vec::new()));
+                stack.push(Scope::Footnote {
+                    label: label.into_string(),
+                    items: Vec::new(),
+                });
 
                 prev
             }
@@ -682,13 +909,22 @@ fn parse_with<'a>(
                 #[cfg(feature = "highlighter")]
                 {
                     highlighter = Some({
+                        let state = state.borrow_mut();
+
+                        let theme = state.theme.unwrap_or(iced_highlighter::Theme::Base16Ocean);
+
                         let mut highlighter = state
-                            .borrow_mut()
                             .highlighter
                             .take()
-                            .filter(|highlighter| highlighter.language == language.as_ref())
+                            .filter(|highlighter| {
+                                highlighter.language == language.as_ref()
+                                    && highlighter.theme == theme
+                            })
                             .unwrap_or_else(|| {
-                                Highlighter::new(language.split(',').next().unwrap_or_default())
+                                Highlighter::new(
+                                    language.split(',').next().unwrap_or_default(),
+                                    theme,
+                                )
                             });
 
                         highlighter.prepare();
@@ -740,12 +976,17 @@ fn parse_with<'a>(
             _ => None,
         },
         pulldown_cmark::Event::End(tag) => match tag {
-            pulldown_cmark::TagEnd::Heading(level) if !metadata => produce(
-                state.borrow_mut(),
-                &mut stack,
-                Item::Heading(level, Text::new(spans.drain(..).collect())),
-                source,
-            ),
+            pulldown_cmark::TagEnd::Heading(level) if !metadata => {
+                keyboard = false;
+                script = None;
+
+                let mut text = Text::new(spans.drain(..).collect());
+
+                let state = state.borrow_mut();
+                text.anchor = Some(state.anchor(&text));
+
+                produce(state, &mut stack, Item::Heading(level, text), source)
+            }
             pulldown_cmark::TagEnd::Strong if !metadata => {
                 strong = false;
                 None
@@ -762,26 +1003,20 @@ fn parse_with<'a>(
                 link = None;
                 None
             }
-            pulldown_cmark::TagEnd::Paragraph if !metadata => {
-                if spans.is_empty() {
-                    None
-                } else {
-                    produce(
-                        state.borrow_mut(),
-                        &mut stack,
-                        Item::Paragraph(Text::new(spans.drain(..).collect())),
-                        source,
-                    )
-                }
-            }
-            pulldown_cmark::TagEnd::Item if !metadata => {
+            pulldown_cmark::TagEnd::Paragraph | pulldown_cmark::TagEnd::Item if !metadata => {
+                // Unclosed inline tags do not leak into the next block
+                keyboard = false;
+                script = None;
+
+                let math = math.take();
+
                 if spans.is_empty() {
                     None
                 } else {
                     produce(
                         state.borrow_mut(),
                         &mut stack,
-                        Item::Paragraph(Text::new(spans.drain(..).collect())),
+                        block(spans.drain(..).collect(), math),
                         source,
                     )
                 }
@@ -804,13 +1039,25 @@ fn parse_with<'a>(
                 )
             }
             pulldown_cmark::TagEnd::BlockQuote(_kind) if !metadata => {
-                let scope = stack.pop()?;
+                let item = match stack.pop()? {
+                    Scope::Quote(quote) => Item::Quote(quote),
+                    Scope::Admonition(kind, items) => Item::Admonition(kind, items),
+                    _ => return None,
+                };
 
-                let Scope::Quote(quote) = scope else {
+                produce(state.borrow_mut(), &mut stack, item, source)
+            }
+            pulldown_cmark::TagEnd::FootnoteDefinition if !metadata => {
+                let Scope::Footnote { label, items } = stack.pop()? else {
                     return None;
                 };
 
-                produce(state.borrow_mut(), &mut stack, Item::Quote(quote), source)
+                produce(
+                    state.borrow_mut(),
+                    &mut stack,
+                    Item::Footnote { label, items },
+                    source,
+                )
             }
             pulldown_cmark::TagEnd::Image if !metadata => {
                 let (url, title) = image.take()?;
@@ -863,6 +1110,9 @@ fn parse_with<'a>(
                 None
             }
             pulldown_cmark::TagEnd::TableCell => {
+                keyboard = false;
+                script = None;
+
                 if !spans.is_empty() {
                     let _ = produce(
                         state.borrow_mut(),
@@ -918,19 +1168,31 @@ fn parse_with<'a>(
                         strikethrough,
                         link: link.clone(),
                         code: false,
+                        script: None,
                     }]));
                 }
 
                 return None;
             }
 
+            // Text without a Unicode form keeps its script, so that it can be
+            // rendered with the script features of the font instead
+            let (text, script) = match script {
+                Some(script) => match script.convert(&text) {
+                    Some(converted) => (converted, None),
+                    None => (text.into_string(), Some(script)),
+                },
+                None => (text.into_string(), None),
+            };
+
             let span = Span::Standard {
-                text: text.into_string(),
+                text,
                 strong,
                 emphasis,
                 strikethrough,
                 link: link.clone(),
-                code: false,
+                code: keyboard,
+                script,
             };
 
             spans.push(span);
@@ -945,6 +1207,7 @@ fn parse_with<'a>(
                 strikethrough,
                 link: link.clone(),
                 code: true,
+                script: None,
             };
 
             spans.push(span);
@@ -958,6 +1221,7 @@ fn parse_with<'a>(
                 emphasis,
                 link: link.clone(),
                 code: false,
+                script: None,
             });
             None
         }
@@ -969,9 +1233,74 @@ fn parse_with<'a>(
                 emphasis,
                 link: link.clone(),
                 code: false,
+                script: None,
+            });
+            None
+        }
+        pulldown_cmark::Event::InlineMath(content) if !metadata => {
+            spans.push(Span::Standard {
+                text: content.into_string(),
+                strong,
+                emphasis,
+                strikethrough,
+                link: link.clone(),
+                code: true,
+                script: None,
             });
             None
         }
+        pulldown_cmark::Event::DisplayMath(content) if !metadata => {
+            math = Some(content.trim().to_owned());
+
+            spans.push(Span::Standard {
+                text: content.into_string(),
+                strong,
+                emphasis,
+                strikethrough,
+                link: link.clone(),
+                code: true,
+                script: None,
+            });
+            None
+        }
+        pulldown_cmark::Event::FootnoteReference(label) if !metadata => {
+            spans.push(Span::Standard {
+                text: footnote_label(&label),
+                strong,
+                emphasis,
+                strikethrough,
+                link: link.clone(),
+                code: false,
+                script: None,
+            });
+            None
+        }
+        pulldown_cmark::Event::InlineHtml(html) if !metadata => {
+            match Html::parse(&html)? {
+                (Html::Keyboard, is_open) => {
+                    keyboard = is_open;
+                }
+                (Html::Subscript, is_open) => {
+                    script = is_open.then_some(Script::Sub);
+                }
+                (Html::Superscript, is_open) => {
+                    script = is_open.then_some(Script::Super);
+                }
+                (Html::Break, _) => {
+                    spans.push(Span::Standard {
+                        text: String::from("\n"),
+                        strikethrough,
+                        strong,
+                        emphasis,
+                        link: link.clone(),
+                        code: false,
+                        script: None,
+                    });
+                }
+            }
+
+            None
+        }
         pulldown_cmark::Event::Rule => produce(state.borrow_mut(), &mut stack, Item::Rule, source),
         pulldown_cmark::Event::TaskListMarker(done) => {
             if let Some(Scope::List(list)) = stack.last_mut()
@@ -981,6 +1310,7 @@ fn parse_with<'a>(
                 *item = Bullet::Task {
                     items: std::mem::take(items),
                     done,
+                    marker: offset + source.start..offset + source.end,
                 };
             }
 
@@ -990,6 +1320,145 @@ fn parse_with<'a>(
     })
 }
 
+/// Turns the given spans into a paragraph; unless they only contain
+/// some display math.
+fn block(spans: Vec<Span>, math: Option<String>) -> Item {
+    match math {
+        Some(math) if spans.len() == 1 => Item::Math(math),
+        _ => Item::Paragraph(Text::new(spans)),
+    }
+}
+
+/// The supported subset of inline HTML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Html {
+    Keyboard,
+    Subscript,
+    Superscript,
+    Break,
+}
+
+impl Html {
+    /// Parses an opening or closing HTML tag; returning whether it opens.
+    fn parse(tag: &str) -> Option<(Self, bool)> {
+        let tag = tag.trim().strip_prefix('<')?.strip_suffix('>')?;
+
+        let (tag, is_open) = match tag.strip_prefix('/') {
+            Some(tag) => (tag, false),
+            None => (tag, true),
+        };
+
+        let name = tag.trim_end_matches('/').split_whitespace().next()?;
+
+        let html = match name.to_ascii_lowercase().as_str() {
+            "kbd" => Html::Keyboard,
+            "sub" => Html::Subscript,
+            "sup" => Html::Superscript,
+            "br" => Html::Break,
+            _ => return None,
+        };
+
+        Some((html, is_open))
+    }
+}
+
+/// The vertical placement of some text, relative to its baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Sub,
+    Super,
+}
+
+impl Script {
+    /// Converts the given text into its Unicode subscript or superscript
+    /// form; if every character of the text has one.
+    fn convert(self, text: &str) -> Option<String> {
+        text.chars()
+            .map(|c| match self {
+                Script::Sub => subscript(c),
+                Script::Super => superscript(c),
+            })
+            .collect()
+    }
+}
+
+/// Returns the given [`Font`] with the OpenType features of the given
+/// [`Script`]; which render smaller glyphs offset from the baseline.
+///
+/// The script features replace any other features of the [`Font`].
+fn script_font(font: Font, script: Option<Script>) -> Font {
+    match script {
+        Some(Script::Sub) => font.features(&[font::Feature::SUBSCRIPT]),
+        Some(Script::Super) => font.features(&[font::Feature::SUPERSCRIPT]),
+        None => font,
+    }
+}
+
+fn subscript(c: char) -> Option<char> {
+    Some(match c {
+        '0' => '₀',
+        '1' => '₁',
+        '2' => '₂',
+        '3' => '₃',
+        '4' => '₄',
+        '5' => '₅',
+        '6' => '₆',
+        '7' => '₇',
+        '8' => '₈',
+        '9' => '₉',
+        '+' => '₊',
+        '-' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'h' => 'ₕ',
+        'k' => 'ₖ',
+        'l' => 'ₗ',
+        'm' => 'ₘ',
+        'n' => 'ₙ',
+        'o' => 'ₒ',
+        'p' => 'ₚ',
+        's' => 'ₛ',
+        't' => 'ₜ',
+        'x' => 'ₓ',
+        ' ' => ' ',
+        _ => return None,
+    })
+}
+
+fn superscript(c: char) -> Option<char> {
+    Some(match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        '+' => '⁺',
+        '-' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        'i' => 'ⁱ',
+        'n' => 'ⁿ',
+        ' ' => ' ',
+        _ => return None,
+    })
+}
+
+/// Returns the label of a footnote, as displayed by its references.
+fn footnote_label(label: &str) -> String {
+    Script::Super
+        .convert(label)
+        .unwrap_or_else(|| format!("[{label}]"))
+}
+
 /// Configuration controlling Markdown rendering in [`view`].
 #[derive(Debug, Clone, Copy)]
 pub struct Settings {
@@ -1219,11 +1688,162 @@ where
             bullets,
         } => viewer.ordered_list(settings, *start, bullets),
         Item::Quote(quote) => viewer.quote(settings, quote),
+        Item::Admonition(kind, contents) => viewer.admonition(settings, *kind, contents),
+        Item::Footnote { label, items } => viewer.footnote(settings, label, items),
+        Item::Math(math) => viewer.math(settings, math),
         Item::Rule => viewer.rule(settings),
         Item::Table { columns, rows } => viewer.table(settings, columns, rows),
     }
 }
 
+/// An entry in the table of contents of some Markdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The level of the heading.
+    pub level: HeadingLevel,
+    /// The plain text of the heading.
+    pub title: String,
+    /// The [`Anchor`] of the heading.
+    pub anchor: Anchor,
+}
+
+/// Generates the table of contents of some Markdown items; with an [`Entry`]
+/// for each top-level heading.
+pub fn toc<'a>(items: impl IntoIterator<Item = &'a Item>) -> Vec<Entry> {
+    items
+        .into_iter()
+        .filter_map(|item| match item {
+            Item::Heading(level, text) => Some(Entry {
+                level: *level,
+                title: text.plain(),
+                anchor: text.anchor.clone()?,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Displays a table of contents using the default look.
+///
+/// Clicking an [`Entry`] produces a link to its heading as a [`Uri`];
+/// like `#getting-started`. You can use [`scroll_to`] to follow it.
+pub fn table_of_contents<'a, Theme, Renderer>(
+    entries: &'a [Entry],
+    settings: impl Into<Settings>,
+) -> Element<'a, Uri, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    let settings = settings.into();
+
+    let top_level = entries
+        .iter()
+        .map(|entry| entry.level as usize)
+        .min()
+        .unwrap_or(1);
+
+    column(entries.iter().map(|entry| {
+        let depth = entry.level as usize - top_level;
+
+        container(
+            rich_text([span(entry.title.as_str())
+                .font(settings.style.font)
+                .color(settings.style.link_color)
+                .link(format!("#{}", entry.anchor))])
+            .size(settings.text_size)
+            .on_link_click(std::convert::identity),
+        )
+        .padding(padding::left(settings.spacing * depth as f32))
+        .into()
+    }))
+    .spacing(settings.spacing.0 / 2.0)
+    .into()
+}
+
+/// Returns the [`Id`] of the heading with the given [`Anchor`].
+///
+/// The default [`heading`] already uses it. If you display headings with
+/// a custom [`Viewer`], you will need to assign it yourself, using
+/// [`Text::anchor`], for [`scroll_to`] to work.
+pub fn heading_id(anchor: &str) -> Id {
+    Id::from(format!("markdown#{}", anchor.trim_start_matches('#')))
+}
+
+/// Scrolls the [`Scrollable`] with the given [`Id`] to the heading with the
+/// given [`Anchor`].
+///
+/// The [`Anchor`] may start with `#`; so the [`Uri`] of a link to a
+/// heading can be used directly.
+///
+/// [`Scrollable`]: crate::Scrollable
+pub fn scroll_to<Message>(scrollable: impl Into<Id>, anchor: &str) -> iced_runtime::Task<Message>
+where
+    Message: iced_runtime::futures::MaybeSend + 'static,
+{
+    iced_runtime::task::widget(ScrollTo::new(scrollable.into(), anchor)).discard()
+}
+
+/// An [`Operation`] that scrolls a [`Scrollable`] to one of its headings.
+///
+/// [`Scrollable`]: crate::Scrollable
+struct ScrollTo {
+    scrollable: Id,
+    heading: Id,
+    content: Option<Rectangle>,
+    target: Option<Rectangle>,
+}
+
+impl ScrollTo {
+    fn new(scrollable: Id, anchor: &str) -> Self {
+        Self {
+            scrollable,
+            heading: heading_id(anchor),
+            content: None,
+            target: None,
+        }
+    }
+}
+
+impl Operation for ScrollTo {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        content_bounds: Rectangle,
+        _translation: Vector,
+        _state: &mut dyn operation::Scrollable,
+    ) {
+        if id == Some(&self.scrollable) {
+            self.content = Some(content_bounds);
+        }
+    }
+
+    fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+        if self.content.is_some() && self.target.is_none() && id == Some(&self.heading) {
+            self.target = Some(bounds);
+        }
+    }
+
+    fn finish(&self) -> operation::Outcome<()> {
+        let (Some(content), Some(target)) = (self.content, self.target) else {
+            return operation::Outcome::None;
+        };
+
+        operation::Outcome::Chain(Box::new(operation::scrollable::scroll_to(
+            self.scrollable.clone(),
+            operation::scrollable::AbsoluteOffset {
+                x: None,
+                y: Some(target.y - content.y),
+            },
+        )))
+    }
+}
+
 /// Displays a heading using the default look.
 pub fn heading<'a, Message, Theme, Renderer>(
     settings: Settings,
@@ -1248,7 +1868,7 @@ where
         ..
     } = settings;
 
-    container(
+    let heading = container(
         rich_text(text.spans(settings.style))
             .on_link_click(on_link_click)
             .size(match level {
@@ -1264,8 +1884,12 @@ where
         text_size / 2.0
     } else {
         Pixels::ZERO
-    }))
-    .into()
+    }));
+
+    match text.anchor() {
+        Some(anchor) => heading.id(heading_id(anchor)).into(),
+        None => heading.into(),
+    }
 }
 
 /// Displays a paragraph using the default look.
@@ -1303,10 +1927,18 @@ where
                 Bullet::Point { .. } => {
                     text("•").size(settings.text_size).into()
                 }
-                Bullet::Task { done, .. } => {
+                Bullet::Task { done, marker, .. } => {
+                    let on_toggle = viewer
+                        .on_task_toggle()
+                        .map(|on_toggle| move |done| on_toggle(marker.clone(), done));
+
                     Element::from(
-                        container(checkbox(*done).size(settings.text_size))
-                            .center_y(text::LineHeight::default().to_absolute(settings.text_size)),
+                        container(
+                            checkbox(*done)
+                                .size(settings.text_size)
+                                .on_toggle_maybe(on_toggle),
+                        )
+                        .center_y(text::LineHeight::default().to_absolute(settings.text_size)),
                     )
                 }
             },
@@ -1424,6 +2056,81 @@ where
     .into()
 }
 
+/// Displays an admonition using the default look.
+pub fn admonition<'a, Message, Theme, Renderer>(
+    viewer: &impl Viewer<'a, Message, Theme, Renderer>,
+    settings: Settings,
+    kind: BlockQuoteKind,
+    contents: &'a [Item],
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    let title = match kind {
+        BlockQuoteKind::Note => "Note",
+        BlockQuoteKind::Tip => "Tip",
+        BlockQuoteKind::Important => "Important",
+        BlockQuoteKind::Warning => "Warning",
+        BlockQuoteKind::Caution => "Caution",
+    };
+
+    container(
+        column![
+            text(title).size(settings.text_size).font(Font {
+                weight: font::Weight::Bold,
+                ..settings.style.font
+            }),
+            items(viewer, settings, contents),
+        ]
+        .spacing(settings.spacing.0 / 2.0),
+    )
+    .width(Length::Fill)
+    .padding(settings.spacing.0)
+    .class(Theme::admonition(kind))
+    .into()
+}
+
+/// Displays a footnote using the default look.
+pub fn footnote<'a, Message, Theme, Renderer>(
+    viewer: &impl Viewer<'a, Message, Theme, Renderer>,
+    settings: Settings,
+    label: &'a str,
+    contents: &'a [Item],
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    row![
+        text(footnote_label(label)).size(settings.text_size),
+        items(viewer, settings, contents),
+    ]
+    .spacing(settings.spacing.0 / 2.0)
+    .into()
+}
+
+/// Displays a block of display math using the default look.
+pub fn math<'a, Message, Theme, Renderer>(
+    settings: Settings,
+    content: &'a str,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer<Font = Font> + 'a,
+{
+    container(
+        text(content)
+            .font(settings.style.code_block_font)
+            .size(settings.text_size),
+    )
+    .center_x(Length::Fill)
+    .into()
+}
+
 /// Displays a rule using the default look.
 pub fn rule<'a, Message, Theme, Renderer>() -> Element<'a, Message, Theme, Renderer>
 where
@@ -1511,6 +2218,18 @@ where
     /// Produces a message when a link is clicked with the given [`Uri`].
     fn on_link_click(url: Uri) -> Message;
 
+    /// Returns the function used to produce a message when a task is toggled,
+    /// if any.
+    ///
+    /// The function receives the byte range of the task marker in the Markdown
+    /// source and whether the task is now done; so you can rewrite the source
+    /// accordingly.
+    ///
+    /// By default, it returns `None` and tasks can't be toggled.
+    fn on_task_toggle(&self) -> Option<fn(Range<usize>, bool) -> Message> {
+        None
+    }
+
     /// Displays an image.
     ///
     /// By default, it will show a container with the image title.
@@ -1600,6 +2319,37 @@ where
         quote(self, settings, contents)
     }
 
+    /// Displays an admonition.
+    ///
+    /// By default, it calls [`admonition`].
+    fn admonition(
+        &self,
+        settings: Settings,
+        kind: BlockQuoteKind,
+        contents: &'a [Item],
+    ) -> Element<'a, Message, Theme, Renderer> {
+        admonition(self, settings, kind, contents)
+    }
+
+    /// Displays the definition of a footnote.
+    ///
+    /// By default, it calls [`footnote`].
+    fn footnote(
+        &self,
+        settings: Settings,
+        label: &'a str,
+        contents: &'a [Item],
+    ) -> Element<'a, Message, Theme, Renderer> {
+        footnote(self, settings, label, contents)
+    }
+
+    /// Displays a block of display math.
+    ///
+    /// By default, it calls [`math`].
+    fn math(&self, settings: Settings, content: &'a str) -> Element<'a, Message, Theme, Renderer> {
+        math(settings, content)
+    }
+
     /// Displays a rule.
     ///
     /// By default, it calls [`rule`](self::rule()).
@@ -1644,10 +2394,254 @@ pub trait Catalog:
 {
     /// The styling class of a Markdown code block.
     fn code_block<'a>() -> <Self as container::Catalog>::Class<'a>;
+
+    /// The styling class of a Markdown admonition of the given kind.
+    ///
+    /// By default, admonitions use the default container class.
+    fn admonition<'a>(_kind: BlockQuoteKind) -> <Self as container::Catalog>::Class<'a> {
+        <Self as container::Catalog>::default()
+    }
 }
 
 impl Catalog for Theme {
     fn code_block<'a>() -> <Self as container::Catalog>::Class<'a> {
         Box::new(container::dark)
     }
+
+    fn admonition<'a>(kind: BlockQuoteKind) -> <Self as container::Catalog>::Class<'a> {
+        Box::new(move |theme: &Theme| {
+            let palette = theme.palette();
+
+            let color = match kind {
+                BlockQuoteKind::Note => palette.primary.base.color,
+                BlockQuoteKind::Tip => palette.success.base.color,
+                BlockQuoteKind::Important => palette.secondary.strong.color,
+                BlockQuoteKind::Warning => palette.warning.base.color,
+                BlockQuoteKind::Caution => palette.danger.base.color,
+            };
+
+            container::Style {
+                background: Some(color.scale_alpha(0.1).into()),
+                border: border::rounded(4).color(color).width(1),
+                ..container::Style::default()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn streamed(markdown: &str) -> Content {
+        let mut content = Content::new();
+        let mut chars = [0; 4];
+
+        for c in markdown.chars() {
+            content.push_str(c.encode_utf8(&mut chars));
+        }
+
+        content
+    }
+
+    fn assert_streams(markdown: &str) {
+        assert_eq!(
+            format!("{:?}", streamed(markdown).items()),
+            format!("{:?}", Content::parse(markdown).items()),
+        );
+    }
+
+    #[test]
+    fn admonitions_are_parsed() {
+        let markdown = "> [!WARNING]\n> Careful!\n\n> Just a quote\n";
+        let content = Content::parse(markdown);
+
+        let [Item::Admonition(kind, items), Item::Quote(quote)] = content.items() else {
+            panic!("unexpected items: {:?}", content.items());
+        };
+
+        assert_eq!(*kind, BlockQuoteKind::Warning);
+        assert!(matches!(&items[..], [Item::Paragraph(text)] if text.plain() == "Careful!"));
+        assert!(matches!(&quote[..], [Item::Paragraph(text)] if text.plain() == "Just a quote"));
+
+        assert_streams(markdown);
+    }
+
+    #[test]
+    fn footnotes_are_parsed() {
+        let markdown = "A claim[^1].\n\n[^1]: A source.\n";
+        let content = Content::parse(markdown);
+
+        let [Item::Paragraph(text), Item::Footnote { label, items }] = content.items() else {
+            panic!("unexpected items: {:?}", content.items());
+        };
+
+        assert_eq!(text.plain(), "A claim¹.");
+        assert_eq!(label, "1");
+        assert!(matches!(&items[..], [Item::Paragraph(text)] if text.plain() == "A source."));
+
+        assert_streams(markdown);
+    }
+
+    #[test]
+    fn math_is_parsed() {
+        let markdown = "Inline $x^2$ math.\n\n$$\n\\sum_i x_i\n$$\n";
+        let content = Content::parse(markdown);
+
+        let [Item::Paragraph(text), Item::Math(math)] = content.items() else {
+            panic!("unexpected items: {:?}", content.items());
+        };
+
+        assert_eq!(text.plain(), "Inline x^2 math.");
+        assert_eq!(math, "\\sum_i x_i");
+
+        assert_streams(markdown);
+    }
+
+    #[test]
+    fn scripts_without_unicode_forms_keep_their_script() {
+        let content = Content::parse("H<sub>2</sub>O is x<sup>y</sup>\n\nunclosed <sup>z\n\nplain");
+
+        let scripts: Vec<Vec<_>> = content
+            .items()
+            .iter()
+            .map(|item| {
+                let Item::Paragraph(text) = item else {
+                    panic!("unexpected item: {item:?}");
+                };
+
+                text.spans
+                    .iter()
+                    .filter_map(|span| match span {
+                        Span::Standard { text, script, .. } => Some((text.as_str(), *script)),
+                        #[cfg(feature = "highlighter")]
+                        Span::Highlight { .. } => None,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(
+            scripts,
+            [
+                vec![
+                    ("H", None),
+                    ("₂", None),
+                    ("O is x", None),
+                    ("y", Some(Script::Super)),
+                ],
+                vec![("unclosed ", None), ("z", Some(Script::Super))],
+                vec![("plain", None)],
+            ]
+        );
+    }
+
+    #[test]
+    fn task_markers_point_to_the_source() {
+        let markdown = "Tasks:\n\n- [ ] Write\n- [x] Test\n\n1. [ ] Ship\n";
+
+        for content in [Content::parse(markdown), streamed(markdown)] {
+            let markers: Vec<_> = content
+                .items()
+                .iter()
+                .filter_map(|item| match item {
+                    Item::List { bullets, .. } => Some(bullets),
+                    _ => None,
+                })
+                .flatten()
+                .filter_map(|bullet| match bullet {
+                    Bullet::Task { done, marker, .. } => Some((*done, &markdown[marker.clone()])),
+                    Bullet::Point { .. } => None,
+                })
+                .collect();
+
+            assert_eq!(markers, [(false, "[ ]"), (true, "[x]"), (false, "[ ]")]);
+        }
+    }
+
+    #[test]
+    fn heading_anchors_are_unique() {
+        let markdown = "# Hello, World!\n\n## Usage\n\n> ## Usage\n\n### Usage\n";
+
+        for content in [Content::parse(markdown), streamed(markdown)] {
+            let mut anchors = Vec::new();
+
+            for item in content.items() {
+                let mut item = item.clone();
+
+                visit_headings(&mut item, &mut |text| {
+                    anchors.push(text.anchor().map(str::to_owned));
+                });
+            }
+
+            assert_eq!(
+                anchors,
+                ["hello-world", "usage", "usage-1", "usage-2"]
+                    .map(|anchor| Some(anchor.to_owned()))
+            );
+        }
+
+        assert_eq!(heading_id("#usage-1"), heading_id("usage-1"));
+        assert_ne!(heading_id("usage"), heading_id("usage-1"));
+    }
+
+    #[test]
+    fn table_of_contents_scrolls_to_its_headings() {
+        use crate::Row;
+        use crate::core::Size;
+        use crate::core::widget::operation::Outcome;
+
+        use iced_runtime::UserInterface;
+        use iced_runtime::user_interface;
+
+        let content = Content::parse("# Intro\n\nSome text.\n\n> # Quoted\n\n# Usage\n\nMore.");
+        let entries = toc(content.items());
+
+        assert_eq!(
+            entries,
+            [
+                Entry {
+                    level: HeadingLevel::H1,
+                    title: String::from("Intro"),
+                    anchor: String::from("intro"),
+                },
+                Entry {
+                    level: HeadingLevel::H1,
+                    title: String::from("Usage"),
+                    anchor: String::from("usage"),
+                },
+            ]
+        );
+
+        let element: Element<'_, Uri, Theme, ()> = Row::new()
+            .push(table_of_contents(&entries, Theme::Light))
+            .push(scrollable(view(content.items(), Theme::Light)).id("document"))
+            .into();
+
+        let mut ui = UserInterface::build(
+            element,
+            Size::new(400.0, 400.0),
+            user_interface::Cache::new(),
+            &mut (),
+        );
+
+        let mut offset = |anchor: &str| {
+            let mut operation = ScrollTo::new(Id::from("document"), anchor);
+            ui.operate(&(), &mut operation);
+
+            assert!(matches!(operation.finish(), Outcome::Chain(_)));
+
+            let (Some(content), Some(target)) = (operation.content, operation.target) else {
+                panic!("heading not found: {anchor}");
+            };
+
+            target.y - content.y
+        };
+
+        let intro = offset("#intro");
+        let usage = offset("#usage");
+
+        assert_eq!(intro, 0.0);
+        assert!(usage > intro);
+    }
 }
@@ -0,0 +1,608 @@
+//! Distribute content of varying heights on columns.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use iced_widget::*; } }
+//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced::widget::{Masonry, container, scrollable};
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! struct Thumbnail {
+//!     width: u32,
+//!     height: u32,
+//! }
+//!
+//! fn view(thumbnails: &[Thumbnail]) -> Element<'_, Message> {
+//!     scrollable(
+//!         thumbnails
+//!             .iter()
+//!             .fold(Masonry::new().fluid(200).spacing(10), |masonry, thumbnail| {
+//!                 masonry.push_with_ratio(
+//!                     thumbnail.width as f32 / thumbnail.height as f32,
+//!                     container("Thumbnail"),
+//!                 )
+//!             }),
+//!     )
+//!     .into()
+//! }
+//! ```
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{Element, Event, Length, Pixels, Point, Rectangle, Shell, Size, Vector, Widget};
+
+/// A container that distributes its contents on columns, placing each child
+/// at the bottom of the shortest column.
+///
+/// Children keep their order: ties between columns of equal height are
+/// resolved in favor of the leftmost one.
+///
+/// Children added with [`push_with_ratio`](Self::push_with_ratio) have a known
+/// height, and so they are only laid out and drawn when they are close to the
+/// visible viewport—like when the [`Masonry`] is inside a `scrollable`.
+pub struct Masonry<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    spacing: f32,
+    columns: Constraint,
+    width: Length,
+    ratios: Vec<Option<f32>>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+enum Constraint {
+    MinWidth(Pixels),
+    Amount(usize),
+}
+
+impl<'a, Message, Theme, Renderer> Masonry<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates an empty [`Masonry`].
+    pub fn new() -> Self {
+        Self::from_vec(Vec::new())
+    }
+
+    /// Creates a [`Masonry`] with the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ratios: Vec::with_capacity(capacity),
+            ..Self::from_vec(Vec::with_capacity(capacity))
+        }
+    }
+
+    /// Creates a [`Masonry`] with the given elements.
+    pub fn with_children(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let iterator = children.into_iter();
+
+        Self::with_capacity(iterator.size_hint().0).extend(iterator)
+    }
+
+    /// Creates a [`Masonry`] from an already allocated [`Vec`].
+    pub fn from_vec(children: Vec<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            spacing: 0.0,
+            columns: Constraint::Amount(3),
+            width: Length::Fill,
+            ratios: vec![None; children.len()],
+            children,
+        }
+    }
+
+    /// Sets the spacing _between_ the items of the [`Masonry`], both
+    /// horizontally and vertically.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Masonry`].
+    ///
+    /// By default, a [`Masonry`] will [`Fill`] its parent.
+    ///
+    /// [`Fill`]: Length::Fill
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the amount of columns in the [`Masonry`].
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Constraint::Amount(columns);
+        self
+    }
+
+    /// Makes the amount of columns dynamic in the [`Masonry`], fitting as
+    /// many columns as possible while never going below the provided
+    /// `min_width`.
+    pub fn fluid(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.columns = Constraint::MinWidth(min_width.into());
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Masonry`].
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.push(child.into());
+        self.ratios.push(None);
+        self
+    }
+
+    /// Adds an [`Element`] with the given aspect ratio to the [`Masonry`].
+    ///
+    /// The ratio is the width of the [`Element`] divided by its height. Since
+    /// its height can be known without laying it out, the [`Element`] will
+    /// only be laid out and drawn when it is close to the visible viewport.
+    pub fn push_with_ratio(
+        mut self,
+        ratio: f32,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.children.push(child.into());
+        self.ratios
+            .push((ratio > 0.0 && ratio.is_finite()).then_some(ratio));
+        self
+    }
+
+    /// Adds an element to the [`Masonry`], if `Some`.
+    pub fn push_maybe(
+        self,
+        child: Option<impl Into<Element<'a, Message, Theme, Renderer>>>,
+    ) -> Self {
+        if let Some(child) = child {
+            self.push(child)
+        } else {
+            self
+        }
+    }
+
+    /// Extends the [`Masonry`] with the given children.
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+}
+
+impl<Message, Renderer> Default for Masonry<'_, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Theme, Renderer: crate::core::Renderer>
+    FromIterator<Element<'a, Message, Theme, Renderer>> for Masonry<'a, Message, Theme, Renderer>
+{
+    fn from_iter<T: IntoIterator<Item = Element<'a, Message, Theme, Renderer>>>(iter: T) -> Self {
+        Self::with_children(iter)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The visible part of the [`Masonry`], relative to its position.
+    viewport: Option<Rectangle>,
+    /// The region of the [`Masonry`] whose children were laid out.
+    region: Option<Rectangle>,
+    /// Whether each child was laid out.
+    visible: Vec<bool>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Masonry<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let available = limits.max();
+
+        state.visible.clear();
+
+        if limits.compression().width && !matches!(self.width, Length::Fixed(_)) {
+            state.region = None;
+            return layout::Node::new(Size::ZERO);
+        }
+
+        let columns = match self.columns {
+            // width = n * (column + spacing) - spacing, given n > 0
+            Constraint::MinWidth(pixels) => {
+                ((available.width + self.spacing) / (pixels.0 + self.spacing)).floor() as usize
+            }
+            Constraint::Amount(amount) => amount,
+        }
+        .max(1);
+
+        if self.children.is_empty() {
+            state.region = None;
+            return layout::Node::new(limits.resolve(self.width, Length::Shrink, Size::ZERO));
+        }
+
+        let column_width = (available.width - self.spacing * (columns - 1) as f32) / columns as f32;
+
+        // Children close to the viewport are laid out; the margin avoids
+        // blank areas while scrolling before the next relayout
+        let region = state
+            .viewport
+            .map(|viewport| viewport.expand([viewport.height, 0.0]));
+
+        let mut heights = vec![0.0; columns];
+        let mut nodes = Vec::with_capacity(self.children.len());
+
+        for ((child, ratio), tree) in self
+            .children
+            .iter_mut()
+            .zip(&self.ratios)
+            .zip(&mut tree.children)
+        {
+            let column = shortest(&heights);
+            let position = Point::new(
+                column as f32 * (column_width + self.spacing),
+                heights[column],
+            );
+
+            let node = match ratio {
+                Some(ratio) => {
+                    let size = Size::new(column_width, column_width / ratio);
+                    let bounds = Rectangle::new(position, size);
+
+                    if region.is_none_or(|region| region.intersects(&bounds)) {
+                        state.visible.push(true);
+
+                        child.as_widget_mut().layout(
+                            tree,
+                            renderer,
+                            &layout::Limits::new(size, size),
+                        )
+                    } else {
+                        state.visible.push(false);
+
+                        layout::Node::new(size)
+                    }
+                }
+                None => {
+                    state.visible.push(true);
+
+                    child.as_widget_mut().layout(
+                        tree,
+                        renderer,
+                        &layout::Limits::new(
+                            Size::new(column_width, 0.0),
+                            Size::new(column_width, f32::INFINITY),
+                        ),
+                    )
+                }
+            };
+
+            heights[column] += node.size().height + self.spacing;
+            nodes.push(node.move_to(position));
+        }
+
+        state.region = region;
+
+        let height = heights.into_iter().fold(0.0, f32::max) - self.spacing;
+
+        layout::Node::with_children(
+            limits.resolve(
+                self.width,
+                Length::Shrink,
+                Size::new(available.width, height),
+            ),
+            nodes,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .zip(&state.visible)
+                .filter(|(_, visible)| **visible)
+                .for_each(|(((child, state), layout), _)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let visible = *viewport - Vector::new(bounds.x, bounds.y);
+
+        if state.viewport != Some(visible) {
+            state.viewport = Some(visible);
+
+            // Without a region, every child was laid out before the viewport
+            // was known
+            let is_outdated = match state.region {
+                Some(region) => !visible.is_within(&region),
+                None => !state.visible.is_empty(),
+            };
+
+            if is_outdated {
+                shell.invalidate_layout();
+            }
+        }
+
+        for (((child, tree), layout), _) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .zip(&state.visible)
+            .filter(|(_, visible)| **visible)
+        {
+            child
+                .as_widget_mut()
+                .update(tree, event, layout, cursor, renderer, shell, viewport);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(&state.visible)
+            .filter(|(_, visible)| **visible)
+            .map(|(((child, tree), layout), _)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(viewport) = layout.bounds().intersection(viewport) {
+            for (((child, tree), layout), _) in self
+                .children
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .zip(&state.visible)
+                .filter(|((_, layout), visible)| **visible && layout.bounds().intersects(&viewport))
+            {
+                child
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, &viewport);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        let children = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .zip(&state.visible)
+            .filter(|(_, visible)| **visible)
+            .filter_map(|(((child, state), layout), _)| {
+                child
+                    .as_widget_mut()
+                    .overlay(state, layout, renderer, viewport, translation)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Masonry<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(masonry: Masonry<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(masonry)
+    }
+}
+
+/// Returns the index of the shortest column, favoring the leftmost one.
+fn shortest(heights: &[f32]) -> usize {
+    heights
+        .iter()
+        .enumerate()
+        .fold((0, f32::INFINITY), |(shortest, min), (i, &height)| {
+            if height < min {
+                (i, height)
+            } else {
+                (shortest, min)
+            }
+        })
+        .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::shell;
+    use crate::core::window;
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// A square that logs its index whenever it is laid out.
+    struct Square {
+        index: usize,
+        log: Rc<RefCell<Vec<usize>>>,
+    }
+
+    impl Widget<(), (), ()> for Square {
+        fn size(&self) -> Size<Length> {
+            Size {
+                width: Length::Fill,
+                height: Length::Fill,
+            }
+        }
+
+        fn layout(
+            &mut self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            self.log.borrow_mut().push(self.index);
+
+            layout::Node::new(limits.max())
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    #[test]
+    fn shortest_favors_leftmost_column() {
+        assert_eq!(shortest(&[0.0, 0.0, 0.0]), 0);
+        assert_eq!(shortest(&[10.0, 5.0, 5.0]), 1);
+        assert_eq!(shortest(&[10.0, 20.0, 0.0]), 2);
+    }
+
+    #[test]
+    fn children_far_from_the_viewport_are_not_laid_out() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let mut masonry: Masonry<'_, (), (), ()> =
+            (0..20).fold(Masonry::new().columns(1).width(100), |masonry, index| {
+                masonry.push_with_ratio(
+                    1.0,
+                    Element::new(Square {
+                        index,
+                        log: log.clone(),
+                    }),
+                )
+            });
+
+        let mut tree = Tree::new(&masonry as &dyn Widget<(), (), ()>);
+        masonry.diff(&mut tree);
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY));
+
+        // Without a viewport, every child is laid out
+        let node = masonry.layout(&mut tree, &(), &limits);
+
+        assert_eq!(node.size(), Size::new(100.0, 2_000.0));
+        assert_eq!(log.take(), (0..20).collect::<Vec<_>>());
+
+        // The first viewport invalidates the layout
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&window::Headless, shell::Waker::noop(), &mut messages);
+
+        masonry.update(
+            &mut tree,
+            &Event::Mouse(mouse::Event::CursorLeft),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut shell,
+            &Rectangle::new(Point::new(0.0, 500.0), Size::new(100.0, 100.0)),
+        );
+
+        assert!(shell.is_layout_invalid().is_some());
+
+        // Only the children close to the viewport are laid out then
+        let node = masonry.layout(&mut tree, &(), &limits);
+
+        assert_eq!(node.size(), Size::new(100.0, 2_000.0));
+        assert_eq!(log.take(), [4, 5, 6]);
+    }
+}
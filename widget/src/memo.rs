@@ -0,0 +1,401 @@
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Widget};
+use crate::core::{Element, Event, Length, Rectangle, Shell, Size, Vector};
+
+use rustc_hash::FxHasher;
+use std::hash::{Hash, Hasher as _};
+
+/// A widget that reuses the layout of its contents as long as a data
+/// dependency does not change.
+///
+/// Like `lazy`, a [`Memo`] hashes its dependency to decide whether its
+/// contents may have changed. If the hash and the layout limits are the same
+/// as in the previous layout, the previous layout is reused and the contents
+/// are not laid out again.
+///
+/// The cached layout is also discarded whenever the contents invalidate the
+/// layout themselves—for instance, after a user interaction.
+pub struct Memo<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    hash: u64,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Memo<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Memo`] with the given data dependency and content.
+    pub fn new(
+        dependency: impl Hash,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let hash = {
+            let mut hasher = FxHasher::default();
+            dependency.hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        Self {
+            hash,
+            content: content.into(),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Memo<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<layout::Cache>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(layout::Cache::new())
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let cache = tree.state.downcast_mut::<layout::Cache>();
+
+        cache.get_or_insert_with(self.hash, limits, || {
+            self.content
+                .as_widget_mut()
+                .layout(&mut tree.children[0], renderer, limits)
+        })
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut messages = Vec::new();
+        let mut local_shell = shell.local(&mut messages);
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_layout_invalid().is_some() || local_shell.are_widgets_invalid() {
+            tree.state.downcast_mut::<layout::Cache>().clear();
+        }
+
+        shell.merge(local_shell, std::convert::identity);
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let cache = tree.state.downcast_mut::<layout::Cache>();
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )?;
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            layout: cache,
+            content,
+        })))
+    }
+}
+
+/// The overlay of the contents of a [`Memo`].
+///
+/// Overlays may change the state of the contents without going through the
+/// `update` of the [`Memo`]; so the cached layout is discarded whenever they
+/// invalidate the layout themselves.
+struct Overlay<'a, Message, Theme, Renderer> {
+    layout: &'a mut layout::Cache,
+    content: overlay::Element<'a, Message, Theme, Renderer>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.content.as_overlay_mut().layout(renderer, bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.content
+            .as_overlay()
+            .draw(renderer, theme, style, layout, cursor);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.content
+            .as_overlay_mut()
+            .operate(layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let mut messages = Vec::new();
+        let mut local_shell = shell.local(&mut messages);
+
+        self.content
+            .as_overlay_mut()
+            .update(event, layout, cursor, renderer, &mut local_shell);
+
+        if local_shell.is_layout_invalid().is_some() || local_shell.are_widgets_invalid() {
+            self.layout.clear();
+        }
+
+        shell.merge(local_shell, std::convert::identity);
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_overlay()
+            .mouse_interaction(layout, cursor, renderer)
+    }
+
+    fn overlay<'a>(
+        &'a mut self,
+        layout: Layout<'a>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'a, Message, Theme, Renderer>> {
+        let content = self.content.as_overlay_mut().overlay(layout, renderer)?;
+
+        Some(overlay::Element::new(Box::new(Overlay {
+            layout: &mut *self.layout,
+            content,
+        })))
+    }
+
+    fn index(&self) -> f32 {
+        self.content.as_overlay().index()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Memo<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(memo: Memo<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(memo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Column;
+
+    use iced_runtime::UserInterface;
+    use iced_runtime::user_interface;
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// A widget that counts how many times it is laid out.
+    struct Counter(Rc<Cell<usize>>);
+
+    impl Widget<(), (), ()> for Counter {
+        fn size(&self) -> Size<Length> {
+            Size {
+                width: Length::Fill,
+                height: Length::Shrink,
+            }
+        }
+
+        fn layout(
+            &mut self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            self.0.set(self.0.get() + 1);
+
+            layout::Node::new(limits.resolve(Length::Fill, Length::Shrink, Size::new(0.0, 10.0)))
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    #[test]
+    fn relayout_reuses_unchanged_layouts() {
+        let memoized = Rc::new(Cell::new(0));
+        let other = Rc::new(Cell::new(0));
+
+        let view = || -> Element<'static, (), (), ()> {
+            Column::new()
+                .push(Memo::new(42, Element::new(Counter(memoized.clone()))))
+                .push(Element::new(Counter(other.clone())))
+                .into()
+        };
+
+        let bounds = Size::new(100.0, 100.0);
+        let ui = UserInterface::build(view(), bounds, user_interface::Cache::new(), &mut ());
+
+        assert_eq!((memoized.get(), other.get()), (1, 1));
+
+        let ui = ui.relayout(bounds, &mut ());
+
+        assert_eq!((memoized.get(), other.get()), (1, 2));
+
+        // Rebuilding with the same dependency reuses the layout too
+        let ui = UserInterface::build(view(), bounds, ui.into_cache(), &mut ());
+
+        assert_eq!((memoized.get(), other.get()), (1, 3));
+
+        // Different limits invalidate the cache
+        let mut ui = ui.relayout(Size::new(50.0, 100.0), &mut ());
+
+        assert_eq!((memoized.get(), other.get()), (2, 4));
+
+        ui.invalidate_layouts();
+
+        let _ui = ui.relayout(Size::new(50.0, 100.0), &mut ());
+
+        assert_eq!((memoized.get(), other.get()), (3, 5));
+    }
+
+    #[test]
+    fn invalidation_is_scoped_to_a_user_interface() {
+        let view = |counter: &Rc<Cell<usize>>| -> Element<'static, (), (), ()> {
+            Memo::new(42, Element::new(Counter(counter.clone()))).into()
+        };
+
+        let bounds = Size::new(100.0, 100.0);
+
+        let first = Rc::new(Cell::new(0));
+        let second = Rc::new(Cell::new(0));
+
+        let mut first_ui =
+            UserInterface::build(view(&first), bounds, user_interface::Cache::new(), &mut ());
+        let second_ui =
+            UserInterface::build(view(&second), bounds, user_interface::Cache::new(), &mut ());
+
+        first_ui.invalidate_layouts();
+
+        let _first_ui = first_ui.relayout(bounds, &mut ());
+        let _second_ui = second_ui.relayout(bounds, &mut ());
+
+        assert_eq!((first.get(), second.get()), (2, 1));
+    }
+}
@@ -70,6 +70,7 @@ use crate::core::renderer;
 use crate::core::text::paragraph;
 use crate::core::text::{self, Text};
 use crate::core::touch;
+use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
@@ -424,7 +425,24 @@ where
                 .expand(self.padding)
         };
 
-        layout::Node::new(size)
+        let label = self
+            .selected
+            .as_ref()
+            .map(Borrow::borrow)
+            .map(&self.to_string)
+            .or_else(|| self.placeholder.clone());
+
+        let Some(label) = label else {
+            return layout::Node::new(size);
+        };
+
+        let _ = state.label.update(Text {
+            content: &label,
+            ..option_text
+        });
+
+        // The label is drawn centered vertically, just like its paragraph
+        widget::text::with_baselines(layout::Node::new(size), state.label.raw())
     }
 
     fn update(
@@ -635,6 +653,9 @@ where
             Handle::None => None,
         };
 
+        // The handle and the label swap sides in right-to-left layouts
+        let is_rtl = layout.direction().is_rtl();
+
         if let Some((font, code_point, size, line_height, shaping)) = handle {
             let size = size.unwrap_or_else(|| renderer.default_size());
 
@@ -645,7 +666,11 @@ where
                     line_height,
                     font,
                     bounds: Size::new(bounds.width, f32::from(line_height.to_absolute(size))),
-                    align_x: text::Alignment::Right,
+                    align_x: if is_rtl {
+                        text::Alignment::Left
+                    } else {
+                        text::Alignment::Right
+                    },
                     align_y: alignment::Vertical::Center,
                     shaping,
                     wrapping: text::Wrapping::None,
@@ -653,7 +678,11 @@ where
                     hint_factor: None,
                 },
                 Point::new(
-                    bounds.x + bounds.width - self.padding.right,
+                    if is_rtl {
+                        bounds.x + self.padding.right
+                    } else {
+                        bounds.x + bounds.width - self.padding.right
+                    },
                     bounds.center_y(),
                 ),
                 style.handle_color,
@@ -676,14 +705,25 @@ where
                         bounds.width - self.padding.x(),
                         f32::from(self.line_height.to_absolute(text_size)),
                     ),
-                    align_x: text::Alignment::Default,
+                    align_x: if is_rtl {
+                        text::Alignment::Right
+                    } else {
+                        text::Alignment::Default
+                    },
                     align_y: alignment::Vertical::Center,
                     shaping: self.shaping,
                     wrapping: text::Wrapping::None,
                     ellipsis: self.ellipsis,
                     hint_factor: renderer.hint_factor(),
                 },
-                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                Point::new(
+                    if is_rtl {
+                        bounds.x + bounds.width - self.padding.left
+                    } else {
+                        bounds.x + self.padding.left
+                    },
+                    bounds.center_y(),
+                ),
                 if selected.is_some() {
                     style.text_color
                 } else {
@@ -770,6 +810,7 @@ struct State<P: text::Paragraph> {
     hovered_option: Option<usize>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
+    label: paragraph::Plain<P>,
 }
 
 impl<P: text::Paragraph> State<P> {
@@ -782,6 +823,7 @@ impl<P: text::Paragraph> State<P> {
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
+            label: paragraph::Plain::default(),
         }
     }
 }
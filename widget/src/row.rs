@@ -1,5 +1,5 @@
 //! Distribute content horizontally.
-use crate::core::alignment::{self, Alignment};
+use crate::core::alignment::{self, Alignment, Justify};
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::overlay;
@@ -112,8 +112,12 @@ where
     }
 
     /// Sets the vertical alignment of the contents of the [`Row`] .
-    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
-        self.align = Alignment::from(align.into());
+    ///
+    /// Besides any [`alignment::Vertical`], the contents can be aligned by
+    /// their text baselines with [`Alignment::Baseline`] or
+    /// [`Alignment::LastBaseline`].
+    pub fn align_y(mut self, align: impl Into<Alignment>) -> Self {
+        self.align = align.into();
         self
     }
 
@@ -150,7 +154,8 @@ where
         Wrapping {
             row: self,
             vertical_spacing: None,
-            align_x: alignment::Horizontal::Left,
+            justify: Justify::Start,
+            align_content: Justify::Start,
         }
     }
 }
@@ -352,7 +357,8 @@ where
 pub struct Wrapping<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
     row: Row<'a, Message, Theme, Renderer>,
     vertical_spacing: Option<f32>,
-    align_x: alignment::Horizontal,
+    justify: Justify,
+    align_content: Justify,
 }
 
 impl<Message, Theme, Renderer> Wrapping<'_, Message, Theme, Renderer> {
@@ -363,8 +369,29 @@ impl<Message, Theme, Renderer> Wrapping<'_, Message, Theme, Renderer> {
     }
 
     /// Sets the horizontal alignment of the wrapping [`Row`].
-    pub fn align_x(mut self, align_x: impl Into<alignment::Horizontal>) -> Self {
-        self.align_x = align_x.into();
+    ///
+    /// This is a shorthand for [`justify`](Self::justify).
+    ///
+    /// Every line is aligned within the width of the [`Row`]; not within
+    /// the width of its widest line. Therefore, lines only move relative to
+    /// each other when the [`Row`] shrinks to fit its contents.
+    pub fn align_x(self, align_x: impl Into<alignment::Horizontal>) -> Self {
+        self.justify(align_x.into())
+    }
+
+    /// Sets how the free horizontal space of every line of the wrapping
+    /// [`Row`] is distributed among its contents.
+    pub fn justify(mut self, justify: impl Into<Justify>) -> Self {
+        self.justify = justify.into();
+        self
+    }
+
+    /// Sets how the free vertical space of the wrapping [`Row`] is
+    /// distributed among its lines.
+    ///
+    /// It only has an effect if the [`Row`] is taller than its lines.
+    pub fn align_content(mut self, align_content: impl Into<Justify>) -> Self {
+        self.align_content = align_content.into();
         self
     }
 }
@@ -399,28 +426,61 @@ where
         let max_width = limits.max().width;
 
         let mut children: Vec<layout::Node> = Vec::new();
+        let mut lines = Vec::new();
         let mut intrinsic_size = Size::ZERO;
         let mut row_start = 0;
-        let mut row_height = 0.0;
         let mut x = 0.0;
         let mut y = 0.0;
 
-        let align_factor = match self.row.align {
-            Alignment::Start => 0.0,
+        let align = self.row.align;
+
+        let align_factor = match align {
+            Alignment::Start | Alignment::Baseline | Alignment::LastBaseline => 0.0,
             Alignment::Center => 2.0,
             Alignment::End => 1.0,
         };
 
-        let align_y = |row_start: std::ops::Range<usize>,
-                       row_height: f32,
-                       children: &mut Vec<layout::Node>| {
+        let baseline = |node: &layout::Node| {
+            match align {
+                Alignment::LastBaseline => node.last_baseline(),
+                _ => node.baseline(),
+            }
+            .unwrap_or(node.size().height)
+        };
+
+        // Aligns the nodes of a row and returns its height
+        let align_y = |row: std::ops::Range<usize>, children: &mut Vec<layout::Node>| {
+            let nodes = &mut children[row];
+
+            if align.is_baseline() {
+                let ascent = nodes.iter().map(baseline).fold(0.0, f32::max);
+
+                let descent = nodes
+                    .iter()
+                    .map(|node| node.size().height - baseline(node))
+                    .fold(0.0, f32::max);
+
+                for node in nodes {
+                    node.translate_mut(Vector::new(0.0, ascent - baseline(node)));
+                }
+
+                return ascent + descent;
+            }
+
+            let row_height = nodes
+                .iter()
+                .map(|node| node.size().height)
+                .fold(0.0, f32::max);
+
             if align_factor != 0.0 {
-                for node in &mut children[row_start] {
+                for node in nodes {
                     let height = node.size().height;
 
                     node.translate_mut(Vector::new(0.0, (row_height - height) / align_factor));
                 }
             }
+
+            row_height
         };
 
         for (i, child) in self.row.children.iter_mut().enumerate() {
@@ -433,16 +493,14 @@ where
             if x != 0.0 && x + child_size.width > max_width {
                 intrinsic_size.width = intrinsic_size.width.max(x - spacing);
 
-                align_y(row_start..i, row_height, &mut children);
+                let row_height = align_y(row_start..i, &mut children);
+                lines.push((row_start..i, x - spacing));
 
                 y += row_height + vertical_spacing;
                 x = 0.0;
                 row_start = i;
-                row_height = 0.0;
             }
 
-            row_height = row_height.max(child_size.height);
-
             children.push(node.move_to((x + self.row.padding.left, y + self.row.padding.top)));
 
             x += child_size.width + spacing;
@@ -450,46 +508,35 @@ where
 
         if x != 0.0 {
             intrinsic_size.width = intrinsic_size.width.max(x - spacing);
+            lines.push((row_start..children.len(), x - spacing));
         }
 
-        intrinsic_size.height = y + row_height;
-        align_y(row_start..children.len(), row_height, &mut children);
+        intrinsic_size.height = y + align_y(row_start..children.len(), &mut children);
 
-        let align_factor = match self.align_x {
-            alignment::Horizontal::Left => 0.0,
-            alignment::Horizontal::Center => 2.0,
-            alignment::Horizontal::Right => 1.0,
-        };
-
-        if align_factor != 0.0 {
-            let total_width = intrinsic_size.width;
-
-            let mut row_start = 0;
-
-            for i in 0..children.len() {
-                let bounds = children[i].bounds();
-                let row_width = bounds.x + bounds.width;
+        let size = limits.resolve(self.row.width, self.row.height, intrinsic_size);
 
-                let next_x = children
-                    .get(i + 1)
-                    .map(|node| node.bounds().x)
-                    .unwrap_or_default();
+        // We distribute the free space of every line among its contents
+        for (line, width) in &lines {
+            let (offset, gap) = self.justify.distribute(size.width - width, line.len());
 
-                if next_x == 0.0 {
-                    let translation = Vector::new((total_width - row_width) / align_factor, 0.0);
+            for (i, node) in children[line.clone()].iter_mut().enumerate() {
+                node.translate_mut(Vector::new(offset + gap * i as f32, 0.0));
+            }
+        }
 
-                    for node in &mut children[row_start..=i] {
-                        node.translate_mut(translation);
-                    }
+        // Then, we distribute the free space of the row among its lines
+        let (offset, gap) = self
+            .align_content
+            .distribute(size.height - intrinsic_size.height, lines.len());
 
-                    row_start = i + 1;
-                }
+        for (i, (line, _)) in lines.iter().enumerate() {
+            for node in &mut children[line.clone()] {
+                node.translate_mut(Vector::new(0.0, offset + gap * i as f32));
             }
         }
 
-        let size = limits.resolve(self.row.width, self.row.height, intrinsic_size);
-
         layout::Node::with_children(size.expand(self.row.padding), children)
+            .with_children_baselines()
     }
 
     fn operate(
@@ -566,3 +613,182 @@ where
         Self::new(row)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Space;
+
+    /// A widget with the metrics of some lines of text of the given size.
+    struct Label {
+        size: f32,
+        lines: usize,
+    }
+
+    impl Widget<(), (), ()> for Label {
+        fn size(&self) -> Size<Length> {
+            Size {
+                width: Length::Shrink,
+                height: Length::Shrink,
+            }
+        }
+
+        fn layout(
+            &mut self,
+            _tree: &mut Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            let line_height = self.size * 1.25;
+            let first = self.size;
+
+            layout::Node::new(limits.resolve(
+                Length::Shrink,
+                Length::Shrink,
+                Size::new(self.size * 2.0, line_height * self.lines as f32),
+            ))
+            .with_baselines(first, first + line_height * (self.lines - 1) as f32)
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &(),
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    #[test]
+    fn wrapping_justifies_every_line() {
+        let mut wrapping: Wrapping<'_, (), (), ()> = (0..5)
+            .fold(Row::new().spacing(10).width(100).height(80), |row, _| {
+                row.push(Space::new().width(30).height(10))
+            })
+            .wrap()
+            .justify(Justify::SpaceBetween)
+            .align_content(Justify::SpaceEvenly);
+
+        let mut tree = Tree::new(&wrapping as &dyn Widget<(), (), ()>);
+        wrapping.diff(&mut tree);
+
+        let node = wrapping.layout(
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        let positions: Vec<_> = node
+            .children()
+            .iter()
+            .map(|node| (node.bounds().x, node.bounds().y))
+            .collect();
+
+        assert_eq!(node.size(), Size::new(100.0, 80.0));
+        assert_eq!(
+            positions,
+            [
+                (0.0, 7.5),
+                (70.0, 7.5),
+                (0.0, 35.0),
+                (70.0, 35.0),
+                (0.0, 62.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn wrapping_aligns_lines_within_its_width() {
+        let layout = |width: Length| {
+            let mut wrapping: Wrapping<'_, (), (), ()> = [40, 30, 20]
+                .into_iter()
+                .fold(Row::new().width(width), |row, item_width| {
+                    row.push(Space::new().width(item_width).height(10))
+                })
+                .wrap()
+                .align_x(alignment::Horizontal::Center);
+
+            let mut tree = Tree::new(&wrapping as &dyn Widget<(), (), ()>);
+            wrapping.diff(&mut tree);
+
+            let node = wrapping.layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(80.0, 100.0)),
+            );
+
+            node.children()
+                .iter()
+                .map(|node| node.bounds().x)
+                .collect::<Vec<_>>()
+        };
+
+        // The lines are centered within the whole row...
+        assert_eq!(layout(Length::Fill), [5.0, 45.0, 30.0]);
+
+        // ...which is only as wide as its widest line when shrinking
+        assert_eq!(layout(Length::Shrink), [0.0, 40.0, 25.0]);
+    }
+
+    #[test]
+    fn baselines_are_shared_across_font_sizes() {
+        let layout = |align: Alignment| {
+            let mut row: Row<'_, (), (), ()> = Row::new()
+                .align_y(align)
+                .push(Element::new(Label {
+                    size: 10.0,
+                    lines: 3,
+                }))
+                .push(Element::new(Label {
+                    size: 20.0,
+                    lines: 1,
+                }))
+                .push(Space::new().width(10).height(12));
+
+            let mut tree = Tree::new(&row as &dyn Widget<(), (), ()>);
+            row.diff(&mut tree);
+
+            row.layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+            )
+        };
+
+        let baselines = |node: &layout::Node, align: Alignment| {
+            node.children()
+                .iter()
+                .map(|child| {
+                    let baseline = match align {
+                        Alignment::LastBaseline => child.last_baseline(),
+                        _ => child.baseline(),
+                    };
+
+                    // Children without a baseline are aligned by their bottom edge
+                    child.bounds().y + baseline.unwrap_or(child.size().height)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first = layout(Alignment::Baseline);
+
+        assert_eq!(baselines(&first, Alignment::Baseline), [20.0, 20.0, 20.0]);
+        assert_eq!(first.size().height, 47.5);
+        assert_eq!(first.baseline(), Some(20.0));
+        assert_eq!(first.last_baseline(), Some(20.0));
+
+        let last = layout(Alignment::LastBaseline);
+
+        assert_eq!(
+            baselines(&last, Alignment::LastBaseline),
+            [35.0, 35.0, 35.0]
+        );
+        assert_eq!(last.size().height, 40.0);
+        assert_eq!(last.baseline(), Some(10.0));
+        assert_eq!(last.last_baseline(), Some(35.0));
+    }
+}
@@ -282,6 +282,24 @@ impl Direction {
             align(vertical_alignment, delta.y),
         )
     }
+
+    /// Flips the [`Anchor`] of the horizontal [`Scrollbar`] in right-to-left
+    /// layouts; so that the horizontal offset starts at the start edge of the
+    /// contents.
+    fn directed(mut self, layout_direction: layout::Direction) -> Self {
+        if layout_direction.is_rtl() {
+            let (Self::Horizontal(horizontal) | Self::Both { horizontal, .. }) = &mut self else {
+                return self;
+            };
+
+            horizontal.alignment = match horizontal.alignment {
+                Anchor::Start => Anchor::End,
+                Anchor::End => Anchor::Start,
+            };
+        }
+
+        self
+    }
 }
 
 impl Default for Direction {
@@ -520,11 +538,12 @@ where
         operation: &mut dyn Operation,
     ) {
         let state = tree.state.downcast_mut::<State>();
+        let direction = self.direction.directed(layout.direction());
 
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let translation = state.translation(self.direction, bounds, content_bounds);
+        let translation = state.translation(direction, bounds, content_bounds);
 
         operation.scrollable(self.id.as_ref(), bounds, content_bounds, translation, state);
 
@@ -552,13 +571,15 @@ where
         const AUTOSCROLL_SMOOTHNESS: f32 = 1.5;
 
         let state = tree.state.downcast_mut::<State>();
+        let direction = self.direction.directed(layout.direction());
         let bounds = layout.bounds();
         let cursor_over_scrollable = cursor.position_over(bounds);
 
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        let scrollbars = Scrollbars::new(state, self.direction, bounds, content_bounds);
+        let scrollbars =
+            Scrollbars::new(state, direction, layout.direction(), bounds, content_bounds);
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) = scrollbars.is_mouse_over(cursor);
 
@@ -726,7 +747,7 @@ where
             if state.last_scrolled.is_none()
                 || !matches!(event, Event::Mouse(mouse::Event::WheelScrolled { .. }))
             {
-                let translation = state.translation(self.direction, bounds, content_bounds);
+                let translation = state.translation(direction, bounds, content_bounds);
 
                 let cursor = match cursor_over_scrollable {
                     Some(cursor_position)
@@ -804,7 +825,7 @@ where
                         mouse::ScrollDelta::Pixels { x, y } => -Vector::new(x, y),
                     };
 
-                    state.scroll(self.direction.align(delta), bounds, content_bounds);
+                    state.scroll(direction.align(delta), bounds, content_bounds);
 
                     let has_scrolled =
                         notify_scroll(state, &self.on_scroll, bounds, content_bounds, shell);
@@ -860,7 +881,7 @@ where
                                 scroll_box_touched_at.y - cursor_position.y,
                             );
 
-                            state.scroll(self.direction.align(delta), bounds, content_bounds);
+                            state.scroll(direction.align(delta), bounds, content_bounds);
 
                             state.interaction = Interaction::TouchScrolling(cursor_position);
 
@@ -940,7 +961,7 @@ where
                             let scroll_factor = time_delta.as_secs_f32();
 
                             state.scroll(
-                                self.direction.align(Vector::new(
+                                direction.align(Vector::new(
                                     delta.x.signum()
                                         * delta.x.abs().powf(AUTOSCROLL_SMOOTHNESS)
                                         * scroll_factor,
@@ -1027,6 +1048,7 @@ where
         viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State>();
+        let direction = self.direction.directed(layout.direction());
 
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
@@ -1036,12 +1058,13 @@ where
             return;
         };
 
-        let scrollbars = Scrollbars::new(state, self.direction, bounds, content_bounds);
+        let scrollbars =
+            Scrollbars::new(state, direction, layout.direction(), bounds, content_bounds);
 
         let cursor_over_scrollable = cursor.position_over(bounds);
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) = scrollbars.is_mouse_over(cursor);
 
-        let translation = state.translation(self.direction, bounds, content_bounds);
+        let translation = state.translation(direction, bounds, content_bounds);
 
         let cursor = match cursor_over_scrollable {
             Some(cursor_position) if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) => {
@@ -1185,13 +1208,15 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<State>();
+        let direction = self.direction.directed(layout.direction());
         let bounds = layout.bounds();
         let cursor_over_scrollable = cursor.position_over(bounds);
 
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
 
-        let scrollbars = Scrollbars::new(state, self.direction, bounds, content_bounds);
+        let scrollbars =
+            Scrollbars::new(state, direction, layout.direction(), bounds, content_bounds);
 
         let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) = scrollbars.is_mouse_over(cursor);
 
@@ -1199,7 +1224,7 @@ where
             return mouse::Interaction::None;
         }
 
-        let translation = state.translation(self.direction, bounds, content_bounds);
+        let translation = state.translation(direction, bounds, content_bounds);
 
         let cursor = match cursor_over_scrollable {
             Some(cursor_position) if !(mouse_over_x_scrollbar || mouse_over_y_scrollbar) => {
@@ -1230,11 +1255,12 @@ where
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let state = tree.state.downcast_ref::<State>();
+        let direction = self.direction.directed(layout.direction());
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
         let visible_bounds = bounds.intersection(viewport).unwrap_or(*viewport);
-        let offset = state.translation(self.direction, bounds, content_bounds);
+        let offset = state.translation(direction, bounds, content_bounds);
 
         let overlay = self.content.as_widget_mut().overlay(
             &mut tree.children[0],
@@ -1245,7 +1271,8 @@ where
         );
 
         let icon = if let Interaction::AutoScrolling { origin, .. } = state.interaction {
-            let scrollbars = Scrollbars::new(state, self.direction, bounds, content_bounds);
+            let scrollbars =
+                Scrollbars::new(state, direction, layout.direction(), bounds, content_bounds);
 
             Some(overlay::Element::new(Box::new(AutoScrollIcon {
                 origin,
@@ -1749,11 +1776,19 @@ impl Scrollbars {
     fn new(
         state: &State,
         direction: Direction,
+        layout_direction: layout::Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) -> Self {
         let translation = state.translation(direction, bounds, content_bounds);
 
+        // Scrollbars are mirrored in right-to-left layouts
+        let mirror = |rectangle: Rectangle| Rectangle {
+            x: bounds.x
+                + layout_direction.mirror(rectangle.x - bounds.x, rectangle.width, bounds.width),
+            ..rectangle
+        };
+
         let show_scrollbar_x = direction
             .horizontal()
             .filter(|_scrollbar| content_bounds.width > bounds.width);
@@ -1778,20 +1813,20 @@ impl Scrollbars {
             let total_scrollbar_width = width.max(scroller_width) + 2.0 * margin;
 
             // Total bounds of the scrollbar + margin + scroller width
-            let total_scrollbar_bounds = Rectangle {
+            let total_scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x + bounds.width - total_scrollbar_width,
                 y: bounds.y,
                 width: total_scrollbar_width,
                 height: (bounds.height - x_scrollbar_height).max(0.0),
-            };
+            });
 
             // Bounds of just the scrollbar
-            let scrollbar_bounds = Rectangle {
+            let scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x + bounds.width - total_scrollbar_width / 2.0 - width / 2.0,
                 y: bounds.y,
                 width,
                 height: (bounds.height - x_scrollbar_height).max(0.0),
-            };
+            });
 
             let ratio = bounds.height / content_bounds.height;
 
@@ -1803,12 +1838,12 @@ impl Scrollbars {
                 let scroller_offset =
                     translation.y * ratio * scrollbar_bounds.height / bounds.height;
 
-                let scroller_bounds = Rectangle {
+                let scroller_bounds = mirror(Rectangle {
                     x: bounds.x + bounds.width - total_scrollbar_width / 2.0 - scroller_width / 2.0,
                     y: (scrollbar_bounds.y + scroller_offset).max(0.0),
                     width: scroller_width,
                     height: scroller_height,
-                };
+                });
 
                 Some(internals::Scroller {
                     bounds: scroller_bounds,
@@ -1842,20 +1877,20 @@ impl Scrollbars {
             let total_scrollbar_height = width.max(scroller_width) + 2.0 * margin;
 
             // Total bounds of the scrollbar + margin + scroller width
-            let total_scrollbar_bounds = Rectangle {
+            let total_scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x,
                 y: bounds.y + bounds.height - total_scrollbar_height,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: total_scrollbar_height,
-            };
+            });
 
             // Bounds of just the scrollbar
-            let scrollbar_bounds = Rectangle {
+            let scrollbar_bounds = mirror(Rectangle {
                 x: bounds.x,
                 y: bounds.y + bounds.height - total_scrollbar_height / 2.0 - width / 2.0,
                 width: (bounds.width - scrollbar_y_width).max(0.0),
                 height: width,
-            };
+            });
 
             let ratio = bounds.width / content_bounds.width;
 
@@ -2221,3 +2256,49 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn right_to_left_offsets_start_at_the_start_edge() {
+        let mut state = State::default();
+        let direction = Direction::Horizontal(Scrollbar::default());
+
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 10.0));
+        let content_bounds = Rectangle::new(Point::ORIGIN, Size::new(300.0, 10.0));
+
+        let node = layout::Node::with_children(
+            bounds.size(),
+            vec![layout::Node::with_children(
+                content_bounds.size(),
+                vec![layout::Node::new(Size::new(20.0, 10.0))],
+            )],
+        )
+        .directed(layout::Direction::RightToLeft);
+
+        let first = node.children()[0].children()[0].bounds();
+        let rtl = direction.directed(layout::Direction::RightToLeft);
+
+        // The first child of the mirrored contents is visible at offset 0
+        let translation = state.translation(rtl, bounds, content_bounds);
+
+        assert_eq!(translation.x, 200.0);
+        assert!(first.x >= translation.x);
+        assert!(first.x + first.width <= translation.x + bounds.width);
+
+        // Scrolling towards the end reveals the left side of the contents
+        state.scroll(rtl.align(Vector::new(-50.0, 0.0)), bounds, content_bounds);
+
+        assert_eq!(state.translation(rtl, bounds, content_bounds).x, 150.0);
+
+        // Left-to-right layouts are left untouched
+        let ltr = direction.directed(layout::Direction::LeftToRight);
+
+        assert_eq!(
+            State::default().translation(ltr, bounds, content_bounds).x,
+            0.0
+        );
+    }
+}
@@ -0,0 +1,416 @@
+//! Select and copy text across multiple text widgets.
+use crate::core::clipboard;
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::text::Selection;
+use crate::core::widget::{Id, Operation, Tree, tree};
+use crate::core::{Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Vector, Widget};
+
+use std::any::Any;
+
+/// A container that lets users select and copy the text of its contents
+/// as a whole.
+///
+/// Dragging the mouse over a [`SelectionArea`] selects all the text
+/// between the start and the end of the drag, in reading order; even if
+/// it spans multiple text widgets. Double and triple clicking select
+/// whole words and lines, respectively.
+///
+/// Any [`Rich`] text and any [`Text`] marked as [`selectable`] take part
+/// in the selection of a [`SelectionArea`]. This includes the contents
+/// of a [`markdown`] document.
+///
+/// A [`SelectionArea`] must be placed inside any [`Scrollable`] it
+/// contains text of, not around it.
+///
+/// [`Rich`]: crate::text::Rich
+/// [`Scrollable`]: crate::Scrollable
+/// [`Text`]: crate::Text
+/// [`selectable`]: crate::Text::selectable
+/// [`markdown`]: https://docs.rs/iced/latest/iced/widget/markdown/index.html
+pub struct SelectionArea<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> SelectionArea<'a, Message, Theme, Renderer> {
+    /// Creates a [`SelectionArea`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+        }
+    }
+}
+
+/// Local state of the [`SelectionArea`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    anchor: Point,
+    kind: mouse::click::Kind,
+    last_click: Option<mouse::Click>,
+    modifiers: keyboard::Modifiers,
+    is_dragging: bool,
+    is_focused: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            anchor: Point::ORIGIN,
+            kind: mouse::click::Kind::Single,
+            last_click: None,
+            modifiers: keyboard::Modifiers::default(),
+            is_dragging: false,
+            is_focused: false,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> SelectionArea<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn run(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for SelectionArea<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && cursor.is_over(bounds)
+        {
+            self.run(tree, layout, renderer, &mut Group);
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let state = *tree.state.downcast_ref::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    if state.is_focused {
+                        *tree.state.downcast_mut::<State>() = State {
+                            is_focused: false,
+                            ..state
+                        };
+
+                        self.run(tree, layout, renderer, &mut Group);
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+
+                let anchor = if state.is_focused
+                    && state.modifiers.shift()
+                    && click.kind() == mouse::click::Kind::Single
+                {
+                    state.anchor
+                } else {
+                    position
+                };
+
+                *tree.state.downcast_mut::<State>() = State {
+                    anchor,
+                    kind: click.kind(),
+                    last_click: Some(click),
+                    is_dragging: true,
+                    is_focused: true,
+                    ..state
+                };
+
+                self.run(
+                    tree,
+                    layout,
+                    renderer,
+                    &mut Select {
+                        start: anchor,
+                        end: position,
+                        kind: click.kind(),
+                    },
+                );
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if state.is_dragging => {
+                let Some(position) = cursor.land().position() else {
+                    return;
+                };
+
+                self.run(
+                    tree,
+                    layout,
+                    renderer,
+                    &mut Select {
+                        start: state.anchor,
+                        end: position,
+                        kind: state.kind,
+                    },
+                );
+
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                tree.state.downcast_mut::<State>().is_dragging = false;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                tree.state.downcast_mut::<State>().modifiers = *modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key, physical_key, ..
+            }) if state.is_focused => match key.to_latin(*physical_key) {
+                Some('a') if state.modifiers.command() => {
+                    self.run(
+                        tree,
+                        layout,
+                        renderer,
+                        &mut Select {
+                            start: Point::new(f32::MIN, f32::MIN),
+                            end: Point::new(f32::MAX, f32::MAX),
+                            kind: mouse::click::Kind::Single,
+                        },
+                    );
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+                Some('c') if state.modifiers.command() => {
+                    let mut collect = Collect::default();
+
+                    self.run(tree, layout, renderer, &mut collect);
+
+                    if !collect.contents.is_empty() {
+                        shell.write_clipboard(clipboard::Content::Text(collect.contents));
+                    }
+
+                    shell.capture_event();
+                }
+                _ if *key == keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                    self.run(tree, layout, renderer, &mut Group);
+                    shell.request_redraw();
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_dragging {
+            return mouse::Interaction::Text;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SelectionArea<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(
+        area: SelectionArea<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(area)
+    }
+}
+
+/// Takes control of every [`Selection`] and clears it.
+struct Group;
+
+impl Operation for Group {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn custom(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+        if let Some(selection) = state.downcast_mut::<Selection>() {
+            selection.group();
+        }
+    }
+}
+
+/// Selects the text between two points of every [`Selection`].
+struct Select {
+    start: Point,
+    end: Point,
+    kind: mouse::click::Kind,
+}
+
+impl Operation for Select {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn custom(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+        if let Some(selection) = state.downcast_mut::<Selection>() {
+            selection.group();
+            selection.select_between(self.start, self.end, self.kind);
+        }
+    }
+}
+
+/// Gathers the contents of every [`Selection`] in document order.
+#[derive(Default)]
+struct Collect {
+    contents: String,
+}
+
+impl Operation for Collect {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn custom(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+        let Some(selection) = state.downcast_ref::<Selection>() else {
+            return;
+        };
+
+        if selection.contents().is_empty() {
+            return;
+        }
+
+        if !self.contents.is_empty() {
+            self.contents.push('\n');
+        }
+
+        self.contents.push_str(selection.contents());
+    }
+}
@@ -261,10 +261,13 @@ where
 
             let locate = |cursor_position: Point| -> Option<T> {
                 let bounds = layout.bounds();
+                let x = layout
+                    .direction()
+                    .mirror(cursor_position.x - bounds.x, 0.0, bounds.width);
 
-                if cursor_position.x <= bounds.x {
+                if x <= 0.0 {
                     Some(*self.range.start())
-                } else if cursor_position.x >= bounds.x + bounds.width {
+                } else if x >= bounds.width {
                     Some(*self.range.end())
                 } else {
                     let step = if state.keyboard_modifiers.shift() {
@@ -276,7 +279,7 @@ where
                     let start = (*self.range.start()).as_();
                     let end = (*self.range.end()).as_();
 
-                    let percent = f64::from(cursor_position.x - bounds.x) / f64::from(bounds.width);
+                    let percent = f64::from(x) / f64::from(bounds.width);
 
                     let steps = (percent * (end - start) / step).round();
                     let value = steps * step + start;
@@ -451,11 +454,13 @@ where
         };
 
         let rail_y = bounds.y + bounds.height / 2.0;
+        let direction = layout.direction();
+        let x = |x: f32, width: f32| bounds.x + direction.mirror(x, width, bounds.width);
 
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
-                    x: bounds.x,
+                    x: x(0.0, offset + handle_width / 2.0),
                     y: rail_y - style.rail.width / 2.0,
                     width: offset + handle_width / 2.0,
                     height: style.rail.width,
@@ -469,7 +474,10 @@ where
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
-                    x: bounds.x + offset + handle_width / 2.0,
+                    x: x(
+                        offset + handle_width / 2.0,
+                        bounds.width - offset - handle_width / 2.0,
+                    ),
                     y: rail_y - style.rail.width / 2.0,
                     width: bounds.width - offset - handle_width / 2.0,
                     height: style.rail.width,
@@ -483,7 +491,7 @@ where
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
-                    x: bounds.x + offset,
+                    x: x(offset, handle_width),
                     y: rail_y - handle_height / 2.0,
                     width: handle_width,
                     height: handle_height,
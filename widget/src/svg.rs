@@ -28,7 +28,7 @@ use crate::core::{
 
 use std::path::PathBuf;
 
-pub use crate::core::svg::Handle;
+pub use crate::core::svg::{Handle, Selector, Stylesheet};
 
 /// A vector graphics image.
 ///
@@ -63,6 +63,7 @@ where
     class: Theme::Class<'a>,
     rotation: Rotation,
     opacity: f32,
+    stylesheet: Stylesheet,
     status: Option<Status>,
 }
 
@@ -80,6 +81,7 @@ where
             class: Theme::default(),
             rotation: Rotation::default(),
             opacity: 1.0,
+            stylesheet: Stylesheet::default(),
             status: None,
         }
     }
@@ -148,6 +150,15 @@ where
         self.opacity = opacity.into();
         self
     }
+
+    /// Sets the [`Stylesheet`] of the [`Svg`].
+    ///
+    /// A [`Stylesheet`] can be used to recolor, restyle, or hide specific
+    /// elements of the [`Svg`] by `id` or `class`.
+    pub fn stylesheet(mut self, stylesheet: impl Into<Stylesheet>) -> Self {
+        self.stylesheet = stylesheet.into();
+        self
+    }
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Svg<'_, Theme>
@@ -263,6 +274,7 @@ where
                 color: style.color,
                 rotation: self.rotation.radians(),
                 opacity: self.opacity,
+                stylesheet: self.stylesheet.clone(),
             },
             drawing_bounds,
             bounds,
@@ -7,7 +7,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget;
 use crate::core::{
-    Alignment, Background, Element, Layout, Length, Pixels, Rectangle, Size, Widget,
+    Alignment, Background, Element, Layout, Length, Pixels, Rectangle, Size, Vector, Widget,
 };
 
 /// Creates a new [`Table`] with the given columns and rows.
@@ -43,7 +43,7 @@ where
         view: Box::new(move |data| view(data).into()),
         width: Length::Shrink,
         align_x: alignment::Horizontal::Left,
-        align_y: alignment::Vertical::Top,
+        align_y: Alignment::Start,
     }
 }
 
@@ -66,7 +66,7 @@ where
 struct Column_ {
     width: Length,
     align_x: alignment::Horizontal,
-    align_y: alignment::Vertical,
+    align_y: Alignment,
 }
 
 impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
@@ -394,6 +394,39 @@ where
             x += size.width + spacing_x;
         }
 
+        // BASELINE PASS
+        // Find the baseline of every row with cells aligned by baseline
+        let baseline = |cell: &layout::Node, align: Alignment| {
+            match align {
+                Alignment::LastBaseline => cell.last_baseline(),
+                _ => cell.baseline(),
+            }
+            .unwrap_or(cell.size().height)
+        };
+
+        let mut ascents = vec![None; rows];
+
+        for (row, ascent) in ascents.iter_mut().enumerate() {
+            let (max_ascent, max_descent) = self
+                .columns
+                .iter()
+                .zip(&cells[row * columns..(row + 1) * columns])
+                .filter(|(column, _)| column.align_y.is_baseline())
+                .map(|(column, cell)| {
+                    let baseline = baseline(cell, column.align_y);
+
+                    (baseline, cell.size().height - baseline)
+                })
+                .fold((f32::NEG_INFINITY, 0.0), |(ascent, descent), (a, d)| {
+                    (f32::max(ascent, a), f32::max(descent, d))
+                });
+
+            if max_ascent.is_finite() {
+                metrics.rows[row] = metrics.rows[row].max(max_ascent + max_descent);
+                *ascent = Some(max_ascent);
+            }
+        }
+
         // THIRD PASS
         // Position each cell
         let mut x = self.padding_x;
@@ -418,10 +451,16 @@ where
             cell.move_to_mut((x, y));
             cell.align_mut(
                 Alignment::from(*align_x),
-                Alignment::from(*align_y),
+                *align_y,
                 Size::new(metrics.columns[column], metrics.rows[row]),
             );
 
+            if align_y.is_baseline()
+                && let Some(ascent) = ascents[row]
+            {
+                cell.translate_mut(Vector::new(0.0, ascent - baseline(cell, *align_y)));
+            }
+
             x += metrics.columns[column] + spacing_x;
         }
 
@@ -607,7 +646,7 @@ pub struct Column<'a, 'b, T, Message, Theme = crate::Theme, Renderer = crate::Re
     view: Box<dyn Fn(T) -> Element<'a, Message, Theme, Renderer> + 'b>,
     width: Length,
     align_x: alignment::Horizontal,
-    align_y: alignment::Vertical,
+    align_y: Alignment,
 }
 
 impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Renderer> {
@@ -624,7 +663,10 @@ impl<'a, 'b, T, Message, Theme, Renderer> Column<'a, 'b, T, Message, Theme, Rend
     }
 
     /// Sets the alignment for the vertical axis of the [`Column`].
-    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+    ///
+    /// The cells of all the columns aligned with [`Alignment::Baseline`] or
+    /// [`Alignment::LastBaseline`] share the same baseline in every row.
+    pub fn align_y(mut self, alignment: impl Into<Alignment>) -> Self {
         self.align_y = alignment.into();
         self
     }
@@ -682,3 +724,115 @@ pub fn default(theme: &crate::Theme) -> Style {
         separator_y: separator,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Space, Theme};
+
+    /// A widget with the metrics of some lines of text of the given size.
+    struct Label {
+        size: f32,
+        lines: usize,
+    }
+
+    impl Widget<(), Theme, ()> for Label {
+        fn size(&self) -> Size<Length> {
+            Size {
+                width: Length::Shrink,
+                height: Length::Shrink,
+            }
+        }
+
+        fn layout(
+            &mut self,
+            _tree: &mut widget::Tree,
+            _renderer: &(),
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            let line_height = self.size * 1.25;
+            let first = self.size;
+
+            layout::Node::new(limits.resolve(
+                Length::Shrink,
+                Length::Shrink,
+                Size::new(self.size * 2.0, line_height * self.lines as f32),
+            ))
+            .with_baselines(first, first + line_height * (self.lines - 1) as f32)
+        }
+
+        fn draw(
+            &self,
+            _tree: &widget::Tree,
+            _renderer: &mut (),
+            _theme: &Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn label(size: f32, lines: usize) -> Element<'static, (), Theme, ()> {
+        Element::new(Label { size, lines })
+    }
+
+    #[test]
+    fn baselines_are_shared_across_font_sizes() {
+        let layout = |align: Alignment| {
+            let mut table: Table<'_, (), Theme, ()> = table(
+                [
+                    column(label(10.0, 1), |(size, lines)| label(size, lines)).align_y(align),
+                    column(label(20.0, 1), |(_size, _lines)| label(20.0, 1)).align_y(align),
+                    column(Space::new().width(10).height(12), |_| {
+                        Space::new().width(10).height(12)
+                    })
+                    .align_y(align),
+                ],
+                [(10.0, 3), (30.0, 1)],
+            );
+
+            let mut tree = widget::Tree::new(&table as &dyn Widget<(), Theme, ()>);
+            table.diff(&mut tree);
+
+            table.layout(
+                &mut tree,
+                &(),
+                &layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+            )
+        };
+
+        for align in [Alignment::Baseline, Alignment::LastBaseline] {
+            let node = layout(align);
+
+            let baselines: Vec<_> = node
+                .children()
+                .iter()
+                .map(|cell| {
+                    let baseline = match align {
+                        Alignment::LastBaseline => cell.last_baseline(),
+                        _ => cell.baseline(),
+                    };
+
+                    // Cells without a baseline are aligned by their bottom edge
+                    cell.bounds().y + baseline.unwrap_or(cell.size().height)
+                })
+                .collect();
+
+            for row in baselines.chunks(3) {
+                assert!(
+                    row.iter().all(|baseline| *baseline == row[0]),
+                    "{align:?}: {baselines:?}"
+                );
+            }
+
+            // The second row is pushed down by its tallest ascent
+            let header = baselines[0];
+            let first = baselines[3];
+            let last = baselines[6];
+
+            assert!(header < first && first < last, "{align:?}: {baselines:?}");
+        }
+    }
+}
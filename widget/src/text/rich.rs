@@ -1,10 +1,12 @@
 use crate::core::alignment;
 use crate::core::layout;
 use crate::core::mouse;
+use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{Paragraph, Span};
+use crate::core::widget::Operation;
 use crate::core::widget::text::{
-    self, Alignment, Catalog, Ellipsis, LineHeight, Shaping, Style, StyleFn, Wrapping,
+    self, Alignment, Catalog, Ellipsis, LineHeight, Selection, Shaping, Style, StyleFn, Wrapping,
 };
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
@@ -12,6 +14,43 @@ use crate::core::{
     Widget,
 };
 
+use std::borrow::Cow;
+
+/// A piece of [`Rich`] text; either a [`Span`] or an inline [`Element`].
+///
+/// Inline elements flow and wrap with the rest of the text. They are
+/// aligned to the baseline of their line, which grows to fit them if
+/// necessary.
+pub enum Inline<'a, Link, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    /// A [`Span`] of text.
+    Span(Span<'a, Link, Renderer::Font>),
+    /// An inline [`Element`].
+    Element(Element<'a, Message, Theme, Renderer>),
+}
+
+impl<'a, Link, Message, Theme, Renderer> From<Span<'a, Link, Renderer::Font>>
+    for Inline<'a, Link, Message, Theme, Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    fn from(span: Span<'a, Link, Renderer::Font>) -> Self {
+        Self::Span(span)
+    }
+}
+
+impl<'a, Link, Message, Theme, Renderer> From<&'a str>
+    for Inline<'a, Link, Message, Theme, Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    fn from(text: &'a str) -> Self {
+        Self::Span(Span::new(text))
+    }
+}
+
 /// A bunch of [`Rich`] text.
 pub struct Rich<'a, Link, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
@@ -32,6 +71,8 @@ where
     class: Theme::Class<'a>,
     hovered_link: Option<usize>,
     on_link_click: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    selectable: bool,
+    inlines: Vec<Element<'a, Message, Theme, Renderer>>,
 }
 
 impl<'a, Link, Message, Theme, Renderer> Rich<'a, Link, Message, Theme, Renderer>
@@ -57,6 +98,8 @@ where
             class: Theme::default(),
             hovered_link: None,
             on_link_click: None,
+            selectable: false,
+            inlines: Vec::new(),
         }
     }
 
@@ -139,6 +182,15 @@ where
         self
     }
 
+    /// Sets whether the contents of the [`Rich`] text can be selected and
+    /// copied.
+    ///
+    /// Links can still be clicked when the [`Rich`] text is selectable.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     /// Sets the default style of the [`Rich`] text.
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -164,7 +216,11 @@ where
     {
         let color = color.map(Into::into);
 
-        self.style(move |_theme| Style { color })
+        self.style(move |_theme| Style {
+            color,
+            selection: None,
+            effects: text::Effects::NONE,
+        })
     }
 
     /// Sets the default style class of the [`Rich`] text.
@@ -192,6 +248,8 @@ struct State<Link, P: Paragraph> {
     spans: Vec<Span<'static, Link, P::Font>>,
     span_pressed: Option<usize>,
     paragraph: P,
+    selection: Selection,
+    visible_inlines: Vec<bool>,
 }
 
 impl<Link, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -210,9 +268,15 @@ where
             spans: Vec::new(),
             span_pressed: None,
             paragraph: Renderer::Paragraph::default(),
+            selection: Selection::new(),
+            visible_inlines: Vec::new(),
         })
     }
 
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(&mut self.inlines);
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -226,14 +290,48 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
-            tree.state
-                .downcast_mut::<State<Link, Renderer::Paragraph>>(),
+        let inlines: Vec<_> = self
+            .inlines
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(inline, tree)| {
+                inline.as_widget_mut().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, limits.max()),
+                )
+            })
+            .collect();
+
+        let state = tree
+            .state
+            .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+        let spans = self.spans.as_ref().as_ref();
+
+        let spans = if inlines.is_empty() {
+            Cow::Borrowed(spans)
+        } else {
+            let mut sizes = inlines.iter().map(layout::Node::size);
+
+            Cow::Owned(
+                spans
+                    .iter()
+                    .map(|span| Span {
+                        inline: span.inline.and_then(|_| sizes.next()),
+                        ..span.clone()
+                    })
+                    .collect(),
+            )
+        };
+
+        let node = layout(
+            state,
             renderer,
             limits,
             self.width,
             self.height,
-            self.spans.as_ref().as_ref(),
+            &spans,
             self.line_height,
             self.size,
             self.font,
@@ -241,6 +339,43 @@ where
             self.align_y,
             self.wrapping,
             self.ellipsis,
+        );
+
+        if inlines.is_empty() {
+            return node;
+        }
+
+        let indices = spans
+            .iter()
+            .enumerate()
+            .filter_map(|(index, span)| span.inline.map(|_| index));
+
+        state.visible_inlines.clear();
+
+        let children = inlines
+            .into_iter()
+            .zip(indices)
+            .map(|(inline, index)| {
+                let Some(bounds) = state.paragraph.span_bounds(index).first().copied() else {
+                    state.visible_inlines.push(false);
+                    return inline;
+                };
+
+                let y = state
+                    .paragraph
+                    .span_baseline(index)
+                    .map_or(bounds.y, |baseline| {
+                        (baseline - inline.size().height).max(bounds.y)
+                    });
+
+                state.visible_inlines.push(true);
+                inline.move_to(Point::new(bounds.x, y))
+            })
+            .collect();
+
+        text::with_baselines(
+            layout::Node::with_children(node.size(), children),
+            &state.paragraph,
         )
     }
 
@@ -251,7 +386,7 @@ where
         theme: &Theme,
         defaults: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         if !layout.bounds().intersects(viewport) {
@@ -264,6 +399,13 @@ where
 
         let style = theme.style(&self.class);
 
+        state.selection.draw(
+            renderer,
+            &state.paragraph,
+            layout.bounds(),
+            text::selection_color(defaults, style),
+        );
+
         for (index, span) in self.spans.as_ref().as_ref().iter().enumerate() {
             let is_hovered_link = self.on_link_click.is_some() && Some(index) == self.hovered_link;
 
@@ -303,17 +445,23 @@ where
                         translation + Vector::new(0.0, size.0 + (line_height.0 - size.0) / 2.0);
 
                     if span.underline || is_hovered_link {
+                        let style = if span.underline {
+                            span.underline_style
+                        } else {
+                            text::LineStyle::Solid
+                        };
+
+                        let color = span.underline_color.unwrap_or(color);
+
                         for bounds in &regions {
-                            renderer.fill_quad(
-                                renderer::Quad {
-                                    bounds: Rectangle::new(
-                                        bounds.position() + baseline
-                                            - Vector::new(0.0, size.0 * 0.08),
-                                        Size::new(bounds.width, 1.0),
-                                    ),
-                                    ..Default::default()
-                                },
+                            text::draw_line(
+                                renderer,
+                                bounds.position() + baseline - Vector::new(0.0, size.0 * 0.08),
+                                bounds.width,
+                                1.0,
+                                style,
                                 color,
+                                *viewport,
                             );
                         }
                     }
@@ -345,6 +493,20 @@ where
             style,
             viewport,
         );
+
+        for (((inline, tree), layout), is_visible) in self
+            .inlines
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(&state.visible_inlines)
+        {
+            if *is_visible {
+                inline
+                    .as_widget()
+                    .draw(tree, renderer, theme, defaults, layout, cursor, viewport);
+            }
+        }
     }
 
     fn update(
@@ -353,87 +515,194 @@ where
         event: &Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
-        let Some(on_link_clicked) = &self.on_link_click else {
-            return;
-        };
-
-        let was_hovered = self.hovered_link.is_some();
-
-        if let Some(position) = cursor.position_in(layout.bounds()) {
+        if !self.inlines.is_empty() {
             let state = tree
                 .state
                 .downcast_ref::<State<Link, Renderer::Paragraph>>();
 
-            self.hovered_link = state.paragraph.hit_span(position).and_then(|span| {
-                if self.spans.as_ref().as_ref().get(span)?.link.is_some() {
-                    Some(span)
-                } else {
-                    None
+            for (((inline, tree), layout), is_visible) in self
+                .inlines
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .zip(&state.visible_inlines)
+            {
+                if *is_visible {
+                    inline
+                        .as_widget_mut()
+                        .update(tree, event, layout, cursor, renderer, shell, viewport);
                 }
-            });
-        } else {
-            self.hovered_link = None;
-        }
+            }
 
-        if was_hovered != self.hovered_link.is_some() {
-            shell.request_redraw();
+            if shell.is_event_captured() {
+                return;
+            }
         }
 
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+        if let Some(on_link_clicked) = &self.on_link_click {
+            let was_hovered = self.hovered_link.is_some();
+
+            if let Some(position) = cursor.position_in(layout.bounds()) {
                 let state = tree
                     .state
-                    .downcast_mut::<State<Link, Renderer::Paragraph>>();
+                    .downcast_ref::<State<Link, Renderer::Paragraph>>();
 
-                if self.hovered_link.is_some() {
-                    state.span_pressed = self.hovered_link;
-                    shell.capture_event();
-                }
+                self.hovered_link = state.paragraph.hit_span(position).and_then(|span| {
+                    if self.spans.as_ref().as_ref().get(span)?.link.is_some() {
+                        Some(span)
+                    } else {
+                        None
+                    }
+                });
+            } else {
+                self.hovered_link = None;
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                let state = tree
-                    .state
-                    .downcast_mut::<State<Link, Renderer::Paragraph>>();
-
-                match state.span_pressed {
-                    Some(span) if Some(span) == self.hovered_link => {
-                        if let Some(link) = self
-                            .spans
-                            .as_ref()
-                            .as_ref()
-                            .get(span)
-                            .and_then(|span| span.link.clone())
-                        {
-                            shell.publish(on_link_clicked(link));
-                        }
+
+            if was_hovered != self.hovered_link.is_some() {
+                shell.request_redraw();
+            }
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    let state = tree
+                        .state
+                        .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+                    if self.hovered_link.is_some() {
+                        state.span_pressed = self.hovered_link;
+                        shell.capture_event();
                     }
-                    _ => {}
                 }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    let state = tree
+                        .state
+                        .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+                    match state.span_pressed {
+                        Some(span) if Some(span) == self.hovered_link => {
+                            if let Some(link) = self
+                                .spans
+                                .as_ref()
+                                .as_ref()
+                                .get(span)
+                                .and_then(|span| span.link.clone())
+                            {
+                                shell.publish(on_link_clicked(link));
+                            }
+                        }
+                        _ => {}
+                    }
 
-                state.span_pressed = None;
+                    state.span_pressed = None;
+                }
+                _ => {}
             }
-            _ => {}
+        }
+
+        if self.selectable && !shell.is_event_captured() {
+            let state = tree
+                .state
+                .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+            state
+                .selection
+                .update(&state.paragraph, event, layout.bounds(), cursor, shell);
         }
     }
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
-        _layout: Layout<'_>,
-        _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
-        _renderer: &Renderer,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
         if self.hovered_link.is_some() {
-            mouse::Interaction::Pointer
+            return mouse::Interaction::Pointer;
+        }
+
+        let state = tree
+            .state
+            .downcast_ref::<State<Link, Renderer::Paragraph>>();
+
+        let interaction = self
+            .inlines
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(&state.visible_inlines)
+            .filter(|(_, is_visible)| **is_visible)
+            .map(|(((inline, tree), layout), _)| {
+                inline
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::None {
+            return interaction;
+        }
+
+        if self.selectable || state.selection.is_grouped() {
+            state.selection.mouse_interaction(layout.bounds(), cursor)
         } else {
             mouse::Interaction::None
         }
     }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree
+            .state
+            .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+        operation.custom(None, layout.bounds(), &mut state.selection);
+
+        state.selection.resolve(&state.paragraph, layout.bounds());
+
+        if !self.inlines.is_empty() {
+            operation.traverse(&mut |operation| {
+                self.inlines
+                    .iter_mut()
+                    .zip(&mut tree.children)
+                    .zip(layout.children())
+                    .for_each(|((inline, tree), layout)| {
+                        inline
+                            .as_widget_mut()
+                            .operate(tree, layout, renderer, operation);
+                    });
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.inlines,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
 }
 
 fn layout<Link, Renderer>(
@@ -455,7 +724,7 @@ where
     Link: Clone,
     Renderer: core::text::Renderer,
 {
-    layout::sized(limits, width, height, |limits| {
+    let node = layout::sized(limits, width, height, |limits| {
         let bounds = limits.max();
 
         let size = size.unwrap_or_else(|| renderer.default_size());
@@ -478,6 +747,7 @@ where
         if state.spans != spans {
             state.paragraph = Renderer::Paragraph::with_spans(text_with_spans());
             state.spans = spans.iter().cloned().map(Span::to_static).collect();
+            state.selection.clear();
         } else {
             match state.paragraph.compare(core::Text {
                 content: (),
@@ -503,7 +773,9 @@ where
         }
 
         state.paragraph.min_bounds()
-    })
+    });
+
+    text::with_baselines(node, &state.paragraph)
 }
 
 impl<'a, Link, Message, Theme, Renderer> FromIterator<Span<'a, Link, Renderer::Font>>
@@ -519,6 +791,39 @@ where
     }
 }
 
+impl<'a, Link, Message, Theme, Renderer> FromIterator<Inline<'a, Link, Message, Theme, Renderer>>
+    for Rich<'a, Link, Message, Theme, Renderer>
+where
+    Link: Clone + 'a,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+    Renderer::Font: 'a,
+{
+    fn from_iter<T: IntoIterator<Item = Inline<'a, Link, Message, Theme, Renderer>>>(
+        inlines: T,
+    ) -> Self {
+        let mut spans = Vec::new();
+        let mut elements = Vec::new();
+
+        for inline in inlines {
+            match inline {
+                Inline::Span(span) => {
+                    spans.push(span);
+                }
+                Inline::Element(element) => {
+                    spans.push(Span::default().inline(Size::ZERO));
+                    elements.push(element);
+                }
+            }
+        }
+
+        Self {
+            inlines: elements,
+            ..Self::with_spans(spans)
+        }
+    }
+}
+
 impl<'a, Link, Message, Theme, Renderer> From<Rich<'a, Link, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -31,6 +31,12 @@
 //!     }
 //! }
 //! ```
+mod completion;
+mod smart;
+
+pub use completion::Completion;
+pub use smart::{Indentation, Smart};
+
 use crate::core::alignment;
 use crate::core::clipboard;
 use crate::core::input_method;
@@ -38,9 +44,10 @@ use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
+use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::editor::Editor as _;
-use crate::core::text::highlighter::{self, Highlighter};
+use crate::core::text::highlighter::{self, Highlighter, Underline};
 use crate::core::text::{self, LineHeight, Text, Wrapping};
 use crate::core::theme;
 use crate::core::time::{Duration, Instant};
@@ -59,7 +66,9 @@ use std::ops;
 use std::ops::DerefMut;
 use std::sync::Arc;
 
-pub use text::editor::{Action, Cursor, Edit, Line, LineEnding, Motion, Position, Selection};
+pub use text::editor::{
+    Action, Cursor, Edit, Line, LineEnding, Motion, Position, Selection, Storage,
+};
 
 /// A multi-line text input.
 ///
@@ -112,7 +121,11 @@ where
     wrapping: Wrapping,
     class: Theme::Class<'a>,
     key_binding: Option<Box<dyn Fn(KeyPress) -> Option<Binding<Message>> + 'a>>,
+    smart: Smart,
+    completions: &'a [Completion],
+    popups: Vec<(Position, Element<'a, Message, Theme, Renderer>)>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    on_hover: Option<Box<dyn Fn(Option<Position>) -> Message + 'a>>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(&Highlighter::Highlight, &Theme) -> highlighter::Format<Renderer::Font>,
     last_status: Option<Status>,
@@ -138,7 +151,11 @@ where
             wrapping: Wrapping::default(),
             class: <Theme as Catalog>::default(),
             key_binding: None,
+            smart: Smart::default(),
+            completions: &[],
+            popups: Vec::new(),
             on_edit: None,
+            on_hover: None,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| highlighter::Format::default(),
             last_status: None,
@@ -257,7 +274,11 @@ where
             wrapping: self.wrapping,
             class: self.class,
             key_binding: self.key_binding,
+            smart: self.smart,
+            completions: self.completions,
+            popups: self.popups,
             on_edit: self.on_edit,
+            on_hover: self.on_hover,
             highlighter_settings: settings,
             highlighter_format: to_format,
             last_status: self.last_status,
@@ -275,6 +296,49 @@ where
         self
     }
 
+    /// Sets the [`Smart`] editing behavior of the [`TextEditor`].
+    ///
+    /// It is only applied to the default actions of the [`Binding`]s.
+    pub fn smart(mut self, smart: Smart) -> Self {
+        self.smart = smart;
+        self
+    }
+
+    /// Sets the [`Completion`]s of the [`TextEditor`].
+    ///
+    /// While focused, the [`TextEditor`] displays the completions matching
+    /// the word at its cursor in a list below it. The list can be navigated
+    /// with the arrow keys, and the selected [`Completion`] can be accepted
+    /// with Tab or Enter; which replaces the word with its text. Escape
+    /// dismisses the list until the next edit.
+    pub fn completions(mut self, completions: &'a [Completion]) -> Self {
+        self.completions = completions;
+        self
+    }
+
+    /// Adds a popup to the [`TextEditor`], displayed above the given
+    /// [`Position`] of its text; like a hover or signature popup.
+    ///
+    /// The popup is not interactive, and it is only displayed while the
+    /// [`Position`] is visible.
+    pub fn popup(
+        mut self,
+        position: Position,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.popups.push((position, content.into()));
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Position`] of
+    /// the text hovered by the mouse cursor changes.
+    ///
+    /// This can be used to request hover information lazily.
+    pub fn on_hover(mut self, on_hover: impl Fn(Option<Position>) -> Message + 'a) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
     /// Sets the style of the [`TextEditor`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
@@ -342,6 +406,7 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    bounds: Option<Rectangle>,
 }
 
 impl<R> Content<R>
@@ -357,9 +422,26 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            bounds: None,
+        }))
+    }
+
+    /// Creates a [`Content`] with the given text, using the given [`Storage`].
+    ///
+    /// [`Storage::Lazy`] keeps huge texts responsive by only laying out
+    /// the lines around the viewport.
+    pub fn with_storage(text: &str, storage: Storage) -> Self {
+        Self(RefCell::new(Internal {
+            editor: R::Editor::with_storage(text, storage),
+            bounds: None,
         }))
     }
 
+    /// Returns the [`Storage`] of the [`Content`].
+    pub fn storage(&self) -> Storage {
+        self.0.borrow().editor.storage()
+    }
+
     /// Performs an [`Action`] on the [`Content`].
     pub fn perform(&mut self, action: Action) {
         let internal = self.0.get_mut();
@@ -379,6 +461,64 @@ where
         self.0.borrow().editor.cursor()
     }
 
+    /// Returns the bounds of the cursor of the [`Content`], as it was last
+    /// drawn by a [`TextEditor`].
+    ///
+    /// See [`bounds_at`](Self::bounds_at) for more details.
+    pub fn cursor_bounds(&self) -> Option<Rectangle> {
+        self.bounds_at(self.cursor().position)
+    }
+
+    /// Returns the bounds of the given [`Position`] of the [`Content`], as it
+    /// was last drawn by a [`TextEditor`].
+    ///
+    /// The bounds have no width and the height of a line; like a caret.
+    /// They are relative to the layout of the [`TextEditor`], which makes
+    /// them useful to position custom overlays.
+    ///
+    /// Nothing is returned if the [`Content`] has not been drawn yet.
+    pub fn bounds_at(&self, position: Position) -> Option<Rectangle> {
+        self.region(position, position).pop()
+    }
+
+    /// Returns the regions covered by the text between the given positions,
+    /// as it was last drawn by a [`TextEditor`].
+    ///
+    /// See [`bounds_at`](Self::bounds_at) for more details.
+    pub fn region(&self, start: Position, end: Position) -> Vec<Rectangle> {
+        let internal = self.0.borrow();
+
+        let Some(bounds) = internal.bounds else {
+            return Vec::new();
+        };
+
+        let translation = bounds.position() - Point::ORIGIN;
+
+        internal
+            .editor
+            .region(start, end)
+            .into_iter()
+            .map(|region| region + translation)
+            .collect()
+    }
+
+    /// Returns the [`Position`] of the text at the given [`Point`], as it was
+    /// last drawn by a [`TextEditor`].
+    ///
+    /// The [`Point`] must be relative to the layout of the [`TextEditor`].
+    pub fn position_at(&self, point: Point) -> Option<Position> {
+        let internal = self.0.borrow();
+        let bounds = internal.bounds?;
+
+        if !bounds.contains(point) {
+            return None;
+        }
+
+        internal
+            .editor
+            .position_at(point - (bounds.position() - Point::ORIGIN))
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -405,22 +545,7 @@ where
 
     /// Returns the text of the [`Content`].
     pub fn text(&self) -> String {
-        let mut contents = String::new();
-        let mut lines = self.lines().peekable();
-
-        while let Some(line) = lines.next() {
-            contents.push_str(&line.text);
-
-            if lines.peek().is_some() {
-                contents.push_str(if line.ending == LineEnding::None {
-                    LineEnding::default().as_str()
-                } else {
-                    line.ending.as_str()
-                });
-            }
-        }
-
-        contents
+        self.0.borrow().editor.text()
     }
 
     /// Returns the selected text of the [`Content`].
@@ -444,7 +569,7 @@ where
     Renderer: text::Renderer,
 {
     fn clone(&self) -> Self {
-        Self::with_text(&self.text())
+        Self::with_storage(&self.text(), self.storage())
     }
 }
 
@@ -483,6 +608,9 @@ pub struct State<Highlighter: text::Highlighter> {
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
+    completion: completion::State,
+    hovered: Option<Position>,
+    closing: smart::Closing,
 }
 
 #[derive(Debug, Clone)]
@@ -555,9 +683,20 @@ where
             highlighter: RefCell::new(Highlighter::new(&self.highlighter_settings)),
             highlighter_settings: self.highlighter_settings.clone(),
             highlighter_format_address: self.highlighter_format as usize,
+            completion: completion::State::default(),
+            hovered: None,
+            closing: smart::Closing::default(),
         })
     }
 
+    fn diff(&mut self, tree: &mut widget::Tree) {
+        tree.diff_children_custom(
+            &mut self.popups,
+            |tree, (_, popup)| tree.diff(popup.as_widget_mut()),
+            |(_, popup)| widget::Tree::new(popup.as_widget()),
+        );
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -630,11 +769,25 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) = event
+            && let Some(on_hover) = &self.on_hover
+        {
+            let hovered = cursor
+                .position_in(layout.bounds())
+                .and_then(|position| self.content.position_at(position));
+
+            if hovered != state.hovered {
+                state.hovered = hovered;
+
+                shell.publish(on_hover(hovered));
+            }
+        }
+
         let Some(on_edit) = self.on_edit.as_ref() else {
             return;
         };
-
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
         let is_redraw = matches!(event, Event::Window(window::Event::RedrawRequested(_now)),);
 
         match event {
@@ -671,12 +824,28 @@ where
                     && let Some(focus) = &mut state.focus
                     && focus.is_window_focused
                 {
+                    state.closing.clear();
                     shell.publish(on_edit(Action::Edit(Edit::Paste(Arc::new(text.clone())))));
                 }
             }
             _ => {}
         }
 
+        if state.focus.is_some()
+            && let Some(actions) =
+                completion::navigate(self.content, self.completions, &mut state.completion, event)
+        {
+            state.closing.clear();
+
+            for action in actions {
+                shell.publish(on_edit(action));
+            }
+
+            shell.capture_event();
+            shell.request_redraw();
+            return;
+        }
+
         if let Some(update) = Update::from_event(
             event,
             state,
@@ -696,6 +865,7 @@ where
                     state.focus = Some(Focus::now());
                     state.last_click = Some(click);
                     state.drag_click = Some(click.kind());
+                    state.closing.clear();
 
                     shell.publish(on_edit(action));
                     shell.capture_event();
@@ -737,6 +907,7 @@ where
                         shell.request_redraw();
                     }
                     Ime::Commit(text) => {
+                        state.closing.clear();
                         shell.publish(on_edit(Action::Edit(Edit::Paste(Arc::new(text)))));
                     }
                 },
@@ -744,6 +915,7 @@ where
                     fn apply_binding<H: text::Highlighter, R: text::Renderer, Message>(
                         binding: Binding<Message>,
                         content: &Content<R>,
+                        smart: &Smart,
                         state: &mut State<H>,
                         on_edit: &dyn Fn(Action) -> Message,
                         shell: &mut Shell<'_, Message>,
@@ -792,20 +964,41 @@ where
                                 publish(Action::SelectAll);
                             }
                             Binding::Insert(c) => {
-                                publish(Action::Edit(Edit::Insert(c)));
+                                for action in smart::insert(content, smart, &mut state.closing, c) {
+                                    publish(action);
+                                }
                             }
                             Binding::Enter => {
-                                publish(Action::Edit(Edit::Enter));
+                                for action in smart::enter(content, smart) {
+                                    publish(action);
+                                }
+                            }
+                            Binding::Indent => {
+                                for action in smart::indent(content, smart) {
+                                    publish(action);
+                                }
+                            }
+                            Binding::Unindent => {
+                                for action in smart::unindent(content, smart) {
+                                    publish(action);
+                                }
+                            }
+                            Binding::ToggleComment => {
+                                for action in smart::toggle_comment(content, smart) {
+                                    publish(action);
+                                }
                             }
                             Binding::Backspace => {
-                                publish(Action::Edit(Edit::Backspace));
+                                for action in smart::backspace(content, smart) {
+                                    publish(action);
+                                }
                             }
                             Binding::Delete => {
                                 publish(Action::Edit(Edit::Delete));
                             }
                             Binding::Sequence(sequence) => {
                                 for binding in sequence {
-                                    apply_binding(binding, content, state, on_edit, shell);
+                                    apply_binding(binding, content, smart, state, on_edit, shell);
                                 }
                             }
                             Binding::Custom(message) => {
@@ -818,7 +1011,18 @@ where
                         shell.capture_event();
                     }
 
-                    apply_binding(binding, self.content, state, on_edit, shell);
+                    if matches!(
+                        binding,
+                        Binding::Insert(_) | Binding::Backspace | Binding::Delete
+                    ) {
+                        state.completion.reset();
+                    }
+
+                    if !matches!(binding, Binding::Insert(_)) {
+                        state.closing.clear();
+                    }
+
+                    apply_binding(binding, self.content, &self.smart, state, on_edit, shell);
 
                     if let Some(focus) = &mut state.focus {
                         focus.updated_at = Instant::now();
@@ -901,6 +1105,7 @@ where
         );
 
         let text_bounds = bounds.shrink(self.padding);
+        internal.bounds = Some(text_bounds);
 
         if internal.editor.is_empty() {
             if let Some(placeholder) = self.placeholder.clone() {
@@ -934,7 +1139,37 @@ where
 
         let translation = text_bounds.position() - Point::ORIGIN;
 
+        for (region, underline) in internal.editor.underlines() {
+            draw_underline(renderer, region + translation, underline, text_bounds);
+        }
+
         if let Some(focus) = state.focus.as_ref() {
+            if self.smart.match_brackets
+                && let Some(brackets) = smart::matching_bracket(&internal.editor, &self.smart.pairs)
+            {
+                for (start, end) in brackets {
+                    for region in internal
+                        .editor
+                        .region(start, end)
+                        .into_iter()
+                        .filter_map(|region| text_bounds.intersection(&(region + translation)))
+                    {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: region.round(),
+                                border: Border {
+                                    width: 1.0,
+                                    color: style.selection,
+                                    ..Border::default()
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            Color::TRANSPARENT,
+                        );
+                    }
+                }
+            }
+
             match internal.editor.selection() {
                 Selection::Caret(position) if focus.is_cursor_visible() => {
                     let cursor = Rectangle::new(
@@ -1017,6 +1252,83 @@ where
 
         operation.focusable(self.id.as_ref(), layout.bounds(), state);
     }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+        let text_bounds = layout.bounds().shrink(self.padding);
+
+        let popups = self.popups.iter_mut().zip(&mut tree.children).filter_map(
+            |((position, content), tree)| {
+                let anchor = completion::anchor(self.content, *position, text_bounds, translation)?;
+
+                Some(overlay::Element::new(Box::new(completion::Popup {
+                    content,
+                    tree,
+                    anchor,
+                })))
+            },
+        );
+
+        let list = if state.focus.is_some()
+            && let Some(on_edit) = self.on_edit.as_deref()
+            && let Some((start, completions)) =
+                completion::visible(self.content, self.completions, &mut state.completion)
+            && let Some(caret) = completion::anchor(
+                self.content,
+                self.content.cursor().position,
+                text_bounds,
+                translation,
+            ) {
+            Some(overlay::Element::new(Box::new(completion::List {
+                content: self.content,
+                completions,
+                start,
+                state: &mut state.completion,
+                caret,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                text_size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+                line_height: self.line_height,
+                on_edit,
+                class: &self.class,
+            })))
+        } else {
+            None
+        };
+
+        let children: Vec<_> = popups.chain(list).collect();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+fn draw_underline<Renderer>(
+    renderer: &mut Renderer,
+    region: Rectangle,
+    underline: Underline,
+    clip_bounds: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    const THICKNESS: f32 = 1.0;
+
+    let style = underline.style();
+
+    crate::text::draw_line(
+        renderer,
+        Point::new(region.x, region.y + region.height - style.height(THICKNESS)),
+        region.width,
+        THICKNESS,
+        style,
+        underline.color(),
+        clip_bounds,
+    );
 }
 
 impl<'a, Highlighter, Message, Theme, Renderer>
@@ -1062,6 +1374,12 @@ pub enum Binding<Message> {
     Insert(char),
     /// Break the current line.
     Enter,
+    /// Indent the selected lines, or insert indentation at the cursor.
+    Indent,
+    /// Unindent the selected lines.
+    Unindent,
+    /// Toggle the line comments of the selected lines.
+    ToggleComment,
     /// Delete the previous character.
     Backspace,
     /// Delete the next character.
@@ -1118,6 +1436,7 @@ impl<Message> Binding<Message> {
             Some('a') if modifiers.command() => Some(Self::SelectAll),
             Some('z') if modifiers.command() => Some(Self::Undo),
             Some('y') if modifiers.command() => Some(Self::Redo),
+            Some('/') if modifiers.command() => Some(Self::ToggleComment),
             _ => None,
         };
 
@@ -1130,6 +1449,11 @@ impl<Message> Binding<Message> {
 
         match modified_key.as_ref() {
             keyboard::Key::Named(key::Named::Enter) => Some(Self::Enter),
+            keyboard::Key::Named(key::Named::Tab) => Some(if modifiers.shift() {
+                Self::Unindent
+            } else {
+                Self::Indent
+            }),
             keyboard::Key::Named(key::Named::Backspace) => Some(Self::Backspace),
             keyboard::Key::Named(key::Named::Delete)
                 if text.is_none() || text.as_deref() == Some("\u{7f}") =>
@@ -0,0 +1,691 @@
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Text};
+use crate::core::widget;
+use crate::core::{Element, Event, Padding, Pixels, Point, Rectangle, Shell, Size, Vector};
+use crate::text_editor::{Action, Catalog, Content, Cursor, Edit, Position, Status};
+
+use std::sync::Arc;
+
+/// An item of the completion list of a [`TextEditor`].
+///
+/// [`TextEditor`]: super::TextEditor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    /// The label of the [`Completion`], used for filtering and display.
+    pub label: String,
+    /// The additional information displayed next to the label, if any.
+    pub detail: Option<String>,
+    /// The text inserted when the [`Completion`] is accepted.
+    pub text: String,
+}
+
+impl Completion {
+    /// Creates a new [`Completion`] with the given label.
+    ///
+    /// By default, the label is also the text to insert.
+    pub fn new(label: impl Into<String>) -> Self {
+        let label = label.into();
+
+        Self {
+            text: label.clone(),
+            label,
+            detail: None,
+        }
+    }
+
+    /// Sets the detail of the [`Completion`].
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Sets the text inserted when the [`Completion`] is accepted.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+}
+
+/// The maximum amount of visible items in the completion list.
+const MAX_VISIBLE: usize = 8;
+
+/// The maximum width of the completion list.
+const MAX_WIDTH: f32 = 320.0;
+
+const PADDING: Padding = Padding {
+    top: 2.0,
+    right: 6.0,
+    bottom: 2.0,
+    left: 6.0,
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct State {
+    selected: usize,
+    is_dismissed: bool,
+}
+
+impl State {
+    pub(super) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Returns the start of the word being typed at the cursor, alongside
+/// its text.
+///
+/// Nothing is returned if the cursor has a selection or if there is no
+/// word right before it.
+fn word<R: text::Renderer>(content: &Content<R>) -> Option<(Position, String)> {
+    let cursor = content.cursor();
+
+    if cursor.selection.is_some() {
+        return None;
+    }
+
+    let line = content.line(cursor.position.line)?;
+    let left = line.text.get(..cursor.position.column)?;
+
+    let start = left
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+        .last()
+        .map(|(i, _)| i)?;
+
+    Some((
+        Position {
+            line: cursor.position.line,
+            column: start,
+        },
+        left[start..].to_owned(),
+    ))
+}
+
+/// Returns the completions matching the given word.
+///
+/// A completion matches if the word is a case-insensitive subsequence
+/// of its label. Completions starting with the word come first.
+fn filter<'a>(completions: &'a [Completion], word: &str) -> Vec<&'a Completion> {
+    let word = word.to_lowercase();
+
+    let mut matches: Vec<_> = completions
+        .iter()
+        .filter_map(|completion| {
+            let label = completion.label.to_lowercase();
+            let mut chars = label.chars();
+
+            word.chars()
+                .all(|c| chars.any(|l| l == c))
+                .then(|| (!label.starts_with(&word), completion))
+        })
+        .collect();
+
+    matches.sort_by_key(|(is_fuzzy, _)| *is_fuzzy);
+    matches
+        .into_iter()
+        .map(|(_, completion)| completion)
+        .collect()
+}
+
+/// Returns the start of the word at the cursor and the completions
+/// matching it, if the completion list should be visible.
+///
+/// The selected completion of the [`State`] is kept within bounds.
+pub(super) fn visible<'a, R: text::Renderer>(
+    content: &Content<R>,
+    completions: &'a [Completion],
+    state: &mut State,
+) -> Option<(Position, Vec<&'a Completion>)> {
+    if state.is_dismissed || completions.is_empty() {
+        return None;
+    }
+
+    let (start, word) = word(content)?;
+    let completions = filter(completions, &word);
+
+    // Nothing is left to complete if the word is already typed in full
+    if completions.is_empty() {
+        return None;
+    }
+
+    // Nothing is left to complete if the only match is already typed in full
+    if let [completion] = completions.as_slice()
+        && completion.text == word
+    {
+        return None;
+    }
+
+    state.selected = state.selected.min(completions.len() - 1);
+
+    Some((start, completions))
+}
+
+/// Returns the actions that replace the word at the cursor with the
+/// given completion.
+fn accept(start: Position, completion: &Completion, cursor: Cursor) -> [Action; 2] {
+    [
+        Action::MoveTo(Cursor {
+            position: cursor.position,
+            selection: Some(start),
+        }),
+        Action::Edit(Edit::Paste(Arc::new(completion.text.clone()))),
+    ]
+}
+
+/// The completion list of a [`TextEditor`], displayed below its cursor.
+///
+/// [`TextEditor`]: super::TextEditor
+pub(super) struct List<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub(super) content: &'b Content<Renderer>,
+    pub(super) completions: Vec<&'a Completion>,
+    pub(super) start: Position,
+    pub(super) state: &'b mut State,
+    pub(super) caret: Rectangle,
+    pub(super) font: Renderer::Font,
+    pub(super) text_size: Pixels,
+    pub(super) line_height: LineHeight,
+    pub(super) on_edit: &'b dyn Fn(Action) -> Message,
+    pub(super) class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> List<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn row_height(&self) -> f32 {
+        f32::from(self.line_height.to_absolute(self.text_size)) + PADDING.y()
+    }
+
+    fn first_visible(&self) -> usize {
+        self.state.selected.saturating_sub(MAX_VISIBLE - 1)
+    }
+
+    fn row_at(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<usize> {
+        let position = cursor.position_in(layout.bounds())?;
+        let row = self.first_visible() + (position.y / self.row_height()) as usize;
+
+        (row < self.completions.len()).then_some(row)
+    }
+}
+
+/// Handles the keyboard navigation of the completion list of a
+/// [`TextEditor`].
+///
+/// Returns the actions to publish, if any; or `None` if the key press
+/// was not handled.
+///
+/// [`TextEditor`]: super::TextEditor
+pub(super) fn navigate<R: text::Renderer>(
+    content: &Content<R>,
+    completions: &[Completion],
+    state: &mut State,
+    event: &Event,
+) -> Option<Vec<Action>> {
+    let Event::Keyboard(keyboard::Event::KeyPressed {
+        key: keyboard::Key::Named(key),
+        modifiers,
+        ..
+    }) = event
+    else {
+        return None;
+    };
+
+    if !modifiers.is_empty() {
+        return None;
+    }
+
+    let (start, completions) = visible(content, completions, state)?;
+    let selected = state.selected;
+
+    match key {
+        key::Named::ArrowUp => {
+            state.selected = selected.checked_sub(1).unwrap_or(completions.len() - 1);
+        }
+        key::Named::ArrowDown => {
+            state.selected = (selected + 1) % completions.len();
+        }
+        key::Named::Tab | key::Named::Enter => {
+            state.is_dismissed = true;
+
+            return Some(accept(start, completions[selected], content.cursor()).to_vec());
+        }
+        key::Named::Escape => {
+            state.is_dismissed = true;
+        }
+        _ => return None,
+    }
+
+    Some(Vec::new())
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for List<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = Size::new(
+            MAX_WIDTH.min(bounds.width),
+            self.row_height() * self.completions.len().min(MAX_VISIBLE) as f32,
+        );
+
+        let below = self.caret.y + self.caret.height;
+
+        let y = if below + size.height > bounds.height && self.caret.y >= size.height {
+            self.caret.y - size.height
+        } else {
+            below
+        };
+
+        let x = self.caret.x.min(bounds.width - size.width).max(0.0);
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(row) = self.row_at(layout, cursor)
+                    && row != self.state.selected
+                {
+                    self.state.selected = row;
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(row) = self.row_at(layout, cursor) {
+                    self.state.is_dismissed = true;
+
+                    for action in accept(self.start, self.completions[row], self.content.cursor()) {
+                        shell.publish((self.on_edit)(action));
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(self.class, Status::Active);
+        let row_height = self.row_height();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let first = self.first_visible();
+        let hint_factor = renderer.hint_factor();
+
+        for (i, completion) in self
+            .completions
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(MAX_VISIBLE)
+        {
+            let row = Rectangle {
+                x: bounds.x,
+                y: bounds.y + (i - first) as f32 * row_height,
+                width: bounds.width,
+                height: row_height,
+            };
+
+            if i == self.state.selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row,
+                        ..renderer::Quad::default()
+                    },
+                    style.selection,
+                );
+            }
+
+            let text_bounds = row.shrink(PADDING);
+
+            let text = |content: &str, align_x| Text {
+                content: content.to_owned(),
+                bounds: text_bounds.size(),
+                size: self.text_size,
+                line_height: self.line_height,
+                font: self.font,
+                align_x,
+                align_y: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+                ellipsis: text::Ellipsis::End,
+                hint_factor,
+            };
+
+            renderer.fill_text(
+                text(&completion.label, text::Alignment::Left),
+                text_bounds.position(),
+                style.value,
+                text_bounds,
+            );
+
+            if let Some(detail) = &completion.detail {
+                renderer.fill_text(
+                    text(detail, text::Alignment::Right),
+                    Point::new(text_bounds.x + text_bounds.width, text_bounds.y),
+                    style.placeholder,
+                    text_bounds,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+}
+
+/// A non-interactive popup of a [`TextEditor`], displayed above some
+/// [`Position`] of its text.
+///
+/// [`TextEditor`]: super::TextEditor
+pub(super) struct Popup<'a, 'b, Message, Theme, Renderer> {
+    pub(super) content: &'b mut Element<'a, Message, Theme, Renderer>,
+    pub(super) tree: &'b mut widget::Tree,
+    pub(super) anchor: Rectangle,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Popup<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let node = self.content.as_widget_mut().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, bounds),
+        );
+
+        let size = node.size();
+
+        let y = if self.anchor.y >= size.height {
+            self.anchor.y - size.height
+        } else {
+            self.anchor.y + self.anchor.height
+        };
+
+        let x = self.anchor.x.min(bounds.width - size.width).max(0.0);
+
+        node.move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &layout.bounds(),
+        );
+    }
+}
+
+/// Returns the caret region of the given [`Position`], translated by the
+/// given vector; if it is within the given bounds.
+pub(super) fn anchor<R: text::Renderer>(
+    content: &Content<R>,
+    position: Position,
+    bounds: Rectangle,
+    translation: Vector,
+) -> Option<Rectangle> {
+    use crate::core::text::editor::Editor as _;
+
+    let internal = content.0.borrow();
+    let region = internal.editor.region(position, position).pop()?;
+
+    let region = region + (bounds.position() - Point::ORIGIN);
+
+    (region.y >= bounds.y && region.y < bounds.y + bounds.height).then_some(region + translation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Content = super::Content<crate::Renderer>;
+
+    fn editor(text: &str, line: usize, column: usize) -> Content {
+        let mut content = Content::with_text(text);
+        content.move_to(Cursor {
+            position: Position { line, column },
+            selection: None,
+        });
+
+        content
+    }
+
+    fn completions(labels: &[&str]) -> Vec<Completion> {
+        labels.iter().copied().map(Completion::new).collect()
+    }
+
+    fn labels<'a>(completions: &[&'a Completion]) -> Vec<&'a str> {
+        completions
+            .iter()
+            .map(|completion| completion.label.as_str())
+            .collect()
+    }
+
+    fn press(key: key::Named) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            modified_key: keyboard::Key::Named(key),
+            physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+            repeat: false,
+        })
+    }
+
+    #[test]
+    fn prefix_matches_come_before_fuzzy_matches() {
+        let completions = completions(&["to_string", "String", "strip", "len"]);
+
+        assert_eq!(
+            labels(&filter(&completions, "str")),
+            ["String", "strip", "to_string"]
+        );
+        assert_eq!(labels(&filter(&completions, "sg")), ["to_string", "String"]);
+        assert!(filter(&completions, "xyz").is_empty());
+    }
+
+    #[test]
+    fn nothing_is_visible_without_a_word() {
+        let completions = completions(&["foo", "bar"]);
+        let mut state = State::default();
+
+        assert!(visible(&editor("", 0, 0), &completions, &mut state).is_none());
+        assert!(visible(&editor("let x = ", 0, 8), &completions, &mut state).is_none());
+
+        let (start, matches) =
+            visible(&editor("let x = fo", 0, 10), &completions, &mut state).unwrap();
+
+        assert_eq!(start, Position { line: 0, column: 8 });
+        assert_eq!(labels(&matches), ["foo"]);
+    }
+
+    #[test]
+    fn enter_and_tab_are_not_intercepted_without_a_word() {
+        let completions = completions(&["foo", "bar"]);
+        let mut state = State::default();
+        let content = editor("foo ", 0, 4);
+
+        assert!(
+            navigate(
+                &content,
+                &completions,
+                &mut state,
+                &press(key::Named::Enter)
+            )
+            .is_none()
+        );
+        assert!(navigate(&content, &completions, &mut state, &press(key::Named::Tab)).is_none());
+    }
+
+    #[test]
+    fn nothing_is_visible_when_the_only_match_is_typed_in_full() {
+        let completions = completions(&["foo", "foobar"]);
+        let mut state = State::default();
+
+        assert!(visible(&editor("foobar", 0, 6), &completions, &mut state).is_none());
+        assert!(
+            navigate(
+                &editor("foobar", 0, 6),
+                &completions,
+                &mut state,
+                &press(key::Named::Enter)
+            )
+            .is_none()
+        );
+
+        let (_, matches) = visible(&editor("foo", 0, 3), &completions, &mut state).unwrap();
+
+        assert_eq!(labels(&matches), ["foo", "foobar"]);
+    }
+
+    #[test]
+    fn arrows_wrap_around_the_matches() {
+        let completions = completions(&["alpha", "also", "altitude"]);
+        let mut state = State::default();
+        let content = editor("al", 0, 2);
+
+        let mut select = |key| {
+            assert_eq!(
+                navigate(&content, &completions, &mut state, &press(key)),
+                Some(Vec::new())
+            );
+
+            state.selected
+        };
+
+        assert_eq!(select(key::Named::ArrowUp), 2);
+        assert_eq!(select(key::Named::ArrowDown), 0);
+        assert_eq!(select(key::Named::ArrowDown), 1);
+        assert_eq!(
+            navigate(
+                &content,
+                &completions,
+                &mut state,
+                &press(key::Named::Space)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn accepting_replaces_the_word_at_the_cursor() {
+        let completions = vec![
+            Completion::new("println"),
+            Completion::new("print").text("print!()"),
+        ];
+        let mut state = State::default();
+        let mut content = editor("    pri", 0, 7);
+
+        let _ = navigate(
+            &content,
+            &completions,
+            &mut state,
+            &press(key::Named::ArrowDown),
+        );
+
+        let actions =
+            navigate(&content, &completions, &mut state, &press(key::Named::Tab)).unwrap();
+
+        for action in actions {
+            content.perform(action);
+        }
+
+        assert_eq!(content.text(), "    print!()");
+        assert!(visible(&content, &completions, &mut state).is_none());
+
+        state.reset();
+
+        assert!(visible(&editor("    pri", 0, 7), &completions, &mut state).is_some());
+    }
+
+    #[test]
+    fn escape_dismisses_the_list() {
+        let completions = completions(&["foo", "foobar"]);
+        let mut state = State::default();
+        let content = editor("fo", 0, 2);
+
+        assert_eq!(
+            navigate(
+                &content,
+                &completions,
+                &mut state,
+                &press(key::Named::Escape)
+            ),
+            Some(Vec::new())
+        );
+        assert!(visible(&content, &completions, &mut state).is_none());
+        assert!(
+            navigate(
+                &content,
+                &completions,
+                &mut state,
+                &press(key::Named::Enter)
+            )
+            .is_none()
+        );
+    }
+}
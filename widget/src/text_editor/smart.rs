@@ -0,0 +1,753 @@
+use crate::core::text;
+use crate::text_editor::{Action, Content, Cursor, Edit, Motion, Position};
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// The smart editing behavior of a [`TextEditor`].
+///
+/// By default, a [`TextEditor`] does not perform any smart editing.
+///
+/// [`TextEditor`]: super::TextEditor
+#[derive(Debug, Clone, PartialEq)]
+pub struct Smart {
+    /// Whether to keep the indentation of the current line when breaking it.
+    ///
+    /// Breaking a line right after an opening bracket indents the new
+    /// line one level further.
+    pub auto_indent: bool,
+    /// The [`Indentation`] inserted when pressing Tab.
+    pub indentation: Indentation,
+    /// The pairs of delimiters to close automatically when typing the
+    /// opening one.
+    ///
+    /// Typing a closing delimiter right before the same one skips over it.
+    pub pairs: Cow<'static, [(char, char)]>,
+    /// Whether to highlight the bracket matching the one next to the cursor.
+    pub match_brackets: bool,
+    /// The token that starts a line comment, if any; like `//`.
+    ///
+    /// It is used to toggle the comments of the selected lines.
+    pub comment: Option<Cow<'static, str>>,
+}
+
+impl Smart {
+    /// The default pairs of delimiters that are closed automatically.
+    pub const PAIRS: &'static [(char, char)] = &[
+        ('(', ')'),
+        ('[', ']'),
+        ('{', '}'),
+        ('"', '"'),
+        ('\'', '\''),
+        ('`', '`'),
+    ];
+
+    /// Creates a [`Smart`] editing behavior suitable for code.
+    ///
+    /// Every feature is enabled, except for comment toggling.
+    pub fn code() -> Self {
+        Self {
+            auto_indent: true,
+            indentation: Indentation::Spaces(4),
+            pairs: Cow::Borrowed(Self::PAIRS),
+            match_brackets: true,
+            comment: None,
+        }
+    }
+
+    /// Creates a [`Smart`] editing behavior suitable for the language with
+    /// the given token; which can be a file extension or a name.
+    ///
+    /// It is the same as [`Smart::code`], with the line comment token of the
+    /// language—if known.
+    pub fn for_language(token: &str) -> Self {
+        Self {
+            comment: comment_token(token).map(Cow::Borrowed),
+            ..Self::code()
+        }
+    }
+
+    /// Sets the [`Indentation`] of the [`Smart`] editing behavior.
+    pub fn indentation(mut self, indentation: Indentation) -> Self {
+        self.indentation = indentation;
+        self
+    }
+
+    /// Sets the line comment token of the [`Smart`] editing behavior.
+    pub fn comment(mut self, token: impl Into<Cow<'static, str>>) -> Self {
+        self.comment = Some(token.into());
+        self
+    }
+}
+
+impl Default for Smart {
+    fn default() -> Self {
+        Self {
+            auto_indent: false,
+            indentation: Indentation::Tabs,
+            pairs: Cow::Borrowed(&[]),
+            match_brackets: false,
+            comment: None,
+        }
+    }
+}
+
+/// The indentation of a [`TextEditor`].
+///
+/// [`TextEditor`]: super::TextEditor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indentation {
+    /// Soft tabs; that is, the given amount of spaces.
+    Spaces(usize),
+    /// Hard tabs.
+    Tabs,
+}
+
+impl Indentation {
+    fn unit(self) -> String {
+        match self {
+            Self::Spaces(width) => " ".repeat(width.max(1)),
+            Self::Tabs => String::from("\t"),
+        }
+    }
+
+    fn width(self) -> usize {
+        match self {
+            Self::Spaces(width) => width.max(1),
+            Self::Tabs => 4,
+        }
+    }
+}
+
+/// The maximum amount of lines to look through when matching brackets.
+const MAX_MATCHING_LINES: usize = 1_000;
+
+/// The positions of the closing delimiters inserted automatically, which
+/// can be skipped over by typing them.
+///
+/// Any action other than typing may move them around, so they are cleared.
+#[derive(Debug, Clone, Default)]
+pub(super) struct Closing(Vec<Position>);
+
+impl Closing {
+    pub(super) fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Shifts the delimiters after the given position by the given amount
+    /// of bytes.
+    fn shift(&mut self, position: Position, length: usize) {
+        for closing in &mut self.0 {
+            if closing.line == position.line && closing.column >= position.column {
+                closing.column += length;
+            }
+        }
+    }
+}
+
+pub(super) fn insert<R: text::Renderer>(
+    content: &Content<R>,
+    smart: &Smart,
+    closing: &mut Closing,
+    c: char,
+) -> Vec<Action> {
+    let insert = vec![Action::Edit(Edit::Insert(c))];
+
+    if smart.pairs.is_empty() {
+        return insert;
+    }
+
+    let cursor = content.cursor();
+
+    if cursor.selection.is_some() {
+        closing.clear();
+    }
+
+    let line = line(content, cursor.position.line);
+    let (left, right) = split(&line, cursor.position.column);
+
+    let before = left.chars().next_back();
+    let after = right.chars().next();
+
+    if after == Some(c)
+        && let Some(index) = closing
+            .0
+            .iter()
+            .position(|position| *position == cursor.position)
+    {
+        let _ = closing.0.remove(index);
+
+        return vec![Action::Move(Motion::Right)];
+    }
+
+    let Some(&(open, close)) = smart.pairs.iter().find(|(open, _)| *open == c) else {
+        closing.shift(cursor.position, c.len_utf8());
+
+        return insert;
+    };
+
+    if let Some(selection) = content.selection() {
+        return vec![Action::Edit(Edit::Paste(Arc::new(format!(
+            "{open}{selection}{close}"
+        ))))];
+    }
+
+    let is_closable = after.is_none_or(|after| {
+        after.is_whitespace() || smart.pairs.iter().any(|(_, close)| *close == after)
+    });
+
+    // Quotes are not closed right after a word; like in `don't`
+    let is_quote = open == close;
+    let is_word = before.is_some_and(|before| before.is_alphanumeric() || before == open);
+
+    if is_closable && !(is_quote && is_word) {
+        closing.shift(cursor.position, open.len_utf8() + close.len_utf8());
+        closing.0.push(Position {
+            column: cursor.position.column + open.len_utf8(),
+            ..cursor.position
+        });
+
+        vec![
+            Action::Edit(Edit::Paste(Arc::new(format!("{open}{close}")))),
+            Action::Move(Motion::Left),
+        ]
+    } else {
+        closing.shift(cursor.position, c.len_utf8());
+
+        insert
+    }
+}
+
+pub(super) fn enter<R: text::Renderer>(content: &Content<R>, smart: &Smart) -> Vec<Action> {
+    if !smart.auto_indent {
+        return vec![Action::Edit(Edit::Enter)];
+    }
+
+    let (start, end) = bounds(content.cursor());
+
+    let first = line(content, start.line);
+    let last = line(content, end.line);
+
+    let (left, _) = split(&first, start.column);
+    let (_, right) = split(&last, end.column);
+
+    let indentation = leading_whitespace(left);
+
+    let open = left.trim_end().chars().next_back().and_then(|before| {
+        smart
+            .pairs
+            .iter()
+            .find(|(open, close)| *open == before && open != close)
+    });
+
+    let Some((_, close)) = open else {
+        return vec![Action::Edit(Edit::Paste(Arc::new(format!(
+            "\n{indentation}"
+        ))))];
+    };
+
+    let inner = format!("{indentation}{}", smart.indentation.unit());
+
+    if right.trim_start().starts_with(*close) {
+        vec![
+            Action::Edit(Edit::Paste(Arc::new(format!("\n{inner}\n{indentation}")))),
+            Action::MoveTo(Cursor {
+                position: Position {
+                    line: start.line + 1,
+                    column: inner.len(),
+                },
+                selection: None,
+            }),
+        ]
+    } else {
+        vec![Action::Edit(Edit::Paste(Arc::new(format!("\n{inner}"))))]
+    }
+}
+
+pub(super) fn backspace<R: text::Renderer>(content: &Content<R>, smart: &Smart) -> Vec<Action> {
+    let backspace = Action::Edit(Edit::Backspace);
+    let cursor = content.cursor();
+
+    if cursor.selection.is_some() {
+        return vec![backspace];
+    }
+
+    let line = line(content, cursor.position.line);
+    let (left, right) = split(&line, cursor.position.column);
+
+    if let (Some(before), Some(after)) = (left.chars().next_back(), right.chars().next())
+        && smart.pairs.contains(&(before, after))
+    {
+        return vec![backspace, Action::Edit(Edit::Delete)];
+    }
+
+    if let Indentation::Spaces(width) = smart.indentation
+        && !left.is_empty()
+        && left.bytes().all(|byte| byte == b' ')
+    {
+        return vec![backspace; (left.len() - 1) % width.max(1) + 1];
+    }
+
+    vec![backspace]
+}
+
+pub(super) fn indent<R: text::Renderer>(content: &Content<R>, smart: &Smart) -> Vec<Action> {
+    let cursor = content.cursor();
+
+    if cursor.selection.is_none() {
+        let text = match smart.indentation {
+            Indentation::Spaces(width) => {
+                let line = line(content, cursor.position.line);
+                let (left, _) = split(&line, cursor.position.column);
+                let width = width.max(1);
+
+                " ".repeat(width - left.chars().count() % width)
+            }
+            Indentation::Tabs => String::from("\t"),
+        };
+
+        return vec![Action::Edit(Edit::Paste(Arc::new(text)))];
+    }
+
+    let unit = smart.indentation.unit();
+
+    replace_lines(content, cursor, |line| {
+        if line.trim().is_empty() {
+            line.to_owned()
+        } else {
+            format!("{unit}{line}")
+        }
+    })
+}
+
+pub(super) fn unindent<R: text::Renderer>(content: &Content<R>, smart: &Smart) -> Vec<Action> {
+    let width = smart.indentation.width();
+
+    replace_lines(content, content.cursor(), |line| {
+        let spaces = line
+            .bytes()
+            .take(width)
+            .take_while(|byte| *byte == b' ')
+            .count();
+
+        if spaces == 0 {
+            line.strip_prefix('\t').unwrap_or(line).to_owned()
+        } else {
+            line[spaces..].to_owned()
+        }
+    })
+}
+
+pub(super) fn toggle_comment<R: text::Renderer>(
+    content: &Content<R>,
+    smart: &Smart,
+) -> Vec<Action> {
+    let Some(token) = smart.comment.as_deref() else {
+        return Vec::new();
+    };
+
+    let cursor = content.cursor();
+    let (start, end) = lines(cursor);
+
+    let lines: Vec<_> = (start..=end).map(|index| line(content, index)).collect();
+    let code = lines.iter().filter(|line| !line.trim().is_empty());
+
+    let is_commented = code
+        .clone()
+        .all(|line| line.trim_start().starts_with(token));
+
+    let indentation = code
+        .map(|line| leading_whitespace(line).len())
+        .min()
+        .unwrap_or_default();
+
+    replace_lines(content, cursor, |line| {
+        if line.trim().is_empty() {
+            return line.to_owned();
+        }
+
+        if is_commented {
+            let indentation = leading_whitespace(line);
+            let rest = &line[indentation.len() + token.len()..];
+
+            format!("{indentation}{}", rest.strip_prefix(' ').unwrap_or(rest))
+        } else {
+            let (indentation, rest) = line.split_at_checked(indentation).unwrap_or(("", line));
+
+            format!("{indentation}{token} {rest}")
+        }
+    })
+}
+
+/// Returns the positions of the bracket next to the cursor and its match,
+/// if any; as pairs of start and end positions.
+pub(super) fn matching_bracket(
+    editor: &impl text::Editor,
+    pairs: &[(char, char)],
+) -> Option<[(Position, Position); 2]> {
+    let cursor = editor.cursor();
+
+    if cursor.selection.is_some() {
+        return None;
+    }
+
+    let line = editor.line(cursor.position.line)?;
+    let (left, right) = split(&line.text, cursor.position.column);
+
+    let candidates = right
+        .chars()
+        .next()
+        .map(|c| (c, cursor.position.column))
+        .into_iter()
+        .chain(
+            left.chars()
+                .next_back()
+                .map(|c| (c, cursor.position.column - c.len_utf8())),
+        );
+
+    for (c, column) in candidates {
+        let position = Position {
+            line: cursor.position.line,
+            column,
+        };
+
+        let found = pairs.iter().find_map(|&(open, close)| {
+            if open == close {
+                None
+            } else if c == open {
+                scan(editor, position, open, close, true)
+            } else if c == close {
+                scan(editor, position, close, open, false)
+            } else {
+                None
+            }
+        });
+
+        if let Some((matching, length)) = found {
+            return Some([
+                (
+                    position,
+                    Position {
+                        column: position.column + c.len_utf8(),
+                        ..position
+                    },
+                ),
+                (
+                    matching,
+                    Position {
+                        column: matching.column + length,
+                        ..matching
+                    },
+                ),
+            ]);
+        }
+    }
+
+    None
+}
+
+/// Looks for the delimiter closing the one at the given position, taking
+/// nesting into account.
+fn scan(
+    editor: &impl text::Editor,
+    start: Position,
+    nest: char,
+    unnest: char,
+    forward: bool,
+) -> Option<(Position, usize)> {
+    let mut depth = 0usize;
+
+    let lines: Box<dyn Iterator<Item = usize>> = if forward {
+        Box::new(start.line..editor.line_count().min(start.line + MAX_MATCHING_LINES))
+    } else {
+        Box::new((start.line.saturating_sub(MAX_MATCHING_LINES)..=start.line).rev())
+    };
+
+    for index in lines {
+        let line = editor.line(index)?;
+        let text = line.text.as_ref();
+
+        let (offset, text) = match (index == start.line, forward) {
+            (true, true) => (start.column, text.get(start.column..)?),
+            (true, false) => (0, text.get(..start.column + nest.len_utf8())?),
+            (false, _) => (0, text),
+        };
+
+        let characters: Box<dyn Iterator<Item = (usize, char)>> = if forward {
+            Box::new(text.char_indices())
+        } else {
+            Box::new(text.char_indices().rev())
+        };
+
+        for (i, c) in characters {
+            if c == nest {
+                depth += 1;
+            } else if c == unnest {
+                depth = depth.saturating_sub(1);
+
+                if depth == 0 {
+                    return Some((
+                        Position {
+                            line: index,
+                            column: offset + i,
+                        },
+                        c.len_utf8(),
+                    ));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Replaces the lines touched by the given [`Cursor`], keeping it in
+/// place.
+fn replace_lines<R: text::Renderer>(
+    content: &Content<R>,
+    cursor: Cursor,
+    mut f: impl FnMut(&str) -> String,
+) -> Vec<Action> {
+    let (start, end) = lines(cursor);
+
+    let old: Vec<_> = (start..=end).map(|index| line(content, index)).collect();
+    let new: Vec<_> = old.iter().map(|line| f(line)).collect();
+
+    if old == new {
+        return Vec::new();
+    }
+
+    let adjust = |position: Position| {
+        if !(start..=end).contains(&position.line) {
+            return position;
+        }
+
+        let i = position.line - start;
+        let delta = new[i].len() as isize - old[i].len() as isize;
+
+        Position {
+            column: position
+                .column
+                .saturating_add_signed(delta)
+                .min(new[i].len()),
+            ..position
+        }
+    };
+
+    vec![
+        Action::MoveTo(Cursor {
+            position: Position {
+                line: end,
+                column: old.last().map(String::len).unwrap_or_default(),
+            },
+            selection: Some(Position {
+                line: start,
+                column: 0,
+            }),
+        }),
+        Action::Edit(Edit::Paste(Arc::new(new.join("\n")))),
+        Action::MoveTo(Cursor {
+            position: adjust(cursor.position),
+            selection: cursor.selection.map(adjust),
+        }),
+    ]
+}
+
+/// Returns the start and end positions of the [`Cursor`], in order.
+fn bounds(cursor: Cursor) -> (Position, Position) {
+    match cursor.selection {
+        Some(selection)
+            if (selection.line, selection.column)
+                < (cursor.position.line, cursor.position.column) =>
+        {
+            (selection, cursor.position)
+        }
+        Some(selection) => (cursor.position, selection),
+        None => (cursor.position, cursor.position),
+    }
+}
+
+/// Returns the first and last lines touched by the [`Cursor`].
+///
+/// A selection ending at the start of a line does not touch it.
+fn lines(cursor: Cursor) -> (usize, usize) {
+    let (start, end) = bounds(cursor);
+
+    if end.line > start.line && end.column == 0 {
+        (start.line, end.line - 1)
+    } else {
+        (start.line, end.line)
+    }
+}
+
+fn line<R: text::Renderer>(content: &Content<R>, index: usize) -> String {
+    content
+        .line(index)
+        .map(|line| line.text.into_owned())
+        .unwrap_or_default()
+}
+
+fn split(line: &str, column: usize) -> (&str, &str) {
+    line.split_at_checked(column).unwrap_or((line, ""))
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+fn comment_token(token: &str) -> Option<&'static str> {
+    match token.to_ascii_lowercase().as_str() {
+        "rs" | "rust" | "c" | "h" | "cc" | "cpp" | "hpp" | "c++" | "cs" | "c#" | "java" | "kt"
+        | "kotlin" | "scala" | "swift" | "go" | "dart" | "zig" | "js" | "javascript" | "jsx"
+        | "ts" | "typescript" | "tsx" | "php" | "wgsl" | "glsl" => Some("//"),
+        "py" | "python" | "rb" | "ruby" | "sh" | "bash" | "zsh" | "fish" | "toml" | "yaml"
+        | "yml" | "pl" | "perl" | "r" | "nix" | "ex" | "exs" | "elixir" | "jl" | "julia"
+        | "cmake" | "makefile" | "dockerfile" => Some("#"),
+        "lua" | "sql" | "hs" | "haskell" | "elm" => Some("--"),
+        "el" | "lisp" | "clj" | "clojure" | "scm" | "scheme" | "ini" | "asm" => Some(";"),
+        "tex" | "latex" | "erl" | "erlang" => Some("%"),
+        "vim" => Some("\""),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Content = super::Content<crate::Renderer>;
+
+    fn editor(text: &str, position: Position, selection: Option<Position>) -> Content {
+        let mut content = Content::with_text(text);
+        content.move_to(Cursor {
+            position,
+            selection,
+        });
+
+        content
+    }
+
+    fn at(line: usize, column: usize) -> Position {
+        Position { line, column }
+    }
+
+    fn perform(content: &mut Content, actions: Vec<Action>) {
+        for action in actions {
+            content.perform(action);
+        }
+    }
+
+    fn apply(content: &mut Content, f: impl FnOnce(&Content) -> Vec<Action>) {
+        let actions = f(content);
+        perform(content, actions);
+    }
+
+    fn type_text(content: &mut Content, smart: &Smart, closing: &mut Closing, text: &str) {
+        for c in text.chars() {
+            apply(content, |content| insert(content, smart, closing, c));
+        }
+    }
+
+    #[test]
+    fn pairs_are_closed_and_skipped_over() {
+        let smart = Smart::code();
+        let mut closing = Closing::default();
+        let mut content = editor("", at(0, 0), None);
+
+        type_text(&mut content, &smart, &mut closing, "f(");
+        assert_eq!(content.text(), "f()");
+        assert_eq!(content.cursor().position, at(0, 2));
+
+        type_text(&mut content, &smart, &mut closing, "[x");
+        assert_eq!(content.text(), "f([x])");
+
+        type_text(&mut content, &smart, &mut closing, "])");
+        assert_eq!(content.text(), "f([x])");
+        assert_eq!(content.cursor().position, at(0, 6));
+
+        // Quotes are not closed after a word
+        type_text(&mut content, &smart, &mut closing, " don'");
+        assert_eq!(content.text(), "f([x]) don'");
+    }
+
+    #[test]
+    fn only_inserted_pairs_are_skipped_over() {
+        let smart = Smart::code();
+        let mut closing = Closing::default();
+        let mut content = editor("f(x)", at(0, 3), None);
+
+        type_text(&mut content, &smart, &mut closing, ")");
+        assert_eq!(content.text(), "f(x))");
+
+        let mut content = editor("", at(0, 0), None);
+
+        type_text(&mut content, &smart, &mut closing, "(");
+        closing.clear();
+        type_text(&mut content, &smart, &mut closing, ")");
+        assert_eq!(content.text(), "())");
+    }
+
+    #[test]
+    fn backspace_removes_pairs_and_indentation() {
+        let smart = Smart::code();
+
+        let mut content = editor("()", at(0, 1), None);
+        apply(&mut content, |content| backspace(content, &smart));
+        assert_eq!(content.text(), "");
+
+        let mut content = editor("      x", at(0, 6), None);
+        apply(&mut content, |content| backspace(content, &smart));
+        assert_eq!(content.text(), "    x");
+
+        apply(&mut content, |content| backspace(content, &smart));
+        assert_eq!(content.text(), "x");
+
+        let mut content = editor("  a x", at(0, 4), None);
+        apply(&mut content, |content| backspace(content, &smart));
+        assert_eq!(content.text(), "  ax");
+    }
+
+    #[test]
+    fn comments_are_toggled_with_mixed_indentation() {
+        let smart = Smart::code().comment("//");
+        let text = "    foo\n\tbar\n\n  baz";
+
+        let mut content = editor(text, at(3, 5), Some(at(0, 0)));
+        apply(&mut content, |content| toggle_comment(content, &smart));
+
+        assert_eq!(content.text(), " //    foo\n\t// bar\n\n //  baz");
+
+        apply(&mut content, |content| toggle_comment(content, &smart));
+
+        assert_eq!(content.text(), text);
+
+        let mut content = editor("    // foo\n\tbar", at(1, 0), Some(at(0, 0)));
+        apply(&mut content, |content| toggle_comment(content, &smart));
+
+        assert_eq!(content.text(), "    foo\n\tbar");
+    }
+
+    #[test]
+    fn nested_brackets_are_matched() {
+        let pairs = Smart::PAIRS;
+        let content = editor("a(b(c)d)\n{\n  [x]\n}", at(0, 1), None);
+
+        let matching = |content: &mut Content, position| {
+            content.move_to(Cursor {
+                position,
+                selection: None,
+            });
+
+            matching_bracket(&content.0.borrow().editor, pairs).map(|[_, (start, _)]| start)
+        };
+
+        let mut content = content;
+
+        assert_eq!(matching(&mut content, at(0, 1)), Some(at(0, 7)));
+        assert_eq!(matching(&mut content, at(0, 3)), Some(at(0, 5)));
+        assert_eq!(matching(&mut content, at(0, 8)), Some(at(0, 1)));
+        assert_eq!(matching(&mut content, at(1, 0)), Some(at(3, 0)));
+        assert_eq!(matching(&mut content, at(3, 1)), Some(at(1, 0)));
+        assert_eq!(matching(&mut content, at(2, 0)), None);
+    }
+}
@@ -344,19 +344,26 @@ where
                 ),
             };
 
-            let text_node =
-                layout::Node::new(text_bounds - Size::new(icon_width + icon.spacing, 0.0))
-                    .move_to(text_position);
+            let text_node = widget::text::with_baselines(
+                layout::Node::new(text_bounds - Size::new(icon_width + icon.spacing, 0.0)),
+                state.baseline_paragraph(),
+            )
+            .move_to(text_position);
 
             let icon_node =
                 layout::Node::new(Size::new(icon_width, text_bounds.height)).move_to(icon_position);
 
             layout::Node::with_children(text_bounds.expand(padding), vec![text_node, icon_node])
+                .with_children_baselines()
         } else {
-            let text =
-                layout::Node::new(text_bounds).move_to(Point::new(padding.left, padding.top));
+            let text = widget::text::with_baselines(
+                layout::Node::new(text_bounds),
+                state.baseline_paragraph(),
+            )
+            .move_to(Point::new(padding.left, padding.top));
 
             layout::Node::with_children(text_bounds.expand(padding), vec![text])
+                .with_children_baselines()
         }
     }
 
@@ -388,8 +395,11 @@ where
         let (cursor_x, scroll_offset) =
             measure_cursor_and_scroll_offset(text, text_bounds, caret_index);
 
-        let alignment_offset =
-            alignment_offset(text_bounds.width, text.min_width(), self.alignment);
+        let alignment_offset = alignment_offset(
+            text_bounds.width,
+            text.min_width(),
+            layout.direction().align(self.alignment),
+        );
 
         let x = (text_bounds.x + cursor_x).floor() - scroll_offset + alignment_offset;
 
@@ -552,8 +562,11 @@ where
                 state.value.raw()
             };
 
-            let alignment_offset =
-                alignment_offset(text_bounds.width, paragraph.min_width(), self.alignment);
+            let alignment_offset = alignment_offset(
+                text_bounds.width,
+                paragraph.min_width(),
+                layout.direction().align(self.alignment),
+            );
 
             if let Some((cursor, color)) = cursor {
                 renderer.with_translation(
@@ -692,7 +705,7 @@ where
                         let alignment_offset = alignment_offset(
                             text_bounds.width,
                             state.value.raw().min_width(),
-                            self.alignment,
+                            layout.direction().align(self.alignment),
                         );
 
                         cursor_position.x - text_bounds.x - alignment_offset
@@ -781,7 +794,7 @@ where
                         let alignment_offset = alignment_offset(
                             text_bounds.width,
                             state.value.raw().min_width(),
-                            self.alignment,
+                            layout.direction().align(self.alignment),
                         );
 
                         position.x - text_bounds.x - alignment_offset
@@ -954,7 +967,23 @@ where
                     #[cfg(target_os = "macos")]
                     let modified_key = macos_shortcut.as_ref().unwrap_or(modified_key);
 
-                    match modified_key.as_ref() {
+                    // The cursor moves logically, so arrows are swapped in
+                    // right-to-left text
+                    let direction = state.value.raw().direction();
+
+                    let modified_key = match (direction, modified_key.as_ref()) {
+                        (
+                            layout::Direction::RightToLeft,
+                            keyboard::Key::Named(key::Named::ArrowLeft),
+                        ) => keyboard::Key::Named(key::Named::ArrowRight),
+                        (
+                            layout::Direction::RightToLeft,
+                            keyboard::Key::Named(key::Named::ArrowRight),
+                        ) => keyboard::Key::Named(key::Named::ArrowLeft),
+                        (_, modified_key) => modified_key,
+                    };
+
+                    match modified_key {
                         keyboard::Key::Named(key::Named::Enter) => {
                             if let Some(on_submit) = self.on_submit.clone() {
                                 shell.publish(on_submit);
@@ -1455,6 +1484,16 @@ impl<P: text::Paragraph> State<P> {
     pub fn select_range(&mut self, start: usize, end: usize) {
         self.cursor.select_range(start, end);
     }
+
+    /// Returns the paragraph the [`TextInput`] is aligned with by its baseline;
+    /// its placeholder when empty.
+    fn baseline_paragraph(&self) -> &P {
+        if self.value.content().is_empty() {
+            self.placeholder.raw()
+        } else {
+            self.value.raw()
+        }
+    }
 }
 
 impl<P: text::Paragraph> operation::Focusable for State<P> {
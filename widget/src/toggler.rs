@@ -411,6 +411,8 @@ where
                 state.raw(),
                 crate::text::Style {
                     color: style.text_color,
+                    selection: None,
+                    effects: crate::text::Effects::NONE,
                 },
                 viewport,
             );
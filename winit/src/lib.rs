@@ -1222,7 +1222,8 @@ where
     view_span.finish();
 
     let layout_span = debug::layout(id);
-    let user_interface = UserInterface::build(view, size, cache, renderer);
+    let user_interface =
+        UserInterface::build(view, size, cache, renderer).direction(program.direction());
     layout_span.finish();
 
     user_interface
@@ -1601,7 +1602,9 @@ fn run_action<'a, P, C>(
             }
             window::Action::RelayoutAll => {
                 for (id, window) in window_manager.iter_mut() {
-                    if let Some(ui) = interfaces.remove(&id) {
+                    if let Some(mut ui) = interfaces.remove(&id) {
+                        ui.invalidate_layouts();
+
                         let _ = interfaces.insert(
                             id,
                             ui.relayout(window.state.logical_size(), &mut window.renderer),
@@ -1654,6 +1657,13 @@ fn run_action<'a, P, C>(
             font::Action::Load { bytes, channel } => {
                 if let Some(compositor) = compositor {
                     let result = compositor.load_font(bytes.clone());
+
+                    if result.is_ok() {
+                        for ui in interfaces.values_mut() {
+                            ui.invalidate_layouts();
+                        }
+                    }
+
                     let _ = channel.send(result);
                 }
             }
@@ -1663,6 +1673,42 @@ fn run_action<'a, P, C>(
                     let _ = channel.send(fonts);
                 }
             }
+            font::Action::Find { name, channel } => {
+                if let Some(compositor) = compositor {
+                    let _ = channel.send(compositor.find_font(&name));
+                }
+            }
+            font::Action::SetFallback { fallback } => {
+                let Some(compositor) = compositor else {
+                    return;
+                };
+
+                compositor.set_font_fallback(fallback);
+
+                // Relayout all windows with the new fallbacks
+                for (id, window) in window_manager.iter_mut() {
+                    let Some(mut ui) = interfaces.remove(&id) else {
+                        continue;
+                    };
+
+                    ui.invalidate_layouts();
+
+                    let size = window.state.logical_size();
+                    let ui = ui.relayout(size, &mut window.renderer);
+                    let _ = interfaces.insert(id, ui);
+
+                    window.raw.request_redraw();
+                }
+            }
+            font::Action::Resolve {
+                text,
+                font,
+                channel,
+            } => {
+                if let Some(compositor) = compositor {
+                    let _ = channel.send(compositor.resolve_fonts(&text, font));
+                }
+            }
             font::Action::SetDefaults { font, text_size } => {
                 renderer_settings.default_font = font;
                 renderer_settings.default_text_size = text_size;
@@ -1675,10 +1721,12 @@ fn run_action<'a, P, C>(
                 for (id, window) in window_manager.iter_mut() {
                     window.renderer = compositor.create_renderer(*renderer_settings);
 
-                    let Some(ui) = interfaces.remove(&id) else {
+                    let Some(mut ui) = interfaces.remove(&id) else {
                         continue;
                     };
 
+                    ui.invalidate_layouts();
+
                     let size = window.state.logical_size();
                     let ui = ui.relayout(size, &mut window.renderer);
                     let _ = interfaces.insert(id, ui);